@@ -0,0 +1,128 @@
+//! Demonstrates viewing a drawing in 3D through the controllers of the `nannou::camera` module.
+//!
+//! - Drag with the left mouse button to orbit, drag with the right to pan and scroll to zoom.
+//! - Press `Tab` to switch to a fly camera. Move with `W`, `A`, `S`, `D`, `Q` and `E` and drag
+//!   with the right mouse button to look around.
+//!
+//! The block beneath the mouse is found by casting a ray from the camera and is highlighted.
+
+use nannou::geom::intersect;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    orbit: OrbitCamera,
+    fly: FlyCamera,
+    flying: bool,
+    blocks: Vec<Cuboid>,
+}
+
+impl Model {
+    fn camera(&self) -> &dyn Camera {
+        if self.flying {
+            &self.fly
+        } else {
+            &self.orbit
+        }
+    }
+
+    fn camera_mut(&mut self) -> &mut dyn Camera {
+        if self.flying {
+            &mut self.fly
+        } else {
+            &mut self.orbit
+        }
+    }
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(1024, 720)
+        .event(event)
+        .view(view)
+        .build()
+        .unwrap();
+
+    // A grid of blocks of varying heights standing on the xz plane.
+    let blocks = (0..8)
+        .flat_map(|i| (0..8).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let x = (i as f32 - 3.5) * 80.0;
+            let z = (j as f32 - 3.5) * 80.0;
+            let h = 40.0 + ((i * j + i) % 5) as f32 * 30.0;
+            Cuboid::from_xyz_whd(pt3(x, h * 0.5, z), vec3(50.0, h, 50.0))
+        })
+        .collect();
+
+    let orbit = OrbitCamera::new(pt3(0.0, 50.0, 0.0), 1000.0)
+        .yaw(0.6)
+        .pitch(0.5);
+    let fly = FlyCamera::new(pt3(0.0, 150.0, 700.0))
+        .look_at(pt3(0.0, 0.0, 0.0))
+        .speed(300.0);
+
+    Model {
+        orbit,
+        fly,
+        flying: false,
+        blocks,
+    }
+}
+
+fn event(_app: &App, model: &mut Model, event: WindowEvent) {
+    if let KeyPressed(Key::Tab) = event {
+        model.flying = !model.flying;
+    }
+    model.camera_mut().event(&event);
+}
+
+fn update(_app: &App, model: &mut Model, update: Update) {
+    model.camera_mut().update(update.since_last.secs() as f32);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let win = app.window_rect();
+    let camera = model.camera();
+
+    // Find the nearest block beneath the mouse.
+    let ray = camera.ray(app.mouse.position(), win);
+    let hovered = model
+        .blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &block)| intersect::ray_cuboid(ray, block).map(|hit| (i, hit.t)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(i, _)| i);
+
+    let draw = app.draw();
+    draw.background().color(rgb(0.07, 0.07, 0.1));
+    draw.set_projection(camera.view_projection(win));
+
+    // Shade each face of the blocks by its angle to the light.
+    let light = vec3(0.4, 1.0, 0.6).normalize();
+    for (i, block) in model.blocks.iter().enumerate() {
+        let color = if Some(i) == hovered {
+            lin_srgb(1.0, 0.5, 0.2)
+        } else {
+            lin_srgb(0.3, 0.5, 0.9)
+        };
+        let tris = block.triangles_iter().map(|tri| {
+            let [a, b, c] = tri.0.map(Vec3::from);
+            let normal = (b - a).cross(c - a).normalize();
+            let shade = 0.3 + 0.7 * normal.dot(light).abs();
+            tri.map_vertices(|v| (Vec3::from(v), color * shade))
+        });
+        draw.mesh().tris_colored(tris);
+    }
+
+    // The ground beneath the blocks.
+    draw.rect()
+        .w_h(800.0, 800.0)
+        .pitch(-PI / 2.0)
+        .color(rgb(0.15, 0.15, 0.2));
+
+    draw.to_frame(app, &frame).unwrap();
+}
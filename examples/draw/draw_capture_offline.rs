@@ -0,0 +1,55 @@
+// Renders a five second animation at a fixed 60fps and captures every frame as a PNG image file to
+// `/<path_to_nannou>/nannou/draw_capture_offline/<frame_number>.png`.
+//
+// In the `Offline` loop mode time is virtual: each update advances `app.time` by exactly 1/60th of
+// a second regardless of how long rendering and capturing takes, and no frames are ever dropped.
+// The resulting image sequence is identical no matter how fast or slow the machine is.
+
+use nannou::prelude::*;
+
+const FPS: f64 = 60.0;
+const SECONDS: f64 = 5.0;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    app.set_loop_mode(LoopMode::offline(FPS));
+    app.new_window().size(640, 360).view(view).build().unwrap();
+    Model
+}
+
+fn update(app: &App, _model: &mut Model, _update: Update) {
+    // Quit once the final frame has been viewed and captured.
+    if app.elapsed_frames() as f64 >= FPS * SECONDS {
+        app.quit();
+    }
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = app.time;
+    let win = app.window_rect();
+    for i in 0..24 {
+        let f = i as f32 / 24.0;
+        let x = map_range(f, 0.0, 1.0, win.left(), win.right());
+        let y = (t * 2.0 + f * TAU).sin() * win.h() * 0.3;
+        draw.ellipse().x_y(x, y).radius(10.0).hsv(f, 0.8, 1.0);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+
+    // Capture the frame!
+    let file_path = app
+        .project_path()
+        .expect("failed to locate `project_path`")
+        .join(app.exe_name().unwrap())
+        .join(format!("{:03}", frame.nth()))
+        .with_extension("png");
+    app.main_window().capture_frame(file_path);
+}
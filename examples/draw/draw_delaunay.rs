@@ -0,0 +1,76 @@
+//! Demonstrates the Delaunay triangulation, Voronoi diagram and convex hull of a set of points.
+//!
+//! Each point wanders about the window. Voronoi cells are filled with a colour per point, the edges
+//! of the triangulation are drawn in white and the convex hull is outlined in red.
+//!
+//! Click to add a point at the mouse position.
+
+use nannou::geom::{Delaunay, Point2};
+use nannou::prelude::*;
+
+struct Model {
+    points: Vec<Point2>,
+}
+
+fn main() {
+    nannou::app(model).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(720, 720)
+        .mouse_pressed(mouse_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+    let points = (0..64)
+        .map(|_| pt2(random_range(-300.0, 300.0), random_range(-300.0, 300.0)))
+        .collect();
+    Model { points }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, _button: MouseButton) {
+    model.points.push(app.mouse.position());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = app.time;
+    let points = model.points.iter().enumerate().map(|(i, &p)| {
+        let phase = i as f32 * 0.61;
+        p + vec2((t + phase).sin(), (t * 0.7 + phase).cos()) * 20.0
+    });
+    let delaunay = Delaunay::new(points);
+    let voronoi = delaunay.voronoi(app.window_rect());
+
+    let n = voronoi.len() as f32;
+    for (i, cell) in voronoi.cells().enumerate() {
+        let hue = i as f32 / n;
+        draw.polygon()
+            .color(hsv(hue, 0.5, 0.4))
+            .points(cell.iter().cloned());
+    }
+
+    let points = delaunay.points();
+    for [a, b] in delaunay.edges() {
+        draw.line()
+            .start(points[a])
+            .end(points[b])
+            .weight(1.0)
+            .color(rgba(1.0, 1.0, 1.0, 0.5));
+    }
+
+    let hull = delaunay.hull().iter().chain(delaunay.hull().first());
+    draw.polyline()
+        .weight(3.0)
+        .color(CRIMSON)
+        .points(hull.map(|&i| points[i]));
+
+    for &p in points {
+        draw.ellipse().xy(p).radius(3.0).color(WHITE);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
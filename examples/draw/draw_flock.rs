@@ -0,0 +1,136 @@
+//! Demonstrates using a `geom::spatial::HashGrid` to find the neighbours of each boid in a flock.
+//!
+//! Each boid steers to avoid, align with and move towards the boids within its radius of sight.
+//! The boids are stored in a grid with cells the size of that radius, so each neighbour query only
+//! visits the few cells surrounding the boid. The boids nearest to the mouse are highlighted via a
+//! k-nearest neighbour query.
+
+use nannou::geom::spatial::HashGrid;
+use nannou::prelude::*;
+
+const N_BOIDS: usize = 2_000;
+const SIGHT: f32 = 24.0;
+const SEPARATION: f32 = 10.0;
+const MAX_SPEED: f32 = 2.5;
+const MAX_FORCE: f32 = 0.06;
+
+struct Model {
+    boids: Vec<Boid>,
+    grid: HashGrid<Vec2, usize>,
+}
+
+#[derive(Copy, Clone)]
+struct Boid {
+    position: Point2,
+    velocity: Vec2,
+}
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(1024, 720).view(view).build().unwrap();
+    let win = app.window_rect();
+    let boids = (0..N_BOIDS)
+        .map(|_| {
+            let position = pt2(
+                random_range(win.left(), win.right()),
+                random_range(win.bottom(), win.top()),
+            );
+            let angle = random_f32() * TAU;
+            let velocity = vec2(angle.cos(), angle.sin()) * MAX_SPEED;
+            Boid { position, velocity }
+        })
+        .collect();
+    let grid = HashGrid::new(SIGHT);
+    Model { boids, grid }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    // Rebuild the grid from the boids' current positions.
+    model.grid.clear();
+    for (i, boid) in model.boids.iter().enumerate() {
+        model.grid.insert(boid.position, i);
+    }
+
+    let win = app.window_rect();
+    let boids = model.boids.clone();
+    for (i, boid) in model.boids.iter_mut().enumerate() {
+        let mut separation = Vec2::ZERO;
+        let mut alignment = Vec2::ZERO;
+        let mut center = Vec2::ZERO;
+        let mut count = 0;
+        for item in model.grid.within_radius(boid.position, SIGHT) {
+            if *item.value == i {
+                continue;
+            }
+            let offset = boid.position - item.point;
+            let distance = offset.length();
+            if distance < SEPARATION && distance > 0.0 {
+                separation += offset / (distance * distance);
+            }
+            alignment += boids[*item.value].velocity;
+            center += item.point;
+            count += 1;
+        }
+
+        let mut force = Vec2::ZERO;
+        if count > 0 {
+            let n = count as f32;
+            force += steer(boid, separation) * 1.5;
+            force += steer(boid, alignment / n);
+            force += steer(boid, center / n - boid.position);
+        }
+        boid.velocity = (boid.velocity + force).clamp_length_max(MAX_SPEED);
+        boid.position += boid.velocity;
+
+        // Wrap around the edges of the window.
+        if boid.position.x < win.left() {
+            boid.position.x += win.w();
+        } else if boid.position.x > win.right() {
+            boid.position.x -= win.w();
+        }
+        if boid.position.y < win.bottom() {
+            boid.position.y += win.h();
+        } else if boid.position.y > win.top() {
+            boid.position.y -= win.h();
+        }
+    }
+}
+
+// The force required to steer the boid towards the desired direction.
+fn steer(boid: &Boid, direction: Vec2) -> Vec2 {
+    if direction == Vec2::ZERO {
+        return Vec2::ZERO;
+    }
+    let desired = direction.normalize() * MAX_SPEED;
+    (desired - boid.velocity).clamp_length_max(MAX_FORCE)
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    for boid in &model.boids {
+        let tail = boid.position - boid.velocity.normalize_or_zero() * 6.0;
+        draw.line()
+            .start(tail)
+            .end(boid.position)
+            .weight(2.0)
+            .color(LIGHTSKYBLUE);
+    }
+
+    // Highlight the boids nearest to the mouse.
+    let mouse = app.mouse.position();
+    for item in model.grid.nearest(mouse, 12) {
+        draw.line()
+            .start(mouse)
+            .end(item.point)
+            .weight(1.0)
+            .color(rgba(1.0, 1.0, 1.0, 0.3));
+        draw.ellipse().xy(item.point).radius(3.0).color(ORANGE);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,93 @@
+//! Demonstrates intersection queries from `geom::intersect`.
+//!
+//! Rays are cast outwards from the mouse in every direction and stop at the first shape they hit,
+//! with the surface normal drawn at each hit. The triangle follows the mouse and turns red while
+//! it overlaps the square, showing the contact normal and depth required to separate them.
+
+use nannou::geom::intersect::{self, Ray2};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).size(720, 720).run();
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let mouse = app.mouse.position();
+    let rect = Rect::from_x_y_w_h(-150.0, 120.0, 160.0, 100.0);
+    let circle = (pt2(150.0, 100.0), 70.0);
+    let square = [
+        pt2(-60.0, -220.0),
+        pt2(60.0, -180.0),
+        pt2(20.0, -60.0),
+        pt2(-100.0, -100.0),
+    ];
+    let tri = [
+        mouse + vec2(0.0, 40.0),
+        mouse + vec2(-35.0, -20.0),
+        mouse + vec2(35.0, -20.0),
+    ];
+
+    draw.rect().xy(rect.xy()).wh(rect.wh()).color(GREY);
+    draw.ellipse().xy(circle.0).radius(circle.1).color(GREY);
+    draw.polygon().color(GREY).points(square.iter().cloned());
+
+    // Cast rays from the mouse and find the nearest hit for each.
+    let win = app.window_rect();
+    let n_rays = 90;
+    for i in 0..n_rays {
+        let angle = i as f32 / n_rays as f32 * TAU;
+        let ray = Ray2::new(mouse, vec2(angle.cos(), angle.sin()));
+        let hits = [
+            intersect::ray_rect(ray, rect),
+            intersect::ray_circle(ray, circle.0, circle.1),
+            intersect::ray_polygon(ray, &square),
+        ];
+        let nearest = hits
+            .iter()
+            .filter_map(|hit| *hit)
+            .min_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+        match nearest {
+            Some(hit) => {
+                draw.line()
+                    .start(mouse)
+                    .end(hit.point)
+                    .weight(1.0)
+                    .color(rgba(1.0, 1.0, 0.6, 0.4));
+                draw.line()
+                    .start(hit.point)
+                    .end(hit.point + hit.normal * 8.0)
+                    .weight(1.0)
+                    .color(SKYBLUE);
+            }
+            None => {
+                let end = intersect::ray_rect(ray, win).map(|hit| hit.point);
+                draw.line()
+                    .start(mouse)
+                    .end(end.unwrap_or(mouse))
+                    .weight(1.0)
+                    .color(rgba(1.0, 1.0, 0.6, 0.1));
+            }
+        }
+    }
+
+    // Show the overlap between the triangle and the square.
+    let contact = intersect::polygon_polygon(&square, &tri);
+    let color = if contact.is_some() { CRIMSON } else { WHITE };
+    draw.polygon()
+        .no_fill()
+        .stroke(color)
+        .stroke_weight(2.0)
+        .points(tri.iter().cloned());
+    if let Some(contact) = contact {
+        draw.arrow()
+            .start(contact.point)
+            .end(contact.point + contact.normal * contact.depth)
+            .weight(2.0)
+            .color(WHITE);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,69 @@
+//! Demonstrates boolean operations and offsetting on paths.
+//!
+//! Two moving shapes are combined with each of the boolean operations. The result of each is
+//! filled, while an outline grown from the result is stroked around it.
+
+use nannou::geom::polygon::{Join, OffsetOptions};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let win = app.window_rect();
+    let t = app.time;
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    // A circle and a star that orbit one another.
+    let radius = win.w().min(win.h()) * 0.12;
+    let offset = vec2(t.cos(), t.sin()) * radius * 0.5;
+    let circle = ngon(offset, radius, 64, 0.0);
+    let star = star(-offset, radius * 1.2, t * 0.2);
+
+    let ops: [(&str, fn(&geom::Path, &geom::Path) -> geom::Path); 4] = [
+        ("union", geom::Path::union),
+        ("intersection", geom::Path::intersection),
+        ("difference", geom::Path::difference),
+        ("xor", geom::Path::xor),
+    ];
+    let outline = OffsetOptions::default().join(Join::Round);
+    for (i, (name, op)) in ops.iter().enumerate() {
+        let x = map_range(i, 0, ops.len() - 1, win.left() * 0.75, win.right() * 0.75);
+        let path = op(&circle, &star);
+        let grown = path.offset(6.0, &outline);
+        let draw = draw.x(x);
+        draw.path()
+            .stroke()
+            .weight(2.0)
+            .color(PLUM)
+            .events(grown.iter());
+        draw.path().fill().color(WHITE).events(path.iter());
+        draw.text(name).y(-radius * 2.0).color(GREY);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+// A closed path describing a regular polygon.
+fn ngon(center: Point2, radius: f32, n: usize, rotation: f32) -> geom::Path {
+    let points: Vec<Point2> = (0..n)
+        .map(|i| {
+            let angle = rotation + i as f32 / n as f32 * TAU;
+            center + vec2(angle.cos(), angle.sin()) * radius
+        })
+        .collect();
+    geom::path().polygon(&points).build()
+}
+
+// A closed, self-intersecting path describing a five pointed star.
+fn star(center: Point2, radius: f32, rotation: f32) -> geom::Path {
+    let points: Vec<Point2> = (0..5)
+        .map(|i| {
+            let angle = rotation + i as f32 * 2.0 / 5.0 * TAU;
+            center + vec2(angle.cos(), angle.sin()) * radius
+        })
+        .collect();
+    geom::path().polygon(&points).build()
+}
@@ -0,0 +1,81 @@
+//! Demonstrates simplifying, smoothing and fitting curves to a hand-drawn polyline.
+//!
+//! Drag with the left mouse button to draw. The raw input is drawn in grey, the result of
+//! Ramer–Douglas–Peucker simplification in red and the cubic béziers fitted to the input in white.
+//! Points smoothed via Chaikin's algorithm are drawn as small dots.
+
+use nannou::geom::polyline;
+use nannou::prelude::*;
+
+struct Model {
+    points: Vec<Point2>,
+}
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(720, 480)
+        .mouse_pressed(mouse_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+    Model { points: vec![] }
+}
+
+fn mouse_pressed(_app: &App, model: &mut Model, _button: MouseButton) {
+    model.points.clear();
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    if app.mouse.buttons.left().is_down() {
+        let p = app.mouse.position();
+        if model.points.last() != Some(&p) {
+            model.points.push(p);
+        }
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let points = &model.points;
+    draw.polyline()
+        .weight(1.0)
+        .color(GREY)
+        .points(points.iter().cloned());
+
+    let simplified = polyline::rdp(points.iter().cloned(), 4.0, false);
+    draw.polyline()
+        .weight(2.0)
+        .color(CRIMSON)
+        .points(simplified.iter().cloned());
+
+    let fitted = polyline::fit_cubics(points.iter().cloned(), 4.0, false);
+    draw.polyline()
+        .weight(3.0)
+        .color(WHITE)
+        .points(polyline::sample_cubics(&fitted, 16));
+
+    for p in polyline::chaikin(simplified.iter().cloned(), 3, false) {
+        draw.ellipse().xy(p).radius(1.5).color(SKYBLUE);
+    }
+
+    let text = format!(
+        "points: {}, simplified: {}, cubics: {}",
+        points.len(),
+        simplified.len(),
+        fitted.len(),
+    );
+    let win = app.window_rect();
+    draw.text(&text)
+        .wh(win.wh())
+        .left_justify()
+        .align_text_top()
+        .color(GREY);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,78 @@
+//! A solar system described by a `scene::Scene`.
+//!
+//! Each planet is the child of an orbit node that rotates around its parent, so moons follow
+//! their planets without any of the transforms being recalculated by hand. Press the space bar to
+//! hide or show the outer planet along with its moons.
+
+use nannou::prelude::*;
+use nannou::scene::{Node, NodeId, Scene};
+
+struct Model {
+    scene: Scene,
+    // Each orbit node along with its rate of rotation in radians per second.
+    orbits: Vec<(NodeId, f32)>,
+    outer: NodeId,
+}
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(800, 800)
+        .key_pressed(key_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+
+    let mut scene = Scene::new();
+    let mut orbits = vec![];
+    let sun = scene.add(Node::new().draw(|draw| {
+        draw.ellipse().radius(40.0).color(GOLD);
+    }));
+
+    // Add an orbit around the parent with a body at the given distance from its centre.
+    let mut orbit = |scene: &mut Scene, parent, distance: f32, rate, radius: f32, color| {
+        let orbit = scene.add_child(parent, Node::new());
+        orbits.push((orbit, rate));
+        scene.add_child(
+            orbit,
+            Node::new().xy(vec2(distance, 0.0)).draw(move |draw| {
+                draw.ellipse().radius(radius).color(color);
+            }),
+        )
+    };
+    let inner = orbit(&mut scene, sun, 90.0, 1.6, 10.0, LIGHTCORAL);
+    orbit(&mut scene, inner, 20.0, 4.0, 3.0, GREY);
+    let outer = orbit(&mut scene, sun, 220.0, 0.5, 20.0, STEELBLUE);
+    orbit(&mut scene, outer, 40.0, 2.5, 5.0, LIGHTGREY);
+    orbit(&mut scene, outer, 60.0, -1.2, 4.0, TAN);
+
+    Model {
+        scene,
+        orbits,
+        outer,
+    }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    for &(orbit, rate) in &model.orbits {
+        let transform = Mat4::from_rotation_z(app.time * rate);
+        model.scene.set_transform(orbit, transform);
+    }
+}
+
+fn key_pressed(_app: &App, model: &mut Model, key: Key) {
+    if let Key::Space = key {
+        let visible = model.scene.node(model.outer).unwrap().is_visible();
+        model.scene.set_visible(model.outer, !visible);
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    draw.scene(&model.scene);
+    draw.to_frame(app, &frame).unwrap();
+}
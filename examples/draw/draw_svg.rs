@@ -0,0 +1,61 @@
+//! Loads the SVG files from the `assets/svg/generative_examples` directory and draws them in a
+//! grid using `draw.svg`.
+//!
+//! Each document is scaled to fit its cell. Hover over a cell to rotate its document.
+
+use nannou::prelude::*;
+use nannou::svg;
+
+struct Model {
+    documents: Vec<svg::Document>,
+}
+
+fn main() {
+    nannou::app(model).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 720).view(view).build().unwrap();
+
+    let dir = app
+        .assets_path()
+        .unwrap()
+        .join("svg")
+        .join("generative_examples");
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().map(|ext| ext == "svg").unwrap_or(false))
+        .collect();
+    paths.sort();
+    let documents = paths
+        .iter()
+        .filter_map(|path| svg::Document::load(path).ok())
+        .collect();
+    Model { documents }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    let win = app.window_rect();
+    let n = model.documents.len().max(1);
+    let cols = (n as f32).sqrt().ceil() as usize;
+    let rows = (n + cols - 1) / cols;
+    let cell_wh = vec2(win.w() / cols as f32, win.h() / rows as f32);
+    let mouse = app.mouse.position();
+
+    for (i, doc) in model.documents.iter().enumerate() {
+        let (col, row) = (i % cols, i / cols);
+        let cell = Rect::from_wh(cell_wh)
+            .top_left_of(win)
+            .shift_x(col as f32 * cell_wh.x)
+            .shift_y(-(row as f32) * cell_wh.y);
+        let scale = (cell.w() / doc.size.x).min(cell.h() / doc.size.y) * 0.8;
+        let rotation = if cell.contains(mouse) { app.time } else { 0.0 };
+        draw.xy(cell.xy()).scale(scale).rotate(rotation).svg(doc);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,69 @@
+//! Demonstrates an editable text field.
+//!
+//! Type to insert text. The arrow keys move the caret (hold `Shift` to select and `Ctrl` or `Alt`
+//! to move by word), while clicking places the caret under the mouse.
+
+use nannou::prelude::*;
+use nannou::text::TextEdit;
+
+struct Model {
+    edit: TextEdit,
+}
+
+fn main() {
+    nannou::app(model).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(640, 360)
+        .raw_event(raw_window_event)
+        .mouse_pressed(mouse_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+    let mut edit = TextEdit::new("Hello, nannou!");
+    edit.set_layout(
+        text::layout::Builder::default()
+            .font_size(24)
+            .left_justify()
+            .build(),
+    );
+    edit.set_wh(vec2(480.0, 240.0));
+    Model { edit }
+}
+
+fn raw_window_event(app: &App, model: &mut Model, event: &nannou::winit::event::WindowEvent) {
+    if model.edit.raw_window_event(event) {
+        // Keep the IME candidate box next to the caret.
+        model
+            .edit
+            .set_ime_position(&app.main_window(), Point2::ZERO);
+    }
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, _button: MouseButton) {
+    let extend_selection = app.keys.mods.shift();
+    model.edit.click(app.mouse.position(), extend_selection);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let wh = model.edit.wh();
+    draw.rect().wh(wh).no_fill().stroke(GRAY).stroke_weight(1.0);
+
+    // Draw the highlights beneath the text and blink the caret.
+    let caret_visible = (app.time * 2.0).fract() < 0.5;
+    draw.text_edit(&model.edit)
+        .color(WHITE)
+        .selection_color(rgba(0.3, 0.5, 1.0, 0.5))
+        .caret_visible(caret_visible);
+    draw.text(&model.edit.display_text())
+        .layout(model.edit.layout())
+        .wh(wh)
+        .color(WHITE);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,29 @@
+//! Demonstrates rendering text via the signed distance field glyph cache.
+//!
+//! SDF text remains crisp while scaling and rotating, and supports outlines and glows.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = app.time;
+    let scale = 1.0 + (t * 0.5).sin() * 0.8;
+
+    draw.scale(scale)
+        .rotate(t * 0.1)
+        .text("nannou")
+        .sdf()
+        .color(WHITE)
+        .outline(2.0, ORANGE)
+        .glow(6.0, rgba(1.0, 0.2, 0.6, 0.8))
+        .font_size(48)
+        .w(400.0);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,93 @@
+//! Demonstrates composing tweens, keyframes and springs via the `nannou::ease` module.
+//!
+//! - A row of dots bounce up and down, each starting shortly after the previous.
+//! - A square moves through a track of keyframes, changing color along the way.
+//! - A ring follows the mouse via a damped spring.
+//!
+//! Press space to pause and resume the animations.
+
+use nannou::ease::{stagger, Animation, Clock, Easing, Keyframes, Spring, Stagger, Tween};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(model).update(update).run();
+}
+
+struct Model {
+    clock: Clock,
+    dots: Stagger<Tween<f32>>,
+    square: Keyframes<(Point2, LinSrgba)>,
+    ring: Spring<Point2>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(800, 600)
+        .key_pressed(key_pressed)
+        .view(view)
+        .build()
+        .unwrap();
+
+    let dots = stagger(
+        (0..16).map(|_| Tween::new(0.0, 120.0, 0.6).ease(Easing::QuadOut)),
+        0.08,
+    );
+    let red = lin_srgba(0.9, 0.2, 0.3, 1.0);
+    let blue = lin_srgba(0.2, 0.4, 0.9, 1.0);
+    let square = Keyframes::new((pt2(-250.0, -150.0), red))
+        .key_eased(1.0, (pt2(250.0, -150.0), blue), Easing::CubicInOut)
+        .key_eased(2.0, (pt2(250.0, -50.0), red), Easing::BackOut)
+        .key_eased(3.0, (pt2(-250.0, -150.0), red), Easing::BounceOut);
+    let ring = Spring::new(pt2(0.0, 0.0)).stiffness(120.0).damping(8.0);
+
+    Model {
+        clock: Clock::new(),
+        dots,
+        square,
+        ring,
+    }
+}
+
+fn update(app: &App, model: &mut Model, update: Update) {
+    model.clock.update(&update);
+    if !model.clock.is_paused() {
+        model.ring.set_target(app.mouse.position());
+        model.ring.update(update.since_last.secs() as f32);
+    }
+}
+
+fn key_pressed(_app: &App, model: &mut Model, key: Key) {
+    if key == Key::Space {
+        model.clock.toggle();
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let time = model.clock.time();
+
+    // Bounce the dots up and back down, looping the whole row.
+    let dots = (&model.dots).repeat(2).yoyo().looped();
+    for (i, height) in dots.sample(time).into_iter().enumerate() {
+        let x = map_range(i, 0, 15, -300.0, 300.0);
+        draw.ellipse()
+            .x_y(x, 100.0 + height)
+            .w_h(20.0, 20.0)
+            .color(WHITE);
+    }
+
+    // Move the square through its keyframes.
+    let (position, color) = (&model.square).looped().sample(time);
+    draw.rect().xy(position).w_h(60.0, 60.0).color(color);
+
+    // Follow the mouse with the ring.
+    draw.ellipse()
+        .xy(model.ring.value())
+        .radius(30.0)
+        .no_fill()
+        .stroke(WHITE)
+        .stroke_weight(3.0);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,56 @@
+//! Renders a short animation without a window or display, e.g. on a render node or in CI.
+//!
+//! The window of a headless app renders to an offscreen texture. After each call to `view`, the
+//! rendered frame can be read back as an image via `Window::frame_image`. Here, every tenth frame
+//! is written to the `headless` directory within the project directory.
+
+use nannou::prelude::*;
+
+const FRAMES: usize = 60;
+
+fn main() {
+    nannou::app(model).update(update).headless(512, 512).run();
+}
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    // Stop once all frames of the animation have been rendered.
+    app.set_loop_mode(LoopMode::loop_ntimes(FRAMES));
+    app.new_window().view(view).build().unwrap();
+    Model
+}
+
+// Each update follows the `view` of the previous frame, so its image is ready to be saved.
+fn update(app: &App, _model: &mut Model, _update: Update) {
+    let window = app.main_window();
+    let image = match window.frame_image() {
+        Some(image) => image,
+        None => return,
+    };
+    let nth = window.elapsed_frames() - 1;
+    if nth % 10 == 0 {
+        let path = app
+            .project_path()
+            .expect("failed to locate `project_path`")
+            .join("headless")
+            .join(format!("{:03}.png", nth));
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        image.save(path).expect("failed to save frame");
+    }
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    let t = frame.nth() as f32 / FRAMES as f32;
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    for i in 0..12 {
+        let angle = i as f32 / 12.0 * TAU + t * TAU;
+        let p = pt2(angle.cos(), angle.sin()) * 160.0;
+        draw.ellipse()
+            .xy(p)
+            .radius(24.0)
+            .hsv(i as f32 / 12.0, 0.7, 1.0);
+    }
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -1,3 +1,4 @@
+use nannou::camera::Perspective;
 use nannou::prelude::*;
 use nannou::winit;
 use std::cell::RefCell;
@@ -7,7 +8,7 @@ mod data;
 struct Model {
     camera_is_active: bool,
     graphics: RefCell<Graphics>,
-    camera: Camera,
+    camera: FlyCamera,
 }
 
 struct Graphics {
@@ -21,16 +22,6 @@ struct Graphics {
     render_pipeline: wgpu::RenderPipeline,
 }
 
-// A simple first person camera.
-struct Camera {
-    // The position of the camera.
-    eye: Point3,
-    // Rotation around the x axis.
-    pitch: f32,
-    // Rotation around the y axis.
-    yaw: f32,
-}
-
 // The vertex type that we will use to represent a point on our triangle.
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -54,28 +45,6 @@ pub struct Uniforms {
 
 const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
 
-impl Camera {
-    // Calculate the direction vector from the pitch and yaw.
-    fn direction(&self) -> Vec3 {
-        pitch_yaw_to_direction(self.pitch, self.yaw)
-    }
-
-    // The camera's "view" matrix.
-    fn view(&self) -> Mat4 {
-        let direction = self.direction();
-        let up = Vec3::Y;
-        Mat4::look_to_rh(self.eye, direction, up)
-    }
-}
-
-fn pitch_yaw_to_direction(pitch: f32, yaw: f32) -> Vec3 {
-    let xz_unit_len = pitch.cos();
-    let x = xz_unit_len * yaw.cos();
-    let y = pitch.sin();
-    let z = xz_unit_len * (-yaw).sin();
-    vec3(x, y, z)
-}
-
 fn main() {
     nannou::app(model).event(event).update(update).run();
 }
@@ -126,12 +95,16 @@ fn model(app: &App) -> Model {
     let depth_texture = create_depth_texture(device, [win_w, win_h], DEPTH_FORMAT, msaa_samples);
     let depth_texture_view = depth_texture.view().build();
 
-    let eye = Point3::new(0.0, 0.0, 1.0);
-    let pitch = 0.0;
-    let yaw = std::f32::consts::PI * 0.5;
-    let camera = Camera { eye, pitch, yaw };
+    let perspective = Perspective {
+        fov_y: std::f32::consts::FRAC_PI_2,
+        near: 0.01,
+        far: 100.0,
+    };
+    let camera = FlyCamera::new(pt3(0.0, 0.0, 1.0))
+        .perspective(perspective)
+        .speed(0.5);
 
-    let uniforms = create_uniforms([win_w, win_h], camera.view());
+    let uniforms = create_uniforms([win_w, win_h], &camera);
     let uniforms_bytes = uniforms_as_bytes(&uniforms);
     let usage = wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST;
     let uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
@@ -175,69 +148,27 @@ fn model(app: &App) -> Model {
     }
 }
 
-// Move the camera based on the current key pressed and its current direction.
-fn update(app: &App, model: &mut Model, update: Update) {
-    const CAM_SPEED_HZ: f64 = 0.5;
-    if model.camera_is_active {
-        let velocity = (update.since_last.secs() * CAM_SPEED_HZ) as f32;
-        // Go forwards on W.
-        if app.keys.down.contains(&Key::W) {
-            model.camera.eye += model.camera.direction() * velocity;
-        }
-        // Go backwards on S.
-        if app.keys.down.contains(&Key::S) {
-            model.camera.eye -= model.camera.direction() * velocity;
-        }
-        // Strafe left on A.
-        if app.keys.down.contains(&Key::A) {
-            let pitch = 0.0;
-            let yaw = model.camera.yaw + std::f32::consts::PI * 0.5;
-            let direction = pitch_yaw_to_direction(pitch, yaw);
-            model.camera.eye += direction * velocity;
-        }
-        // Strafe right on D.
-        if app.keys.down.contains(&Key::D) {
-            let pitch = 0.0;
-            let yaw = model.camera.yaw - std::f32::consts::PI * 0.5;
-            let direction = pitch_yaw_to_direction(pitch, yaw);
-            model.camera.eye += direction * velocity;
-        }
-        // Float down on Q.
-        if app.keys.down.contains(&Key::Q) {
-            let pitch = model.camera.pitch - std::f32::consts::PI * 0.5;
-            let direction = pitch_yaw_to_direction(pitch, model.camera.yaw);
-            model.camera.eye += direction * velocity;
-        }
-        // Float up on E.
-        if app.keys.down.contains(&Key::E) {
-            let pitch = model.camera.pitch + std::f32::consts::PI * 0.5;
-            let direction = pitch_yaw_to_direction(pitch, model.camera.yaw);
-            model.camera.eye += direction * velocity;
-        }
-    }
+// Move the camera based on the keys held and ease it towards the latest input.
+fn update(_app: &App, model: &mut Model, update: Update) {
+    model.camera.update(update.since_last.secs() as f32);
 }
 
-// Use raw device motion event for camera pitch and yaw.
+// Pass window events to the camera for movement, using raw device motion to look around while the
+// cursor is grabbed.
 // TODO: Check device ID for mouse here - not sure if possible with winit currently.
 fn event(_app: &App, model: &mut Model, event: Event) {
-    if model.camera_is_active {
-        if let Event::DeviceEvent(_device_id, event) = event {
-            if let winit::event::DeviceEvent::Motion { axis, value } = event {
-                let sensitivity = 0.004;
-                match axis {
-                    // Yaw left and right on mouse x axis movement.
-                    0 => model.camera.yaw -= (value * sensitivity) as f32,
-                    // Pitch up and down on mouse y axis movement.
-                    _ => {
-                        let max_pitch = std::f32::consts::PI * 0.5 - 0.0001;
-                        let min_pitch = -max_pitch;
-                        model.camera.pitch = (model.camera.pitch + (-value * sensitivity) as f32)
-                            .min(max_pitch)
-                            .max(min_pitch)
-                    }
-                }
+    match event {
+        Event::WindowEvent {
+            simple: Some(event),
+            ..
+        } => model.camera.event(&event),
+        Event::DeviceEvent(_device_id, winit::event::DeviceEvent::MouseMotion { delta }) => {
+            if model.camera_is_active {
+                let (x, y) = delta;
+                model.camera.look(vec2(x as f32, -y as f32));
             }
         }
+        _ => (),
     }
 }
 
@@ -273,7 +204,7 @@ fn view(_app: &App, model: &Model, frame: Frame) {
     }
 
     // Update the uniforms (rotate around the teapot).
-    let uniforms = create_uniforms(frame_size, model.camera.view());
+    let uniforms = create_uniforms(frame_size, &model.camera);
     let uniforms_size = std::mem::size_of::<Uniforms>() as wgpu::BufferAddress;
     let uniforms_bytes = uniforms_as_bytes(&uniforms);
     let usage = wgpu::BufferUsage::COPY_SRC;
@@ -301,13 +232,10 @@ fn view(_app: &App, model: &Model, frame: Frame) {
     render_pass.draw_indexed(index_range, start_vertex, instance_range);
 }
 
-fn create_uniforms([w, h]: [u32; 2], view: Mat4) -> Uniforms {
+fn create_uniforms([w, h]: [u32; 2], camera: &FlyCamera) -> Uniforms {
     let rotation = Mat4::from_rotation_y(0f32);
-    let aspect_ratio = w as f32 / h as f32;
-    let fov_y = std::f32::consts::FRAC_PI_2;
-    let near = 0.01;
-    let far = 100.0;
-    let proj = Mat4::perspective_rh_gl(fov_y, aspect_ratio, near, far);
+    let view = camera.view();
+    let proj = camera.projection(Rect::from_w_h(w as f32, h as f32));
     let scale = Mat4::from_scale(Vec3::splat(0.01));
     Uniforms {
         world: rotation,
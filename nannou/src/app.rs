@@ -20,7 +20,7 @@ use find_folder;
 use instant::Instant;
 use std;
 use std::cell::{RefCell, RefMut};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::atomic::{self, AtomicBool};
 use std::sync::Arc;
@@ -67,6 +67,7 @@ pub struct Builder<M = (), E = Event> {
     capture_frame_timeout: Option<Option<Duration>>,
     max_capture_frame_jobs: Option<u32>,
     backends: wgpu::BackendBit,
+    headless: bool,
 }
 
 /// A nannou `Sketch` builder.
@@ -97,6 +98,7 @@ fn default_model(_: &App) -> () {
 ///
 /// - The **window and input event loop** used to drive the application forward.
 /// - **All windows** for graphics and user input. Windows can be referenced via their IDs.
+///   If the **App** is headless, its window renders to an offscreen texture instead.
 /// - The sharing of wgpu devices between windows.
 /// - A default **Draw** instance for ease of use.
 /// - A map of channels for submitting user input updates to active **Ui**s.
@@ -110,6 +112,8 @@ pub struct App {
     pub(crate) windows: RefCell<HashMap<window::Id, Window>>,
     /// The wgpu backends to choose between.
     backends: wgpu::BackendBit,
+    /// Whether or not the app runs without an event loop or display.
+    headless: bool,
     /// The main wgpu instance.
     instance: wgpu::Instance,
     /// A map of active wgpu physical device adapters.
@@ -174,7 +178,8 @@ struct DrawState {
 /// **App**'s inner event loop.
 #[derive(Clone)]
 pub struct Proxy {
-    event_loop_proxy: winit::event_loop::EventLoopProxy<()>,
+    // `None` if the app is headless.
+    event_loop_proxy: Option<winit::event_loop::EventLoopProxy<()>>,
     // Indicates whether or not the events loop is currently asleep.
     //
     // This is set to `true` each time the events loop is ready to return and the `LoopMode` is
@@ -190,6 +195,8 @@ struct LoopState {
     loop_start: Instant,
     last_update: Instant,
     total_updates: u64,
+    // Windows that have yet to view the latest update in `Offline` mode.
+    awaiting_view: HashSet<window::Id>,
 }
 
 /// The mode in which the **App** is currently running the event loop and emitting `Update` events.
@@ -230,6 +237,23 @@ pub enum LoopMode {
         /// The number of updates that must be emited regardless of non-update events
         number_of_updates: usize,
     },
+
+    /// Renders at a fixed timestep, independent of the wall clock and the speed of the machine.
+    ///
+    /// The first update occurs at a virtual time of zero, and every following update advances
+    /// `app.time`, `app.duration` and the `Update` event by exactly `1 / fps` seconds. The next
+    /// update is not applied until every window has viewed the last one, and frames captured via
+    /// `Window::capture_frame` are read back before the view returns. As a result, no frame is
+    /// ever skipped or dropped, even if rendering or capturing is slower than real-time. Frames
+    /// skipped due to swap chain errors are not counted by `Frame::nth`.
+    ///
+    /// This is useful for exporting animations that must be reproducible, e.g. rendering a smooth
+    /// 60fps video on a slow machine. Combined with `app::Builder::headless`, frames are rendered
+    /// as fast as the machine allows rather than at the display's refresh rate.
+    Offline {
+        /// The number of updates per second of virtual time.
+        fps: f64,
+    },
 }
 
 impl<M> Builder<M, Event>
@@ -262,6 +286,7 @@ where
             max_capture_frame_jobs: None,
             capture_frame_timeout: None,
             backends: Self::DEFAULT_BACKENDS,
+            headless: false,
         }
     }
 
@@ -287,6 +312,7 @@ where
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends,
+            headless,
             ..
         } = self;
         Builder {
@@ -299,7 +325,8 @@ where
             default_window_size,
             max_capture_frame_jobs,
             capture_frame_timeout,
-            backends
+            backends,
+            headless
         }
     }
 }
@@ -427,6 +454,28 @@ where
         self
     }
 
+    /// Run the app without a window event loop or display, e.g. on a render node or in CI.
+    ///
+    /// Rather than presenting to a window on the display, the app's window renders to an
+    /// offscreen texture of the given size in pixels unless a size is specified while building the
+    /// window. After each call to `view`, the rendered frame is read back and made available via
+    /// `Window::frame_image`. Any number of windows may be built, each with its own offscreen
+    /// texture.
+    ///
+    /// As a headless app has no surface with which the adapter must be compatible, wgpu falls
+    /// back to a software adapter if no hardware adapter is available.
+    ///
+    /// The app updates and views its window repeatedly without waiting for the display. As there
+    /// is no input, no window events are emitted. The loop ends once the `LoopMode::NTimes` mode
+    /// has applied its updates, `App::quit` is called or every window is closed. A headless app
+    /// starts in `LoopMode::loop_once()`, so that it renders a single frame and exits unless
+    /// another mode is set, e.g. `LoopMode::loop_ntimes(n)`. In any other mode, the loop runs
+    /// until `App::quit` is called.
+    pub fn headless(mut self, width: u32, height: u32) -> Self {
+        self.headless = true;
+        self.size(width, height)
+    }
+
     /// Build and run an `App` with the specified parameters.
     ///
     /// This function will not return until the application has exited.
@@ -435,11 +484,14 @@ where
     /// thread as some platforms require that their application event loop and windows are
     /// initialised on the main thread.
     pub fn run(self) {
-        // Start the winit window event loop.
-        let event_loop = winit::event_loop::EventLoop::new();
+        // Start the winit window event loop, unless the app is headless.
+        let event_loop = match self.headless {
+            true => None,
+            false => Some(winit::event_loop::EventLoop::new()),
+        };
 
         // Create the proxy used to awaken the event loop.
-        let event_loop_proxy = event_loop.create_proxy();
+        let event_loop_proxy = event_loop.as_ref().map(|el| el.create_proxy());
         let event_loop_is_asleep = Arc::new(AtomicBool::new(false));
         let event_loop_proxy = Proxy {
             event_loop_proxy,
@@ -453,7 +505,7 @@ where
         let capture_frame_timeout = self
             .capture_frame_timeout
             .unwrap_or(Some(Self::DEFAULT_CAPTURE_FRAME_TIMEOUT));
-        let event_loop_window_target = Some(EventLoopWindowTarget::Owned(event_loop));
+        let event_loop_window_target = event_loop.map(EventLoopWindowTarget::Owned);
         let app = App::new(
             event_loop_proxy,
             event_loop_window_target,
@@ -461,6 +513,7 @@ where
             max_capture_frame_jobs,
             capture_frame_timeout,
             self.backends,
+            self.headless,
         );
 
         // Create the default window if necessary
@@ -482,14 +535,25 @@ where
             }
         }
 
-        run_loop(
-            app,
-            model,
-            self.event,
-            self.update,
-            self.default_view,
-            self.exit,
-        );
+        if app.is_headless() {
+            run_headless_loop(
+                app,
+                model,
+                self.event,
+                self.update,
+                self.default_view,
+                self.exit,
+            );
+        } else {
+            run_loop(
+                app,
+                model,
+                self.event,
+                self.update,
+                self.default_view,
+                self.exit,
+            );
+        }
     }
 }
 
@@ -503,6 +567,14 @@ where
         self
     }
 
+    /// Run the sketch without a window event loop or display.
+    ///
+    /// See `app::Builder::headless` for details.
+    pub fn headless(mut self, width: u32, height: u32) -> Self {
+        self.builder = self.builder.headless(width, height);
+        self
+    }
+
     /// Build and run a `Sketch` with the specified parameters.
     ///
     /// This calls `App::run` internally. See that method for details!
@@ -571,6 +643,14 @@ impl LoopMode {
     pub fn loop_once() -> Self {
         Self::loop_ntimes(1)
     }
+
+    /// Specify the **Offline** mode with the given frames-per-second.
+    ///
+    /// **Panics** if `fps` is not greater than `0.0`.
+    pub fn offline(fps: f64) -> Self {
+        assert!(fps > 0.0);
+        LoopMode::Offline { fps }
+    }
 }
 
 impl Default for LoopMode {
@@ -605,12 +685,19 @@ impl App {
         max_capture_frame_jobs: u32,
         capture_frame_timeout: Option<Duration>,
         backends: wgpu::BackendBit,
+        headless: bool,
     ) -> Self {
         let instance = wgpu::Instance::new(backends);
         let adapters = Default::default();
         let windows = RefCell::new(HashMap::new());
         let draw = RefCell::new(draw::Draw::default());
-        let config = RefCell::new(Default::default());
+        // Headless apps have no display to pace them, so they apply a finite number of updates
+        // by default.
+        let mut config = Config::default();
+        if headless {
+            config.loop_mode = LoopMode::loop_once();
+        }
+        let config = RefCell::new(config);
         let renderers = RefCell::new(Default::default());
         let draw_state = DrawState { draw, renderers };
         let focused_window = RefCell::new(None);
@@ -626,6 +713,7 @@ impl App {
             capture_frame_timeout,
             focused_window,
             backends,
+            headless,
             instance,
             adapters,
             windows,
@@ -647,9 +735,9 @@ impl App {
             }
             _ => {
                 let windows = self.windows.borrow();
-                match windows.values().next() {
+                match windows.values().next().and_then(|w| w.window.as_ref()) {
                     None => vec![],
-                    Some(window) => window.window.available_monitors().collect(),
+                    Some(window) => window.available_monitors().collect(),
                 }
             }
         }
@@ -657,8 +745,11 @@ impl App {
 
     /// Returns the primary monitor of the system.
     /// May return None if none can be detected. For example, this can happen when running on Linux
-    /// with Wayland.
+    /// with Wayland. Always returns `None` for a headless app.
     pub fn primary_monitor(&self) -> Option<winit::monitor::MonitorHandle> {
+        if self.is_headless() {
+            return None;
+        }
         match self.event_loop_window_target {
             Some(EventLoopWindowTarget::Owned(ref event_loop)) => event_loop.primary_monitor(),
             _ => {
@@ -668,7 +759,7 @@ impl App {
                         "yet to implement a way to get `primary_monitor` if neither \
                          event loop or window can be safely accessed"
                     ),
-                    Some(window) => window.window.primary_monitor(),
+                    Some(window) => window.winit_window().primary_monitor(),
                 }
             }
         }
@@ -788,6 +879,13 @@ impl App {
         self.backends
     }
 
+    /// Whether or not the app is running without a window event loop or display.
+    ///
+    /// See `app::Builder::headless` for details.
+    pub fn is_headless(&self) -> bool {
+        self.headless
+    }
+
     /// Return the main wgpu `Instance` in use.
     ///
     /// This must be passed into the various methods on `AdapterMap`.
@@ -878,6 +976,20 @@ impl App {
         draw.clone()
     }
 
+    /// Statistics describing the usage of the glyph caches of the **Draw** renderer associated
+    /// with the given window.
+    ///
+    /// Returns `None` if nothing has been drawn to the window via `Draw::to_frame` yet.
+    pub fn draw_glyph_cache_stats(
+        &self,
+        window: window::Id,
+    ) -> Option<draw::renderer::GlyphCacheStats> {
+        let renderers = self.draw_state.renderers.borrow();
+        let renderer = renderers.get(&window)?.borrow();
+        let stats = renderer.glyph_cache().stats;
+        Some(stats)
+    }
+
     /// The number of times the focused window's **view** function has been called since the start
     /// of the program.
     pub fn elapsed_frames(&self) -> u64 {
@@ -917,9 +1029,15 @@ impl Proxy {
     /// immediately set the flag to false afterwards. This makes it safe to call the `wakeup`
     /// method as frequently as necessary across methods without causing any underlying OS methods
     /// to be called more than necessary.
+    ///
+    /// This has no effect for a headless **App**, as its loop never sleeps.
     pub fn wakeup(&self) -> Result<(), winit::event_loop::EventLoopClosed<()>> {
+        let event_loop_proxy = match self.event_loop_proxy {
+            Some(ref proxy) => proxy,
+            None => return Ok(()),
+        };
         if self.event_loop_is_asleep.load(atomic::Ordering::Relaxed) {
-            self.event_loop_proxy.send_event(())?;
+            event_loop_proxy.send_event(())?;
             self.event_loop_is_asleep
                 .store(false, atomic::Ordering::Relaxed);
         }
@@ -1074,6 +1192,7 @@ fn run_loop<M, E>(
         loop_start,
         last_update: loop_start,
         total_updates: 0,
+        awaiting_view: HashSet::new(),
     };
 
     // Run the event loop.
@@ -1090,13 +1209,32 @@ fn run_loop<M, E>(
             winit::event::Event::MainEventsCleared => {
                 if let Some(model) = model.as_mut() {
                     let loop_mode = app.loop_mode();
-                    let now = Instant::now();
+                    let now = loop_state.next_update(&loop_mode);
+                    {
+                        let windows = app.windows.borrow();
+                        loop_state
+                            .awaiting_view
+                            .retain(|id| windows.contains_key(id));
+                    }
                     let mut do_update = |loop_state: &mut LoopState| {
                         apply_update(&mut app, model, event_fn, update_fn, loop_state, now);
                     };
                     match loop_mode {
                         LoopMode::NTimes { number_of_updates }
                             if loop_state.total_updates >= number_of_updates as u64 => {}
+                        // Hold back the next update until the last has been viewed by all windows.
+                        LoopMode::Offline { .. } if !loop_state.awaiting_view.is_empty() => {
+                            let windows = app.windows.borrow();
+                            for id in &loop_state.awaiting_view {
+                                if let Some(window) = windows[id].window.as_ref() {
+                                    window.request_redraw();
+                                }
+                            }
+                        }
+                        LoopMode::Offline { .. } => {
+                            do_update(&mut loop_state);
+                            loop_state.awaiting_view = app.window_ids().into_iter().collect();
+                        }
                         _ => do_update(&mut loop_state),
                     }
                 }
@@ -1107,8 +1245,13 @@ fn run_loop<M, E>(
             // TODO: Only request a frame from the user if this redraw was requested following an
             // update. Otherwise, just use the existing intermediary frame.
             winit::event::Event::RedrawRequested(window_id) => {
+                let window_id = window::Id::from(window_id);
                 // Take the render data and swapchain.
                 // We'll replace them before the end of this block.
+                //
+                // In `Offline` mode, only frames that are delivered to the view are counted so that
+                // the frame number matches the number of updates viewed.
+                let offline = matches!(app.loop_mode(), LoopMode::Offline { .. });
                 let (mut swap_chain, nth_frame) = {
                     let swap_chain = app.take_or_rebuild_swap_chain(window_id);
 
@@ -1118,7 +1261,9 @@ fn run_loop<M, E>(
                         .expect("no window for redraw request ID");
 
                     let nth_frame = window.frame_count;
-                    window.frame_count += 1;
+                    if !offline {
+                        window.frame_count += 1;
+                    }
                     (swap_chain, nth_frame)
                 };
 
@@ -1150,74 +1295,29 @@ fn run_loop<M, E>(
                     if let Ok(swap_chain_output) = swap_chain_output {
                         let swap_chain_texture = &swap_chain_output.output.view;
 
+                        if offline {
+                            let mut windows = app.windows.borrow_mut();
+                            let window = windows
+                                .get_mut(&window_id)
+                                .expect("no window for redraw request ID");
+                            window.frame_count += 1;
+                        }
+
                         // Borrow the window now that we don't need it mutably until setting the render
                         // data back.
                         let windows = app.windows.borrow();
                         let window = windows
                             .get(&window_id)
                             .expect("failed to find window for redraw request");
-                        let frame_data = &window.frame_data;
-
-                        // Construct and emit a frame via `view` for receiving the user's graphics commands.
-                        let sf = window.tracked_state.scale_factor;
-                        let (w, h) = window
-                            .tracked_state
-                            .physical_size
-                            .to_logical::<f32>(sf)
-                            .into();
-                        let window_rect = geom::Rect::from_w_h(w, h);
-                        let raw_frame = RawFrame::new_empty(
-                            window.swap_chain_device_queue_pair().clone(),
-                            window_id,
+                        view_window(
+                            &app,
+                            model,
+                            &default_view,
+                            window,
                             nth_frame,
                             swap_chain_texture,
-                            window.swap_chain.descriptor.format,
-                            window_rect,
                         );
 
-                        // If the user specified a view function specifically for this window, use it.
-                        // Otherwise, use the fallback, default view passed to the app if there was one.
-                        let window_view = window.user_functions.view.clone();
-
-                        match window_view {
-                            Some(window::View::Sketch(view)) => {
-                                let data = frame_data.as_ref().expect("missing `frame_data`");
-                                let frame =
-                                    Frame::new_empty(raw_frame, &data.render, &data.capture);
-                                view(&app, frame);
-                            }
-                            Some(window::View::WithModel(view)) => {
-                                let data = frame_data.as_ref().expect("missing `frame_data`");
-                                let frame =
-                                    Frame::new_empty(raw_frame, &data.render, &data.capture);
-                                let view = view.to_fn_ptr::<M>().expect(
-                                    "unexpected model argument given to window view function",
-                                );
-                                (*view)(&app, &model, frame);
-                            }
-                            Some(window::View::WithModelRaw(raw_view)) => {
-                                let raw_view = raw_view.to_fn_ptr::<M>().expect(
-                                    "unexpected model argument given to window raw_view function",
-                                );
-                                (*raw_view)(&app, &model, raw_frame);
-                            }
-                            None => match default_view {
-                                Some(View::Sketch(view)) => {
-                                    let data = frame_data.as_ref().expect("missing `frame_data`");
-                                    let frame =
-                                        Frame::new_empty(raw_frame, &data.render, &data.capture);
-                                    view(&app, frame);
-                                }
-                                Some(View::WithModel(view)) => {
-                                    let data = frame_data.as_ref().expect("missing `frame_data`");
-                                    let frame =
-                                        Frame::new_empty(raw_frame, &data.render, &data.capture);
-                                    view(&app, &model, frame);
-                                }
-                                None => raw_frame.submit(),
-                            },
-                        }
-
                         // Release immutable lock
                         drop(windows);
 
@@ -1228,6 +1328,7 @@ fn run_loop<M, E>(
                             .expect("no window for redraw request ID");
 
                         window.swap_chain.swap_chain = Some(swap_chain);
+                        loop_state.awaiting_view.remove(&window_id);
                     }
                 }
             }
@@ -1259,6 +1360,7 @@ fn run_loop<M, E>(
             window_id,
         } = event
         {
+            let window_id = window::Id::from(window_id);
             match event {
                 winit::event::WindowEvent::Resized(new_inner_size) => {
                     let mut windows = app.windows.borrow_mut();
@@ -1330,6 +1432,100 @@ fn run_loop<M, E>(
     }
 }
 
+// The application loop for headless apps.
+//
+// There is no event loop to drive the app, so updates and frames are applied back-to-back until
+// the `NTimes` loop mode completes, the app quits or every window is closed.
+fn run_headless_loop<M, E>(
+    mut app: App,
+    mut model: M,
+    event_fn: Option<EventFn<M, E>>,
+    update_fn: Option<UpdateFn<M>>,
+    default_view: Option<View<M>>,
+    exit_fn: Option<ExitFn<M>>,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    let loop_start = Instant::now();
+    let mut loop_state = LoopState {
+        updates_since_event: 0,
+        loop_start,
+        last_update: loop_start,
+        total_updates: 0,
+        awaiting_view: HashSet::new(),
+    };
+
+    while !app.windows.borrow().is_empty() {
+        if let LoopMode::NTimes { number_of_updates } = app.loop_mode() {
+            if loop_state.total_updates >= number_of_updates as u64 {
+                break;
+            }
+        }
+
+        let now = loop_state.next_update(&app.loop_mode());
+        apply_update(
+            &mut app,
+            &mut model,
+            event_fn,
+            update_fn,
+            &mut loop_state,
+            now,
+        );
+
+        // Emit a frame to the view of each window and read the result back to the CPU.
+        for window_id in app.window_ids() {
+            let nth_frame = match app.windows.borrow_mut().get_mut(&window_id) {
+                None => continue,
+                Some(window) => {
+                    let nth_frame = window.frame_count;
+                    window.frame_count += 1;
+                    nth_frame
+                }
+            };
+            if let Some(window) = app.windows.borrow().get(&window_id) {
+                let headless = window
+                    .headless
+                    .as_ref()
+                    .expect("headless app window has no offscreen target");
+                let texture = &headless.texture_view;
+                view_window(&app, &model, &default_view, window, nth_frame, texture);
+            }
+            if let Some(window) = app.windows.borrow_mut().get_mut(&window_id) {
+                window.read_headless_frame();
+            }
+        }
+
+        app.wgpu_adapters().clear_inactive_adapters_and_devices();
+    }
+
+    if let Some(exit_fn) = exit_fn {
+        exit_fn(&app, model);
+    }
+}
+
+impl LoopState {
+    // The moment at which the next update is applied.
+    //
+    // In `Offline` mode this is the virtual time of the update following the start of the loop,
+    // such that the first update occurs at the start of the loop and each following update occurs
+    // exactly `1 / fps` seconds after the last.
+    fn next_update(&self, loop_mode: &LoopMode) -> Instant {
+        match *loop_mode {
+            LoopMode::Offline { fps } => self.loop_start + offline_time(self.total_updates, fps),
+            _ => Instant::now(),
+        }
+    }
+}
+
+// The virtual time since the start of the loop of the update with the given index in `Offline`
+// mode.
+//
+// Calculated from the index rather than accumulated so that rounding errors do not drift.
+fn offline_time(nth_update: u64, fps: f64) -> Duration {
+    Duration::from_secs_f64(nth_update as f64 / fps)
+}
+
 // Apply an update to the model via the user's function and update the app and loop state
 // accordingly.
 fn apply_update<M, E>(
@@ -1367,8 +1563,82 @@ fn apply_update<M, E>(
     loop_state.updates_since_event += 1;
     // Request redraw from windows.
     let windows = app.windows.borrow();
-    for window in windows.values() {
-        window.window.request_redraw();
+    for window in windows.values().filter_map(|w| w.window.as_ref()) {
+        window.request_redraw();
+    }
+}
+
+// Construct a frame for the given window targeting the given texture and emit it to the user's
+// view function.
+fn view_window<M>(
+    app: &App,
+    model: &M,
+    default_view: &Option<View<M>>,
+    window: &Window,
+    nth_frame: u64,
+    texture: &wgpu::TextureViewHandle,
+) where
+    M: 'static,
+{
+    let frame_data = &window.frame_data;
+
+    // Construct and emit a frame via `view` for receiving the user's graphics commands.
+    let sf = window.tracked_state.scale_factor;
+    let (w, h) = window
+        .tracked_state
+        .physical_size
+        .to_logical::<f32>(sf)
+        .into();
+    let window_rect = geom::Rect::from_w_h(w, h);
+    let raw_frame = RawFrame::new_empty(
+        window.swap_chain_device_queue_pair().clone(),
+        window.id(),
+        nth_frame,
+        texture,
+        window.swap_chain.descriptor.format,
+        window_rect,
+    );
+
+    // If the user specified a view function specifically for this window, use it.
+    // Otherwise, use the fallback, default view passed to the app if there was one.
+    let window_view = window.user_functions.view.clone();
+
+    // Offline rendering must never drop a captured frame.
+    let offline = matches!(app.loop_mode(), LoopMode::Offline { .. });
+
+    match window_view {
+        Some(window::View::Sketch(view)) => {
+            let data = frame_data.as_ref().expect("missing `frame_data`");
+            let frame = Frame::new_empty(raw_frame, &data.render, &data.capture, offline);
+            view(app, frame);
+        }
+        Some(window::View::WithModel(view)) => {
+            let data = frame_data.as_ref().expect("missing `frame_data`");
+            let frame = Frame::new_empty(raw_frame, &data.render, &data.capture, offline);
+            let view = view
+                .to_fn_ptr::<M>()
+                .expect("unexpected model argument given to window view function");
+            (*view)(app, model, frame);
+        }
+        Some(window::View::WithModelRaw(raw_view)) => {
+            let raw_view = raw_view
+                .to_fn_ptr::<M>()
+                .expect("unexpected model argument given to window raw_view function");
+            (*raw_view)(app, model, raw_frame);
+        }
+        None => match *default_view {
+            Some(View::Sketch(view)) => {
+                let data = frame_data.as_ref().expect("missing `frame_data`");
+                let frame = Frame::new_empty(raw_frame, &data.render, &data.capture, offline);
+                view(app, frame);
+            }
+            Some(View::WithModel(view)) => {
+                let data = frame_data.as_ref().expect("missing `frame_data`");
+                let frame = Frame::new_empty(raw_frame, &data.render, &data.capture, offline);
+                view(app, model, frame);
+            }
+            None => raw_frame.submit(),
+        },
     }
 }
 
@@ -1436,6 +1706,7 @@ where
         ref event,
     } = *winit_event
     {
+        let window_id = window::Id::from(window_id);
         // If we should exit the app on escape, check for the escape key.
         if app.exit_on_escape() {
             if let winit::event::WindowEvent::KeyboardInput { input, .. } = *event {
@@ -1558,6 +1829,7 @@ where
         ref event,
     } = *winit_event
     {
+        let window_id = window::Id::from(window_id);
         // Raw window events.
         if let Some(raw_window_event_fn) = {
             let windows = app.windows.borrow();
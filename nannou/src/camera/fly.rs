@@ -0,0 +1,261 @@
+//! A first-person camera that flies freely through a scene.
+
+use super::{smoothing, Camera, Mouse, Perspective};
+use crate::event::{Key, MouseButton, WindowEvent};
+use crate::geom::{Point3, Rect};
+use crate::glam::{Mat4, Vec2, Vec3};
+use std::f32::consts::FRAC_PI_2;
+
+/// A first-person camera that moves with the keyboard and looks around with the mouse.
+///
+/// Holding the movement keys moves the camera relative to the direction it faces, while the
+/// **up** and **down** keys move it along the world's y axis. Moving the mouse while holding the
+/// **look** button turns the camera.
+///
+/// When the cursor is grabbed, the window may not report mouse movement. Pass the raw movement of
+/// the mouse device to `look` instead, e.g. from `winit::event::DeviceEvent::MouseMotion`.
+///
+/// With a `yaw` and `pitch` of `0.0`, the camera looks towards the negative z axis.
+#[derive(Clone, Debug)]
+pub struct FlyCamera {
+    eye: Point3,
+    velocity: Vec3,
+    current: Look,
+    target: Look,
+    perspective: Perspective,
+    bindings: FlyBindings,
+    speed: f32,
+    fast_multiplier: f32,
+    look_speed: f32,
+    damping: f32,
+    mouse: Mouse,
+    held: Vec<Key>,
+}
+
+/// The keys and mouse button used to control a **FlyCamera**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FlyBindings {
+    /// Move forwards. `W` by default.
+    pub forward: Key,
+    /// Move backwards. `S` by default.
+    pub backward: Key,
+    /// Move to the left. `A` by default.
+    pub left: Key,
+    /// Move to the right. `D` by default.
+    pub right: Key,
+    /// Move upwards. `E` by default.
+    pub up: Key,
+    /// Move downwards. `Q` by default.
+    pub down: Key,
+    /// Move faster while held. `LShift` by default.
+    pub fast: Key,
+    /// Turn the camera by moving the mouse while this button is held, or whenever the mouse moves
+    /// if `None`. `Some(Right)` by default.
+    pub look: Option<MouseButton>,
+}
+
+// The direction in which the camera faces.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Look {
+    yaw: f32,
+    pitch: f32,
+}
+
+impl FlyCamera {
+    /// The default speed of movement in units per second.
+    pub const DEFAULT_SPEED: f32 = 100.0;
+    /// The default factor by which the speed is multiplied while the **fast** key is held.
+    pub const DEFAULT_FAST_MULTIPLIER: f32 = 4.0;
+    /// The default rotation in radians per point of mouse movement.
+    pub const DEFAULT_LOOK_SPEED: f32 = 0.004;
+    /// The default time in seconds taken to ease towards the input.
+    pub const DEFAULT_DAMPING: f32 = 0.08;
+
+    // Keep the pitch just short of straight up or down so that the camera never turns over.
+    const MAX_PITCH: f32 = FRAC_PI_2 - 0.001;
+
+    /// A camera at the given position looking towards the negative z axis.
+    pub fn new(eye: Point3) -> Self {
+        let look = Look {
+            yaw: 0.0,
+            pitch: 0.0,
+        };
+        FlyCamera {
+            eye,
+            velocity: Vec3::ZERO,
+            current: look,
+            target: look,
+            perspective: Perspective::default(),
+            bindings: FlyBindings::default(),
+            speed: Self::DEFAULT_SPEED,
+            fast_multiplier: Self::DEFAULT_FAST_MULTIPLIER,
+            look_speed: Self::DEFAULT_LOOK_SPEED,
+            damping: Self::DEFAULT_DAMPING,
+            mouse: Mouse::default(),
+            held: vec![],
+        }
+    }
+
+    /// Specify the rotation around the vertical axis in radians.
+    pub fn yaw(mut self, yaw: f32) -> Self {
+        self.target.yaw = yaw;
+        self.current.yaw = yaw;
+        self
+    }
+
+    /// Specify the rotation above the horizon in radians.
+    ///
+    /// The pitch is limited to just short of straight up or down.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        let pitch = pitch.clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+        self.target.pitch = pitch;
+        self.current.pitch = pitch;
+        self
+    }
+
+    /// Face the camera towards the given point.
+    pub fn look_at(self, point: Point3) -> Self {
+        let direction = point - self.eye;
+        let yaw = (-direction.x).atan2(-direction.z);
+        let pitch = direction.y.atan2(direction.x.hypot(direction.z));
+        self.yaw(yaw).pitch(pitch)
+    }
+
+    /// Specify the perspective projection.
+    pub fn perspective(mut self, perspective: Perspective) -> Self {
+        self.perspective = perspective;
+        self
+    }
+
+    /// Specify the keys and mouse button used to control the camera.
+    pub fn bindings(mut self, bindings: FlyBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Specify the speed of movement in units per second.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Specify the factor by which the speed is multiplied while the **fast** key is held.
+    pub fn fast_multiplier(mut self, multiplier: f32) -> Self {
+        self.fast_multiplier = multiplier;
+        self
+    }
+
+    /// Specify the rotation in radians per point of mouse movement.
+    pub fn look_speed(mut self, speed: f32) -> Self {
+        self.look_speed = speed;
+        self
+    }
+
+    /// Specify the time in seconds taken to ease towards the input, where `0.0` disables damping.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// The position of the camera.
+    pub fn eye(&self) -> Point3 {
+        self.eye
+    }
+
+    /// The unit vector in the direction that the camera faces.
+    pub fn direction(&self) -> Vec3 {
+        self.current.rotation().transform_vector3(-Vec3::Z)
+    }
+
+    /// Move the camera to the given position.
+    pub fn set_eye(&mut self, eye: Point3) {
+        self.eye = eye;
+    }
+
+    /// Turn the camera by the given mouse movement in points, where positive `y` turns upwards.
+    ///
+    /// This is applied automatically for mouse movement reported by the window. Call this
+    /// directly with the raw movement of the mouse device while the cursor is grabbed.
+    pub fn look(&mut self, delta: Vec2) {
+        self.target.yaw -= delta.x * self.look_speed;
+        self.target.pitch = (self.target.pitch + delta.y * self.look_speed)
+            .clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+    }
+
+    fn is_held(&self, key: Key) -> bool {
+        self.held.contains(&key)
+    }
+}
+
+impl Camera for FlyCamera {
+    fn event(&mut self, event: &WindowEvent) {
+        match *event {
+            WindowEvent::KeyPressed(key) if !self.is_held(key) => self.held.push(key),
+            WindowEvent::KeyReleased(key) => self.held.retain(|&k| k != key),
+            WindowEvent::Unfocused => self.held.clear(),
+            _ => (),
+        }
+        if let Some(delta) = self.mouse.event(event) {
+            let looking = match self.bindings.look {
+                None => true,
+                Some(button) => self.mouse.is_pressed(button),
+            };
+            if looking {
+                self.look(delta);
+            }
+        }
+    }
+
+    fn update(&mut self, secs: f32) {
+        let t = smoothing(self.damping, secs);
+        self.current.yaw += (self.target.yaw - self.current.yaw) * t;
+        self.current.pitch += (self.target.pitch - self.current.pitch) * t;
+
+        // Determine the velocity towards which the camera accelerates from the held keys.
+        let b = self.bindings;
+        let axis =
+            |pos: Key, neg: Key| self.is_held(pos) as i32 as f32 - self.is_held(neg) as i32 as f32;
+        let right = Mat4::from_rotation_y(self.current.yaw).transform_vector3(Vec3::X);
+        let direction = self.direction() * axis(b.forward, b.backward)
+            + right * axis(b.right, b.left)
+            + Vec3::Y * axis(b.up, b.down);
+        let speed = if self.is_held(b.fast) {
+            self.speed * self.fast_multiplier
+        } else {
+            self.speed
+        };
+        let velocity = direction.normalize_or_zero() * speed;
+        self.velocity += (velocity - self.velocity) * t;
+        self.eye += self.velocity * secs;
+    }
+
+    fn view(&self) -> Mat4 {
+        self.current.rotation().transpose() * Mat4::from_translation(-self.eye)
+    }
+
+    fn projection(&self, window: Rect) -> Mat4 {
+        self.perspective.matrix(window)
+    }
+}
+
+impl Look {
+    // The orientation of the camera.
+    fn rotation(&self) -> Mat4 {
+        Mat4::from_rotation_y(self.yaw) * Mat4::from_rotation_x(self.pitch)
+    }
+}
+
+impl Default for FlyBindings {
+    fn default() -> Self {
+        FlyBindings {
+            forward: Key::W,
+            backward: Key::S,
+            left: Key::A,
+            right: Key::D,
+            up: Key::E,
+            down: Key::Q,
+            fast: Key::LShift,
+            look: Some(MouseButton::Right),
+        }
+    }
+}
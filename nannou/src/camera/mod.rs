@@ -0,0 +1,188 @@
+//! Reusable camera controllers driven by window input.
+//!
+//! - **OrbitCamera** orbits around a center point, rotating, panning and zooming with the mouse.
+//! - **FlyCamera** moves freely through a scene with the keyboard while looking with the mouse.
+//! - **PanZoom** pans and zooms a 2D scene with the mouse.
+//!
+//! Each controller implements the **Camera** trait. Pass each **WindowEvent** to `event`, advance
+//! the camera once per update via `update` and read the resulting transforms:
+//!
+//! - `view` and `projection` produce matrices for use within custom wgpu pipelines.
+//! - `view_projection` may also be passed to `Draw::set_projection`, so that a **Draw**ing is
+//!   viewed through the camera.
+//! - `ray` produces a **Ray3** through a point on the window for picking objects with the mouse.
+//!
+//! Controllers follow their input smoothly according to their `damping`, which must be advanced
+//! via `update` even while there is no input.
+//!
+//! ```no_run
+//! use nannou::prelude::*;
+//!
+//! struct Model {
+//!     camera: OrbitCamera,
+//! }
+//!
+//! fn model(app: &App) -> Model {
+//!     app.new_window().event(event).view(view).build().unwrap();
+//!     let camera = OrbitCamera::new(pt3(0.0, 0.0, 0.0), 800.0);
+//!     Model { camera }
+//! }
+//!
+//! fn event(_app: &App, model: &mut Model, event: WindowEvent) {
+//!     model.camera.event(&event);
+//! }
+//!
+//! fn update(_app: &App, model: &mut Model, update: Update) {
+//!     model.camera.update(update.since_last.secs() as f32);
+//! }
+//!
+//! fn view(app: &App, model: &Model, frame: Frame) {
+//!     let win = app.window_rect();
+//!     let draw = app.draw();
+//!     draw.set_projection(model.camera.view_projection(win));
+//!     draw.background().color(BLACK);
+//!     draw.ellipse().w_h(200.0, 200.0).color(PLUM);
+//!     draw.to_frame(app, &frame).unwrap();
+//! }
+//!
+//! fn main() {
+//!     nannou::app(model).update(update).run();
+//! }
+//! ```
+
+use crate::event::{MouseButton, MouseScrollDelta, WindowEvent};
+use crate::geom::{Point2, Ray3, Rect};
+use crate::glam::{Mat4, Vec2};
+
+pub use self::fly::{FlyBindings, FlyCamera};
+pub use self::orbit::{OrbitBindings, OrbitCamera};
+pub use self::pan_zoom::{PanZoom, PanZoomBindings};
+
+pub mod fly;
+pub mod orbit;
+pub mod pan_zoom;
+
+/// A camera controlled by window input.
+pub trait Camera {
+    /// Update the camera's input state with the given window event.
+    fn event(&mut self, event: &WindowEvent);
+
+    /// Advance the camera by the given number of seconds, e.g. `update.since_last.secs()`.
+    fn update(&mut self, secs: f32);
+
+    /// The transform from world space to the camera's view space.
+    fn view(&self) -> Mat4;
+
+    /// The projection from view space to clip space for the given window, where depth lies within
+    /// `0.0..=1.0` as expected by wgpu.
+    fn projection(&self, window: Rect) -> Mat4;
+
+    /// The transform from world space to clip space for the given window.
+    ///
+    /// Pass this to `Draw::set_projection` in order to view a **Draw**ing through the camera.
+    fn view_projection(&self, window: Rect) -> Mat4 {
+        self.projection(window) * self.view()
+    }
+
+    /// The ray from the camera through the given point on the window, e.g. the mouse position.
+    fn ray(&self, point: Point2, window: Rect) -> Ray3 {
+        Ray3::from_window_point(point, window, self.view_projection(window))
+    }
+}
+
+/// The perspective projection used by the 3D camera controllers.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Perspective {
+    /// The vertical field of view in radians.
+    pub fov_y: f32,
+    /// The distance to the near clipping plane.
+    pub near: f32,
+    /// The distance to the far clipping plane.
+    pub far: f32,
+}
+
+impl Perspective {
+    /// The default vertical field of view in radians.
+    pub const DEFAULT_FOV_Y: f32 = std::f32::consts::FRAC_PI_4;
+    /// The default distance to the near clipping plane.
+    pub const DEFAULT_NEAR: f32 = 0.1;
+    /// The default distance to the far clipping plane.
+    pub const DEFAULT_FAR: f32 = 10_000.0;
+
+    /// The projection matrix for the given window.
+    pub fn matrix(&self, window: Rect) -> Mat4 {
+        let aspect = window.w() / window.h();
+        Mat4::perspective_rh(self.fov_y, aspect, self.near, self.far)
+    }
+}
+
+impl Default for Perspective {
+    fn default() -> Self {
+        Perspective {
+            fov_y: Self::DEFAULT_FOV_Y,
+            near: Self::DEFAULT_NEAR,
+            far: Self::DEFAULT_FAR,
+        }
+    }
+}
+
+// The number of pixels treated as a single line of scrolling.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+// Tracks the mouse position and pressed buttons across window events.
+#[derive(Clone, Debug, Default)]
+struct Mouse {
+    position: Option<Point2>,
+    pressed: Vec<MouseButton>,
+}
+
+impl Mouse {
+    // Update the state with the given event, returning the movement of the mouse if it moved.
+    fn event(&mut self, event: &WindowEvent) -> Option<Vec2> {
+        match *event {
+            WindowEvent::MouseMoved(p) => {
+                let delta = self.position.map(|last| p - last);
+                self.position = Some(p);
+                delta
+            }
+            WindowEvent::MousePressed(button) => {
+                if !self.is_pressed(button) {
+                    self.pressed.push(button);
+                }
+                None
+            }
+            WindowEvent::MouseReleased(button) => {
+                self.pressed.retain(|&b| b != button);
+                None
+            }
+            WindowEvent::Unfocused => {
+                self.pressed.clear();
+                self.position = None;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn is_pressed(&self, button: MouseButton) -> bool {
+        self.pressed.contains(&button)
+    }
+}
+
+// The number of lines scrolled vertically by the given mouse wheel movement.
+fn scroll_lines(delta: &MouseScrollDelta) -> f32 {
+    match *delta {
+        MouseScrollDelta::LineDelta(_, y) => y,
+        MouseScrollDelta::PixelDelta(p) => p.y as f32 / PIXELS_PER_LINE,
+    }
+}
+
+// The fraction of the remaining distance to a target to cover over the given number of seconds,
+// such that a value settles at the same rate regardless of how often it is updated.
+fn smoothing(damping: f32, secs: f32) -> f32 {
+    if damping <= 0.0 {
+        1.0
+    } else {
+        1.0 - (-secs / damping).exp()
+    }
+}
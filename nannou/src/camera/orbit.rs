@@ -0,0 +1,250 @@
+//! A camera that orbits around a center point.
+
+use super::{scroll_lines, smoothing, Camera, Mouse, Perspective};
+use crate::event::{MouseButton, WindowEvent};
+use crate::geom::{Point3, Rect};
+use crate::glam::{Mat4, Vec3};
+use std::f32::consts::FRAC_PI_2;
+
+/// A camera that orbits around a center point at some distance.
+///
+/// Dragging with the **rotate** button orbits the camera around the center, dragging with the
+/// **pan** button moves the center across the view and scrolling the mouse wheel moves the camera
+/// towards or away from the center.
+///
+/// With a `yaw` and `pitch` of `0.0`, the camera lies along the positive z axis from the center,
+/// looking towards the negative z axis.
+#[derive(Clone, Debug)]
+pub struct OrbitCamera {
+    current: Orbit,
+    target: Orbit,
+    perspective: Perspective,
+    bindings: OrbitBindings,
+    rotate_speed: f32,
+    pan_speed: f32,
+    zoom_speed: f32,
+    min_distance: f32,
+    max_distance: f32,
+    damping: f32,
+    mouse: Mouse,
+}
+
+/// The mouse buttons used to control an **OrbitCamera**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrbitBindings {
+    /// Drag with this button to orbit around the center. `Left` by default.
+    pub rotate: MouseButton,
+    /// Drag with this button to move the center across the view. `Right` by default.
+    pub pan: MouseButton,
+}
+
+// The placement of the camera relative to its center.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Orbit {
+    center: Vec3,
+    yaw: f32,
+    pitch: f32,
+    distance: f32,
+}
+
+impl OrbitCamera {
+    /// The default rotation in radians per point of mouse movement.
+    pub const DEFAULT_ROTATE_SPEED: f32 = 0.01;
+    /// The default distance panned per point of mouse movement as a fraction of the distance to
+    /// the center.
+    pub const DEFAULT_PAN_SPEED: f32 = 0.002;
+    /// The default change in distance per line scrolled as a fraction of the distance to the
+    /// center.
+    pub const DEFAULT_ZOOM_SPEED: f32 = 0.1;
+    /// The default time in seconds taken to ease towards the input.
+    pub const DEFAULT_DAMPING: f32 = 0.08;
+
+    // Keep the pitch just short of the poles so that the camera never turns upside down.
+    const MAX_PITCH: f32 = FRAC_PI_2 - 0.001;
+
+    /// A camera orbiting the given center at the given distance.
+    pub fn new(center: Point3, distance: f32) -> Self {
+        let orbit = Orbit {
+            center,
+            yaw: 0.0,
+            pitch: 0.0,
+            distance,
+        };
+        OrbitCamera {
+            current: orbit,
+            target: orbit,
+            perspective: Perspective::default(),
+            bindings: OrbitBindings::default(),
+            rotate_speed: Self::DEFAULT_ROTATE_SPEED,
+            pan_speed: Self::DEFAULT_PAN_SPEED,
+            zoom_speed: Self::DEFAULT_ZOOM_SPEED,
+            min_distance: 0.0,
+            max_distance: f32::INFINITY,
+            damping: Self::DEFAULT_DAMPING,
+            mouse: Mouse::default(),
+        }
+    }
+
+    /// Specify the rotation around the vertical axis in radians.
+    pub fn yaw(mut self, yaw: f32) -> Self {
+        self.target.yaw = yaw;
+        self.current.yaw = yaw;
+        self
+    }
+
+    /// Specify the elevation above the center in radians.
+    ///
+    /// The pitch is limited to just short of straight up or down.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        let pitch = pitch.clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+        self.target.pitch = pitch;
+        self.current.pitch = pitch;
+        self
+    }
+
+    /// Specify the perspective projection.
+    pub fn perspective(mut self, perspective: Perspective) -> Self {
+        self.perspective = perspective;
+        self
+    }
+
+    /// Specify the mouse buttons used to control the camera.
+    pub fn bindings(mut self, bindings: OrbitBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Specify the rotation in radians per point of mouse movement.
+    pub fn rotate_speed(mut self, speed: f32) -> Self {
+        self.rotate_speed = speed;
+        self
+    }
+
+    /// Specify the distance panned per point of mouse movement as a fraction of the distance to
+    /// the center.
+    pub fn pan_speed(mut self, speed: f32) -> Self {
+        self.pan_speed = speed;
+        self
+    }
+
+    /// Specify the change in distance per line scrolled as a fraction of the distance to the
+    /// center.
+    pub fn zoom_speed(mut self, speed: f32) -> Self {
+        self.zoom_speed = speed;
+        self
+    }
+
+    /// Specify the range of distances to which zooming is limited.
+    ///
+    /// **Panics** if `min` is greater than `max`.
+    pub fn distance_limits(mut self, min: f32, max: f32) -> Self {
+        assert!(
+            min <= max,
+            "the minimum distance must not exceed the maximum"
+        );
+        self.min_distance = min;
+        self.max_distance = max;
+        self
+    }
+
+    /// Specify the time in seconds taken to ease towards the input, where `0.0` disables damping.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// The point around which the camera orbits.
+    pub fn center(&self) -> Point3 {
+        self.current.center
+    }
+
+    /// The position of the camera.
+    pub fn eye(&self) -> Point3 {
+        self.current.transform().transform_point3(Vec3::ZERO)
+    }
+
+    /// The distance between the camera and the center.
+    pub fn distance(&self) -> f32 {
+        self.current.distance
+    }
+
+    /// Ease the camera towards orbiting the given center.
+    pub fn set_center(&mut self, center: Point3) {
+        self.target.center = center;
+    }
+
+    /// Ease the camera towards the given distance from the center.
+    pub fn set_distance(&mut self, distance: f32) {
+        self.target.distance = distance.clamp(self.min_distance, self.max_distance);
+    }
+
+    /// Ease the camera towards the given yaw and pitch in radians.
+    pub fn set_rotation(&mut self, yaw: f32, pitch: f32) {
+        self.target.yaw = yaw;
+        self.target.pitch = pitch.clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+    }
+}
+
+impl Camera for OrbitCamera {
+    fn event(&mut self, event: &WindowEvent) {
+        if let WindowEvent::MouseWheel(ref delta, _) = *event {
+            let distance = self.target.distance * (-scroll_lines(delta) * self.zoom_speed).exp();
+            self.set_distance(distance);
+        }
+        let delta = match self.mouse.event(event) {
+            None => return,
+            Some(delta) => delta,
+        };
+        if self.mouse.is_pressed(self.bindings.rotate) {
+            let yaw = self.target.yaw - delta.x * self.rotate_speed;
+            let pitch = self.target.pitch - delta.y * self.rotate_speed;
+            self.set_rotation(yaw, pitch);
+        } else if self.mouse.is_pressed(self.bindings.pan) {
+            let rotation = self.target.rotation();
+            let right = rotation.transform_vector3(Vec3::X);
+            let up = rotation.transform_vector3(Vec3::Y);
+            let scale = self.target.distance * self.pan_speed;
+            self.target.center -= (right * delta.x + up * delta.y) * scale;
+        }
+    }
+
+    fn update(&mut self, secs: f32) {
+        let t = smoothing(self.damping, secs);
+        let (current, target) = (&mut self.current, &self.target);
+        current.center += (target.center - current.center) * t;
+        current.yaw += (target.yaw - current.yaw) * t;
+        current.pitch += (target.pitch - current.pitch) * t;
+        current.distance += (target.distance - current.distance) * t;
+    }
+
+    fn view(&self) -> Mat4 {
+        self.current.transform().inverse()
+    }
+
+    fn projection(&self, window: Rect) -> Mat4 {
+        self.perspective.matrix(window)
+    }
+}
+
+impl Orbit {
+    // The orientation of the camera.
+    fn rotation(&self) -> Mat4 {
+        Mat4::from_rotation_y(self.yaw) * Mat4::from_rotation_x(-self.pitch)
+    }
+
+    // The transform from the camera's view space to world space.
+    fn transform(&self) -> Mat4 {
+        Mat4::from_translation(self.center)
+            * self.rotation()
+            * Mat4::from_translation(Vec3::new(0.0, 0.0, self.distance))
+    }
+}
+
+impl Default for OrbitBindings {
+    fn default() -> Self {
+        OrbitBindings {
+            rotate: MouseButton::Left,
+            pan: MouseButton::Right,
+        }
+    }
+}
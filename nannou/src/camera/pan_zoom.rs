@@ -0,0 +1,166 @@
+//! A camera for panning and zooming around a 2D scene.
+
+use super::{scroll_lines, smoothing, Camera, Mouse};
+use crate::draw;
+use crate::event::{MouseButton, WindowEvent};
+use crate::geom::{Point2, Rect};
+use crate::glam::{Mat4, Vec3};
+
+/// A 2D camera that pans by dragging the mouse and zooms towards the cursor with the mouse wheel.
+///
+/// At a zoom of `1.0`, one unit in the scene spans one point on the window, matching the default
+/// coordinates of a **Draw**ing.
+#[derive(Clone, Debug)]
+pub struct PanZoom {
+    current: View,
+    target: View,
+    bindings: PanZoomBindings,
+    zoom_speed: f32,
+    min_zoom: f32,
+    max_zoom: f32,
+    damping: f32,
+    mouse: Mouse,
+}
+
+/// The mouse button used to control a **PanZoom** camera.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PanZoomBindings {
+    /// Drag with this button to pan the view. `Left` by default.
+    pub pan: MouseButton,
+}
+
+// The region of the scene in view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct View {
+    center: Point2,
+    zoom: f32,
+}
+
+impl PanZoom {
+    /// The default change in zoom per line scrolled as a fraction of the current zoom.
+    pub const DEFAULT_ZOOM_SPEED: f32 = 0.1;
+    /// The default time in seconds taken to ease towards the input.
+    pub const DEFAULT_DAMPING: f32 = 0.05;
+
+    /// A camera centered on the given point in the scene at the given zoom, where a zoom of `2.0`
+    /// shows the scene at twice its size.
+    pub fn new(center: Point2, zoom: f32) -> Self {
+        let view = View { center, zoom };
+        PanZoom {
+            current: view,
+            target: view,
+            bindings: PanZoomBindings::default(),
+            zoom_speed: Self::DEFAULT_ZOOM_SPEED,
+            min_zoom: 0.0,
+            max_zoom: f32::INFINITY,
+            damping: Self::DEFAULT_DAMPING,
+            mouse: Mouse::default(),
+        }
+    }
+
+    /// Specify the mouse button used to control the camera.
+    pub fn bindings(mut self, bindings: PanZoomBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// Specify the change in zoom per line scrolled as a fraction of the current zoom.
+    pub fn zoom_speed(mut self, speed: f32) -> Self {
+        self.zoom_speed = speed;
+        self
+    }
+
+    /// Specify the range to which zooming is limited.
+    ///
+    /// **Panics** if `min` is greater than `max`.
+    pub fn zoom_limits(mut self, min: f32, max: f32) -> Self {
+        assert!(min <= max, "the minimum zoom must not exceed the maximum");
+        self.min_zoom = min;
+        self.max_zoom = max;
+        self
+    }
+
+    /// Specify the time in seconds taken to ease towards the input, where `0.0` disables damping.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// The point in the scene at the center of the window.
+    pub fn center(&self) -> Point2 {
+        self.current.center
+    }
+
+    /// The scale of the scene.
+    pub fn zoom(&self) -> f32 {
+        self.current.zoom
+    }
+
+    /// Ease the camera towards centering the given point in the scene.
+    pub fn set_center(&mut self, center: Point2) {
+        self.target.center = center;
+    }
+
+    /// Ease the camera towards the given zoom.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.target.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+    }
+
+    /// The point in the scene beneath the given point on the window, e.g. the mouse position.
+    pub fn scene_point(&self, window_point: Point2) -> Point2 {
+        self.current.center + window_point / self.current.zoom
+    }
+
+    /// The point on the window above the given point in the scene.
+    pub fn window_point(&self, scene_point: Point2) -> Point2 {
+        (scene_point - self.current.center) * self.current.zoom
+    }
+}
+
+impl Camera for PanZoom {
+    fn event(&mut self, event: &WindowEvent) {
+        if let WindowEvent::MouseWheel(ref delta, _) = *event {
+            // Zoom towards the cursor, keeping the point beneath it in place.
+            let cursor = self.mouse.position.unwrap_or(Point2::ZERO);
+            let before = self.target.center + cursor / self.target.zoom;
+            self.set_zoom(self.target.zoom * (scroll_lines(delta) * self.zoom_speed).exp());
+            self.target.center = before - cursor / self.target.zoom;
+        }
+        if let Some(delta) = self.mouse.event(event) {
+            if self.mouse.is_pressed(self.bindings.pan) {
+                self.target.center -= delta / self.target.zoom;
+            }
+        }
+    }
+
+    fn update(&mut self, secs: f32) {
+        let t = smoothing(self.damping, secs);
+        let (current, target) = (&mut self.current, &self.target);
+        current.center += (target.center - current.center) * t;
+        current.zoom += (target.zoom - current.zoom) * t;
+    }
+
+    fn view(&self) -> Mat4 {
+        let View { center, zoom } = self.current;
+        Mat4::from_scale(Vec3::new(zoom, zoom, 1.0)) * Mat4::from_translation(-center.extend(0.0))
+    }
+
+    /// The same orthographic projection applied by the **Draw** renderer.
+    fn projection(&self, window: Rect) -> Mat4 {
+        draw::renderer::projection(window.w(), window.h())
+    }
+}
+
+impl Default for PanZoom {
+    fn default() -> Self {
+        Self::new(Point2::ZERO, 1.0)
+    }
+}
+
+impl Default for PanZoomBindings {
+    fn default() -> Self {
+        PanZoomBindings {
+            pan: MouseButton::Left,
+        }
+    }
+}
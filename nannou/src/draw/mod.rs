@@ -5,6 +5,9 @@
 use crate::geom::{self, Point2};
 use crate::glam::{vec3, EulerRot, Mat4, Quat, Vec2, Vec3};
 use crate::math::{deg_to_rad, turns_to_rad};
+use crate::scene;
+use crate::svg;
+use crate::text;
 use crate::wgpu;
 use lyon::path::PathEvent;
 use std::cell::RefCell;
@@ -106,6 +109,9 @@ pub struct State {
     last_draw_context: Option<Context>,
     /// If `Some`, the **Draw** should first clear the frame's texture with the given color.
     background_color: Option<properties::LinSrgba>,
+    /// If `Some`, the projection applied by the renderer in place of its default orthographic
+    /// projection.
+    projection: Option<Mat4>,
     /// Primitives that are in the process of being drawn.
     ///
     /// Keys are indices into the `draw_commands` Vec.
@@ -149,6 +155,7 @@ impl State {
     // Resets all state within the `Draw` instance.
     fn reset(&mut self) {
         self.background_color = None;
+        self.projection = None;
         self.last_draw_context = None;
         self.drawing.clear();
         self.draw_commands.clear();
@@ -192,6 +199,19 @@ impl Draw {
         self.state.borrow_mut().reset();
     }
 
+    /// Specify the projection from the **Draw**'s coordinates to clip space, replacing the
+    /// renderer's default orthographic projection.
+    ///
+    /// Unlike the transform, the projection is applied to the whole **Draw**ing by the GPU,
+    /// allowing for perspective projections with correct clipping and texturing, e.g. the
+    /// `view_projection` of a `Camera`. Clip space depth should lie within `0.0..=1.0`.
+    ///
+    /// The projection is shared by all **Draw** instances that share this state and is cleared
+    /// upon `reset`.
+    pub fn set_projection(&self, projection: Mat4) {
+        self.state.borrow_mut().projection = Some(projection);
+    }
+
     // Context changes.
 
     /// Produce a new **Draw** instance transformed by the given transform matrix.
@@ -513,6 +533,22 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Draw all paths within the given SVG document, centred at the origin.
+    ///
+    /// Each path is filled and stroked according to its style within the document. Transforms
+    /// may be applied to the document as a whole via the **Draw** context, e.g.
+    /// `draw.scale(2.0).svg(&doc)`.
+    pub fn svg(&self, document: &svg::Document) {
+        svg::draw(self, document);
+    }
+
+    /// Draw every visible node within the given **Scene**.
+    ///
+    /// The world transform of each node is applied on top of the transform of this **Draw**.
+    pub fn scene(&self, scene: &scene::Scene) {
+        scene.draw(self);
+    }
+
     /// Begin drawing an **Ellipse**.
     pub fn ellipse(&self) -> Drawing<primitive::Ellipse> {
         self.a(Default::default())
@@ -571,6 +607,20 @@ impl Draw {
         self.a(text)
     }
 
+    /// Begin drawing the caret, selection and preedit highlights of a **TextEdit**.
+    ///
+    /// The text itself may be drawn via `draw.text(&edit.display_text())` with the same layout,
+    /// dimensions and position.
+    pub fn text_edit(&self, edit: &text::TextEdit) -> Drawing<primitive::TextEdit> {
+        let text_edit = {
+            let state = self.state.borrow();
+            let mut intermediary_state = state.intermediary_state.borrow_mut();
+            let ctxt = DrawingContext::from_intermediary_state(&mut *intermediary_state);
+            primitive::text_edit::TextEdit::new(ctxt, edit)
+        };
+        self.a(text_edit)
+    }
+
     /// Begin drawing a **Texture**.
     pub fn texture(&self, view: &dyn wgpu::ToTextureView) -> Drawing<primitive::Texture> {
         self.a(primitive::Texture::new(view))
@@ -615,6 +665,7 @@ impl Default for State {
     fn default() -> Self {
         let last_draw_context = None;
         let background_color = Default::default();
+        let projection = None;
         let draw_commands = Default::default();
         let drawing = Default::default();
         let intermediary_state = RefCell::new(Default::default());
@@ -626,6 +677,7 @@ impl Default for State {
             intermediary_state,
             theme,
             background_color,
+            projection,
         }
     }
 }
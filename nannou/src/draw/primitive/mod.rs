@@ -7,6 +7,7 @@ pub mod polygon;
 pub mod quad;
 pub mod rect;
 pub mod text;
+pub mod text_edit;
 pub mod texture;
 pub mod tri;
 
@@ -19,6 +20,7 @@ pub use self::polygon::{Polygon, PolygonInit};
 pub use self::quad::Quad;
 pub use self::rect::Rect;
 pub use self::text::Text;
+pub use self::text_edit::TextEdit;
 pub use self::texture::Texture;
 pub use self::tri::Tri;
 
@@ -43,6 +45,7 @@ pub enum Primitive {
     Quad(Quad),
     Rect(Rect),
     Text(Text),
+    TextEdit(TextEdit),
     Texture(Texture),
     Tri(Tri),
 }
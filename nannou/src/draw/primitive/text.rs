@@ -7,6 +7,7 @@ use crate::draw::properties::{
 };
 use crate::draw::{self, theme, Drawing};
 use crate::geom::{self, Point2};
+use crate::glam::{Mat4, Vec2};
 use crate::text::{self, Align, Font, FontSize, Justify, Layout, Scalar, Wrap};
 
 /// Properties related to drawing the **Text** primitive.
@@ -24,6 +25,20 @@ pub struct Style {
     pub color: Option<LinSrgba>,
     pub glyph_colors: Vec<LinSrgba>, // Overrides `color` if non-empty.
     pub layout: text::layout::Builder,
+    /// Render the text via the signed distance field glyph cache.
+    ///
+    /// Implied by `outline` and `glow`.
+    pub sdf: bool,
+    pub outline: Option<Effect>,
+    pub glow: Option<Effect>,
+}
+
+/// An outline or glow effect applied to text rendered via the SDF glyph cache.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Effect {
+    /// The width of the outline or the radius of the glow in points.
+    pub width: f32,
+    pub color: LinSrgba,
 }
 
 /// The drawing context for the **Text** primitive.
@@ -156,6 +171,34 @@ impl Text {
         self.style.glyph_colors = colors;
         self
     }
+
+    /// Render the text via the renderer's signed distance field glyph cache.
+    ///
+    /// SDF text remains crisp under any scale, rotation or zoom and does not require re-caching
+    /// glyphs when the size of the text changes.
+    pub fn sdf(mut self) -> Self {
+        self.style.sdf = true;
+        self
+    }
+
+    /// Draw an outline of the given width (in points) around each glyph.
+    ///
+    /// Implies `sdf`. The maximum width is limited by the renderer's `sdf_glyph_spread`.
+    pub fn outline(mut self, width: f32, color: LinSrgba) -> Self {
+        self.style.outline = Some(Effect { width, color });
+        self
+    }
+
+    /// Draw a glow with the given radius (in points) around each glyph.
+    ///
+    /// Implies `sdf`. The maximum radius is limited by the renderer's `sdf_glyph_spread`.
+    pub fn glow(mut self, radius: f32, color: LinSrgba) -> Self {
+        self.style.glow = Some(Effect {
+            width: radius,
+            color,
+        });
+        self
+    }
 }
 
 impl<'a> DrawingText<'a> {
@@ -257,6 +300,33 @@ impl<'a> DrawingText<'a> {
 
         self.map_ty(|ty| ty.glyph_colors(glyph_colors))
     }
+
+    /// Render the text via the renderer's signed distance field glyph cache.
+    ///
+    /// SDF text remains crisp under any scale, rotation or zoom.
+    pub fn sdf(self) -> Self {
+        self.map_ty(|ty| ty.sdf())
+    }
+
+    /// Draw an outline of the given width (in points) around each glyph.
+    ///
+    /// Implies `sdf`.
+    pub fn outline<C>(self, width: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.outline(width, color.into_lin_srgba()))
+    }
+
+    /// Draw a glow with the given radius (in points) around each glyph.
+    ///
+    /// Implies `sdf`.
+    pub fn glow<C>(self, radius: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.glow(radius, color.into_lin_srgba()))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Text {
@@ -274,6 +344,9 @@ impl draw::renderer::RenderPrimitive for Text {
             color,
             glyph_colors,
             layout,
+            sdf,
+            outline,
+            glow,
         } = style;
         let layout = layout.build();
         let (maybe_x, maybe_y, maybe_z) = (
@@ -293,6 +366,35 @@ impl draw::renderer::RenderPrimitive for Text {
         let text_str = &ctxt.text_buffer[text.clone()];
         let text = text::text(text_str).layout(&layout).build(rect);
 
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = spatial.position.transform() * spatial.orientation.transform();
+        let transform = global_transform * local_transform;
+
+        // Skips non-rendered colors (e.g. due to line breaks),
+        //   assuming LineInfos are ordered by ascending character position.
+        let glyph_colors_iter = text
+            .line_infos()
+            .iter()
+            .flat_map(|li| li.char_range())
+            .take_while(|&i| i < glyph_colors.len())
+            .map(|i| &glyph_colors[i])
+            // Repeat `color` if more glyphs than glyph_colors
+            .chain(std::iter::repeat(&color));
+
+        if sdf || outline.is_some() || glow.is_some() {
+            render_sdf(
+                ctxt,
+                mesh,
+                &text,
+                transform,
+                glyph_colors_iter,
+                outline,
+                glow,
+            );
+            return draw::renderer::PrimitiveRender::text_sdf();
+        }
+
         // Queue the glyphs to be cached
         let font_id = text::font::id(text.font());
         let positioned_glyphs: Vec<_> = text
@@ -306,45 +408,10 @@ impl draw::renderer::RenderPrimitive for Text {
         }
 
         // Cache the enqueued glyphs within the pixel buffer.
-        let (glyph_cache_w, _) = ctxt.glyph_cache.dimensions();
-        {
-            let draw::renderer::RenderContext {
-                glyph_cache:
-                    &mut draw::renderer::GlyphCache {
-                        ref mut cache,
-                        ref mut pixel_buffer,
-                        ref mut requires_upload,
-                        ..
-                    },
-                ..
-            } = ctxt;
-            let glyph_cache_w = glyph_cache_w as usize;
-            let res = cache.cache_queued(|rect, data| {
-                let width = (rect.max.x - rect.min.x) as usize;
-                let height = (rect.max.y - rect.min.y) as usize;
-                let mut dst_ix = rect.min.y as usize * glyph_cache_w + rect.min.x as usize;
-                let mut src_ix = 0;
-                for _ in 0..height {
-                    let dst_range = dst_ix..dst_ix + width;
-                    let src_range = src_ix..src_ix + width;
-                    let dst_slice = &mut pixel_buffer[dst_range];
-                    let src_slice = &data[src_range];
-                    dst_slice.copy_from_slice(src_slice);
-                    dst_ix += glyph_cache_w;
-                    src_ix += width;
-                }
-                *requires_upload = true;
-            });
-            if let Err(err) = res {
-                eprintln!("failed to cache queued glyphs: {}", err);
-            }
+        if let Err(err) = ctxt.glyph_cache.cache_queued_glyphs() {
+            eprintln!("failed to cache queued glyphs: {}", err);
         }
 
-        // Determine the transform to apply to all points.
-        let global_transform = *ctxt.transform;
-        let local_transform = spatial.position.transform() * spatial.orientation.transform();
-        let transform = global_transform * local_transform;
-
         // A function for converting RustType rects to nannou rects.
         let scale_factor = ctxt.output_attachment_scale_factor;
         let (out_w, out_h) = ctxt.output_attachment_size.into();
@@ -357,17 +424,6 @@ impl draw::renderer::RenderPrimitive for Text {
             geom::Rect::from_corners([l, b].into(), [r, t].into())
         };
 
-        // Skips non-rendered colors (e.g. due to line breaks),
-        //   assuming LineInfos are ordered by ascending character position.
-        let glyph_colors_iter = text
-            .line_infos()
-            .iter()
-            .flat_map(|li| li.char_range())
-            .take_while(|&i| i < glyph_colors.len())
-            .map(|i| &glyph_colors[i])
-            // Repeat `color` if more glyphs than glyph_colors
-            .chain(std::iter::repeat(&color));
-
         // Extend the mesh with a rect for each displayed glyph.
         for (g, g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
             if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), &g)
@@ -416,6 +472,114 @@ impl draw::renderer::RenderPrimitive for Text {
     }
 }
 
+// Render the given text via the SDF glyph cache.
+//
+// Glyphs are laid out in logical coordinates rather than pixel coordinates, as the distance field
+// may be sampled at any scale. The glow, outline and fill are pushed as separate layers of quads
+// in that order, so that each is drawn over the last.
+fn render_sdf<'a, I>(
+    ctxt: draw::renderer::RenderContext,
+    mesh: &mut draw::Mesh,
+    text: &text::Text,
+    transform: Mat4,
+    glyph_colors: I,
+    outline: Option<Effect>,
+    glow: Option<Effect>,
+) where
+    I: Iterator<Item = &'a LinSrgba>,
+{
+    let draw::renderer::RenderContext {
+        glyph_cache,
+        sdf_params_buffer,
+        ..
+    } = ctxt;
+
+    // With a zero-sized window and a scale factor of `1.0`, rusttype's pixel coordinates map
+    // directly to logical coordinates with the y axis flipped.
+    let font_id = text::font::id(text.font()).index();
+    let positioned_glyphs: Vec<_> = text.rt_glyphs(Vec2::ZERO, 1.0).collect();
+    for glyph in positioned_glyphs.iter() {
+        glyph_cache.queue_sdf_glyph(font_id, text.font(), glyph.id());
+    }
+
+    // Cache the enqueued glyphs within the SDF pixel buffer.
+    if let Err(err) = glyph_cache.cache_queued_sdf() {
+        eprintln!("failed to cache queued SDF glyphs: {}", err);
+    }
+
+    // Each layer is described by its color (or `None` for the glyph colors) and SDF params.
+    let sdf_cache = &glyph_cache.sdf_cache;
+    let outline_w = outline.map(|o| o.width).unwrap_or(0.0);
+    let mut layers = vec![];
+    if let Some(glow) = glow {
+        layers.push((Some(glow.color), outline_w, glow.width));
+    }
+    if let Some(outline) = outline {
+        layers.push((Some(outline.color), outline.width, 0.0));
+    }
+    let glyph_colors: Vec<LinSrgba> = glyph_colors
+        .take(positioned_glyphs.len())
+        .cloned()
+        .collect();
+    layers.push((None, 0.0, 0.0));
+
+    for (layer_color, dilate, softness) in layers {
+        for (i, g) in positioned_glyphs.iter().enumerate() {
+            let (uv_rect, screen_rect) = match sdf_cache.rect_for(font_id, g) {
+                Some(rects) => rects,
+                None => continue,
+            };
+            let g_color = layer_color.unwrap_or(glyph_colors[i]);
+
+            // Convert the effect distances to the atlas' normalised distance units.
+            let dilate = sdf_cache.normalised_distance(dilate, g.scale()).min(0.5);
+            let softness = sdf_cache
+                .normalised_distance(softness, g.scale())
+                .min(0.5 - dilate);
+            let params = draw::renderer::SdfParams { dilate, softness };
+
+            // Create a mesh-compatible vertex from the position and tex_coords.
+            let v = |x: f32, y: f32, tex_coords: [f32; 2]| -> draw::mesh::Vertex {
+                let p = transform.transform_point3([x, -y, 0.0].into());
+                let point = draw::mesh::vertex::Point::from(p);
+                draw::mesh::vertex::new(point, g_color, tex_coords.into())
+            };
+
+            // The sides of the screen and UV rects.
+            let (l, t, r, b) = (
+                screen_rect.min.x,
+                screen_rect.min.y,
+                screen_rect.max.x,
+                screen_rect.max.y,
+            );
+            let uv_l = uv_rect.min.x;
+            let uv_t = uv_rect.min.y;
+            let uv_r = uv_rect.max.x;
+            let uv_b = uv_rect.max.y;
+
+            // Insert the vertices.
+            let start_ix = mesh.points().len() as u32;
+            mesh.push_vertex(v(l, t, [uv_l, uv_t]));
+            mesh.push_vertex(v(l, b, [uv_l, uv_b]));
+            mesh.push_vertex(v(r, b, [uv_r, uv_b]));
+            mesh.push_vertex(v(r, t, [uv_r, uv_t]));
+            sdf_params_buffer.extend((0..4).map(|_| params));
+
+            // Now the indices.
+            let tl_ix = start_ix;
+            let bl_ix = start_ix + 1;
+            let br_ix = start_ix + 2;
+            let tr_ix = start_ix + 3;
+            mesh.push_index(tl_ix);
+            mesh.push_index(bl_ix);
+            mesh.push_index(br_ix);
+            mesh.push_index(tl_ix);
+            mesh.push_index(br_ix);
+            mesh.push_index(tr_ix);
+        }
+    }
+}
+
 impl SetOrientation for Text {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.spatial)
@@ -0,0 +1,286 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::drawing::DrawingContext;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, theme, Drawing};
+use crate::geom;
+use crate::glam::Mat4;
+use crate::text::{self, Layout};
+
+/// Properties related to drawing the caret, selection and preedit highlights of a
+/// **text::TextEdit**.
+///
+/// The text itself is drawn separately via `draw.text(&edit.display_text())`, using the same
+/// layout, dimensions and position.
+#[derive(Clone, Debug)]
+pub struct TextEdit {
+    spatial: spatial::Properties,
+    style: Style,
+    // The byte range of the edit's display text within the `Draw` context's text buffer.
+    text: std::ops::Range<usize>,
+    // The byte index of the caret within the display text.
+    caret: usize,
+    // The byte range of the selection within the display text.
+    selection: Option<std::ops::Range<usize>>,
+    // The byte range of the preedit within the display text.
+    preedit: Option<std::ops::Range<usize>>,
+}
+
+/// Styling properties for the **TextEdit** primitive.
+#[derive(Clone, Debug, Default)]
+pub struct Style {
+    /// The color of the caret and the preedit underline.
+    pub color: Option<LinSrgba>,
+    /// The color of the selection highlight.
+    ///
+    /// By default, this is the caret `color` at 30% opacity.
+    pub selection_color: Option<LinSrgba>,
+    pub layout: text::layout::Builder,
+    /// The width of the caret and the thickness of the preedit underline in points.
+    pub caret_width: Option<f32>,
+    /// Skip drawing the caret, e.g. to make it blink.
+    pub hide_caret: bool,
+}
+
+/// The drawing context for the **TextEdit** primitive.
+pub type DrawingTextEdit<'a> = Drawing<'a, TextEdit>;
+
+impl TextEdit {
+    /// The default width of the caret in points.
+    pub const DEFAULT_CARET_WIDTH: f32 = 1.0;
+
+    /// Begin drawing the highlights for the given **text::TextEdit**.
+    ///
+    /// The layout and dimensions are initialised to those of the edit.
+    pub fn new(ctxt: DrawingContext, edit: &text::TextEdit) -> Self {
+        let start = ctxt.text_buffer.len();
+        ctxt.text_buffer.push_str(&edit.display_text());
+        let end = ctxt.text_buffer.len();
+        let text = start..end;
+        let mut spatial = spatial::Properties::default();
+        let wh = edit.wh();
+        spatial.dimensions.x = Some(wh.x);
+        spatial.dimensions.y = Some(wh.y);
+        let style = Style {
+            layout: text::layout::Builder::default().layout(edit.layout()),
+            ..Default::default()
+        };
+        TextEdit {
+            spatial,
+            style,
+            text,
+            caret: edit.display_caret(),
+            selection: edit.selection(),
+            preedit: edit.display_preedit(),
+        }
+    }
+
+    /// Set all the layout parameters via an existing `Layout`.
+    ///
+    /// This should match the layout of the drawn text.
+    pub fn layout(mut self, layout: &Layout) -> Self {
+        self.style.layout = self.style.layout.layout(layout);
+        self
+    }
+
+    /// Specify the color of the selection highlight.
+    pub fn selection_color(mut self, color: LinSrgba) -> Self {
+        self.style.selection_color = Some(color);
+        self
+    }
+
+    /// Specify the width of the caret in points.
+    pub fn caret_width(mut self, width: f32) -> Self {
+        self.style.caret_width = Some(width);
+        self
+    }
+
+    /// Specify whether or not the caret should be drawn.
+    pub fn caret_visible(mut self, visible: bool) -> Self {
+        self.style.hide_caret = !visible;
+        self
+    }
+
+    /// Specify the entire styling for the **TextEdit**.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a> DrawingTextEdit<'a> {
+    /// Set all the layout parameters via an existing `Layout`.
+    ///
+    /// This should match the layout of the drawn text.
+    pub fn layout(self, layout: &Layout) -> Self {
+        self.map_ty(|ty| ty.layout(layout))
+    }
+
+    /// Specify the color of the selection highlight.
+    pub fn selection_color<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.selection_color(color.into_lin_srgba()))
+    }
+
+    /// Specify the width of the caret in points.
+    pub fn caret_width(self, width: f32) -> Self {
+        self.map_ty(|ty| ty.caret_width(width))
+    }
+
+    /// Specify whether or not the caret should be drawn.
+    pub fn caret_visible(self, visible: bool) -> Self {
+        self.map_ty(|ty| ty.caret_visible(visible))
+    }
+
+    /// Build the **TextEdit** with the given **Style**.
+    pub fn with_style(self, style: Style) -> Self {
+        self.map_ty(|ty| ty.with_style(style))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for TextEdit {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let TextEdit {
+            spatial,
+            style,
+            text,
+            caret,
+            selection,
+            preedit,
+        } = self;
+        let Style {
+            color,
+            selection_color,
+            layout,
+            caret_width,
+            hide_caret,
+        } = style;
+        let layout = layout.build();
+        let (maybe_x, maybe_y, maybe_z) = (
+            spatial.dimensions.x,
+            spatial.dimensions.y,
+            spatial.dimensions.z,
+        );
+        assert!(
+            maybe_z.is_none(),
+            "z dimension support for text edit is unimplemented"
+        );
+        let w = maybe_x.unwrap_or(200.0);
+        let h = maybe_y.unwrap_or(200.0);
+        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
+        let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
+        let selection_color = selection_color.unwrap_or_else(|| {
+            let mut c = color;
+            c.alpha *= 0.3;
+            c
+        });
+        let caret_width = caret_width.unwrap_or(Self::DEFAULT_CARET_WIDTH);
+
+        let text_str = &ctxt.text_buffer[text.clone()];
+        let text = text::text(text_str).layout(&layout).build(rect);
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = spatial.position.transform() * spatial.orientation.transform();
+        let transform = global_transform * local_transform;
+
+        // Highlight the selection.
+        if let Some(selection) = selection {
+            for r in text::edit::range_rects(&text, selection) {
+                push_rect(mesh, transform, r, selection_color);
+            }
+        }
+
+        // Underline the text being composed.
+        if let Some(preedit) = preedit {
+            for r in text::edit::range_rects(&text, preedit) {
+                let y = geom::Range::new(r.bottom(), r.bottom() + caret_width);
+                push_rect(mesh, transform, geom::Rect { x: r.x, y }, color);
+            }
+        }
+
+        // Draw the caret.
+        if !hide_caret {
+            let r = text::edit::caret_rect(&text, caret);
+            let x = geom::Range::from_pos_and_len(r.x(), caret_width);
+            push_rect(mesh, transform, geom::Rect { x, y: r.y }, color);
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+// Push the given rect to the mesh as two triangles.
+fn push_rect(mesh: &mut draw::Mesh, transform: Mat4, rect: geom::Rect, color: LinSrgba) {
+    let v = |x: f32, y: f32| -> draw::mesh::Vertex {
+        let p = transform.transform_point3([x, y, 0.0].into());
+        let point = draw::mesh::vertex::Point::from(p);
+        draw::mesh::vertex::new(point, color, [0.0; 2].into())
+    };
+    let (l, r, b, t) = rect.l_r_b_t();
+    let start_ix = mesh.points().len() as u32;
+    mesh.push_vertex(v(l, t));
+    mesh.push_vertex(v(l, b));
+    mesh.push_vertex(v(r, b));
+    mesh.push_vertex(v(r, t));
+    let tl_ix = start_ix;
+    let bl_ix = start_ix + 1;
+    let br_ix = start_ix + 2;
+    let tr_ix = start_ix + 3;
+    mesh.push_index(tl_ix);
+    mesh.push_index(bl_ix);
+    mesh.push_index(br_ix);
+    mesh.push_index(tl_ix);
+    mesh.push_index(br_ix);
+    mesh.push_index(tr_ix);
+}
+
+impl SetOrientation for TextEdit {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for TextEdit {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for TextEdit {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor<ColorScalar> for TextEdit {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.style.color)
+    }
+}
+
+// Primitive conversions.
+
+impl From<TextEdit> for Primitive {
+    fn from(prim: TextEdit) -> Self {
+        Primitive::TextEdit(prim)
+    }
+}
+
+impl Into<Option<TextEdit>> for Primitive {
+    fn into(self) -> Option<TextEdit> {
+        match self {
+            Primitive::TextEdit(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
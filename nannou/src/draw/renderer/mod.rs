@@ -5,10 +5,11 @@ use crate::geom::{self, Point2, Rect};
 use crate::glam::{Mat4, Vec2, Vec3};
 use crate::math::map_range;
 use crate::text;
+use crate::text::rt::gpu_cache::{CacheWriteErr, CachedBy};
 use crate::wgpu;
 use lyon::path::PathEvent;
 use lyon::tessellation::{FillTessellator, StrokeTessellator};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
@@ -42,6 +43,7 @@ pub struct RenderContext<'a> {
     pub text_buffer: &'a str,
     pub theme: &'a draw::Theme,
     pub glyph_cache: &'a mut GlyphCache,
+    pub sdf_params_buffer: &'a mut Vec<SdfParams>,
     pub fill_tessellator: &'a mut FillTessellator,
     pub stroke_tessellator: &'a mut StrokeTessellator,
     pub output_attachment_size: Vec2, // logical coords
@@ -55,6 +57,101 @@ pub struct GlyphCache {
     pub pixel_buffer: Vec<u8>,
     /// Will be set to `true` after the cache has been updated if the texture requires re-uploading.
     pub requires_upload: bool,
+    /// Tracks signed distance field glyphs and their location within the SDF atlas.
+    pub sdf_cache: text::sdf::Cache,
+    /// The buffer used to store the distance field of each SDF glyph.
+    pub sdf_pixel_buffer: Vec<u8>,
+    /// Will be set to `true` after the SDF cache has been updated if the texture requires
+    /// re-uploading.
+    pub sdf_requires_upload: bool,
+    /// The maximum dimensions to which the glyph cache textures may grow.
+    pub max_size: [u32; 2],
+    /// Statistics describing the usage of the glyph caches.
+    pub stats: GlyphCacheStats,
+    // Keys for glyphs that have been cached since the cache was last cleared.
+    cached_keys: HashSet<GlyphKey>,
+    // Keys for glyphs queued since the last call to `cache_queued_glyphs`.
+    queued_keys: Vec<GlyphKey>,
+    // Tracks glyph cache usage throughout the current frame.
+    frame: GlyphCacheFrame,
+    // Whether or not the glyph cache should grow at the start of the next frame.
+    grow_pending: bool,
+    // Whether or not the SDF cache should grow (or be cleared if at its maximum size) at the
+    // start of the next frame.
+    sdf_grow_pending: bool,
+}
+
+/// Statistics describing the usage of the renderer's **GlyphCache**.
+///
+/// Counts accumulate from the moment the renderer is created until `GlyphCache::reset_stats`
+/// is called.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GlyphCacheStats {
+    /// The number of glyphs queued for caching.
+    pub queued: u64,
+    /// The number of glyphs rasterized and written to the cache.
+    pub uploads: u64,
+    /// The number of glyphs that had to be re-uploaded after being evicted to make room for
+    /// others.
+    pub evictions: u64,
+    /// The number of times the cache had to be cleared and re-packed to fit the queued glyphs.
+    pub reorders: u64,
+    /// The number of times the cache texture has grown.
+    pub resizes: u64,
+    /// The number of times that queued glyphs could not be cached.
+    ///
+    /// Glyphs that do not fit are skipped for the remainder of the frame and the cache grows
+    /// before the next frame.
+    pub failures: u64,
+    /// The number of distance field glyphs written to the SDF cache.
+    pub sdf_uploads: u64,
+    /// The number of times the SDF cache texture has grown.
+    pub sdf_resizes: u64,
+    /// The number of times the SDF cache was full at its maximum size and had to be cleared.
+    pub sdf_clears: u64,
+    /// The number of times that queued SDF glyphs could not be cached.
+    pub sdf_failures: u64,
+    /// The texel area of all unique glyphs drawn during the last frame relative to the area of
+    /// the glyph cache texture.
+    ///
+    /// Values approaching or exceeding `1.0` indicate that glyphs are likely being evicted while
+    /// still in use. The cache grows automatically once this exceeds
+    /// `GlyphCache::GROW_PRESSURE`.
+    pub pressure: f32,
+    /// The fraction of the SDF cache texture that is currently occupied.
+    pub sdf_pressure: f32,
+}
+
+// Identifies a glyph within the rusttype cache with the same accuracy as the cache itself.
+type GlyphKey = (usize, text::GlyphId, (u32, u32), (u16, u16));
+
+// Glyph cache usage throughout a single frame.
+#[derive(Debug, Default)]
+struct GlyphCacheFrame {
+    // Keys of all unique glyphs queued during the frame.
+    keys: HashSet<GlyphKey>,
+    // The total texel area of the unique glyphs queued during the frame.
+    area: u64,
+    // The number of calls to `cache_queued_glyphs` during the frame. Once non-zero, texture
+    // coordinates may have been handed out and the cache must not be resized until the next frame.
+    batches: usize,
+    // The number of calls to `cache_queued_sdf` during the frame.
+    sdf_batches: usize,
+    // Whether or not glyphs were evicted while still in use during the frame.
+    invalidated: bool,
+}
+
+/// Per-vertex parameters used to render text in the `VertexMode::TextSdf` mode.
+///
+/// Distances are in the normalised units of the SDF atlas, where `0.5` is the edge of the glyph.
+/// See `text::sdf::Cache::normalised_distance`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SdfParams {
+    /// Shifts the edge of the glyph outwards. Used to render outlines.
+    pub dilate: f32,
+    /// Extends the falloff beyond the edge of the glyph. Used to render glows.
+    pub softness: f32,
 }
 
 /// A top-level indicator of whether or not
@@ -69,6 +166,11 @@ pub enum VertexMode {
     ///
     /// Uses the color values, but multiplies the alpha by the glyph cache texture's red value.
     Text = 2,
+    /// A special mode used by the text primitive when rendering via the SDF glyph cache.
+    ///
+    /// Uses the color values, but multiplies the alpha by the coverage determined from the SDF
+    /// glyph cache texture's distance value and the vertex's `SdfParams`.
+    TextSdf = 3,
 }
 
 /// A helper type aimed at simplifying the rendering of conrod primitives via wgpu.
@@ -80,6 +182,7 @@ pub struct Renderer {
     // One pipeline per unique Pipeline ID (combination of blend, topology and component type).
     pipelines: HashMap<PipelineId, wgpu::RenderPipeline>,
     glyph_cache_texture: wgpu::Texture,
+    sdf_glyph_cache_texture: wgpu::Texture,
     depth_texture: wgpu::Texture,
     depth_texture_view: wgpu::TextureView,
     default_texture: wgpu::Texture,
@@ -88,15 +191,18 @@ pub struct Renderer {
     uniform_bind_group: wgpu::BindGroup,
     text_bind_group_layout: wgpu::BindGroupLayout,
     text_bind_group: wgpu::BindGroup,
+    text_sampler: wgpu::Sampler,
     texture_samplers: HashMap<SamplerId, wgpu::Sampler>,
     texture_bind_group_layouts: HashMap<wgpu::TextureSampleType, wgpu::BindGroupLayout>,
     texture_bind_groups: HashMap<BindGroupId, wgpu::BindGroup>,
     output_color_format: wgpu::TextureFormat,
     sample_count: u32,
     scale_factor: f32,
+    projection: Option<Mat4>,
     render_commands: Vec<RenderCommand>,
     mesh: draw::Mesh,
     vertex_mode_buffer: Vec<VertexMode>,
+    sdf_params_buffer: Vec<SdfParams>,
     uniform_buffer: wgpu::Buffer,
 }
 
@@ -107,6 +213,10 @@ pub struct Builder {
     pub glyph_cache_size: [u32; 2],
     pub glyph_cache_scale_tolerance: f32,
     pub glyph_cache_position_tolerance: f32,
+    pub sdf_glyph_cache_size: [u32; 2],
+    pub sdf_glyph_scale: f32,
+    pub sdf_glyph_spread: u32,
+    pub glyph_cache_max_size: [u32; 2],
 }
 
 /// Commands that map to wgpu encodable commands.
@@ -184,6 +294,7 @@ impl RenderPrimitive for draw::Primitive {
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Text(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::TextEdit(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Texture(prim) => prim.render_primitive(ctxt, mesh),
             _ => PrimitiveRender::default(),
         }
@@ -196,6 +307,11 @@ impl fmt::Debug for GlyphCache {
             .field("cache", &self.cache.dimensions())
             .field("pixel_buffer", &self.pixel_buffer.len())
             .field("requires_upload", &self.requires_upload)
+            .field("sdf_cache", &self.sdf_cache.dimensions())
+            .field("sdf_pixel_buffer", &self.sdf_pixel_buffer.len())
+            .field("sdf_requires_upload", &self.sdf_requires_upload)
+            .field("max_size", &self.max_size)
+            .field("stats", &self.stats)
             .finish()
     }
 }
@@ -223,6 +339,10 @@ impl PrimitiveRender {
     pub fn text() -> Self {
         Self::vertex_mode(VertexMode::Text)
     }
+
+    pub fn text_sdf() -> Self {
+        Self::vertex_mode(VertexMode::TextSdf)
+    }
 }
 
 impl Builder {
@@ -236,6 +356,14 @@ impl Builder {
     /// The default position tolerance for the glyph cache.
     pub const DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE: f32 =
         Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE;
+    /// The default size for the inner SDF glyph cache.
+    pub const DEFAULT_SDF_GLYPH_CACHE_SIZE: [u32; 2] = Renderer::DEFAULT_SDF_GLYPH_CACHE_SIZE;
+    /// The default scale at which glyphs are rasterized for the SDF glyph cache.
+    pub const DEFAULT_SDF_GLYPH_SCALE: f32 = Renderer::DEFAULT_SDF_GLYPH_SCALE;
+    /// The default distance encoded either side of the edge of each SDF glyph.
+    pub const DEFAULT_SDF_GLYPH_SPREAD: u32 = Renderer::DEFAULT_SDF_GLYPH_SPREAD;
+    /// The default maximum size to which the glyph caches may grow.
+    pub const DEFAULT_GLYPH_CACHE_MAX_SIZE: [u32; 2] = Renderer::DEFAULT_GLYPH_CACHE_MAX_SIZE;
 
     /// Begin building a new **draw::Renderer**.
     pub fn new() -> Self {
@@ -244,6 +372,10 @@ impl Builder {
             glyph_cache_size: Self::DEFAULT_GLYPH_CACHE_SIZE,
             glyph_cache_scale_tolerance: Self::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
             glyph_cache_position_tolerance: Self::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+            sdf_glyph_cache_size: Self::DEFAULT_SDF_GLYPH_CACHE_SIZE,
+            sdf_glyph_scale: Self::DEFAULT_SDF_GLYPH_SCALE,
+            sdf_glyph_spread: Self::DEFAULT_SDF_GLYPH_SPREAD,
+            glyph_cache_max_size: Self::DEFAULT_GLYPH_CACHE_MAX_SIZE,
         }
     }
 
@@ -254,11 +386,12 @@ impl Builder {
         self
     }
 
-    /// The dimensions of the texture used to cache glyphs.
+    /// The initial dimensions of the texture used to cache glyphs.
     ///
-    /// Some text-heavy apps may require a text cache larger than the default size in order to run
-    /// efficiently without text glitching. If the texture is insufficiently large for all text
-    /// currently appearing within the output attachment, artifacts will appear in the text.
+    /// The glyph cache grows automatically (up to `glyph_cache_max_size`) when the glyphs drawn
+    /// within a frame no longer fit. Growth is applied between frames, so glyphs that do not fit
+    /// are skipped for the frame in which the cache overflows. Text-heavy apps may specify a
+    /// larger initial size in order to avoid this.
     pub fn glyph_cache_size(mut self, size: [u32; 2]) -> Self {
         self.glyph_cache_size = size;
         self
@@ -283,6 +416,42 @@ impl Builder {
         self
     }
 
+    /// The dimensions of the texture used to cache signed distance field glyphs.
+    ///
+    /// SDF glyphs are cached once per font and glyph regardless of the size, rotation or zoom at
+    /// which they are drawn, so this atlas typically fills far more slowly than the regular
+    /// glyph cache.
+    pub fn sdf_glyph_cache_size(mut self, size: [u32; 2]) -> Self {
+        self.sdf_glyph_cache_size = size;
+        self
+    }
+
+    /// The scale in pixels at which glyphs are rasterized before being converted to distance
+    /// fields.
+    ///
+    /// Higher values preserve more detail in sharp corners at the cost of atlas space.
+    pub fn sdf_glyph_scale(mut self, scale: f32) -> Self {
+        self.sdf_glyph_scale = scale;
+        self
+    }
+
+    /// The distance in pixels (at the `sdf_glyph_scale`) encoded either side of the edge of each
+    /// SDF glyph.
+    ///
+    /// This limits the maximum width of text outlines and glows.
+    pub fn sdf_glyph_spread(mut self, spread: u32) -> Self {
+        self.sdf_glyph_spread = spread;
+        self
+    }
+
+    /// The maximum dimensions to which the glyph cache textures may grow.
+    ///
+    /// This should not exceed the maximum texture dimensions supported by the device.
+    pub fn glyph_cache_max_size(mut self, size: [u32; 2]) -> Self {
+        self.glyph_cache_max_size = size;
+        self
+    }
+
     /// Build the **draw::Renderer** ready to target an output attachment of the given descriptor.
     pub fn build_from_texture_descriptor(
         self,
@@ -319,12 +488,28 @@ impl Builder {
             self.glyph_cache_size,
             self.glyph_cache_scale_tolerance,
             self.glyph_cache_position_tolerance,
+            self.sdf_glyph_cache_size,
+            self.sdf_glyph_scale,
+            self.sdf_glyph_spread,
+            self.glyph_cache_max_size,
         )
     }
 }
 
 impl GlyphCache {
-    fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
+    /// Create a new, empty glyph cache.
+    ///
+    /// This is normally constructed by the **Renderer**. See the **Builder** methods for a
+    /// description of each parameter.
+    pub fn new(
+        size: [u32; 2],
+        scale_tolerance: f32,
+        position_tolerance: f32,
+        sdf_size: [u32; 2],
+        sdf_glyph_scale: f32,
+        sdf_glyph_spread: u32,
+        max_size: [u32; 2],
+    ) -> Self {
         let [w, h] = size;
         let cache = text::GlyphCache::builder()
             .dimensions(w, h)
@@ -334,12 +519,261 @@ impl GlyphCache {
             .into();
         let pixel_buffer = vec![0u8; w as usize * h as usize];
         let requires_upload = false;
+        let sdf_cache = text::sdf::Cache::new(sdf_size, sdf_glyph_scale, sdf_glyph_spread);
+        let [sdf_w, sdf_h] = sdf_size;
+        let sdf_pixel_buffer = vec![0u8; sdf_w as usize * sdf_h as usize];
+        let sdf_requires_upload = false;
         GlyphCache {
             cache,
             pixel_buffer,
             requires_upload,
+            sdf_cache,
+            sdf_pixel_buffer,
+            sdf_requires_upload,
+            max_size,
+            stats: Default::default(),
+            cached_keys: Default::default(),
+            queued_keys: Default::default(),
+            frame: Default::default(),
+            grow_pending: false,
+            sdf_grow_pending: false,
         }
     }
+
+    /// The `pressure` beyond which the glyph caches are grown ahead of the next frame.
+    pub const GROW_PRESSURE: f32 = 0.6;
+
+    /// Queue the given glyph to be cached during the next call to `cache_queued_glyphs`.
+    pub fn queue_glyph(&mut self, font_id: usize, glyph: text::PositionedGlyph) {
+        let bb = match glyph.pixel_bounding_box() {
+            Some(bb) => bb,
+            None => return,
+        };
+        let key = glyph_key(&self.cache, font_id, &glyph);
+        self.stats.queued += 1;
+
+        // Check whether or not the glyph was evicted since it was last cached.
+        let is_cached = matches!(self.cache.rect_for(font_id, &glyph), Ok(Some(_)));
+        if !is_cached && self.cached_keys.remove(&key) {
+            self.stats.evictions += 1;
+            if self.frame.keys.contains(&key) {
+                self.frame.invalidated = true;
+            }
+        }
+
+        // Track the working set of glyphs for the frame. Glyphs are padded by 1 texel per side.
+        if self.frame.keys.insert(key) {
+            let (w, h) = (bb.width() as u64 + 2, bb.height() as u64 + 2);
+            self.frame.area += w * h;
+        }
+
+        self.queued_keys.push(key);
+        self.cache.queue_glyph(font_id, glyph);
+    }
+
+    /// Cache all queued glyphs, writing them to the `pixel_buffer`.
+    ///
+    /// If the queued glyphs do not fit, the cache grows up to its `max_size`. As resizing
+    /// invalidates all texture coordinates, the cache only grows immediately if no glyphs have
+    /// been cached yet during the current frame. Otherwise the queue is cleared, an error is
+    /// returned and the cache grows at the start of the next frame.
+    pub fn cache_queued_glyphs(&mut self) -> Result<CachedBy, CacheWriteErr> {
+        let res = loop {
+            let res = {
+                let GlyphCache {
+                    ref mut cache,
+                    ref mut pixel_buffer,
+                    ref mut requires_upload,
+                    ref mut stats,
+                    ..
+                } = *self;
+                let (cache_w, _) = cache.dimensions();
+                cache.cache_queued(|rect, data| {
+                    write_glyph_pixels(pixel_buffer, cache_w, rect, data);
+                    stats.uploads += 1;
+                    *requires_upload = true;
+                })
+            };
+            match res {
+                Err(_) if self.frame.batches == 0 && self.grow() => continue,
+                res => break res,
+            }
+        };
+        match res {
+            Ok(cached_by) => {
+                if let CachedBy::Reordering = cached_by {
+                    self.stats.reorders += 1;
+                    self.cached_keys.clear();
+                    if self.frame.batches > 0 {
+                        self.frame.invalidated = true;
+                    }
+                }
+                self.cached_keys.extend(self.queued_keys.drain(..));
+            }
+            Err(_) => {
+                self.stats.failures += 1;
+                self.grow_pending = true;
+                self.queued_keys.clear();
+                self.cache.clear_queue();
+            }
+        }
+        self.frame.batches += 1;
+        res
+    }
+
+    /// Queue the glyph with the given ID to be cached within the SDF cache during the next call
+    /// to `cache_queued_sdf`.
+    pub fn queue_sdf_glyph(&mut self, font_id: usize, font: &text::Font, glyph_id: text::GlyphId) {
+        self.sdf_cache.queue_glyph(font_id, font, glyph_id);
+    }
+
+    /// Cache all glyphs queued for the SDF cache, writing them to the `sdf_pixel_buffer`.
+    ///
+    /// If the queued glyphs do not fit, the SDF cache grows up to the `max_size`, or is cleared
+    /// if it is already at its maximum size. As with `cache_queued_glyphs`, this only occurs
+    /// immediately if no SDF glyphs have been cached yet during the current frame. Otherwise the
+    /// queue is cleared, an error is returned and the cache grows at the start of the next frame.
+    pub fn cache_queued_sdf(&mut self) -> Result<(), CacheWriteErr> {
+        let mut cleared = false;
+        let res = loop {
+            let res = {
+                let GlyphCache {
+                    ref mut sdf_cache,
+                    ref mut sdf_pixel_buffer,
+                    ref mut sdf_requires_upload,
+                    ref mut stats,
+                    ..
+                } = *self;
+                let (cache_w, _) = sdf_cache.dimensions();
+                sdf_cache.cache_queued(|rect, data| {
+                    write_glyph_pixels(sdf_pixel_buffer, cache_w, rect, data);
+                    stats.sdf_uploads += 1;
+                    *sdf_requires_upload = true;
+                })
+            };
+            match res {
+                Err(_) if self.frame.sdf_batches == 0 && self.grow_sdf() => continue,
+                Err(_) if self.frame.sdf_batches == 0 && !cleared => {
+                    self.sdf_cache.clear();
+                    self.stats.sdf_clears += 1;
+                    cleared = true;
+                }
+                res => break res,
+            }
+        };
+        if res.is_err() {
+            self.stats.sdf_failures += 1;
+            self.sdf_grow_pending = true;
+            self.sdf_cache.clear_queue();
+        }
+        self.frame.sdf_batches += 1;
+        res
+    }
+
+    /// Reset all statistics to their defaults.
+    pub fn reset_stats(&mut self) {
+        self.stats = Default::default();
+    }
+
+    /// Resize the glyph cache to the given dimensions.
+    ///
+    /// All cached glyphs are cleared, though any queued glyphs remain queued.
+    pub fn resize(&mut self, [w, h]: [u32; 2]) {
+        self.cache
+            .to_builder()
+            .dimensions(w, h)
+            .rebuild(&mut self.cache);
+        self.pixel_buffer = vec![0u8; w as usize * h as usize];
+        self.requires_upload = true;
+        self.cached_keys.clear();
+    }
+
+    /// Resize the SDF glyph cache to the given dimensions.
+    ///
+    /// If the cache grows, all cached glyphs retain their position within the cache.
+    pub fn resize_sdf(&mut self, [w, h]: [u32; 2]) {
+        let (old_w, old_h) = self.sdf_cache.dimensions();
+        let mut pixel_buffer = vec![0u8; w as usize * h as usize];
+        if w >= old_w && h >= old_h {
+            for (src, dst) in self
+                .sdf_pixel_buffer
+                .chunks(old_w as usize)
+                .zip(pixel_buffer.chunks_mut(w as usize))
+            {
+                dst[..src.len()].copy_from_slice(src);
+            }
+        }
+        self.sdf_cache.resize([w, h]);
+        self.sdf_pixel_buffer = pixel_buffer;
+        self.sdf_requires_upload = true;
+    }
+
+    // Double the size of the glyph cache up to the `max_size`.
+    //
+    // Returns `false` if the cache is already at its maximum size.
+    fn grow(&mut self) -> bool {
+        let (w, h) = self.cache.dimensions();
+        match grown_size([w, h], self.max_size) {
+            None => false,
+            Some(size) => {
+                self.resize(size);
+                self.stats.resizes += 1;
+                true
+            }
+        }
+    }
+
+    // Double the size of the SDF glyph cache up to the `max_size`.
+    //
+    // Returns `false` if the cache is already at its maximum size.
+    fn grow_sdf(&mut self) -> bool {
+        let (w, h) = self.sdf_cache.dimensions();
+        match grown_size([w, h], self.max_size) {
+            None => false,
+            Some(size) => {
+                self.resize_sdf(size);
+                self.stats.sdf_resizes += 1;
+                true
+            }
+        }
+    }
+
+    /// Apply any growth scheduled by the previous frame.
+    ///
+    /// This is called by the **Renderer** before the primitives of each frame are rendered, and
+    /// after the previous frame has been encoded using the old texture coordinates.
+    pub fn begin_frame(&mut self) {
+        if std::mem::take(&mut self.grow_pending) {
+            self.grow();
+        }
+        if std::mem::take(&mut self.sdf_grow_pending) && !self.grow_sdf() {
+            self.sdf_cache.clear();
+            self.stats.sdf_clears += 1;
+        }
+    }
+
+    /// Update the pressure statistics for the frame and schedule the caches to grow ahead of the
+    /// next frame if necessary.
+    ///
+    /// This is called by the **Renderer** once all primitives of a frame have been rendered.
+    pub fn end_frame(&mut self) {
+        let (w, h) = self.cache.dimensions();
+        let area = (w as u64 * h as u64).max(1);
+        self.stats.pressure = self.frame.area as f32 / area as f32;
+        if self.stats.pressure > Self::GROW_PRESSURE || self.frame.invalidated {
+            self.grow_pending = true;
+        }
+        self.stats.sdf_pressure = self.sdf_cache.occupancy();
+        if self.stats.sdf_pressure > Self::GROW_PRESSURE {
+            self.sdf_grow_pending = true;
+        }
+        let mut keys = std::mem::take(&mut self.frame.keys);
+        keys.clear();
+        self.frame = GlyphCacheFrame {
+            keys,
+            ..Default::default()
+        };
+    }
 }
 
 impl Renderer {
@@ -351,6 +785,14 @@ impl Renderer {
     pub const DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE: f32 = 0.1;
     /// The default position tolerance for the glyph cache.
     pub const DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE: f32 = 0.1;
+    /// The default size for the inner SDF glyph cache.
+    pub const DEFAULT_SDF_GLYPH_CACHE_SIZE: [u32; 2] = [1024; 2];
+    /// The default scale at which glyphs are rasterized for the SDF glyph cache.
+    pub const DEFAULT_SDF_GLYPH_SCALE: f32 = text::sdf::Cache::DEFAULT_GLYPH_SCALE;
+    /// The default distance encoded either side of the edge of each SDF glyph.
+    pub const DEFAULT_SDF_GLYPH_SPREAD: u32 = text::sdf::Cache::DEFAULT_SPREAD;
+    /// The default maximum size to which the glyph caches may grow.
+    pub const DEFAULT_GLYPH_CACHE_MAX_SIZE: [u32; 2] = [4096; 2];
     /// The texture format of the inner glyph cache.
     pub const GLYPH_CACHE_TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
     /// The index format used to index into vertices.
@@ -366,6 +808,9 @@ impl Renderer {
     ///
     /// The `glyph_cache_size` will be used to create a texture on which glyphs will be stored for
     /// efficient look-up.
+    ///
+    /// The `sdf_glyph_cache_size` will be used to create a texture on which signed distance field
+    /// glyphs will be stored for text that is drawn in SDF mode.
     pub fn new(
         device: &wgpu::Device,
         output_attachment_size: [u32; 2],
@@ -376,12 +821,20 @@ impl Renderer {
         glyph_cache_size: [u32; 2],
         glyph_cache_scale_tolerance: f32,
         glyph_cache_position_tolerance: f32,
+        sdf_glyph_cache_size: [u32; 2],
+        sdf_glyph_scale: f32,
+        sdf_glyph_spread: u32,
+        glyph_cache_max_size: [u32; 2],
     ) -> Self {
         // Construct the glyph cache.
         let glyph_cache = GlyphCache::new(
             glyph_cache_size,
             glyph_cache_scale_tolerance,
             glyph_cache_position_tolerance,
+            sdf_glyph_cache_size,
+            sdf_glyph_scale,
+            sdf_glyph_spread,
+            glyph_cache_max_size,
         );
 
         // Load shader modules.
@@ -392,14 +845,15 @@ impl Renderer {
         let text_sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
         let text_sampler_filtering = wgpu::sampler_filtering(&text_sampler_desc);
         let text_sampler = device.create_sampler(&text_sampler_desc);
-        let glyph_cache_texture = wgpu::TextureBuilder::new()
-            .size(glyph_cache_size)
-            .usage(wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST)
-            .format(Self::GLYPH_CACHE_TEXTURE_FORMAT)
-            .build(device);
+        let glyph_cache_texture = create_glyph_cache_texture(device, glyph_cache_size);
         let glyph_cache_texture_view =
             glyph_cache_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
+        // Create the SDF glyph cache texture.
+        let sdf_glyph_cache_texture = create_glyph_cache_texture(device, sdf_glyph_cache_size);
+        let sdf_glyph_cache_texture_view =
+            sdf_glyph_cache_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
         // Create the depth texture.
         let depth_texture =
             create_depth_texture(device, output_attachment_size, depth_format, sample_count);
@@ -413,7 +867,7 @@ impl Renderer {
         let default_texture_view = default_texture.view().build();
 
         // Initial uniform buffer values. These will be overridden on draw.
-        let uniforms = create_uniforms(output_attachment_size, output_scale_factor);
+        let uniforms = create_uniforms(output_attachment_size, output_scale_factor, None);
         let contents = uniforms_as_bytes(&uniforms);
         let usage = wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST;
         let uniform_buffer = device.create_buffer_init(&wgpu::BufferInitDescriptor {
@@ -434,6 +888,7 @@ impl Renderer {
             &text_bind_group_layout,
             &text_sampler,
             &glyph_cache_texture_view,
+            &sdf_glyph_cache_texture_view,
         );
 
         // Initialise the sampler set with the default sampler.
@@ -452,12 +907,14 @@ impl Renderer {
         let render_commands = vec![];
         let mesh = Default::default();
         let vertex_mode_buffer = vec![];
+        let sdf_params_buffer = vec![];
 
         Self {
             vs_mod,
             fs_mod,
             glyph_cache,
             glyph_cache_texture,
+            sdf_glyph_cache_texture,
             depth_texture,
             depth_texture_view,
             default_texture,
@@ -466,6 +923,7 @@ impl Renderer {
             uniform_bind_group,
             text_bind_group_layout,
             text_bind_group,
+            text_sampler,
             texture_samplers,
             texture_bind_group_layouts,
             texture_bind_groups,
@@ -473,18 +931,28 @@ impl Renderer {
             output_color_format,
             sample_count,
             scale_factor: output_scale_factor,
+            projection: None,
             render_commands,
             mesh,
             vertex_mode_buffer,
+            sdf_params_buffer,
             uniform_buffer,
         }
     }
 
+    /// The renderer's glyph cache.
+    ///
+    /// Useful for querying glyph cache statistics and pressure.
+    pub fn glyph_cache(&self) -> &GlyphCache {
+        &self.glyph_cache
+    }
+
     /// Clear all pending render commands vertex data.
     pub fn clear(&mut self) {
         self.render_commands.clear();
         self.mesh.clear();
         self.vertex_mode_buffer.clear();
+        self.sdf_params_buffer.clear();
     }
 
     /// Generate a list of `RenderCommand`s from the given **Draw** instance and prepare any
@@ -539,6 +1007,9 @@ impl Renderer {
         let mut fill_tessellator = FillTessellator::new();
         let mut stroke_tessellator = StrokeTessellator::new();
 
+        // Grow the glyph caches if the previous frame requires it.
+        self.glyph_cache.begin_frame();
+
         // Keep track of context changes.
         let mut curr_ctxt = draw::Context::default();
         let mut new_pipeline_ids = HashMap::new();
@@ -575,6 +1046,7 @@ impl Renderer {
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
                         glyph_cache: &mut self.glyph_cache,
+                        sdf_params_buffer: &mut self.sdf_params_buffer,
                         output_attachment_size: Vec2::new(px_to_pt(w_px), px_to_pt(h_px)),
                         output_attachment_scale_factor: scale_factor,
                     };
@@ -688,6 +1160,11 @@ impl Renderer {
                     let mode = render.vertex_mode;
                     let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
                     self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+
+                    // Primitives rendered in SDF text mode submit their own params. Fill in the
+                    // rest with defaults.
+                    self.sdf_params_buffer
+                        .resize(self.mesh.points().len(), SdfParams::default());
                 }
             }
         }
@@ -699,6 +1176,9 @@ impl Renderer {
             &mut self.render_commands,
         );
 
+        // Update glyph cache stats and schedule growth for the next frame if necessary.
+        self.glyph_cache.end_frame();
+
         // Clear out unnecessary pipelines.
         self.pipelines
             .retain(|id, _| new_pipeline_ids.contains_key(id));
@@ -780,11 +1260,10 @@ impl Renderer {
     ) {
         self.clear();
         self.fill(device, draw, scale_factor, output_attachment_size);
+        self.update_glyph_cache_textures(device, encoder);
 
         let Renderer {
             ref pipelines,
-            ref glyph_cache,
-            ref glyph_cache_texture,
             ref mut depth_texture,
             ref mut depth_texture_view,
             ref uniform_bind_group,
@@ -792,17 +1271,14 @@ impl Renderer {
             ref texture_bind_groups,
             ref mesh,
             ref vertex_mode_buffer,
+            ref sdf_params_buffer,
             ref mut render_commands,
             ref uniform_buffer,
             scale_factor: ref mut old_scale_factor,
+            projection: ref mut old_projection,
             ..
         } = *self;
 
-        // Update glyph cache texture if necessary.
-        if glyph_cache.requires_upload {
-            glyph_cache_texture.upload_data(device, encoder, &glyph_cache.pixel_buffer);
-        }
-
         // Resize the depth texture if the output attachment size has changed.
         let depth_size = depth_texture.size();
         if output_attachment_size != depth_size {
@@ -845,6 +1321,7 @@ impl Renderer {
         let colors_bytes = colors_as_bytes(mesh.colors());
         let tex_coords_bytes = tex_coords_as_bytes(mesh.tex_coords());
         let modes_bytes = vertex_modes_as_bytes(vertex_mode_buffer);
+        let sdf_params_bytes = sdf_params_as_bytes(sdf_params_buffer);
         let indices_bytes = indices_as_bytes(mesh.indices());
         let point_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer point_buffer"),
@@ -866,17 +1343,28 @@ impl Renderer {
             contents: modes_bytes,
             usage: vertex_usage,
         });
+        let sdf_params_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer sdf_params_buffer"),
+            contents: sdf_params_bytes,
+            usage: vertex_usage,
+        });
         let index_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer index_buffer"),
             contents: indices_bytes,
             usage: wgpu::BufferUsage::INDEX,
         });
 
-        // If the scale factor or window size has changed, update the uniforms for vertex scaling.
-        if *old_scale_factor != scale_factor || output_attachment_size != depth_size {
+        // If the scale factor, window size or projection has changed, update the uniforms for
+        // vertex scaling.
+        let projection = draw.state.borrow().projection;
+        if *old_scale_factor != scale_factor
+            || output_attachment_size != depth_size
+            || *old_projection != projection
+        {
             *old_scale_factor = scale_factor;
+            *old_projection = projection;
             // Upload uniform data for vertex scaling.
-            let uniforms = create_uniforms(output_attachment_size, scale_factor);
+            let uniforms = create_uniforms(output_attachment_size, scale_factor, projection);
             let uniforms_size = std::mem::size_of::<Uniforms>() as wgpu::BufferAddress;
             let uniforms_bytes = uniforms_as_bytes(&uniforms);
             let usage = wgpu::BufferUsage::COPY_SRC;
@@ -898,6 +1386,7 @@ impl Renderer {
         render_pass.set_vertex_buffer(1, color_buffer.slice(..));
         render_pass.set_vertex_buffer(2, tex_coords_buffer.slice(..));
         render_pass.set_vertex_buffer(3, mode_buffer.slice(..));
+        render_pass.set_vertex_buffer(4, sdf_params_buffer.slice(..));
 
         // Set the uniform and text bind groups here.
         render_pass.set_bind_group(0, uniform_bind_group, &[]);
@@ -936,6 +1425,56 @@ impl Renderer {
         }
     }
 
+    // Re-create the glyph cache textures if the caches have been resized and upload any new
+    // glyphs.
+    fn update_glyph_cache_textures(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let (w, h) = self.glyph_cache.dimensions();
+        let (sdf_w, sdf_h) = self.glyph_cache.sdf_cache.dimensions();
+        let resized = self.glyph_cache_texture.size() != [w, h];
+        let sdf_resized = self.sdf_glyph_cache_texture.size() != [sdf_w, sdf_h];
+        if resized {
+            self.glyph_cache_texture = create_glyph_cache_texture(device, [w, h]);
+        }
+        if sdf_resized {
+            self.sdf_glyph_cache_texture = create_glyph_cache_texture(device, [sdf_w, sdf_h]);
+        }
+        if resized || sdf_resized {
+            let glyph_cache_texture_view = self
+                .glyph_cache_texture
+                .create_view(&wgpu::TextureViewDescriptor::default());
+            let sdf_glyph_cache_texture_view = self
+                .sdf_glyph_cache_texture
+                .create_view(&wgpu::TextureViewDescriptor::default());
+            self.text_bind_group = create_text_bind_group(
+                device,
+                &self.text_bind_group_layout,
+                &self.text_sampler,
+                &glyph_cache_texture_view,
+                &sdf_glyph_cache_texture_view,
+            );
+        }
+
+        // Update glyph cache textures if necessary.
+        let glyph_cache = &mut self.glyph_cache;
+        if glyph_cache.requires_upload {
+            self.glyph_cache_texture
+                .upload_data(device, encoder, &glyph_cache.pixel_buffer);
+            glyph_cache.requires_upload = false;
+        }
+        if glyph_cache.sdf_requires_upload {
+            self.sdf_glyph_cache_texture.upload_data(
+                device,
+                encoder,
+                &glyph_cache.sdf_pixel_buffer,
+            );
+            glyph_cache.sdf_requires_upload = false;
+        }
+    }
+
     /// Encode the necessary commands to render the contents of the given **Draw**ing to the given
     /// **Texture**.
     pub fn render_to_texture(
@@ -1019,12 +1558,106 @@ fn create_depth_texture(
         .build(device)
 }
 
-fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
-    let right = img_w as f32 * 0.5 / scale_factor;
+fn create_glyph_cache_texture(device: &wgpu::Device, size: [u32; 2]) -> wgpu::Texture {
+    wgpu::TextureBuilder::new()
+        .size(size)
+        .usage(wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST)
+        .format(Renderer::GLYPH_CACHE_TEXTURE_FORMAT)
+        .build(device)
+}
+
+// Produce a key matching the accuracy with which the rusttype cache distinguishes glyphs.
+fn glyph_key(
+    cache: &text::GlyphCache<'static>,
+    font_id: usize,
+    glyph: &text::PositionedGlyph,
+) -> GlyphKey {
+    // Normalise the sub-pixel offset to the range -0.5..=0.5.
+    fn offset(v: f32) -> f32 {
+        let o = v.fract();
+        if o > 0.5 {
+            o - 1.0
+        } else if o < -0.5 {
+            o + 1.0
+        } else {
+            o
+        }
+    }
+    let scale = glyph.scale();
+    let position = glyph.position();
+    let scale_tolerance = cache.scale_tolerance();
+    let position_tolerance = cache.position_tolerance();
+    let scale_over_tolerance = (
+        (scale.x / scale_tolerance + 0.5) as u32,
+        (scale.y / scale_tolerance + 0.5) as u32,
+    );
+    let offset_over_tolerance = (
+        ((offset(position.x) + 0.5) / position_tolerance + 0.5) as u16,
+        ((offset(position.y) + 0.5) / position_tolerance + 0.5) as u16,
+    );
+    (
+        font_id,
+        glyph.id(),
+        scale_over_tolerance,
+        offset_over_tolerance,
+    )
+}
+
+// Write the given glyph pixel data into the given rect of a glyph cache's pixel buffer.
+fn write_glyph_pixels(
+    pixel_buffer: &mut [u8],
+    cache_w: u32,
+    rect: text::rt::Rect<u32>,
+    data: &[u8],
+) {
+    let cache_w = cache_w as usize;
+    let width = (rect.max.x - rect.min.x) as usize;
+    let height = (rect.max.y - rect.min.y) as usize;
+    let mut dst_ix = rect.min.y as usize * cache_w + rect.min.x as usize;
+    let mut src_ix = 0;
+    for _ in 0..height {
+        let dst_range = dst_ix..dst_ix + width;
+        let src_range = src_ix..src_ix + width;
+        pixel_buffer[dst_range].copy_from_slice(&data[src_range]);
+        dst_ix += cache_w;
+        src_ix += width;
+    }
+}
+
+// Double the given size along both axes, limited to `max`. Returns `None` if no larger.
+fn grown_size([w, h]: [u32; 2], [max_w, max_h]: [u32; 2]) -> Option<[u32; 2]> {
+    let new_w = (w * 2).min(max_w).max(w);
+    let new_h = (h * 2).min(max_h).max(h);
+    if [new_w, new_h] == [w, h] {
+        None
+    } else {
+        Some([new_w, new_h])
+    }
+}
+
+fn create_uniforms(
+    [img_w, img_h]: [u32; 2],
+    scale_factor: f32,
+    projection_override: Option<Mat4>,
+) -> Uniforms {
+    let w = img_w as f32 / scale_factor;
+    let h = img_h as f32 / scale_factor;
+    let proj = projection_override
+        .unwrap_or_else(|| projection(w, h))
+        .into();
+    Uniforms { proj }
+}
+
+/// The projection applied by the renderer to a **Draw**ing of the given size in points.
+///
+/// The origin lies at the centre of the output and each unit is one point. Depth is mapped from
+/// the range `-max(w, h)..=max(w, h)` to `0.0..=1.0`.
+pub(crate) fn projection(w: f32, h: f32) -> Mat4 {
+    let right = w * 0.5;
     let left = -right;
-    let top = img_h as f32 * 0.5 / scale_factor;
+    let top = h * 0.5;
     let bottom = -top;
-    let far = std::cmp::max(img_w, img_h) as f32 / scale_factor;
+    let far = w.max(h);
     let near = -far;
     let proj = Mat4::orthographic_rh_gl(left, right, bottom, top, near, far);
     // By default, ortho scales z values to the range -1.0 to 1.0. We want to scale and translate
@@ -1032,9 +1665,7 @@ fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
     // TODO: Can possibly solve this more easily by using `Mat4::orthographic_rh` above instead.
     let trans = Mat4::from_translation(Vec3::Z);
     let scale = Mat4::from_scale([1.0, 1.0, 0.5].into());
-    let proj = scale * trans * proj;
-    let proj = proj.into();
-    Uniforms { proj }
+    scale * trans * proj
 }
 
 fn create_uniform_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
@@ -1052,6 +1683,12 @@ fn create_text_bind_group_layout(device: &wgpu::Device, filtering: bool) -> wgpu
             wgpu::TextureViewDimension::D2,
             Renderer::GLYPH_CACHE_TEXTURE_FORMAT.describe().sample_type,
         )
+        .texture(
+            wgpu::ShaderStage::FRAGMENT,
+            false,
+            wgpu::TextureViewDimension::D2,
+            Renderer::GLYPH_CACHE_TEXTURE_FORMAT.describe().sample_type,
+        )
         .build(device)
 }
 
@@ -1086,10 +1723,12 @@ fn create_text_bind_group(
     layout: &wgpu::BindGroupLayout,
     sampler: &wgpu::Sampler,
     glyph_cache_texture_view: &wgpu::TextureViewHandle,
+    sdf_glyph_cache_texture_view: &wgpu::TextureViewHandle,
 ) -> wgpu::BindGroup {
     wgpu::BindGroupBuilder::new()
         .sampler(sampler)
         .texture_view(glyph_cache_texture_view)
+        .texture_view(sdf_glyph_cache_texture_view)
         .build(device, layout)
 }
 
@@ -1129,6 +1768,7 @@ fn create_render_pipeline(
             &wgpu::vertex_attr_array![2 => Float32x2],
         )
         .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
+        .add_vertex_buffer::<SdfParams>(&wgpu::vertex_attr_array![4 => Float32x2])
         .depth_format(depth_format)
         .sample_count(sample_count)
         .color_blend(color_blend)
@@ -1183,6 +1823,10 @@ fn vertex_modes_as_bytes(data: &[VertexMode]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
 
+fn sdf_params_as_bytes(data: &[SdfParams]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
 fn indices_as_bytes(data: &[u32]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
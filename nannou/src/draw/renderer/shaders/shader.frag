@@ -8,19 +8,23 @@
 
 layout(set = 1, binding = 0) uniform sampler text_sampler;
 layout(set = 1, binding = 1) uniform texture2D text;
+layout(set = 1, binding = 2) uniform texture2D sdf_text;
 layout(set = 2, binding = 0) uniform sampler tex_sampler;
 layout(set = 2, binding = 1) uniform texture2D tex;
 
 layout(location = 0) in vec4 v_color;
 layout(location = 1) in vec2 v_tex_coords;
 layout(location = 2) flat in uint v_mode;
+layout(location = 3) in vec2 v_sdf_params;
 
 layout(location = 0) out vec4 f_color;
 
 void main() {
     vec4 tex_color = texture(sampler2D(tex, tex_sampler), v_tex_coords);
     float text_alpha = texture(sampler2D(text, text_sampler), v_tex_coords).r;
-    
+    float sdf_dist = texture(sampler2D(sdf_text, text_sampler), v_tex_coords).r;
+    float sdf_aa = max(fwidth(sdf_dist) * 0.5, 0.0001);
+
     // Color
     if (v_mode == uint(0)) {
         f_color = v_color;
@@ -30,6 +34,12 @@ void main() {
     // Text
     } else if (v_mode == uint(2)) {
         f_color = vec4(v_color.rgb, v_color.a * text_alpha);
+    // Text via the signed distance field glyph cache.
+    } else if (v_mode == uint(3)) {
+        float edge = 0.5 - v_sdf_params.x;
+        float softness = v_sdf_params.y;
+        float sdf_alpha = smoothstep(edge - sdf_aa - softness, edge + sdf_aa, sdf_dist);
+        f_color = vec4(v_color.rgb, v_color.a * sdf_alpha);
     // Unhandled mode - Indicate error with red.
     } else {
         f_color = vec4(1.0, 0.0, 0.0, 1.0);
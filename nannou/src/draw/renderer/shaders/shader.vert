@@ -14,14 +14,17 @@ layout(location = 0) in vec3 position;
 layout(location = 1) in vec4 color;
 layout(location = 2) in vec2 tex_coords;
 layout(location = 3) in uint mode;
+layout(location = 4) in vec2 sdf_params;
 
 layout(location = 0) out vec4 v_color;
 layout(location = 1) out vec2 v_tex_coords;
 layout(location = 2) flat out uint v_mode;
+layout(location = 3) out vec2 v_sdf_params;
 
 void main() {
     gl_Position = uniforms.proj * vec4(position, 1.0);
     v_color = color;
     v_tex_coords = tex_coords;
     v_mode = mode;
+    v_sdf_params = sdf_params;
 }
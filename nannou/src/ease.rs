@@ -1,4 +0,0 @@
-//! A suite of common interpolation functions often referred to as "easing" and "tweening"
-//! functions. This API is provided by the [pennereq crate](https://docs.rs/pennereq).
-
-pub use pennereq::*;
@@ -0,0 +1,397 @@
+//! The **Animation** trait and the combinators used to compose animations.
+
+/// A value that changes over a duration of time.
+///
+/// Animations are stateless and are sampled by the time in seconds since they started, e.g.
+/// `app.time` or the time of a **Clock**. Times before the start produce the initial value and
+/// times after the end produce the final value.
+pub trait Animation {
+    /// The type of value produced by the animation.
+    type Output;
+
+    /// The duration of the animation in seconds.
+    ///
+    /// This is `f32::INFINITY` for animations that loop forever.
+    fn duration(&self) -> f32;
+
+    /// The value of the animation at the given time in seconds since it started.
+    fn sample(&self, time: f32) -> Self::Output;
+
+    /// Whether or not the animation has finished by the given time.
+    fn is_finished(&self, time: f32) -> bool {
+        time >= self.duration()
+    }
+
+    /// Delay the start of the animation by the given number of seconds.
+    fn delay(self, secs: f32) -> Delay<Self>
+    where
+        Self: Sized,
+    {
+        Delay { anim: self, secs }
+    }
+
+    /// Play the animation the given number of times.
+    fn repeat(self, count: u32) -> Repeat<Self>
+    where
+        Self: Sized,
+    {
+        Repeat {
+            anim: self,
+            count: Some(count),
+            yoyo: false,
+        }
+    }
+
+    /// Play the animation forever.
+    fn looped(self) -> Repeat<Self>
+    where
+        Self: Sized,
+    {
+        Repeat {
+            anim: self,
+            count: None,
+            yoyo: false,
+        }
+    }
+
+    /// Play the given animation once this animation has finished.
+    fn then<B>(self, next: B) -> Then<Self, B>
+    where
+        Self: Sized,
+        B: Animation<Output = Self::Output>,
+    {
+        Then { first: self, next }
+    }
+
+    /// Play the given animation alongside this animation, producing the values of both.
+    fn join<B>(self, other: B) -> Join<Self, B>
+    where
+        Self: Sized,
+        B: Animation,
+    {
+        Join { a: self, b: other }
+    }
+
+    /// Map each value produced by the animation to a new value.
+    fn map<F, T>(self, map: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> T,
+    {
+        Map { anim: self, map }
+    }
+
+    /// Box the animation, allowing animations of different types to be composed together, e.g.
+    /// within a **Sequence**.
+    fn boxed<'a>(self) -> Box<dyn Animation<Output = Self::Output> + 'a>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+/// An animation whose start is delayed by some number of seconds.
+#[derive(Clone, Debug)]
+pub struct Delay<A> {
+    anim: A,
+    secs: f32,
+}
+
+/// An animation played a number of times or forever, optionally alternating direction.
+#[derive(Clone, Debug)]
+pub struct Repeat<A> {
+    anim: A,
+    count: Option<u32>,
+    yoyo: bool,
+}
+
+/// Two animations played one after the other.
+#[derive(Clone, Debug)]
+pub struct Then<A, B> {
+    first: A,
+    next: B,
+}
+
+/// Two animations played at the same time.
+#[derive(Clone, Debug)]
+pub struct Join<A, B> {
+    a: A,
+    b: B,
+}
+
+/// An animation whose values are mapped by a function.
+#[derive(Clone, Debug)]
+pub struct Map<A, F> {
+    anim: A,
+    map: F,
+}
+
+/// A list of animations played one after the other.
+#[derive(Clone, Debug)]
+pub struct Sequence<A> {
+    anims: Vec<A>,
+    // The time at which each animation ends.
+    ends: Vec<f32>,
+}
+
+/// A list of animations played at the same time, producing the value of each.
+#[derive(Clone, Debug)]
+pub struct Parallel<A> {
+    anims: Vec<A>,
+}
+
+/// A list of animations each starting some offset after the previous, producing the value of
+/// each.
+#[derive(Clone, Debug)]
+pub struct Stagger<A> {
+    anims: Vec<A>,
+    offset: f32,
+}
+
+/// Play the given animations one after the other.
+///
+/// The sequence produces the value of the animation that is playing, or the final value of the
+/// last animation once all have finished.
+///
+/// **Panics** if no animations are given.
+pub fn sequence<I>(anims: I) -> Sequence<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Animation,
+{
+    let anims: Vec<_> = anims.into_iter().collect();
+    assert!(
+        !anims.is_empty(),
+        "a sequence requires at least one animation"
+    );
+    let mut end = 0.0;
+    let ends = anims
+        .iter()
+        .map(|anim| {
+            end += anim.duration();
+            end
+        })
+        .collect();
+    Sequence { anims, ends }
+}
+
+/// Play the given animations at the same time.
+pub fn parallel<I>(anims: I) -> Parallel<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Animation,
+{
+    let anims = anims.into_iter().collect();
+    Parallel { anims }
+}
+
+/// Play the given animations at the same time, with each starting `offset` seconds after the
+/// previous.
+pub fn stagger<I>(anims: I, offset: f32) -> Stagger<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Animation,
+{
+    let anims = anims.into_iter().collect();
+    Stagger { anims, offset }
+}
+
+impl<A> Repeat<A> {
+    /// Alternate the direction of the animation with each repetition, playing forwards and then
+    /// backwards.
+    pub fn yoyo(mut self) -> Self {
+        self.yoyo = true;
+        self
+    }
+}
+
+impl<A> Sequence<A> {
+    /// The animations within the sequence.
+    pub fn anims(&self) -> &[A] {
+        &self.anims
+    }
+}
+
+impl<A> Parallel<A> {
+    /// The animations played in parallel.
+    pub fn anims(&self) -> &[A] {
+        &self.anims
+    }
+}
+
+impl<A> Stagger<A> {
+    /// The animations played in parallel.
+    pub fn anims(&self) -> &[A] {
+        &self.anims
+    }
+
+    /// The time between the start of each animation and the next.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+}
+
+impl<A> Animation for &A
+where
+    A: Animation + ?Sized,
+{
+    type Output = A::Output;
+    fn duration(&self) -> f32 {
+        (**self).duration()
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        (**self).sample(time)
+    }
+}
+
+impl<A> Animation for Box<A>
+where
+    A: Animation + ?Sized,
+{
+    type Output = A::Output;
+    fn duration(&self) -> f32 {
+        (**self).duration()
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        (**self).sample(time)
+    }
+}
+
+impl<A: Animation> Animation for Delay<A> {
+    type Output = A::Output;
+    fn duration(&self) -> f32 {
+        self.anim.duration() + self.secs
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        self.anim.sample(time - self.secs)
+    }
+}
+
+impl<A: Animation> Animation for Repeat<A> {
+    type Output = A::Output;
+
+    fn duration(&self) -> f32 {
+        match self.count {
+            Some(count) => self.anim.duration() * count as f32,
+            None => f32::INFINITY,
+        }
+    }
+
+    fn sample(&self, time: f32) -> Self::Output {
+        let duration = self.anim.duration();
+        // Playing the animation no times leaves it at its initial value.
+        if self.count == Some(0) {
+            return self.anim.sample(0.0);
+        }
+        if time <= 0.0 || duration <= 0.0 {
+            return self.anim.sample(time);
+        }
+        // The index of the current repetition and the time within it.
+        let mut cycle = (time / duration).floor();
+        let mut local = time - cycle * duration;
+        if let Some(count) = self.count {
+            if cycle >= count as f32 {
+                cycle = count as f32 - 1.0;
+                local = duration;
+            }
+        }
+        if self.yoyo && cycle % 2.0 == 1.0 {
+            local = duration - local;
+        }
+        self.anim.sample(local)
+    }
+}
+
+impl<A, B> Animation for Then<A, B>
+where
+    A: Animation,
+    B: Animation<Output = A::Output>,
+{
+    type Output = A::Output;
+    fn duration(&self) -> f32 {
+        self.first.duration() + self.next.duration()
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        let first = self.first.duration();
+        if time < first {
+            self.first.sample(time)
+        } else {
+            self.next.sample(time - first)
+        }
+    }
+}
+
+impl<A, B> Animation for Join<A, B>
+where
+    A: Animation,
+    B: Animation,
+{
+    type Output = (A::Output, B::Output);
+    fn duration(&self) -> f32 {
+        self.a.duration().max(self.b.duration())
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        (self.a.sample(time), self.b.sample(time))
+    }
+}
+
+impl<A, F, T> Animation for Map<A, F>
+where
+    A: Animation,
+    F: Fn(A::Output) -> T,
+{
+    type Output = T;
+    fn duration(&self) -> f32 {
+        self.anim.duration()
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        (self.map)(self.anim.sample(time))
+    }
+}
+
+impl<A: Animation> Animation for Sequence<A> {
+    type Output = A::Output;
+
+    fn duration(&self) -> f32 {
+        self.ends[self.ends.len() - 1]
+    }
+
+    fn sample(&self, time: f32) -> Self::Output {
+        // The first animation that has not yet ended, or the last.
+        let i = self
+            .ends
+            .partition_point(|&end| end <= time)
+            .min(self.anims.len() - 1);
+        let start = if i == 0 { 0.0 } else { self.ends[i - 1] };
+        self.anims[i].sample(time - start)
+    }
+}
+
+impl<A: Animation> Animation for Parallel<A> {
+    type Output = Vec<A::Output>;
+    fn duration(&self) -> f32 {
+        self.anims.iter().map(|a| a.duration()).fold(0.0, f32::max)
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        self.anims.iter().map(|a| a.sample(time)).collect()
+    }
+}
+
+impl<A: Animation> Animation for Stagger<A> {
+    type Output = Vec<A::Output>;
+    fn duration(&self) -> f32 {
+        self.anims
+            .iter()
+            .enumerate()
+            .map(|(i, a)| a.duration() + i as f32 * self.offset)
+            .fold(0.0, f32::max)
+    }
+    fn sample(&self, time: f32) -> Self::Output {
+        self.anims
+            .iter()
+            .enumerate()
+            .map(|(i, a)| a.sample(time - i as f32 * self.offset))
+            .collect()
+    }
+}
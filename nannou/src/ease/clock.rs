@@ -0,0 +1,101 @@
+//! A manually controlled clock for driving animations.
+
+use crate::event::Update;
+use crate::time::DurationF64;
+
+/// A time in seconds that advances only when told to, and that may be paused, scaled and scrubbed.
+///
+/// While animations may be sampled directly with `app.time`, a **Clock** allows for controlling
+/// the playback of animations independently of the app, e.g. pausing or slowing an animation, or
+/// seeking to a specific moment.
+///
+/// ```
+/// use nannou::ease::Clock;
+///
+/// let mut clock = Clock::new().speed(0.5);
+/// clock.advance(2.0);
+/// assert_eq!(clock.time(), 1.0);
+/// clock.pause();
+/// clock.advance(2.0);
+/// assert_eq!(clock.time(), 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Clock {
+    time: f32,
+    speed: f32,
+    paused: bool,
+}
+
+impl Clock {
+    /// A playing clock starting at time `0.0` with a speed of `1.0`.
+    pub fn new() -> Self {
+        Clock {
+            time: 0.0,
+            speed: 1.0,
+            paused: false,
+        }
+    }
+
+    /// Specify the rate at which the clock advances relative to the elapsed time.
+    ///
+    /// Negative speeds play the clock in reverse.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Change the rate at which the clock advances relative to the elapsed time.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    /// The current time of the clock in seconds.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Whether or not the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Stop the clock from advancing.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Allow the clock to advance.
+    pub fn play(&mut self) {
+        self.paused = false;
+    }
+
+    /// Pause the clock if it is playing or play the clock if it is paused.
+    pub fn toggle(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Jump to the given time in seconds.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time;
+    }
+
+    /// Advance the clock by the given number of elapsed seconds, scaled by the speed.
+    ///
+    /// Does nothing while the clock is paused.
+    pub fn advance(&mut self, secs: f32) {
+        if !self.paused {
+            self.time += secs * self.speed;
+        }
+    }
+
+    /// Advance the clock by the time elapsed since the last update.
+    pub fn update(&mut self, update: &Update) {
+        self.advance(update.since_last.secs() as f32);
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,204 @@
+//! Easing functions mapping the linear progress of an animation to its eased progress.
+
+/// Maps the linear progress of an animation within `0.0..=1.0` to its eased progress.
+///
+/// The named easings are those of Robert Penner as provided by the **pennereq** crate, while
+/// **CubicBezier** matches CSS's `cubic-bezier` timing function.
+#[derive(Copy, Clone, Debug, Default)]
+pub enum Easing {
+    /// No easing.
+    #[default]
+    Linear,
+    SineIn,
+    SineOut,
+    SineInOut,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A CSS-style `cubic-bezier` timing function.
+    CubicBezier(BezierEasing),
+    /// Progress in the given number of discrete, equal steps, jumping at the end of each step.
+    Steps(u32),
+    /// A user-provided easing function.
+    Custom(fn(f32) -> f32),
+}
+
+/// A timing function described by a cubic bézier curve from `(0, 0)` to `(1, 1)` with the two
+/// given control points, matching CSS's `cubic-bezier(x1, y1, x2, y2)`.
+///
+/// The `x` coordinates of the control points are clamped to `0.0..=1.0` so that the curve
+/// describes a function of time, while the `y` coordinates may overshoot.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BezierEasing {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+// The number of Newton-Raphson iterations before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 8;
+// The maximum number of bisection iterations.
+const BISECTION_ITERATIONS: usize = 32;
+// The tolerance within which the curve's `x` must match the progress.
+const EPSILON: f32 = 1e-6;
+
+impl Easing {
+    /// A CSS-style `cubic-bezier(x1, y1, x2, y2)` timing function.
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Easing::CubicBezier(BezierEasing::new(x1, y1, x2, y2))
+    }
+
+    /// Map the linear progress `t` to the eased progress.
+    ///
+    /// `t` is clamped to `0.0..=1.0`. The result is `0.0` at the start and `1.0` at the end,
+    /// though some easings overshoot in between.
+    pub fn apply(&self, t: f32) -> f32 {
+        use pennereq::*;
+        let t = t.clamp(0.0, 1.0);
+        let (b, c, d) = (0.0, 1.0, 1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::SineIn => sine::ease_in(t, b, c, d),
+            Easing::SineOut => sine::ease_out(t, b, c, d),
+            Easing::SineInOut => sine::ease_in_out(t, b, c, d),
+            Easing::QuadIn => quad::ease_in(t, b, c, d),
+            Easing::QuadOut => quad::ease_out(t, b, c, d),
+            Easing::QuadInOut => quad::ease_in_out(t, b, c, d),
+            Easing::CubicIn => cubic::ease_in(t, b, c, d),
+            Easing::CubicOut => cubic::ease_out(t, b, c, d),
+            Easing::CubicInOut => cubic::ease_in_out(t, b, c, d),
+            Easing::QuartIn => quart::ease_in(t, b, c, d),
+            Easing::QuartOut => quart::ease_out(t, b, c, d),
+            Easing::QuartInOut => quart::ease_in_out(t, b, c, d),
+            Easing::QuintIn => quint::ease_in(t, b, c, d),
+            Easing::QuintOut => quint::ease_out(t, b, c, d),
+            Easing::QuintInOut => quint::ease_in_out(t, b, c, d),
+            Easing::ExpoIn => expo::ease_in(t, b, c, d),
+            Easing::ExpoOut => expo::ease_out(t, b, c, d),
+            Easing::ExpoInOut => expo::ease_in_out(t, b, c, d),
+            Easing::CircIn => circ::ease_in(t, b, c, d),
+            Easing::CircOut => circ::ease_out(t, b, c, d),
+            Easing::CircInOut => circ::ease_in_out(t, b, c, d),
+            Easing::BackIn => back::ease_in(t, b, c, d),
+            Easing::BackOut => back::ease_out(t, b, c, d),
+            Easing::BackInOut => back::ease_in_out(t, b, c, d),
+            Easing::ElasticIn => elastic::ease_in(t, b, c, d),
+            Easing::ElasticOut => elastic::ease_out(t, b, c, d),
+            Easing::ElasticInOut => elastic::ease_in_out(t, b, c, d),
+            Easing::BounceIn => bounce::ease_in(t, b, c, d),
+            Easing::BounceOut => bounce::ease_out(t, b, c, d),
+            Easing::BounceInOut => bounce::ease_in_out(t, b, c, d),
+            Easing::CubicBezier(ref bezier) => bezier.apply(t),
+            Easing::Steps(steps) => {
+                let steps = steps.max(1) as f32;
+                (t * steps).floor().min(steps) / steps
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+impl BezierEasing {
+    /// CSS's `ease` timing function.
+    pub const EASE: Self = BezierEasing::from_points(0.25, 0.1, 0.25, 1.0);
+    /// CSS's `ease-in` timing function.
+    pub const EASE_IN: Self = BezierEasing::from_points(0.42, 0.0, 1.0, 1.0);
+    /// CSS's `ease-out` timing function.
+    pub const EASE_OUT: Self = BezierEasing::from_points(0.0, 0.0, 0.58, 1.0);
+    /// CSS's `ease-in-out` timing function.
+    pub const EASE_IN_OUT: Self = BezierEasing::from_points(0.42, 0.0, 0.58, 1.0);
+
+    /// The timing function with the control points `(x1, y1)` and `(x2, y2)`.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::from_points(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2)
+    }
+
+    const fn from_points(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        BezierEasing { x1, y1, x2, y2 }
+    }
+
+    /// The control points `[x1, y1, x2, y2]`.
+    pub fn points(&self) -> [f32; 4] {
+        [self.x1, self.y1, self.x2, self.y2]
+    }
+
+    /// Map the linear progress `t` to the eased progress.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+        let s = self.solve_x(t);
+        bezier(self.y1, self.y2, s)
+    }
+
+    // Find the curve parameter at which the curve's `x` is `x`.
+    fn solve_x(&self, x: f32) -> f32 {
+        // Newton-Raphson converges quickly for most curves.
+        let mut s = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < EPSILON {
+                return s;
+            }
+            let slope = bezier_slope(self.x1, self.x2, s);
+            if slope.abs() < EPSILON {
+                break;
+            }
+            s -= error / slope;
+        }
+        // Otherwise fall back to bisection, as `x` is monotonic in the parameter.
+        let (mut lo, mut hi) = (0.0, 1.0);
+        s = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < EPSILON {
+                break;
+            }
+            if error > 0.0 {
+                hi = s;
+            } else {
+                lo = s;
+            }
+            s = (lo + hi) * 0.5;
+        }
+        s
+    }
+}
+
+// A single coordinate of the cubic bézier from `0.0` to `1.0` with the given control coordinates.
+fn bezier(c1: f32, c2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * c1 + 3.0 * inv * s * s * c2 + s * s * s
+}
+
+// The derivative of `bezier` with respect to `s`.
+fn bezier_slope(c1: f32, c2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * c1 + 6.0 * inv * s * (c2 - c1) + 3.0 * s * s * (1.0 - c2)
+}
@@ -0,0 +1,190 @@
+//! The **Lerp** trait for types that may be interpolated.
+
+use crate::color::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::color::{Alpha, Hsl, Hsv, Oklab, Oklch, RgbHue};
+use crate::glam::{DVec2, DVec3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+/// Types that may be linearly interpolated.
+pub trait Lerp {
+    /// Interpolate between `self` and `other`, where a `t` of `0.0` produces `self` and a `t` of
+    /// `1.0` produces `other`.
+    ///
+    /// Values of `t` outside of `0.0..=1.0` extrapolate, as produced by easings that overshoot.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec2::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec3::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec4::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for DVec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        DVec2::lerp(*self, *other, t as f64)
+    }
+}
+
+impl Lerp for DVec3 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        DVec3::lerp(*self, *other, t as f64)
+    }
+}
+
+impl<A, B> Lerp for (A, B)
+where
+    A: Lerp,
+    B: Lerp,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        (self.0.lerp(&other.0, t), self.1.lerp(&other.1, t))
+    }
+}
+
+impl<A, B, C> Lerp for (A, B, C)
+where
+    A: Lerp,
+    B: Lerp,
+    C: Lerp,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        (
+            self.0.lerp(&other.0, t),
+            self.1.lerp(&other.1, t),
+            self.2.lerp(&other.2, t),
+        )
+    }
+}
+
+/// Rotations are interpolated along the shortest arc via spherical linear interpolation.
+impl Lerp for Quat {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+/// Transforms are decomposed into their scale, rotation and translation, which are interpolated
+/// independently before being recomposed.
+///
+/// Transforms containing shear or projection cannot be decomposed exactly.
+impl Lerp for Mat4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let (s_a, r_a, t_a) = self.to_scale_rotation_translation();
+        let (s_b, r_b, t_b) = other.to_scale_rotation_translation();
+        Mat4::from_scale_rotation_translation(
+            s_a.lerp(s_b, t),
+            Lerp::lerp(&r_a, &r_b, t),
+            t_a.lerp(t_b, t),
+        )
+    }
+}
+
+/// RGB colors are interpolated component-wise within their own encoding. Convert colors to a
+/// linear encoding first for physically accurate blending.
+impl<S> Lerp for Rgb<S, f32>
+where
+    S: RgbStandard,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Rgb::new(
+            self.red.lerp(&other.red, t),
+            self.green.lerp(&other.green, t),
+            self.blue.lerp(&other.blue, t),
+        )
+    }
+}
+
+/// Hues are interpolated along the shortest path around the color wheel.
+impl<S> Lerp for Hsl<S, f32>
+where
+    S: RgbSpace,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Hsl::with_wp(
+            self.hue.lerp(&other.hue, t),
+            self.saturation.lerp(&other.saturation, t),
+            self.lightness.lerp(&other.lightness, t),
+        )
+    }
+}
+
+/// Hues are interpolated along the shortest path around the color wheel.
+impl<S> Lerp for Hsv<S, f32>
+where
+    S: RgbSpace,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Hsv::with_wp(
+            self.hue.lerp(&other.hue, t),
+            self.saturation.lerp(&other.saturation, t),
+            self.value.lerp(&other.value, t),
+        )
+    }
+}
+
+/// Hues are interpolated along the shortest path around the color wheel.
+impl Lerp for RgbHue<f32> {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let delta = (*other - *self).to_degrees();
+        RgbHue::from_degrees(self.to_degrees() + delta * t)
+    }
+}
+
+impl Lerp for Oklab {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Oklab::new(
+            self.l.lerp(&other.l, t),
+            self.a.lerp(&other.a, t),
+            self.b.lerp(&other.b, t),
+        )
+    }
+}
+
+/// Hues are interpolated along the shortest path around the color wheel.
+impl Lerp for Oklch {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let delta = (other.hue - self.hue).rem_euclid(360.0);
+        let delta = if delta > 180.0 { delta - 360.0 } else { delta };
+        Oklch::new(
+            self.l.lerp(&other.l, t),
+            self.chroma.lerp(&other.chroma, t),
+            self.hue + delta * t,
+        )
+    }
+}
+
+impl<C> Lerp for Alpha<C, f32>
+where
+    C: Lerp,
+{
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Alpha {
+            color: self.color.lerp(&other.color, t),
+            alpha: self.alpha.lerp(&other.alpha, t),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! A suite of common interpolation functions often referred to as "easing" and "tweening"
+//! functions. This API is provided by the [pennereq crate](https://docs.rs/pennereq).
+//!
+//! Built upon these, the module provides an animation system:
+//!
+//! - **Tween** interpolates between two values over a duration with some **Easing**.
+//! - **Keyframes** interpolates through a track of values at given times.
+//! - The **Animation** trait describes values that change over time and allows for composing
+//!   animations in sequence, in parallel, staggered, looped and yoyo-ed.
+//! - **Spring** smoothly follows a target via a damped spring.
+//! - **Clock** provides a manually controlled time that may be paused, scrubbed and scaled.
+//!
+//! Any type implementing **Lerp** may be animated, including `f32`, `Vec2`, `Vec3`, `Quat`,
+//! `Mat4` and colors. Animations are sampled by time in seconds, e.g. `app.time` or the time of a
+//! **Clock**.
+//!
+//! ```
+//! use nannou::ease::{Animation, Easing, Tween};
+//! use nannou::geom::pt2;
+//!
+//! let anim = Tween::new(pt2(0.0, 0.0), pt2(100.0, 0.0), 2.0)
+//!     .ease(Easing::QuadInOut)
+//!     .looped()
+//!     .yoyo();
+//! assert_eq!(anim.sample(2.0), pt2(100.0, 0.0));
+//! assert_eq!(anim.sample(4.0), pt2(0.0, 0.0));
+//! ```
+
+pub use pennereq::*;
+
+pub use self::animation::{
+    parallel, sequence, stagger, Animation, Delay, Join, Map, Parallel, Repeat, Sequence, Stagger,
+    Then,
+};
+pub use self::clock::Clock;
+pub use self::easing::{BezierEasing, Easing};
+pub use self::lerp::Lerp;
+pub use self::spring::Spring;
+pub use self::tween::{Keyframes, Tween};
+
+pub mod animation;
+pub mod clock;
+pub mod easing;
+pub mod lerp;
+pub mod spring;
+pub mod tween;
@@ -0,0 +1,158 @@
+//! A damped spring for smoothly following a moving target.
+
+use std::ops::{Add, Mul, Sub};
+
+/// A value that follows its target as though attached by a damped spring.
+///
+/// Unlike a **Tween**, a spring has state in the form of its velocity and may have its target
+/// changed at any time without discontinuities, making it well suited to following the mouse or
+/// other interactive input. The spring is advanced by calling `update` with the elapsed time.
+///
+/// The motion is solved analytically, so the result is stable and independent of the rate at
+/// which the spring is updated.
+///
+/// ```
+/// use nannou::ease::Spring;
+///
+/// let mut spring = Spring::new(0.0);
+/// spring.set_target(10.0);
+/// for _ in 0..120 {
+///     spring.update(1.0 / 60.0);
+/// }
+/// assert!((spring.value() - 10.0).abs() < 0.01);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spring<T> {
+    value: T,
+    velocity: T,
+    target: T,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+}
+
+impl<T> Spring<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    /// The default stiffness of the spring.
+    pub const DEFAULT_STIFFNESS: f32 = 170.0;
+    /// The default damping of the spring.
+    pub const DEFAULT_DAMPING: f32 = 26.0;
+    /// The default mass attached to the spring.
+    pub const DEFAULT_MASS: f32 = 1.0;
+
+    /// A spring at rest at the given value.
+    pub fn new(value: T) -> Self {
+        Spring {
+            value,
+            velocity: value * 0.0,
+            target: value,
+            stiffness: Self::DEFAULT_STIFFNESS,
+            damping: Self::DEFAULT_DAMPING,
+            mass: Self::DEFAULT_MASS,
+        }
+    }
+
+    /// Specify the stiffness of the spring. Stiffer springs move more quickly.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Specify the damping of the spring. Less damping produces more oscillation.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Specify the mass attached to the spring. Heavier masses move more slowly.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Change the target that the spring pulls towards.
+    pub fn set_target(&mut self, target: T) {
+        self.target = target;
+    }
+
+    /// Move the spring to the given value and bring it to rest.
+    pub fn reset(&mut self, value: T) {
+        self.value = value;
+        self.velocity = value * 0.0;
+    }
+
+    /// The current value of the spring.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// The current velocity of the spring in units per second.
+    pub fn velocity(&self) -> T {
+        self.velocity
+    }
+
+    /// The target that the spring pulls towards.
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// The damping ratio of the spring, where values less than `1.0` oscillate, `1.0` is
+    /// critically damped and values greater than `1.0` are overdamped.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Advance the spring by the given number of seconds, returning the new value.
+    pub fn update(&mut self, secs: f32) -> T {
+        let [[a, b], [c, d]] = self.step(secs.max(0.0));
+        let offset = self.value - self.target;
+        let velocity = self.velocity;
+        self.value = self.target + offset * a + velocity * b;
+        self.velocity = offset * c + velocity * d;
+        self.value
+    }
+
+    // The coefficients mapping the current offset from the target and velocity to the offset and
+    // velocity after `t` seconds, solving the damped harmonic oscillator.
+    fn step(&self, t: f32) -> [[f32; 2]; 2] {
+        if self.stiffness <= 0.0 || self.mass <= 0.0 {
+            return [[1.0, 0.0], [0.0, 1.0]];
+        }
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio().max(0.0);
+        if (zeta - 1.0).abs() < CRITICAL_EPSILON {
+            // Critically damped.
+            let e = (-omega * t).exp();
+            [
+                [e * (1.0 + omega * t), e * t],
+                [-e * omega * omega * t, e * (1.0 - omega * t)],
+            ]
+        } else if zeta < 1.0 {
+            // Underdamped, oscillating about the target.
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let e = (-zeta * omega * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+            let k = zeta * omega / omega_d;
+            [
+                [e * (cos + k * sin), e * sin / omega_d],
+                [-e * omega * omega / omega_d * sin, e * (cos - k * sin)],
+            ]
+        } else {
+            // Overdamped, approaching the target without oscillation.
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let (r1, r2) = (-zeta * omega + root, -zeta * omega - root);
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            let delta = r1 - r2;
+            [
+                [(r1 * e2 - r2 * e1) / delta, (e1 - e2) / delta],
+                [r1 * r2 * (e2 - e1) / delta, (r1 * e1 - r2 * e2) / delta],
+            ]
+        }
+    }
+}
+
+// The distance from a damping ratio of `1.0` within which the spring is considered critically
+// damped, avoiding the division by zero of the other solutions.
+const CRITICAL_EPSILON: f32 = 1e-4;
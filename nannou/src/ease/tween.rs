@@ -0,0 +1,144 @@
+//! Animations interpolating between values.
+
+use super::{Animation, Easing, Lerp};
+
+/// Interpolates from one value to another over a duration with some **Easing**.
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: f32,
+    easing: Easing,
+}
+
+/// A track of values at given times, interpolating between each value and the next.
+///
+/// ```
+/// use nannou::ease::{Animation, Easing, Keyframes};
+///
+/// let track = Keyframes::new(0.0)
+///     .key(1.0, 10.0)
+///     .key_eased(3.0, 0.0, Easing::QuadOut);
+/// assert_eq!(track.duration(), 3.0);
+/// assert_eq!(track.sample(0.5), 5.0);
+/// assert_eq!(track.sample(3.0), 0.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Keyframes<T> {
+    // Each key's time and value along with the easing used to arrive at the value.
+    keys: Vec<(f32, T, Easing)>,
+}
+
+impl<T> Tween<T> {
+    /// Interpolate from `from` to `to` over `duration` seconds without easing.
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        Tween {
+            from,
+            to,
+            duration: duration.max(0.0),
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Specify the easing applied to the progress of the tween.
+    pub fn ease(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The value at the start of the tween.
+    pub fn from(&self) -> &T {
+        &self.from
+    }
+
+    /// The value at the end of the tween.
+    pub fn to(&self) -> &T {
+        &self.to
+    }
+
+    /// The easing applied to the progress of the tween.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The eased progress of the tween at the given time, `0.0` at the start and `1.0` at the end.
+    pub fn progress(&self, time: f32) -> f32 {
+        let t = if self.duration > 0.0 {
+            time / self.duration
+        } else if time < 0.0 {
+            0.0
+        } else {
+            1.0
+        };
+        self.easing.apply(t)
+    }
+}
+
+impl<T> Keyframes<T> {
+    /// A track starting with the given value at time `0.0`.
+    pub fn new(value: T) -> Self {
+        Keyframes {
+            keys: vec![(0.0, value, Easing::Linear)],
+        }
+    }
+
+    /// Add a key with the given value at the given time in seconds, interpolated linearly from
+    /// the previous key.
+    pub fn key(self, time: f32, value: T) -> Self {
+        self.key_eased(time, value, Easing::Linear)
+    }
+
+    /// Add a key with the given value at the given time in seconds, interpolated from the
+    /// previous key with the given easing.
+    ///
+    /// Keys may be added in any order. A key added at the same time as an existing key is placed
+    /// after it, producing an instant jump between the values.
+    pub fn key_eased(mut self, time: f32, value: T, easing: Easing) -> Self {
+        let i = self.keys.partition_point(|k| k.0 <= time);
+        self.keys.insert(i, (time, value, easing));
+        self
+    }
+
+    /// The time and value of each key.
+    pub fn keys(&self) -> impl Iterator<Item = (f32, &T)> + '_ {
+        self.keys.iter().map(|(time, value, _)| (*time, value))
+    }
+}
+
+impl<T> Animation for Tween<T>
+where
+    T: Lerp,
+{
+    type Output = T;
+    fn duration(&self) -> f32 {
+        self.duration
+    }
+    fn sample(&self, time: f32) -> T {
+        self.from.lerp(&self.to, self.progress(time))
+    }
+}
+
+impl<T> Animation for Keyframes<T>
+where
+    T: Clone + Lerp,
+{
+    type Output = T;
+
+    fn duration(&self) -> f32 {
+        self.keys[self.keys.len() - 1].0.max(0.0)
+    }
+
+    fn sample(&self, time: f32) -> T {
+        // The first key after the given time.
+        let i = self.keys.partition_point(|k| k.0 <= time);
+        if i == 0 {
+            return self.keys[0].1.clone();
+        } else if i == self.keys.len() {
+            return self.keys[i - 1].1.clone();
+        }
+        let (start, ref from, _) = self.keys[i - 1];
+        let (end, ref to, easing) = self.keys[i];
+        let t = easing.apply((time - start) / (end - start));
+        from.lerp(to, t)
+    }
+}
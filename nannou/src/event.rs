@@ -312,6 +312,7 @@ impl LoopEvent for Event {
     fn from_winit_event<'a, T>(event: &winit::event::Event<'a, T>, app: &App) -> Option<Self> {
         let event = match event {
             winit::event::Event::WindowEvent { window_id, event } => {
+                let window_id = window::Id::from(*window_id);
                 let windows = app.windows.borrow();
                 let (win_w, win_h, scale_factor) = match windows.get(&window_id) {
                     None => (0.0, 0.0, 1.0), // The window was likely closed, these will be ignored.
@@ -325,7 +326,7 @@ impl LoopEvent for Event {
                 let simple =
                     WindowEvent::from_winit_window_event(event, win_w, win_h, scale_factor);
                 Event::WindowEvent {
-                    id: window_id.clone(),
+                    id: window_id,
                     simple,
                     // TODO: Re-add this when winit#1387 is resolved.
                     // raw,
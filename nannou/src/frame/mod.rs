@@ -1,9 +1,11 @@
 //! Items related to the **Frame** type, describing a single frame of graphics for a single window.
 
 use crate::color::IntoLinSrgba;
+use crate::image;
 use crate::wgpu;
+use futures::FutureExt;
 use std::ops;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::Duration;
 
@@ -23,6 +25,9 @@ pub struct Frame<'swap_chain> {
     raw_frame: RawFrame<'swap_chain>,
     render_data: &'swap_chain RenderData,
     capture_data: &'swap_chain CaptureData,
+    // Whether or not to read captures on the current thread, blocking until complete, rather than
+    // on a worker thread where they may be dropped if no worker becomes available in time.
+    wait_for_capture: bool,
 }
 
 /// Data specific to the intermediary textures.
@@ -78,11 +83,13 @@ impl<'swap_chain> Frame<'swap_chain> {
         raw_frame: RawFrame<'swap_chain>,
         render_data: &'swap_chain RenderData,
         capture_data: &'swap_chain CaptureData,
+        wait_for_capture: bool,
     ) -> Self {
         Frame {
             raw_frame,
             render_data,
             capture_data,
+            wait_for_capture,
         }
     }
 
@@ -93,6 +100,7 @@ impl<'swap_chain> Frame<'swap_chain> {
             ref capture_data,
             ref render_data,
             ref mut raw_frame,
+            wait_for_capture,
         } = *self;
 
         // Resolve the MSAA if necessary.
@@ -134,26 +142,26 @@ impl<'swap_chain> Frame<'swap_chain> {
         // Submit all commands on the device queue.
         raw_frame.submit_inner();
 
-        // If the user did specify capturing the frame, submit the asynchronous read.
+        // If the user did specify capturing the frame, read it on this thread if it must not be
+        // dropped, otherwise submit the asynchronous read.
         if let Some((path, snapshot)) = snapshot_capture {
-            let result = snapshot.read(move |result| match result {
-                // TODO: Log errors, don't print to stderr.
-                Err(e) => eprintln!("failed to async read captured frame: {:?}", e),
-                Ok(image) => {
-                    let image = image.to_owned();
-                    if let Err(e) = image.save(&path) {
-                        // TODO: Log errors, don't print to stderr.
-                        eprintln!(
-                            "failed to save captured frame to \"{}\": {}",
-                            path.display(),
-                            e
-                        );
-                    }
+            if wait_for_capture {
+                let device = raw_frame.device_queue_pair().device();
+                match read_snapshot_blocking(device, &snapshot) {
+                    // TODO: Log errors, don't print to stderr.
+                    Err(e) => eprintln!("failed to read captured frame: {:?}", e),
+                    Ok(image) => save_captured_frame(&image, &path),
+                }
+            } else {
+                let result = snapshot.read(move |result| match result {
+                    // TODO: Log errors, don't print to stderr.
+                    Err(e) => eprintln!("failed to async read captured frame: {:?}", e),
+                    Ok(image) => save_captured_frame(&image.to_owned(), &path),
+                });
+                if let Err(wgpu::TextureCapturerAwaitWorkerTimeout(_)) = result {
+                    // TODO: Log errors, don't print to stderr.
+                    eprintln!("timed out while waiting for a worker thread to capture the frame");
                 }
-            });
-            if let Err(wgpu::TextureCapturerAwaitWorkerTimeout(_)) = result {
-                // TODO: Log errors, don't print to stderr.
-                eprintln!("timed out while waiting for a worker thread to capture the frame");
             }
         }
     }
@@ -323,6 +331,37 @@ impl<'swap_chain> Drop for Frame<'swap_chain> {
     }
 }
 
+// Read the given snapshot back to the CPU on the current thread, blocking until the device has
+// completed all submitted work.
+pub(crate) fn read_snapshot_blocking(
+    device: &wgpu::Device,
+    snapshot: &wgpu::TextueSnapshot,
+) -> Result<image::RgbaImage, wgpu::BufferAsyncError> {
+    // Polling the read once requests the mapping of the buffer, which completes once the device
+    // has finished the submitted work.
+    let mut read = Box::pin(snapshot.read_async());
+    let result = match read.as_mut().now_or_never() {
+        Some(result) => result,
+        None => {
+            device.poll(wgpu::Maintain::Wait);
+            futures::executor::block_on(read)
+        }
+    };
+    result.map(|image| image.to_owned())
+}
+
+// Save a captured frame to the given path.
+fn save_captured_frame(image: &image::RgbaImage, path: &Path) {
+    if let Err(e) = image.save(path) {
+        // TODO: Log errors, don't print to stderr.
+        eprintln!(
+            "failed to save captured frame to \"{}\": {}",
+            path.display(),
+            e
+        );
+    }
+}
+
 fn create_lin_srgba_msaa_texture(
     device: &wgpu::Device,
     swap_chain_dims: [u32; 2],
@@ -0,0 +1,86 @@
+//! Boolean operations and offsetting for **Path**s.
+//!
+//! Paths are flattened into contours of line segments and processed by the polygon operations in
+//! `geom::polygon`. The results are paths made up of closed polygons with counter-clockwise outer
+//! contours and clockwise holes, ready to be filled or stroked via `draw.path()`.
+
+use crate::geom::path::{Builder, Path};
+use crate::geom::polygon::{self, BooleanOp, FillRule, OffsetOptions};
+use crate::geom::{pt2, Point2};
+use lyon::path::iterator::PathIterator;
+
+/// The tolerance used to flatten curves when performing boolean operations via the convenience
+/// methods on **Path**.
+pub const DEFAULT_TOLERANCE: f32 = 0.01;
+
+/// Flatten the path into a list of contours, one for each sub-path.
+///
+/// All sub-paths are treated as closed.
+pub fn contours(path: &Path, tolerance: f32) -> Vec<Vec<Point2>> {
+    let mut contours = vec![];
+    let mut contour = vec![];
+    for event in path.iter().flattened(tolerance) {
+        match event {
+            lyon::path::PathEvent::Begin { at } => {
+                contour.push(pt2(at.x, at.y));
+            }
+            lyon::path::PathEvent::Line { to, .. } => {
+                contour.push(pt2(to.x, to.y));
+            }
+            lyon::path::PathEvent::End { .. } => {
+                contours.push(std::mem::take(&mut contour));
+            }
+            // Curves are flattened into lines.
+            _ => (),
+        }
+    }
+    contours
+}
+
+/// Build a path from the given list of closed contours.
+pub fn from_contours<I, C>(contours: I) -> Path
+where
+    I: IntoIterator<Item = C>,
+    C: IntoIterator<Item = Point2>,
+{
+    let mut builder = Builder::new();
+    for contour in contours {
+        let mut points = contour.into_iter();
+        let first = match points.next() {
+            None => continue,
+            Some(p) => p,
+        };
+        builder = points
+            .fold(builder.move_to(first), |b, p| b.line_to(p))
+            .close();
+    }
+    builder.build()
+}
+
+/// Perform the given boolean operation between the `subject` and `clip` paths.
+///
+/// Curves are flattened with the given `tolerance` and both paths are filled with `fill_rule`.
+pub fn boolean(
+    op: BooleanOp,
+    subject: &Path,
+    clip: &Path,
+    fill_rule: FillRule,
+    tolerance: f32,
+) -> Path {
+    let subject = contours(subject, tolerance);
+    let clip = contours(clip, tolerance);
+    from_contours(polygon::boolean(op, subject, clip, fill_rule))
+}
+
+/// Resolve all self-intersections and overlapping sub-paths within the given path.
+pub fn simplify(path: &Path, fill_rule: FillRule, tolerance: f32) -> Path {
+    from_contours(polygon::simplify(contours(path, tolerance), fill_rule))
+}
+
+/// Grow the filled region of the path by `delta`, or shrink it if `delta` is negative.
+///
+/// Curves are flattened with the `tolerance` of the given `options`.
+pub fn offset(path: &Path, delta: f32, options: &OffsetOptions) -> Path {
+    let contours = contours(path, options.tolerance);
+    from_contours(polygon::offset(contours, delta, options))
+}
@@ -0,0 +1,393 @@
+//! Measuring distances along a **Path** and querying positions at those distances.
+//!
+//! The **Measure** type flattens each segment of a path once, allowing for cheap repeated queries
+//! such as `point_at_length`, `tangent_at_length` and `resample`.
+
+use crate::geom::curve::{CubicBezier, Curve, QuadraticBezier};
+use crate::geom::path::{Builder, Path};
+use crate::geom::Point2;
+use crate::glam::{vec2, Vec2};
+
+/// The tolerance used to flatten curves when measuring via the convenience methods on **Path**.
+pub const DEFAULT_TOLERANCE: f32 = 0.01;
+
+// The maximum number of line segments used to approximate a single curve.
+const MAX_SUBDIVISIONS: usize = 1024;
+
+/// Arc-length information for a **Path**, used to query positions along its length.
+///
+/// Distances along the path do not include the gaps between sub-paths.
+#[derive(Clone, Debug)]
+pub struct Measure {
+    segments: Vec<Segment>,
+    length: f32,
+}
+
+// A single line or curve of a path.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Edge {
+    Line { from: Point2, to: Point2 },
+    Quadratic(QuadraticBezier<Point2>),
+    Cubic(CubicBezier<Point2>),
+}
+
+// An edge along with its position within the path and its flattened arc-length table.
+#[derive(Clone, Debug)]
+struct Segment {
+    edge: Edge,
+    // The index of the sub-path to which the segment belongs.
+    sub_path: usize,
+    // Whether or not this is the first segment of its sub-path.
+    first: bool,
+    // Whether or not this segment closes its sub-path.
+    close: bool,
+    // The `(t, distance)` of each flattened point along the segment, where `distance` is the
+    // distance from the start of the path.
+    lengths: Vec<(f32, f32)>,
+}
+
+impl Measure {
+    /// Measure the given path, flattening curves with the given tolerance.
+    pub fn new(path: &Path, tolerance: f32) -> Self {
+        Self::from_events(path.iter(), tolerance)
+    }
+
+    /// Measure the path described by the given events, flattening curves with the given
+    /// tolerance.
+    pub fn from_events<I>(events: I, tolerance: f32) -> Self
+    where
+        I: IntoIterator<Item = lyon::path::PathEvent>,
+    {
+        let mut segments: Vec<Segment> = vec![];
+        let mut length = 0.0;
+        let mut sub_path = 0;
+        let mut first = true;
+        for event in events {
+            let (edge, close) = match event {
+                lyon::path::PathEvent::Begin { .. } => {
+                    first = true;
+                    continue;
+                }
+                lyon::path::PathEvent::Line { from, to } => {
+                    let (from, to) = (pt(from), pt(to));
+                    (Edge::Line { from, to }, false)
+                }
+                lyon::path::PathEvent::Quadratic { from, ctrl, to } => {
+                    let curve = QuadraticBezier::new(pt(from), pt(ctrl), pt(to));
+                    (Edge::Quadratic(curve), false)
+                }
+                lyon::path::PathEvent::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    let curve = CubicBezier::new(pt(from), pt(ctrl1), pt(ctrl2), pt(to));
+                    (Edge::Cubic(curve), false)
+                }
+                lyon::path::PathEvent::End {
+                    last,
+                    first: start,
+                    close,
+                } => {
+                    // Skip empty sub-paths.
+                    if first {
+                        continue;
+                    }
+                    if !close {
+                        sub_path += 1;
+                        first = true;
+                        continue;
+                    }
+                    let (from, to) = (pt(last), pt(start));
+                    (Edge::Line { from, to }, true)
+                }
+            };
+            let lengths = edge.lengths(length, tolerance);
+            length = lengths.last().map(|&(_, d)| d).unwrap_or(length);
+            segments.push(Segment {
+                edge,
+                sub_path,
+                first,
+                close,
+                lengths,
+            });
+            if close {
+                sub_path += 1;
+            }
+            first = close;
+        }
+        Measure { segments, length }
+    }
+
+    /// The total length of the path.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// The point at the given normalised position `t` along the path, where `0.0` is the start
+    /// and `1.0` is the end.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn point_at(&self, t: f32) -> Option<Point2> {
+        self.point_at_length(t * self.length)
+    }
+
+    /// The point at the given distance along the path.
+    ///
+    /// The distance is clamped to the length of the path. Returns `None` if the path is empty.
+    pub fn point_at_length(&self, distance: f32) -> Option<Point2> {
+        self.locate(distance)
+            .map(|(ix, t)| self.segments[ix].edge.point(t))
+    }
+
+    /// The unit tangent (the direction of travel) at the given distance along the path.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn tangent_at_length(&self, distance: f32) -> Option<Vec2> {
+        self.locate(distance)
+            .map(|(ix, t)| self.segments[ix].edge.tangent(t))
+    }
+
+    /// The unit normal at the given distance along the path.
+    ///
+    /// This is the tangent rotated 90 degrees counter-clockwise. Returns `None` if the path is
+    /// empty.
+    pub fn normal_at_length(&self, distance: f32) -> Option<Vec2> {
+        self.tangent_at_length(distance).map(|t| vec2(-t.y, t.x))
+    }
+
+    /// Resample the path to `n` evenly spaced points, including both the start and end.
+    ///
+    /// For closed paths, the last point is equal to the first. Returns an empty `Vec` if the path
+    /// is empty.
+    pub fn resample(&self, n: usize) -> Vec<Point2> {
+        if self.segments.is_empty() {
+            return vec![];
+        }
+        let step = if n > 1 {
+            self.length / (n - 1) as f32
+        } else {
+            0.0
+        };
+        (0..n)
+            .filter_map(|i| self.point_at_length(i as f32 * step))
+            .collect()
+    }
+
+    /// Split the path into the parts before and after the given distance along the path.
+    ///
+    /// Curves are split exactly, rather than being flattened.
+    pub fn split_at_length(&self, distance: f32) -> (Path, Path) {
+        let distance = distance.max(0.0).min(self.length);
+        let before = self.sub_path(0.0..distance);
+        let after = self.sub_path(distance..self.length);
+        (before, after)
+    }
+
+    /// Extract the part of the path between the given distances along the path.
+    ///
+    /// Curves are split exactly, rather than being flattened. Sub-paths that are entirely within
+    /// the range retain whether or not they are closed.
+    pub fn sub_path(&self, range: std::ops::Range<f32>) -> Path {
+        let start = range.start.max(0.0);
+        let end = range.end.min(self.length);
+        let mut builder = Builder::new();
+        if end <= start {
+            return builder.build();
+        }
+        let mut current_sub_path = None;
+        let mut whole_sub_path = false;
+        for (ix, seg) in self.segments.iter().enumerate() {
+            let (seg_start, seg_end) = seg.range();
+            if seg_end < start || seg_start > end || (seg_end == start && seg_start < start) {
+                continue;
+            }
+            if seg_start == end && seg_end > end {
+                break;
+            }
+            let t0 = if seg_start < start {
+                self.t_at(ix, start)
+            } else {
+                0.0
+            };
+            let t1 = if seg_end > end {
+                self.t_at(ix, end)
+            } else {
+                1.0
+            };
+            let edge = seg.edge.slice(t0, t1);
+            if current_sub_path != Some(seg.sub_path) {
+                builder = builder.move_to(edge.from());
+                current_sub_path = Some(seg.sub_path);
+                whole_sub_path = seg.first && t0 == 0.0;
+            }
+            builder = match edge {
+                _ if seg.close && whole_sub_path && t1 == 1.0 => builder.close(),
+                Edge::Line { to, .. } => builder.line_to(to),
+                Edge::Quadratic(q) => builder.quadratic_bezier_to(q.ctrl, q.to),
+                Edge::Cubic(c) => builder.cubic_bezier_to(c.ctrl1, c.ctrl2, c.to),
+            };
+        }
+        builder.build()
+    }
+
+    // Find the index of the segment at the given distance and the curve parameter `t` at that
+    // distance within the segment.
+    fn locate(&self, distance: f32) -> Option<(usize, f32)> {
+        if self.segments.is_empty() {
+            return None;
+        }
+        let distance = distance.max(0.0).min(self.length);
+        let ix = self
+            .segments
+            .partition_point(|seg| seg.range().1 < distance)
+            .min(self.segments.len() - 1);
+        Some((ix, self.t_at(ix, distance)))
+    }
+
+    // The curve parameter `t` at the given distance within the segment at the given index.
+    fn t_at(&self, segment: usize, distance: f32) -> f32 {
+        let lengths = &self.segments[segment].lengths;
+        let ix = lengths.partition_point(|&(_, d)| d < distance);
+        if ix == 0 {
+            return lengths[0].0;
+        }
+        if ix >= lengths.len() {
+            return lengths[lengths.len() - 1].0;
+        }
+        let (t0, d0) = lengths[ix - 1];
+        let (t1, d1) = lengths[ix];
+        let span = d1 - d0;
+        if span <= 0.0 {
+            return t1;
+        }
+        t0 + (t1 - t0) * (distance - d0) / span
+    }
+}
+
+impl Segment {
+    // The distance along the path at the start and end of the segment.
+    fn range(&self) -> (f32, f32) {
+        let start = self.lengths[0].1;
+        let end = self.lengths[self.lengths.len() - 1].1;
+        (start, end)
+    }
+}
+
+impl Edge {
+    // The start of the edge.
+    fn from(&self) -> Point2 {
+        match *self {
+            Edge::Line { from, .. } => from,
+            Edge::Quadratic(q) => q.from,
+            Edge::Cubic(c) => c.from,
+        }
+    }
+
+    // The end of the edge.
+    fn to(&self) -> Point2 {
+        match *self {
+            Edge::Line { to, .. } => to,
+            Edge::Quadratic(q) => q.to,
+            Edge::Cubic(c) => c.to,
+        }
+    }
+
+    // The point at the given curve parameter `t` in the range `0.0..=1.0`.
+    fn point(&self, t: f32) -> Point2 {
+        match *self {
+            Edge::Line { from, to } => from.lerp(to, t),
+            Edge::Quadratic(q) => q.point(t),
+            Edge::Cubic(c) => c.point(t),
+        }
+    }
+
+    // The unit tangent at the given curve parameter `t`.
+    //
+    // Falls back to the direction from the start to the end of the edge where the derivative
+    // vanishes, e.g. at a control point that coincides with an endpoint.
+    fn tangent(&self, t: f32) -> Vec2 {
+        let d = match *self {
+            Edge::Line { from, to } => to - from,
+            Edge::Quadratic(q) => q.derivative(t),
+            Edge::Cubic(c) => c.derivative(t),
+        };
+        if d.length_squared() > f32::EPSILON {
+            return d.normalize();
+        }
+        (self.to() - self.from()).normalize_or_zero()
+    }
+
+    // The part of the edge between the given curve parameters.
+    fn slice(&self, t0: f32, t1: f32) -> Self {
+        let (_, after) = self.split(t0);
+        let t = if t0 < 1.0 {
+            (t1 - t0) / (1.0 - t0)
+        } else {
+            0.0
+        };
+        let (before, _) = after.split(t);
+        before
+    }
+
+    // Split the edge in two at the given curve parameter.
+    fn split(&self, t: f32) -> (Self, Self) {
+        match *self {
+            Edge::Line { from, to } => {
+                let mid = from.lerp(to, t);
+                (Edge::Line { from, to: mid }, Edge::Line { from: mid, to })
+            }
+            Edge::Quadratic(q) => {
+                let (a, b) = q.split(t);
+                (Edge::Quadratic(a), Edge::Quadratic(b))
+            }
+            Edge::Cubic(c) => {
+                let (a, b) = c.split(t);
+                (Edge::Cubic(a), Edge::Cubic(b))
+            }
+        }
+    }
+
+    // The number of line segments required to approximate the edge within the tolerance.
+    //
+    // Derived from the maximum distance between a curve and its chord given the curve's second
+    // derivative.
+    fn subdivisions(&self, tolerance: f32) -> usize {
+        let tolerance = tolerance.max(f32::EPSILON);
+        let n = match *self {
+            Edge::Line { .. } => return 1,
+            Edge::Quadratic(q) => {
+                let dd = (q.from - q.ctrl * 2.0 + q.to).length();
+                (dd / (4.0 * tolerance)).sqrt()
+            }
+            Edge::Cubic(c) => {
+                let dd1 = (c.from - c.ctrl1 * 2.0 + c.ctrl2).length();
+                let dd2 = (c.ctrl1 - c.ctrl2 * 2.0 + c.to).length();
+                (3.0 * dd1.max(dd2) / (4.0 * tolerance)).sqrt()
+            }
+        };
+        (n.ceil() as usize).clamp(1, MAX_SUBDIVISIONS)
+    }
+
+    // The `(t, distance)` of each point along the flattened edge, starting from `start`.
+    fn lengths(&self, start: f32, tolerance: f32) -> Vec<(f32, f32)> {
+        let n = self.subdivisions(tolerance);
+        let mut lengths = Vec::with_capacity(n + 1);
+        let mut distance = start;
+        let mut last = self.from();
+        lengths.push((0.0, distance));
+        for i in 1..=n {
+            let t = i as f32 / n as f32;
+            let p = self.point(t);
+            distance += p.distance(last);
+            lengths.push((t, distance));
+            last = p;
+        }
+        lengths
+    }
+}
+
+fn pt(p: lyon::math::Point) -> Point2 {
+    [p.x, p.y].into()
+}
@@ -0,0 +1,572 @@
+//! Items related to working with paths for 2D geometry and vector graphics.
+//!
+//! This module attempts to provide abstractions around the various `Path` and `Builder` types
+//! offerred by `lyon` in a way that interoperates a little more fluidly and consistently with the
+//! rest of nannou's API.
+
+use crate::geom::curve::{BSpline, CatmullRom, CubicBezier, Curve, QuadraticBezier};
+use crate::geom::polygon::{BooleanOp, FillRule, OffsetOptions};
+use crate::geom::Point2;
+use crate::glam::Vec2;
+
+pub mod boolean;
+pub mod measure;
+pub mod polyline;
+
+pub use self::measure::Measure;
+
+/// A wrapper around a 2D lyon path exposing a nannou-friendly API.
+#[derive(Clone, Debug)]
+pub struct Path {
+    path: lyon::path::Path,
+}
+
+/// A type used for building a 2D lyon path.
+pub struct Builder {
+    builder: lyon::path::Builder,
+}
+
+impl Path {
+    /// Begin building a new path.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Create an empty path.
+    pub fn new() -> Self {
+        lyon::path::Path::new().into()
+    }
+
+    /// Returns a lyon view on this **Path**.
+    pub fn as_slice(&self) -> lyon::path::PathSlice {
+        self.path.as_slice()
+    }
+
+    /// Returns a slice over an endpoint's custom attributes.
+    pub fn attributes(&self, endpoint: lyon::path::EndpointId) -> &[f32] {
+        self.path.attributes(endpoint)
+    }
+
+    /// Iterates over the entire **Path** yielding **PathEvent**s.
+    pub fn iter(&self) -> lyon::path::Iter {
+        self.path.iter()
+    }
+
+    /// Iterates over the endpoint and control point ids of the **Path**.
+    pub fn id_iter(&self) -> lyon::path::IdIter {
+        self.path.id_iter()
+    }
+
+    /// Iterate over points alongside their attributes.
+    pub fn iter_with_attributes(&self) -> lyon::path::IterWithAttributes {
+        self.path.iter_with_attributes()
+    }
+
+    /// Applies a transform to all endpoints and control points of this path and returns the
+    /// result.
+    pub fn transformed<T>(&self, transform: &T) -> Self
+    where
+        T: lyon::geom::traits::Transformation<f32>,
+    {
+        self.path.transformed(transform).into()
+    }
+
+    /// Reversed version of this path with edge loops specified in the opposite order.
+    pub fn reversed(&self) -> Self {
+        self.path.reversed().into()
+    }
+
+    /// Concatenate two paths.
+    pub fn merge(&self, other: &Self) -> Self {
+        self.path.merge(&other.path).into()
+    }
+
+    /// Measure the path, flattening curves with the given tolerance.
+    ///
+    /// The resulting **Measure** can be used to efficiently perform many queries along the
+    /// length of the path.
+    pub fn measure(&self, tolerance: f32) -> Measure {
+        Measure::new(self, tolerance)
+    }
+
+    /// The total length of the path.
+    ///
+    /// Curves are flattened with `measure::DEFAULT_TOLERANCE`. When performing many queries, see
+    /// the `measure` method.
+    pub fn length(&self) -> f32 {
+        self.measure(measure::DEFAULT_TOLERANCE).length()
+    }
+
+    /// The point at the given normalised position `t` along the path, where `0.0` is the start
+    /// and `1.0` is the end.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn point_at(&self, t: f32) -> Option<Point2> {
+        self.measure(measure::DEFAULT_TOLERANCE).point_at(t)
+    }
+
+    /// The point at the given distance along the path.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn point_at_length(&self, distance: f32) -> Option<Point2> {
+        self.measure(measure::DEFAULT_TOLERANCE)
+            .point_at_length(distance)
+    }
+
+    /// The unit tangent at the given distance along the path.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn tangent_at_length(&self, distance: f32) -> Option<Vec2> {
+        self.measure(measure::DEFAULT_TOLERANCE)
+            .tangent_at_length(distance)
+    }
+
+    /// The unit normal at the given distance along the path.
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn normal_at_length(&self, distance: f32) -> Option<Vec2> {
+        self.measure(measure::DEFAULT_TOLERANCE)
+            .normal_at_length(distance)
+    }
+
+    /// Resample the path to `n` evenly spaced points, including both the start and end.
+    pub fn resample(&self, n: usize) -> Vec<Point2> {
+        self.measure(measure::DEFAULT_TOLERANCE).resample(n)
+    }
+
+    /// Split the path into the parts before and after the given distance along the path.
+    pub fn split_at_length(&self, distance: f32) -> (Self, Self) {
+        self.measure(measure::DEFAULT_TOLERANCE)
+            .split_at_length(distance)
+    }
+
+    /// Extract the part of the path between the given distances along the path.
+    pub fn sub_path(&self, range: std::ops::Range<f32>) -> Self {
+        self.measure(measure::DEFAULT_TOLERANCE).sub_path(range)
+    }
+
+    /// Perform the given boolean operation between this path and the `clip` path.
+    ///
+    /// Curves are flattened with the given `tolerance` and both paths are filled with
+    /// `fill_rule`. The result consists of closed polygons, with outer contours wound
+    /// counter-clockwise and holes wound clockwise.
+    pub fn boolean(&self, op: BooleanOp, clip: &Self, fill_rule: FillRule, tolerance: f32) -> Self {
+        boolean::boolean(op, self, clip, fill_rule, tolerance)
+    }
+
+    /// The area covered by either this path or the `other` path.
+    ///
+    /// Curves are flattened with `boolean::DEFAULT_TOLERANCE` and the `EvenOdd` fill rule is used.
+    pub fn union(&self, other: &Self) -> Self {
+        self.boolean(
+            BooleanOp::Union,
+            other,
+            FillRule::default(),
+            boolean::DEFAULT_TOLERANCE,
+        )
+    }
+
+    /// The area covered by both this path and the `other` path.
+    ///
+    /// Curves are flattened with `boolean::DEFAULT_TOLERANCE` and the `EvenOdd` fill rule is used.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.boolean(
+            BooleanOp::Intersection,
+            other,
+            FillRule::default(),
+            boolean::DEFAULT_TOLERANCE,
+        )
+    }
+
+    /// The area covered by this path but not the `other` path.
+    ///
+    /// Curves are flattened with `boolean::DEFAULT_TOLERANCE` and the `EvenOdd` fill rule is used.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.boolean(
+            BooleanOp::Difference,
+            other,
+            FillRule::default(),
+            boolean::DEFAULT_TOLERANCE,
+        )
+    }
+
+    /// The area covered by exactly one of this path and the `other` path.
+    ///
+    /// Curves are flattened with `boolean::DEFAULT_TOLERANCE` and the `EvenOdd` fill rule is used.
+    pub fn xor(&self, other: &Self) -> Self {
+        self.boolean(
+            BooleanOp::Xor,
+            other,
+            FillRule::default(),
+            boolean::DEFAULT_TOLERANCE,
+        )
+    }
+
+    /// Resolve all self-intersections and overlapping sub-paths, producing a path that covers the
+    /// same area when filled with `fill_rule`.
+    pub fn simplify(&self, fill_rule: FillRule, tolerance: f32) -> Self {
+        boolean::simplify(self, fill_rule, tolerance)
+    }
+
+    /// Grow the filled region of the path by `delta`, or shrink it if `delta` is negative.
+    ///
+    /// Curves are flattened with the `tolerance` of the given `options`.
+    pub fn offset(&self, delta: f32, options: &OffsetOptions) -> Self {
+        boolean::offset(self, delta, options)
+    }
+
+    /// Simplify the path using the Ramer–Douglas–Peucker algorithm, removing points closer than
+    /// `tolerance` to the simplified line.
+    ///
+    /// Curves are first flattened with `polyline::DEFAULT_TOLERANCE`.
+    pub fn rdp(&self, tolerance: f32) -> Self {
+        polyline::rdp(self, tolerance, polyline::DEFAULT_TOLERANCE)
+    }
+
+    /// Simplify the path using the Visvalingam–Whyatt algorithm, removing points that form
+    /// triangles with their neighbours smaller than `min_area`.
+    ///
+    /// Curves are first flattened with `polyline::DEFAULT_TOLERANCE`.
+    pub fn visvalingam(&self, min_area: f32) -> Self {
+        polyline::visvalingam(self, min_area, polyline::DEFAULT_TOLERANCE)
+    }
+
+    /// Smooth the path by cutting its corners the given number of times via Chaikin's algorithm.
+    ///
+    /// Curves are first flattened with `polyline::DEFAULT_TOLERANCE`.
+    pub fn chaikin(&self, iterations: usize) -> Self {
+        polyline::chaikin(self, iterations, polyline::DEFAULT_TOLERANCE)
+    }
+
+    /// Replace the path with a Catmull-Rom spline through each of its points, where an `alpha` of
+    /// `0.5` produces a centripetal spline.
+    ///
+    /// Curves are first flattened with `polyline::DEFAULT_TOLERANCE`.
+    pub fn catmull_rom(&self, alpha: f32) -> Self {
+        polyline::catmull_rom(self, alpha, polyline::DEFAULT_TOLERANCE)
+    }
+
+    /// Fit a smooth sequence of cubic bézier curves to the path, staying within `tolerance` of
+    /// each of its points.
+    ///
+    /// Curves are first flattened with `polyline::DEFAULT_TOLERANCE`.
+    pub fn fit_cubics(&self, tolerance: f32) -> Self {
+        polyline::fit_cubics(self, tolerance, polyline::DEFAULT_TOLERANCE)
+    }
+}
+
+impl Builder {
+    /// Begin building a new path.
+    pub fn new() -> Self {
+        lyon::path::Builder::new().into()
+    }
+
+    /// Build a path with the given capacity for the inner path event storage.
+    pub fn with_capacity(points: usize, edges: usize) -> Self {
+        lyon::path::Builder::with_capacity(points, edges).into()
+    }
+
+    /// Returns a lyon builder that supports SVG commands.
+    pub fn with_svg(self) -> lyon::path::builder::SvgPathBuilder<Self> {
+        lyon::path::builder::SvgPathBuilder::new(self)
+    }
+
+    /// Returns a lyon builder that approximates all curves with sequences of line segments.
+    pub fn flattened(self, tolerance: f32) -> lyon::path::builder::FlatteningBuilder<Self> {
+        lyon::path::builder::FlatteningBuilder::new(self, tolerance)
+    }
+
+    /// Sets the position in preparation for the next sub-path.
+    ///
+    /// If the current sub-path contains edges, this ends the sub-path without closing it.
+    pub fn move_to(mut self, to: Point2) -> Self {
+        self.builder.move_to(to.to_array().into());
+        self
+    }
+
+    /// Adds a line segment to the current sub-path and sets the current position.
+    pub fn line_to(mut self, to: Point2) -> Self {
+        self.builder.line_to(to.to_array().into());
+        self
+    }
+
+    /// Closes the current sub path and sets the current position to the first position of the
+    /// current sub-path.
+    pub fn close(mut self) -> Self {
+        self.builder.close();
+        self
+    }
+
+    /// Add a quadratic bezier curve to the path.
+    pub fn quadratic_bezier_to(mut self, ctrl: Point2, to: Point2) -> Self {
+        self.builder
+            .quadratic_bezier_to(ctrl.to_array().into(), to.to_array().into());
+        self
+    }
+
+    /// Add a cubic bezier curve to the path.
+    pub fn cubic_bezier_to(mut self, ctrl1: Point2, ctrl2: Point2, to: Point2) -> Self {
+        self.builder.cubic_bezier_to(
+            ctrl1.to_array().into(),
+            ctrl2.to_array().into(),
+            to.to_array().into(),
+        );
+        self
+    }
+
+    /// Add an arc to the path.
+    pub fn arc(
+        mut self,
+        center: Point2,
+        radii: Vec2,
+        sweep_angle_radians: f32,
+        x_rotation_radians: f32,
+    ) -> Self {
+        self.builder.arc(
+            center.to_array().into(),
+            radii.to_array().into(),
+            lyon::math::Angle::radians(sweep_angle_radians),
+            lyon::math::Angle::radians(x_rotation_radians),
+        );
+        self
+    }
+
+    /// Add a closed polygon.
+    pub fn polygon(mut self, points: &[Point2]) -> Self {
+        self.builder.polygon(point_slice_nannou_to_lyon(points));
+        self
+    }
+
+    /// Returns the current position of the head of the path.
+    pub fn position(&self) -> Point2 {
+        let p = self.builder.current_position();
+        [p.x, p.y].into()
+    }
+
+    /// Build the path and return it.
+    pub fn build(self) -> Path {
+        self.builder.build().into()
+    }
+
+    /// Access to the inner `lyon::path::Builder`.
+    pub fn inner(&self) -> &lyon::path::Builder {
+        &self.builder
+    }
+
+    /// Mutable access to the inner `lyon::path::Builder`.
+    pub fn inner_mut(&mut self) -> &mut lyon::path::Builder {
+        &mut self.builder
+    }
+}
+
+// lyon builder traits
+
+impl lyon::path::builder::Build for Builder {
+    type PathType = Path;
+
+    fn build(self) -> Self::PathType {
+        self.builder.build().into()
+    }
+
+    fn build_and_reset(&mut self) -> Self::PathType {
+        self.builder.build_and_reset().into()
+    }
+}
+
+impl lyon::path::builder::FlatPathBuilder for Builder {
+    fn move_to(&mut self, to: lyon::math::Point) {
+        self.builder.move_to(to);
+    }
+
+    fn line_to(&mut self, to: lyon::math::Point) {
+        self.builder.line_to(to);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    fn current_position(&self) -> lyon::math::Point {
+        self.builder.current_position()
+    }
+}
+
+impl lyon::path::builder::PathBuilder for Builder {
+    fn quadratic_bezier_to(&mut self, ctrl: lyon::math::Point, to: lyon::math::Point) {
+        self.builder.quadratic_bezier_to(ctrl, to);
+    }
+
+    fn cubic_bezier_to(
+        &mut self,
+        ctrl1: lyon::math::Point,
+        ctrl2: lyon::math::Point,
+        to: lyon::math::Point,
+    ) {
+        self.builder.cubic_bezier_to(ctrl1, ctrl2, to);
+    }
+
+    fn arc(
+        &mut self,
+        center: lyon::math::Point,
+        radii: lyon::math::Vector,
+        sweep_angle: lyon::math::Angle,
+        x_rotation: lyon::math::Angle,
+    ) {
+        self.builder.arc(center, radii, sweep_angle, x_rotation);
+    }
+
+    fn path_event(&mut self, event: lyon::path::PathEvent) {
+        self.builder.path_event(event);
+    }
+}
+
+impl lyon::path::builder::PolygonBuilder for Builder {
+    fn polygon(&mut self, points: &[lyon::math::Point]) {
+        self.builder.polygon(points);
+    }
+}
+
+// Indexing
+
+impl std::ops::Index<lyon::path::ControlPointId> for Path {
+    type Output = Point2;
+    fn index(&self, id: lyon::path::ControlPointId) -> &Self::Output {
+        point_lyon_to_nannou(self.path.index(id))
+    }
+}
+
+impl std::ops::Index<lyon::path::EndpointId> for Path {
+    type Output = Point2;
+    fn index(&self, id: lyon::path::EndpointId) -> &Self::Output {
+        point_lyon_to_nannou(self.path.index(id))
+    }
+}
+
+// Path iteration
+
+impl<'a> IntoIterator for &'a Path {
+    type Item = lyon::path::PathEvent;
+    type IntoIter = lyon::path::Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Conversions
+
+impl From<lyon::path::Path> for Path {
+    fn from(path: lyon::path::Path) -> Self {
+        Path { path }
+    }
+}
+
+impl From<lyon::path::Builder> for Builder {
+    fn from(builder: lyon::path::Builder) -> Self {
+        Builder { builder }
+    }
+}
+
+impl Into<lyon::path::Path> for Path {
+    fn into(self) -> lyon::path::Path {
+        self.path
+    }
+}
+
+impl Into<lyon::path::Builder> for Builder {
+    fn into(self) -> lyon::path::Builder {
+        self.builder
+    }
+}
+
+impl<'a> Into<lyon::path::PathSlice<'a>> for &'a Path {
+    fn into(self) -> lyon::path::PathSlice<'a> {
+        self.as_slice()
+    }
+}
+
+impl From<QuadraticBezier<Point2>> for Path {
+    fn from(curve: QuadraticBezier<Point2>) -> Self {
+        path()
+            .move_to(curve.from)
+            .quadratic_bezier_to(curve.ctrl, curve.to)
+            .build()
+    }
+}
+
+impl From<CubicBezier<Point2>> for Path {
+    fn from(curve: CubicBezier<Point2>) -> Self {
+        path()
+            .move_to(curve.from)
+            .cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to)
+            .build()
+    }
+}
+
+impl<'a> From<&'a CatmullRom<Point2>> for Path {
+    fn from(spline: &'a CatmullRom<Point2>) -> Self {
+        let mut segments = spline.segments().peekable();
+        let mut builder = match segments.peek() {
+            None => return Path::new(),
+            Some(segment) => path().move_to(segment.from),
+        };
+        for s in segments {
+            builder = builder.cubic_bezier_to(s.ctrl1, s.ctrl2, s.to);
+        }
+        if spline.is_closed() {
+            builder = builder.close();
+        }
+        builder.build()
+    }
+}
+
+/// B-splines of up to degree `3` are described exactly by their bézier pieces, while higher degree
+/// curves are flattened with `measure::DEFAULT_TOLERANCE`.
+impl<'a> From<&'a BSpline<Point2>> for Path {
+    fn from(spline: &'a BSpline<Point2>) -> Self {
+        let mut builder = path().move_to(spline.start());
+        let degree = spline.degree();
+        // Curves above cubic degree have no exact path representation, so they are flattened.
+        if degree > 3 {
+            for p in spline.flattened(measure::DEFAULT_TOLERANCE).skip(1) {
+                builder = builder.line_to(p);
+            }
+            return builder.build();
+        }
+        let points = spline.bezier_points();
+        for c in points[1..].chunks(degree) {
+            builder = match *c {
+                [to] => builder.line_to(to),
+                [ctrl, to] => builder.quadratic_bezier_to(ctrl, to),
+                [ctrl1, ctrl2, to] => builder.cubic_bezier_to(ctrl1, ctrl2, to),
+                _ => unreachable!(),
+            };
+        }
+        builder.build()
+    }
+}
+
+// Simplified constructors
+
+/// Begin building a path.
+pub fn path() -> Builder {
+    Builder::new()
+}
+
+/// Build a path with the given capacity for the inner path event storage.
+pub fn path_with_capacity(points: usize, edges: usize) -> Builder {
+    Builder::with_capacity(points, edges)
+}
+
+// Conversions between slice types.
+//
+// The following conversions are safe as both `Point2` and `lyon::path::Point` have the same size,
+// fields and `repr(C)` layout.
+
+fn point_slice_nannou_to_lyon(ps: &[Point2]) -> &[lyon::math::Point] {
+    unsafe { std::mem::transmute(ps) }
+}
+
+fn point_lyon_to_nannou(p: &lyon::math::Point) -> &Point2 {
+    unsafe { std::mem::transmute(p) }
+}
@@ -0,0 +1,134 @@
+//! Simplification, smoothing and curve fitting for **Path**s.
+//!
+//! Each sub-path is flattened into a polyline and processed by the functions in `geom::polyline`,
+//! preserving whether or not the sub-path is closed.
+
+use crate::geom::curve::{CatmullRom, CubicBezier};
+use crate::geom::path::{Builder, Path};
+use crate::geom::polyline;
+use crate::geom::{pt2, Point2};
+use lyon::path::iterator::PathIterator;
+
+/// The tolerance used to flatten curves when simplifying or smoothing via the convenience methods
+/// on **Path**.
+pub const DEFAULT_TOLERANCE: f32 = 0.01;
+
+/// Flatten the path into a polyline for each sub-path, along with whether or not it is closed.
+///
+/// Closed polylines do not repeat their first point at the end.
+pub fn polylines(path: &Path, tolerance: f32) -> Vec<(Vec<Point2>, bool)> {
+    let mut polylines = vec![];
+    let mut points = vec![];
+    for event in path.iter().flattened(tolerance) {
+        match event {
+            lyon::path::PathEvent::Begin { at } => {
+                points.push(pt2(at.x, at.y));
+            }
+            lyon::path::PathEvent::Line { to, .. } => {
+                points.push(pt2(to.x, to.y));
+            }
+            lyon::path::PathEvent::End { close, .. } => {
+                let mut points = std::mem::take(&mut points);
+                if close && points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+                polylines.push((points, close));
+            }
+            // Curves are flattened into lines.
+            _ => (),
+        }
+    }
+    polylines
+}
+
+/// Build a path from the given polylines, closing those marked as closed.
+pub fn from_polylines<I, P>(polylines: I) -> Path
+where
+    I: IntoIterator<Item = (P, bool)>,
+    P: IntoIterator<Item = Point2>,
+{
+    let mut builder = Builder::new();
+    for (points, closed) in polylines {
+        let mut points = points.into_iter();
+        let first = match points.next() {
+            None => continue,
+            Some(p) => p,
+        };
+        builder = points.fold(builder.move_to(first), |b, p| b.line_to(p));
+        if closed {
+            builder = builder.close();
+        }
+    }
+    builder.build()
+}
+
+/// Build a path from the given sequences of cubic bézier segments, closing those marked as
+/// closed.
+pub fn from_cubics<I, C>(sub_paths: I) -> Path
+where
+    I: IntoIterator<Item = (C, bool)>,
+    C: IntoIterator<Item = CubicBezier<Point2>>,
+{
+    let mut builder = Builder::new();
+    for (cubics, closed) in sub_paths {
+        let mut cubics = cubics.into_iter().peekable();
+        let first = match cubics.peek() {
+            None => continue,
+            Some(c) => c.from,
+        };
+        builder = cubics.fold(builder.move_to(first), |b, c| {
+            b.cubic_bezier_to(c.ctrl1, c.ctrl2, c.to)
+        });
+        if closed {
+            builder = builder.close();
+        }
+    }
+    builder.build()
+}
+
+/// Simplify each sub-path using the Ramer–Douglas–Peucker algorithm.
+///
+/// See `geom::polyline::rdp` for details.
+pub fn rdp(path: &Path, tolerance: f32, flatten_tolerance: f32) -> Path {
+    let polylines = polylines(path, flatten_tolerance).into_iter();
+    from_polylines(polylines.map(|(ps, closed)| (polyline::rdp(ps, tolerance, closed), closed)))
+}
+
+/// Simplify each sub-path using the Visvalingam–Whyatt algorithm.
+///
+/// See `geom::polyline::visvalingam` for details.
+pub fn visvalingam(path: &Path, min_area: f32, flatten_tolerance: f32) -> Path {
+    let polylines = polylines(path, flatten_tolerance).into_iter();
+    from_polylines(
+        polylines.map(|(ps, closed)| (polyline::visvalingam(ps, min_area, closed), closed)),
+    )
+}
+
+/// Smooth each sub-path using Chaikin's corner cutting algorithm.
+///
+/// See `geom::polyline::chaikin` for details.
+pub fn chaikin(path: &Path, iterations: usize, flatten_tolerance: f32) -> Path {
+    let polylines = polylines(path, flatten_tolerance).into_iter();
+    from_polylines(
+        polylines.map(|(ps, closed)| (polyline::chaikin(ps, iterations, closed), closed)),
+    )
+}
+
+/// Replace each sub-path with a Catmull-Rom spline passing through each of its points.
+///
+/// See `geom::CatmullRom` for details.
+pub fn catmull_rom(path: &Path, alpha: f32, flatten_tolerance: f32) -> Path {
+    let polylines = polylines(path, flatten_tolerance).into_iter();
+    from_cubics(polylines.map(|(ps, closed)| {
+        let spline = CatmullRom::new(ps).alpha(alpha).closed(closed);
+        (spline.segments().collect::<Vec<_>>(), closed)
+    }))
+}
+
+/// Fit a sequence of cubic bézier segments to each sub-path.
+///
+/// See `geom::polyline::fit_cubics` for details.
+pub fn fit_cubics(path: &Path, tolerance: f32, flatten_tolerance: f32) -> Path {
+    let polylines = polylines(path, flatten_tolerance).into_iter();
+    from_cubics(polylines.map(|(ps, closed)| (polyline::fit_cubics(ps, tolerance, closed), closed)))
+}
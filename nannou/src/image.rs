@@ -1,5 +0,0 @@
-//! Items related to working with images. Currently, this module simply re-exports the renown
-//! [image crate](https://docs.rs/image) which supports reading and writing PNG, JPEG, GIF, WEBP,
-//! BMP and more.
-
-pub use image::*;
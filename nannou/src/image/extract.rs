@@ -0,0 +1,309 @@
+//! Extracting a palette of dominant colors from an image.
+
+use super::{distance_squared, linear_lut, to_oklab, to_srgb8};
+use crate::color::{Component, IntoLinSrgba, LinSrgba, Srgb};
+use crate::math::num_traits::Float;
+use crate::rand::SeededRng;
+use image::{DynamicImage, GenericImageView};
+use std::cmp::Ordering;
+
+/// A color extracted from an image along with the share of the image that it represents.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Swatch {
+    /// The color.
+    pub color: Srgb<u8>,
+    /// The fraction of sampled pixels nearest to this color, within `0.0..=1.0`.
+    pub population: f32,
+}
+
+/// The algorithm used to group the pixels of an image into a palette.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// Refine clusters of pixels around randomly seeded centers until they settle.
+    ///
+    /// Produces colors that best represent the image, at the cost of more computation.
+    #[default]
+    KMeans,
+    /// Recursively split the box bounding the pixels at the median of its longest axis.
+    ///
+    /// Fast and deterministic, though less accurate than **KMeans**.
+    MedianCut,
+}
+
+/// A builder for extracting a palette of dominant colors from an image.
+///
+/// Pixels that are more than half transparent are ignored.
+#[derive(Clone, Debug)]
+pub struct Extractor {
+    method: Method,
+    max_samples: usize,
+    iterations: usize,
+    seed: u64,
+}
+
+impl Extractor {
+    /// The maximum number of pixels sampled from an image by default.
+    pub const DEFAULT_MAX_SAMPLES: usize = 1 << 16;
+    /// The maximum number of k-means refinement iterations by default.
+    pub const DEFAULT_ITERATIONS: usize = 24;
+    /// The seed used to place the initial k-means centers by default.
+    pub const DEFAULT_SEED: u64 = 0;
+
+    /// Begin building a palette extractor with the default parameters.
+    pub fn new() -> Self {
+        Extractor {
+            method: Method::default(),
+            max_samples: Self::DEFAULT_MAX_SAMPLES,
+            iterations: Self::DEFAULT_ITERATIONS,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+
+    /// Specify the algorithm used to group pixels into a palette.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Specify the maximum number of pixels sampled from the image.
+    ///
+    /// Larger images are sampled along an evenly spaced grid.
+    pub fn max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples.max(1);
+        self
+    }
+
+    /// Specify the maximum number of k-means refinement iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Specify the seed used to place the initial k-means centers.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Extract up to `n` dominant colors from the image, ordered from most to least common.
+    ///
+    /// Fewer than `n` colors are produced if the image contains fewer than `n` distinct colors.
+    pub fn extract(&self, image: &DynamicImage, n: usize) -> Vec<Swatch> {
+        let samples = self.samples(image);
+        if samples.is_empty() || n == 0 {
+            return vec![];
+        }
+        let mut swatches = match self.method {
+            Method::KMeans => self.k_means(&samples, n),
+            Method::MedianCut => median_cut(samples, n),
+        };
+        swatches.sort_by(|a, b| {
+            b.population
+                .partial_cmp(&a.population)
+                .unwrap_or(Ordering::Equal)
+        });
+        swatches
+    }
+
+    // Sample the opaque pixels of the image as Oklab colors.
+    fn samples(&self, image: &DynamicImage) -> Vec<[f32; 3]> {
+        let lut = linear_lut();
+        let (w, h) = image.dimensions();
+        let pixels = w as usize * h as usize;
+        let step = if pixels > self.max_samples {
+            ((pixels as f64 / self.max_samples as f64).sqrt().ceil()) as usize
+        } else {
+            1
+        };
+        let mut samples = Vec::with_capacity(pixels / (step * step) + 1);
+        for y in (0..h).step_by(step) {
+            for x in (0..w).step_by(step) {
+                let [r, g, b, a] = image.get_pixel(x, y).0;
+                if a >= 128 {
+                    samples.push(to_oklab(&lut, [r, g, b]));
+                }
+            }
+        }
+        samples
+    }
+
+    // Cluster the samples via k-means, seeding the initial centers via k-means++.
+    fn k_means(&self, samples: &[[f32; 3]], n: usize) -> Vec<Swatch> {
+        let mut rng = SeededRng::new(self.seed);
+
+        // Choose each center with a probability proportional to its squared distance from the
+        // nearest existing center, spreading the initial centers across the samples.
+        let mut centers = vec![samples[rng.random_range(0, samples.len())]];
+        let mut distances: Vec<f32> = samples
+            .iter()
+            .map(|&s| distance_squared(s, centers[0]))
+            .collect();
+        while centers.len() < n {
+            let total: f32 = distances.iter().sum();
+            if total <= 0.0 {
+                break;
+            }
+            let mut target = rng.random_f32() * total;
+            let i = distances
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < 0.0
+                })
+                .unwrap_or(samples.len() - 1);
+            centers.push(samples[i]);
+            for (d, &s) in distances.iter_mut().zip(samples) {
+                *d = d.min(distance_squared(s, samples[i]));
+            }
+        }
+
+        // Refine the centers until the assignment of samples no longer changes.
+        let mut assignments = vec![0; samples.len()];
+        let mut counts = vec![0; centers.len()];
+        for iteration in 0..=self.iterations {
+            let mut changed = false;
+            for (assignment, &s) in assignments.iter_mut().zip(samples) {
+                let nearest = nearest(&centers, s);
+                changed |= *assignment != nearest;
+                *assignment = nearest;
+            }
+            if iteration > 0 && !changed {
+                break;
+            }
+            let mut sums = vec![[0.0f64; 3]; centers.len()];
+            counts.iter_mut().for_each(|c| *c = 0);
+            for (&i, s) in assignments.iter().zip(samples) {
+                counts[i] += 1;
+                for (sum, &c) in sums[i].iter_mut().zip(s) {
+                    *sum += c as f64;
+                }
+            }
+            for ((center, sum), &count) in centers.iter_mut().zip(&sums).zip(&counts) {
+                if count > 0 {
+                    *center = sum.map(|c| (c / count as f64) as f32);
+                }
+            }
+        }
+
+        let total = samples.len() as f32;
+        centers
+            .iter()
+            .zip(&counts)
+            .filter(|&(_, &count)| count > 0)
+            .map(|(&center, &count)| Swatch {
+                color: to_srgb8(center),
+                population: count as f32 / total,
+            })
+            .collect()
+    }
+}
+
+/// Extract up to `n` dominant colors from the image via k-means clustering, ordered from most to
+/// least common.
+///
+/// See **Extractor** for more control over the extraction.
+pub fn extract_palette(image: &DynamicImage, n: usize) -> Vec<Swatch> {
+    Extractor::new().extract(image, n)
+}
+
+impl Default for Extractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> IntoLinSrgba<S> for Swatch
+where
+    S: Component + Float,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        self.color.into_lin_srgba()
+    }
+}
+
+// The index of the center nearest to the given sample.
+fn nearest(centers: &[[f32; 3]], sample: [f32; 3]) -> usize {
+    let mut nearest = (0, f32::INFINITY);
+    for (i, &c) in centers.iter().enumerate() {
+        let d = distance_squared(sample, c);
+        if d < nearest.1 {
+            nearest = (i, d);
+        }
+    }
+    nearest.0
+}
+
+// Group the samples by repeatedly splitting the box with the greatest extent weighted by its
+// population at the median of its longest axis.
+fn median_cut(mut samples: Vec<[f32; 3]>, n: usize) -> Vec<Swatch> {
+    // The longest axis of the box bounding the samples along with its extent.
+    fn longest_axis(samples: &[[f32; 3]]) -> (usize, f32) {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for s in samples {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(s[axis]);
+                max[axis] = max[axis].max(s[axis]);
+            }
+        }
+        (0..3)
+            .map(|axis| (axis, max[axis] - min[axis]))
+            .fold((0, 0.0), |a, b| if b.1 > a.1 { b } else { a })
+    }
+
+    let mut boxes = Vec::with_capacity(n);
+    boxes.push(0..samples.len());
+    while boxes.len() < n {
+        let split = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                let (axis, extent) = longest_axis(&samples[range.clone()]);
+                (i, axis, extent * range.len() as f32)
+            })
+            .filter(|&(_, _, priority)| priority > 0.0)
+            .fold(None, |a: Option<(usize, usize, f32)>, b| match a {
+                Some(a) if a.2 >= b.2 => Some(a),
+                _ => Some(b),
+            });
+        let (i, axis, _) = match split {
+            Some(split) => split,
+            None => break,
+        };
+        let range = boxes[i].clone();
+        let slice = &mut samples[range.clone()];
+        slice.sort_unstable_by(|a, b| a[axis].partial_cmp(&b[axis]).unwrap_or(Ordering::Equal));
+        // Split at the boundary between differing values nearest the median, so that equal
+        // samples are never divided between boxes.
+        let mid = slice.len() / 2;
+        let is_boundary = |j: &usize| slice[j - 1][axis] < slice[*j][axis];
+        let above = (mid.max(1)..slice.len()).find(is_boundary);
+        let below = (1..mid.max(1)).rev().find(is_boundary);
+        let mid = match (below, above) {
+            (Some(b), Some(a)) if mid - b <= a - mid => b,
+            (_, Some(a)) => a,
+            (Some(b), None) => b,
+            (None, None) => break,
+        };
+        boxes[i] = range.start..range.start + mid;
+        boxes.push(range.start + mid..range.end);
+    }
+
+    let total = samples.len() as f32;
+    boxes
+        .into_iter()
+        .map(|range| {
+            let mut sum = [0.0f64; 3];
+            for s in &samples[range.clone()] {
+                for (sum, &c) in sum.iter_mut().zip(s) {
+                    *sum += c as f64;
+                }
+            }
+            let len = range.len();
+            Swatch {
+                color: to_srgb8(sum.map(|c| (c / len as f64) as f32)),
+                population: len as f32 / total,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+//! Items related to working with images. This module re-exports the renown
+//! [image crate](https://docs.rs/image) which supports reading and writing PNG, JPEG, GIF, WEBP,
+//! BMP and more.
+//!
+//! Alongside the re-exports, this module provides helpers for working with the colors of an image:
+//!
+//! - **extract_palette** and the **Extractor** find the dominant colors of an image via k-means
+//!   clustering or median cut.
+//! - **quantize** maps each pixel of an image to the nearest color within a palette, optionally
+//!   dithering the result.
+//! - **sort_colors** orders colors by their hue, saturation or lightness.
+//!
+//! Colors are compared within the perceptual Oklab color space.
+//!
+//! ```no_run
+//! use nannou::image::{self, Dither, SortBy};
+//!
+//! let img = image::open("photo.jpg").unwrap();
+//! let mut palette: Vec<_> = image::extract_palette(&img, 6)
+//!     .into_iter()
+//!     .map(|swatch| swatch.color)
+//!     .collect();
+//! image::sort_colors(&mut palette, SortBy::Lightness);
+//! let poster = image::quantize(&img, &palette, Dither::FloydSteinberg);
+//! poster.save("poster.png").unwrap();
+//! ```
+
+use crate::color::{IntoLinSrgba, LinSrgb, LinSrgba, Oklab, Srgb};
+
+pub use self::extract::{extract_palette, Extractor, Method, Swatch};
+pub use self::quantize::{quantize, Dither};
+pub use self::sort::{sort_colors, SortBy};
+pub use image::*;
+
+pub mod extract;
+pub mod quantize;
+pub mod sort;
+
+// The linear intensity of each 8-bit sRGB channel value.
+fn linear_lut() -> [f32; 256] {
+    let mut lut = [0.0; 256];
+    for (i, linear) in lut.iter_mut().enumerate() {
+        let c = i as f32 / 255.0;
+        *linear = if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        };
+    }
+    lut
+}
+
+// Convert an 8-bit sRGB color to Oklab via the given linear intensity lookup table.
+fn to_oklab(lut: &[f32; 256], [r, g, b]: [u8; 3]) -> [f32; 3] {
+    let linear = LinSrgb::new(lut[r as usize], lut[g as usize], lut[b as usize]);
+    let lab = Oklab::from(linear);
+    [lab.l, lab.a, lab.b]
+}
+
+// Convert an Oklab color to 8-bit sRGB, mapping it into the sRGB gamut if necessary.
+fn to_srgb8([l, a, b]: [f32; 3]) -> Srgb<u8> {
+    let linear: LinSrgba = Oklab::new(l, a, b).into_lin_srgba();
+    Srgb::from_linear(linear.color).into_format()
+}
+
+// The squared distance between two Oklab colors.
+fn distance_squared(a: [f32; 3], b: [f32; 3]) -> f32 {
+    let (dl, da, db) = (a[0] - b[0], a[1] - b[1], a[2] - b[2]);
+    dl * dl + da * da + db * db
+}
@@ -0,0 +1,86 @@
+//! Reducing the colors of an image to those of a palette.
+
+use super::{distance_squared, linear_lut, to_oklab};
+use crate::color::{LinSrgb, Oklab, Srgb};
+use image::{DynamicImage, RgbaImage};
+
+/// The dithering applied when quantizing an image.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dither {
+    /// Map each pixel to its nearest palette color, producing flat regions of color.
+    #[default]
+    None,
+    /// Diffuse the difference between each pixel and its palette color onto neighbouring pixels
+    /// via Floyd-Steinberg error diffusion, approximating gradients with patterns of palette colors.
+    FloydSteinberg,
+}
+
+/// Map each pixel of the image to the perceptually nearest color within the palette.
+///
+/// The alpha of each pixel is preserved.
+///
+/// **Panics** if the palette is empty.
+pub fn quantize(image: &DynamicImage, palette: &[Srgb<u8>], dither: Dither) -> RgbaImage {
+    assert!(
+        !palette.is_empty(),
+        "cannot quantize an image to an empty palette"
+    );
+    let lut = linear_lut();
+    let linear = |c: Srgb<u8>| [c.red, c.green, c.blue].map(|c| lut[c as usize]);
+    let colors: Vec<([f32; 3], [f32; 3])> = palette
+        .iter()
+        .map(|&c| (linear(c), to_oklab(&lut, [c.red, c.green, c.blue])))
+        .collect();
+    let mut image = image.to_rgba8();
+    let (w, h) = image.dimensions();
+
+    // The error diffused onto the current and next rows, padded by a pixel either side. Error is
+    // diffused in linear light so that dithered regions preserve the intensity of the original.
+    let mut errors = vec![[0.0f32; 3]; w as usize + 2];
+    let mut next_errors = errors.clone();
+
+    for y in 0..h {
+        for x in 0..w {
+            let pixel = image.get_pixel_mut(x, y);
+            let [r, g, b, a] = pixel.0;
+            let i = x as usize + 1;
+            let mut target = linear(Srgb::new(r, g, b));
+            if dither == Dither::FloydSteinberg {
+                for (t, e) in target.iter_mut().zip(&errors[i]) {
+                    *t += e;
+                }
+            }
+            let nearest = nearest(&colors, target);
+            let c = palette[nearest];
+            pixel.0 = [c.red, c.green, c.blue, a];
+            if dither == Dither::FloydSteinberg {
+                let (color, _) = colors[nearest];
+                for axis in 0..3 {
+                    let error = target[axis] - color[axis];
+                    errors[i + 1][axis] += error * 7.0 / 16.0;
+                    next_errors[i - 1][axis] += error * 3.0 / 16.0;
+                    next_errors[i][axis] += error * 5.0 / 16.0;
+                    next_errors[i + 1][axis] += error / 16.0;
+                }
+            }
+        }
+        std::mem::swap(&mut errors, &mut next_errors);
+        next_errors.iter_mut().for_each(|e| *e = [0.0; 3]);
+    }
+
+    image
+}
+
+// The index of the palette color perceptually nearest to the given linear color.
+fn nearest(colors: &[([f32; 3], [f32; 3])], [r, g, b]: [f32; 3]) -> usize {
+    let lab = Oklab::from(LinSrgb::new(r, g, b));
+    let lab = [lab.l, lab.a, lab.b];
+    let mut nearest = (0, f32::INFINITY);
+    for (i, &(_, c)) in colors.iter().enumerate() {
+        let d = distance_squared(lab, c);
+        if d < nearest.1 {
+            nearest = (i, d);
+        }
+    }
+    nearest.0
+}
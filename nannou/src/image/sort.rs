@@ -0,0 +1,61 @@
+//! Ordering colors by their perceptual properties.
+
+use crate::color::{IntoLinSrgba, LinSrgba, Oklch, Srgb};
+use std::cmp::Ordering;
+
+// Colors with a chroma below this threshold are considered gray.
+const ACHROMATIC_EPSILON: f32 = 1e-3;
+
+/// The property by which colors are ordered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortBy {
+    /// The Oklch hue, starting from pinkish red and moving through orange, yellow, green and blue.
+    ///
+    /// Grays have no hue and are placed before all other colors.
+    Hue,
+    /// The HSL saturation, from gray to fully saturated.
+    Saturation,
+    /// The Oklab perceived lightness, from black to white.
+    Lightness,
+}
+
+/// Sort the colors in ascending order of the given property.
+///
+/// The sort is stable, preserving the order of colors that are equal in the given property.
+pub fn sort_colors<C>(colors: &mut [C], by: SortBy)
+where
+    C: Copy + IntoLinSrgba<f32>,
+{
+    let key = |c: C| {
+        let linear: LinSrgba = c.into_lin_srgba();
+        match by {
+            SortBy::Hue => {
+                let lch = Oklch::from(linear.color);
+                if lch.chroma < ACHROMATIC_EPSILON {
+                    -1.0
+                } else {
+                    lch.hue
+                }
+            }
+            SortBy::Saturation => saturation(Srgb::from_linear(linear.color)),
+            SortBy::Lightness => Oklch::from(linear.color).l,
+        }
+    };
+    let mut keyed: Vec<(f32, C)> = colors.iter().map(|&c| (key(c), c)).collect();
+    keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    for (c, (_, k)) in colors.iter_mut().zip(keyed) {
+        *c = k;
+    }
+}
+
+// The saturation of the color within HSL.
+fn saturation(color: Srgb) -> f32 {
+    let max = color.red.max(color.green).max(color.blue);
+    let min = color.red.min(color.green).min(color.blue);
+    let lightness = (max + min) / 2.0;
+    if max - min <= f32::EPSILON {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    }
+}
@@ -16,6 +16,7 @@
 pub use daggy;
 pub use find_folder;
 pub use lyon;
+pub use usvg;
 pub use winit;
 
 pub use self::app::{App, LoopMode};
@@ -30,6 +31,7 @@ pub use nannou_mesh as mesh;
 pub use nannou_wgpu as wgpu;
 
 pub mod app;
+pub mod camera;
 pub mod draw;
 pub mod ease;
 pub mod event;
@@ -39,7 +41,9 @@ pub mod image;
 pub mod io;
 pub mod noise;
 pub mod prelude;
+pub mod scene;
 pub mod state;
+pub mod svg;
 pub mod text;
 pub mod time;
 pub mod window;
@@ -1,4 +1,10 @@
 //! Items related to [noise generation](https://docs.rs/noise/0.5.1/noise/), API offered via the
 //! [noise-rs crate](https://docs.rs/noise).
+//!
+//! The `core` module provides nannou's own seeded noise functions, which produce the same noise
+//! for a given seed on every target, including `#![no_std]` targets via `nannou_core`.
 
 pub use noise::*;
+
+#[doc(inline)]
+pub use nannou_core::noise as core;
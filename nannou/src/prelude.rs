@@ -1,7 +1,9 @@
 //! A collection of commonly used items that we recommend importing for ease of use.
 
 pub use crate::app::{self, App, LoopMode};
+pub use crate::camera::{Camera, FlyCamera, OrbitCamera, PanZoom};
 pub use crate::draw::Draw;
+pub use crate::ease::{Animation, Easing, Keyframes, Tween};
 pub use crate::event::WindowEvent::*;
 pub use crate::event::{
     AxisMotion, Event, Key, MouseButton, MouseScrollDelta, TouchEvent, TouchPhase,
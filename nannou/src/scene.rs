@@ -0,0 +1,501 @@
+//! A retained scene graph of nodes with hierarchical transforms.
+//!
+//! Unlike the transforms of a **Draw**, which only last for the duration of a frame, a **Scene**
+//! persists its hierarchy across frames. Each **Node** has a local transform relative to its
+//! parent, a visibility flag and any number of attached **Content**s. The world transform of each
+//! node is cached and only recalculated after the local transform of the node or one of its
+//! ancestors changes.
+//!
+//! The whole scene may be drawn in one call via `draw.scene(&scene)`. Nodes are drawn parents
+//! first, with siblings drawn in the order in which they were added. Hiding a node also hides all
+//! of its descendants.
+
+use crate::draw::{self, Draw};
+use crate::geom;
+use crate::glam::{Mat4, Quat, Vec2, Vec3};
+use crate::wgpu;
+use std::cell::Cell;
+use std::fmt;
+
+/// A retained hierarchy of transformed **Node**s.
+#[derive(Debug, Default)]
+pub struct Scene {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    roots: Vec<NodeId>,
+    len: usize,
+}
+
+/// A unique identifier for a **Node** within a **Scene**.
+///
+/// The identifier of a removed node never refers to any node added afterwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+/// A single node within a **Scene**.
+#[derive(Debug)]
+pub struct Node {
+    transform: Mat4,
+    visible: bool,
+    contents: Vec<Content>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// Content attached to a **Node**, drawn with the world transform of the node.
+pub enum Content {
+    /// A mesh drawn with the colors of its vertices.
+    Mesh(draw::Mesh),
+    /// A mesh drawn with the given texture, sampled via the texture coordinates of its vertices.
+    TexturedMesh(draw::Mesh, wgpu::TextureView),
+    /// A texture drawn over the given rect.
+    Texture(wgpu::TextureView, geom::Rect),
+    /// The drawings produced by a function, e.g. `|draw| { draw.ellipse().radius(10.0); }`.
+    Draw(Box<dyn Fn(&Draw)>),
+}
+
+// The storage for a node, reused after the node is removed.
+#[derive(Debug, Default)]
+struct Slot {
+    // Incremented each time the node within the slot is removed, invalidating its id.
+    generation: u32,
+    entry: Option<Entry>,
+}
+
+// A node along with its cached world transform.
+#[derive(Debug)]
+struct Entry {
+    node: Node,
+    world: Cell<Mat4>,
+    dirty: Cell<bool>,
+}
+
+impl Scene {
+    /// Create an empty scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of nodes within the scene.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether or not the scene contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The nodes without a parent, in the order in which they are drawn.
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    /// Add the given node to the root of the scene.
+    pub fn add(&mut self, node: Node) -> NodeId {
+        let id = self.insert(node, None);
+        self.roots.push(id);
+        id
+    }
+
+    /// Add the given node as the last child of the `parent` node.
+    ///
+    /// **Panics** if `parent` is not within the scene.
+    pub fn add_child(&mut self, parent: NodeId, node: Node) -> NodeId {
+        assert!(self.contains(parent), "no parent node with the given id");
+        let id = self.insert(node, Some(parent));
+        self.entry_mut(parent).unwrap().node.children.push(id);
+        id
+    }
+
+    /// Remove the node along with all of its descendants.
+    ///
+    /// Returns the removed node, or `None` if there was no node with the given id.
+    pub fn remove(&mut self, id: NodeId) -> Option<Node> {
+        self.detach(id)?;
+        let mut node = None;
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let entry = self.release(id.index).expect("no entry for descendant");
+            stack.extend(entry.node.children.iter().cloned());
+            self.len -= 1;
+            if node.is_none() {
+                node = Some(entry.node);
+            }
+        }
+        node.map(|mut node| {
+            node.parent = None;
+            node
+        })
+    }
+
+    /// Remove all nodes from the scene.
+    pub fn clear(&mut self) {
+        for index in 0..self.slots.len() {
+            self.release(index);
+        }
+        self.roots.clear();
+        self.len = 0;
+    }
+
+    /// Whether or not the scene contains a node with the given id.
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.entry(id).is_some()
+    }
+
+    /// The node with the given id.
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.entry(id).map(|entry| &entry.node)
+    }
+
+    /// Mutable access to the contents attached to the node with the given id.
+    pub fn contents_mut(&mut self, id: NodeId) -> Option<&mut Vec<Content>> {
+        self.entry_mut(id).map(|entry| &mut entry.node.contents)
+    }
+
+    /// An iterator yielding every node within the scene along with its id.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)> + '_ {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let id = NodeId {
+                index,
+                generation: slot.generation,
+            };
+            slot.entry.as_ref().map(|entry| (id, &entry.node))
+        })
+    }
+
+    /// Set the transform of the node relative to its parent.
+    ///
+    /// Returns `false` if there was no node with the given id.
+    pub fn set_transform(&mut self, id: NodeId, transform: Mat4) -> bool {
+        match self.entry_mut(id) {
+            None => false,
+            Some(entry) => {
+                entry.node.transform = transform;
+                self.invalidate(id);
+                true
+            }
+        }
+    }
+
+    /// Show or hide the node along with all of its descendants.
+    ///
+    /// Returns `false` if there was no node with the given id.
+    pub fn set_visible(&mut self, id: NodeId, visible: bool) -> bool {
+        match self.entry_mut(id) {
+            None => false,
+            Some(entry) => {
+                entry.node.visible = visible;
+                true
+            }
+        }
+    }
+
+    /// Move the node and its descendants to become the last child of `parent`, or a root node if
+    /// `parent` is `None`. The local transform of the node is unchanged.
+    ///
+    /// Returns `false` if either node is not within the scene, or if `parent` is the node itself
+    /// or one of its descendants.
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        if let Some(parent) = parent {
+            if !self.contains(parent) || self.ancestors(parent).any(|a| a == id) {
+                return false;
+            }
+        }
+        self.detach(id);
+        self.entry_mut(id).unwrap().node.parent = parent;
+        match parent {
+            None => self.roots.push(id),
+            Some(parent) => self.entry_mut(parent).unwrap().node.children.push(id),
+        }
+        self.invalidate(id);
+        true
+    }
+
+    /// An iterator yielding the node followed by each of its ancestors up to the root.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut next = if self.contains(id) { Some(id) } else { None };
+        std::iter::from_fn(move || {
+            let id = next?;
+            next = self.node(id).and_then(|node| node.parent);
+            Some(id)
+        })
+    }
+
+    /// The transform of the node relative to the scene, i.e. the transforms of each of its
+    /// ancestors applied to its local transform.
+    pub fn world_transform(&self, id: NodeId) -> Option<Mat4> {
+        let entry = self.entry(id)?;
+        if entry.dirty.get() {
+            // Collect the dirty ancestors up to the nearest clean one. The descendants of a dirty
+            // node are always dirty, so everything above a clean ancestor is clean too.
+            let mut dirty = vec![];
+            let mut world = Mat4::IDENTITY;
+            let mut next = Some(id);
+            while let Some(id) = next {
+                let entry = self.entry(id).expect("no entry for ancestor");
+                if !entry.dirty.get() {
+                    world = entry.world.get();
+                    break;
+                }
+                dirty.push(entry);
+                next = entry.node.parent;
+            }
+            for entry in dirty.into_iter().rev() {
+                world *= entry.node.transform;
+                entry.world.set(world);
+                entry.dirty.set(false);
+            }
+        }
+        Some(entry.world.get())
+    }
+
+    /// Whether or not the node and all of its ancestors are visible.
+    pub fn is_visible(&self, id: NodeId) -> bool {
+        self.contains(id) && self.ancestors(id).all(|a| self.node(a).unwrap().visible)
+    }
+
+    /// Draw every visible node within the scene to the given **Draw**.
+    ///
+    /// The world transform of each node is applied on top of the transform of `draw`.
+    pub fn draw(&self, draw: &Draw) {
+        let mut stack: Vec<NodeId> = self.roots.iter().rev().cloned().collect();
+        while let Some(id) = stack.pop() {
+            let node = self.node(id).expect("no entry for node");
+            if !node.visible {
+                continue;
+            }
+            if !node.contents.is_empty() {
+                let draw = draw.transform(self.world_transform(id).unwrap());
+                for content in &node.contents {
+                    content.draw(&draw);
+                }
+            }
+            stack.extend(node.children.iter().rev().cloned());
+        }
+    }
+
+    fn entry(&self, id: NodeId) -> Option<&Entry> {
+        self.slots
+            .get(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_ref())
+    }
+
+    fn entry_mut(&mut self, id: NodeId) -> Option<&mut Entry> {
+        self.slots
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.entry.as_mut())
+    }
+
+    // Insert the node without attaching it to its parent.
+    fn insert(&mut self, mut node: Node, parent: Option<NodeId>) -> NodeId {
+        node.parent = parent;
+        node.children.clear();
+        let entry = Some(Entry {
+            node,
+            world: Cell::new(Mat4::IDENTITY),
+            dirty: Cell::new(true),
+        });
+        self.len += 1;
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot::default());
+                self.slots.len() - 1
+            }
+        };
+        let slot = &mut self.slots[index];
+        slot.entry = entry;
+        let generation = slot.generation;
+        NodeId { index, generation }
+    }
+
+    // Take the entry from the slot at the given index, invalidating its id and freeing the slot.
+    fn release(&mut self, index: usize) -> Option<Entry> {
+        let slot = &mut self.slots[index];
+        let entry = slot.entry.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index);
+        Some(entry)
+    }
+
+    // Remove the node from the children of its parent, or from the roots.
+    fn detach(&mut self, id: NodeId) -> Option<()> {
+        let siblings = match self.node(id)?.parent {
+            None => &mut self.roots,
+            Some(parent) => &mut self.entry_mut(parent)?.node.children,
+        };
+        siblings.retain(|&sibling| sibling != id);
+        Some(())
+    }
+
+    // Mark the cached world transforms of the node and its descendants as out of date.
+    //
+    // The descendants of a dirty node are always dirty, so there is no need to descend below
+    // nodes that were already dirty.
+    fn invalidate(&self, id: NodeId) {
+        let entry = self.entry(id).expect("no entry for node");
+        entry.dirty.set(true);
+        let mut stack = entry.node.children.clone();
+        while let Some(id) = stack.pop() {
+            let entry = self.entry(id).expect("no entry for descendant");
+            if !entry.dirty.replace(true) {
+                stack.extend(entry.node.children.iter().cloned());
+            }
+        }
+    }
+}
+
+impl Node {
+    /// A visible node with no transform or contents.
+    pub fn new() -> Self {
+        Node {
+            transform: Mat4::IDENTITY,
+            visible: true,
+            contents: Vec::new(),
+            parent: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Apply the given transform to the node's local transform.
+    pub fn transform(mut self, transform: Mat4) -> Self {
+        self.transform *= transform;
+        self
+    }
+
+    /// Translate the node by the given vector.
+    pub fn translate(self, v: Vec3) -> Self {
+        self.transform(Mat4::from_translation(v))
+    }
+
+    /// Translate the node by the given vector along the *x* and *y* axes.
+    pub fn xy(self, v: Vec2) -> Self {
+        self.translate(v.extend(0.0))
+    }
+
+    /// Rotate the node around the *z* axis by the given number of radians.
+    pub fn rotate(self, radians: f32) -> Self {
+        self.transform(Mat4::from_rotation_z(radians))
+    }
+
+    /// Rotate the node by the given quaternion.
+    pub fn quaternion(self, q: Quat) -> Self {
+        self.transform(Mat4::from_quat(q))
+    }
+
+    /// Scale the node uniformly by the given amount.
+    pub fn scale(self, s: f32) -> Self {
+        self.transform(Mat4::from_scale(Vec3::splat(s)))
+    }
+
+    /// Specify whether or not the node and its descendants are drawn.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Attach the given content to the node.
+    pub fn content(mut self, content: Content) -> Self {
+        self.contents.push(content);
+        self
+    }
+
+    /// Attach the drawings produced by the given function to the node.
+    pub fn draw<F>(self, f: F) -> Self
+    where
+        F: 'static + Fn(&Draw),
+    {
+        self.content(Content::Draw(Box::new(f)))
+    }
+
+    /// Attach a mesh drawn with the colors of its vertices to the node.
+    pub fn mesh(self, mesh: draw::Mesh) -> Self {
+        self.content(Content::Mesh(mesh))
+    }
+
+    /// Attach a texture drawn over the given rect to the node.
+    pub fn texture(self, view: wgpu::TextureView, rect: geom::Rect) -> Self {
+        self.content(Content::Texture(view, rect))
+    }
+
+    /// The transform of the node relative to its parent.
+    pub fn local_transform(&self) -> Mat4 {
+        self.transform
+    }
+
+    /// Whether or not the node is visible, regardless of the visibility of its ancestors.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// The parent of the node, or `None` if it is a root node.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// The children of the node, in the order in which they are drawn.
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    /// The contents attached to the node.
+    pub fn contents(&self) -> &[Content] {
+        &self.contents
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Content {
+    /// Draw the content with the given **Draw**.
+    pub fn draw(&self, draw: &Draw) {
+        match *self {
+            Content::Mesh(ref mesh) => {
+                let points = mesh.points().iter().cloned();
+                let vertices = points.zip(mesh.colors().iter().cloned());
+                let indices = mesh.indices().iter().map(|&i| i as usize);
+                draw.mesh().indexed_colored(vertices, indices);
+            }
+            Content::TexturedMesh(ref mesh, ref view) => {
+                let points = mesh.points().iter().cloned();
+                let vertices = points.zip(mesh.tex_coords().iter().cloned());
+                let indices = mesh.indices().iter().map(|&i| i as usize);
+                draw.mesh().indexed_textured(view, vertices, indices);
+            }
+            Content::Texture(ref view, rect) => {
+                draw.texture(view).xy(rect.xy()).wh(rect.wh());
+            }
+            Content::Draw(ref f) => f(draw),
+        }
+    }
+}
+
+impl fmt::Debug for Content {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Content::Mesh(ref mesh) => f.debug_tuple("Mesh").field(mesh).finish(),
+            Content::TexturedMesh(ref mesh, ref view) => f
+                .debug_tuple("TexturedMesh")
+                .field(mesh)
+                .field(view)
+                .finish(),
+            Content::Texture(ref view, rect) => {
+                f.debug_tuple("Texture").field(view).field(&rect).finish()
+            }
+            Content::Draw(_) => f.debug_tuple("Draw").finish(),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Loading SVG documents into trees of **geom::Path**s along with their styling.
+//!
+//! Documents are parsed and normalised via the `usvg` crate. Basic shapes are converted to paths,
+//! CSS and presentation attributes are resolved and `use` elements are expanded.
+//!
+//! All transforms, including the mapping of the `viewBox` to the document's `width` and `height`,
+//! are applied to the resulting paths. Paths are described in nannou's coordinate system, where
+//! the centre of the document lies at the origin and the *y* axis points upwards. Stroke weights
+//! are scaled along with their paths.
+//!
+//! The following are approximated:
+//!
+//! - Group opacity is multiplied into the alpha of the fill and stroke of each descendant path.
+//! - Gradients are replaced by the average color of their stops.
+//!
+//! Text, images, patterns, clip paths, masks, filters and dashes are ignored.
+
+use crate::color::{self, Srgba};
+use crate::draw::Draw;
+use crate::geom::{self, pt2, Point2, Vec2};
+use lyon::tessellation::{FillRule, LineCap, LineJoin};
+use std::path::Path as FilePath;
+
+pub use usvg::Error;
+
+/// A loaded SVG document.
+#[derive(Clone, Debug)]
+pub struct Document {
+    /// The width and height of the document in points.
+    pub size: Vec2,
+    /// The root group containing all paths within the document.
+    pub root: Group,
+}
+
+/// A group of nodes within an SVG document, i.e. a `<g>` element.
+#[derive(Clone, Debug, Default)]
+pub struct Group {
+    /// The `id` of the group, or an empty string if it has none.
+    pub id: String,
+    /// The group's children, in the order in which they should be drawn.
+    pub children: Vec<Node>,
+}
+
+/// A single node within the tree of an SVG document.
+#[derive(Clone, Debug)]
+pub enum Node {
+    Group(Group),
+    Path(Path),
+}
+
+/// A path within an SVG document along with its style.
+#[derive(Clone, Debug)]
+pub struct Path {
+    /// The `id` of the path, or an empty string if it has none.
+    pub id: String,
+    /// The geometry of the path, with all transforms applied.
+    pub path: geom::Path,
+    /// How the path should be filled, if at all.
+    pub fill: Option<Fill>,
+    /// How the path should be stroked, if at all.
+    pub stroke: Option<Stroke>,
+}
+
+/// The fill style of a **Path**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub color: Srgba,
+    pub rule: FillRule,
+}
+
+/// The stroke style of a **Path**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stroke {
+    pub color: Srgba,
+    pub weight: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    pub miter_limit: f32,
+}
+
+/// An iterator yielding all paths within a **Group** and its descendants in draw order.
+#[derive(Clone, Debug)]
+pub struct Paths<'a> {
+    stack: Vec<std::slice::Iter<'a, Node>>,
+}
+
+impl Document {
+    /// Load the SVG or SVGZ file at the given path.
+    pub fn load<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<FilePath>,
+    {
+        let tree = usvg::Tree::from_file(path, &usvg::Options::default())?;
+        Ok(Self::from_tree(&tree))
+    }
+
+    /// Parse an SVG document from the given data, which may also be gzip compressed.
+    pub fn from_data(data: &[u8]) -> Result<Self, Error> {
+        let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+        Ok(Self::from_tree(&tree))
+    }
+
+    /// Convert an existing `usvg` tree.
+    pub fn from_tree(tree: &usvg::Tree) -> Self {
+        let svg = tree.svg_node();
+        let (w, h) = (svg.size.width(), svg.size.height());
+        // Map the view box to the document size, then to nannou's coordinates.
+        let mut transform = usvg::Transform::new(1.0, 0.0, 0.0, -1.0, -w / 2.0, h / 2.0);
+        transform.append(&usvg::utils::view_box_to_transform(
+            svg.view_box.rect,
+            svg.view_box.aspect,
+            svg.size,
+        ));
+        let mut root = Group::default();
+        convert_children(tree, &tree.root(), transform, 1.0, &mut root);
+        let size = [w as f32, h as f32].into();
+        Document { size, root }
+    }
+
+    /// The rectangle covered by the document, centred at the origin.
+    pub fn rect(&self) -> geom::Rect {
+        geom::Rect::from_wh(self.size)
+    }
+
+    /// All paths within the document in draw order.
+    pub fn paths(&self) -> Paths<'_> {
+        self.root.paths()
+    }
+}
+
+impl std::str::FromStr for Document {
+    type Err = Error;
+    /// Parse an SVG document from the given string.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let tree = usvg::Tree::from_str(text, &usvg::Options::default())?;
+        Ok(Self::from_tree(&tree))
+    }
+}
+
+impl Group {
+    /// All paths within this group and its descendants in draw order.
+    pub fn paths(&self) -> Paths<'_> {
+        Paths {
+            stack: vec![self.children.iter()],
+        }
+    }
+
+    /// Find the group or path with the given `id` within this group and its descendants.
+    pub fn find(&self, id: &str) -> Option<&Node> {
+        for child in &self.children {
+            match child {
+                Node::Group(g) if g.id == id => return Some(child),
+                Node::Path(p) if p.id == id => return Some(child),
+                Node::Group(g) => {
+                    if let Some(node) = g.find(id) {
+                        return Some(node);
+                    }
+                }
+                Node::Path(_) => (),
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = &'a Path;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.stack.last_mut()?.next() {
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+                Some(node) => node,
+            };
+            match node {
+                Node::Group(g) => self.stack.push(g.children.iter()),
+                Node::Path(p) => return Some(p),
+            }
+        }
+    }
+}
+
+/// Draw all paths within the given document, filling and then stroking each in turn.
+///
+/// This is called by `Draw::svg`.
+pub fn draw(draw: &Draw, document: &Document) {
+    for path in document.paths() {
+        if let Some(fill) = path.fill {
+            draw.path()
+                .fill()
+                .rule(fill.rule)
+                .color(fill.color)
+                .events(path.path.iter());
+        }
+        if let Some(stroke) = path.stroke {
+            draw.path()
+                .stroke()
+                .weight(stroke.weight)
+                .caps(stroke.cap)
+                .join(stroke.join)
+                .miter_limit(stroke.miter_limit)
+                .color(stroke.color)
+                .events(path.path.iter());
+        }
+    }
+}
+
+// Convert the children of the given `usvg` node into the given group.
+fn convert_children(
+    tree: &usvg::Tree,
+    node: &usvg::Node,
+    transform: usvg::Transform,
+    opacity: f64,
+    group: &mut Group,
+) {
+    for child in node.children() {
+        match *child.borrow() {
+            usvg::NodeKind::Group(ref g) => {
+                let mut ts = transform;
+                ts.append(&g.transform);
+                let mut converted = Group {
+                    id: g.id.clone(),
+                    children: vec![],
+                };
+                let opacity = opacity * g.opacity.value();
+                convert_children(tree, &child, ts, opacity, &mut converted);
+                group.children.push(Node::Group(converted));
+            }
+            usvg::NodeKind::Path(ref p) => {
+                if p.visibility != usvg::Visibility::Visible {
+                    continue;
+                }
+                let mut ts = transform;
+                ts.append(&p.transform);
+                if let Some(path) = convert_path(tree, p, ts, opacity) {
+                    group.children.push(Node::Path(path));
+                }
+            }
+            // Paint servers, clip paths, etc are only referenced from paths.
+            _ => (),
+        }
+    }
+}
+
+// Convert a `usvg` path, returning `None` if it has neither fill nor stroke.
+fn convert_path(
+    tree: &usvg::Tree,
+    p: &usvg::Path,
+    ts: usvg::Transform,
+    opacity: f64,
+) -> Option<Path> {
+    let fill = p.fill.as_ref().and_then(|fill| {
+        let color = paint_color(tree, &fill.paint, fill.opacity.value() * opacity)?;
+        let rule = match fill.rule {
+            usvg::FillRule::NonZero => FillRule::NonZero,
+            usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+        };
+        Some(Fill { color, rule })
+    });
+    let stroke = p.stroke.as_ref().and_then(|stroke| {
+        let color = paint_color(tree, &stroke.paint, stroke.opacity.value() * opacity)?;
+        let (sx, sy) = ts.get_scale();
+        let weight = (stroke.width.value() * (sx * sy).sqrt()) as f32;
+        let cap = match stroke.linecap {
+            usvg::LineCap::Butt => LineCap::Butt,
+            usvg::LineCap::Round => LineCap::Round,
+            usvg::LineCap::Square => LineCap::Square,
+        };
+        let join = match stroke.linejoin {
+            usvg::LineJoin::Miter => LineJoin::Miter,
+            usvg::LineJoin::Round => LineJoin::Round,
+            usvg::LineJoin::Bevel => LineJoin::Bevel,
+        };
+        let miter_limit = stroke.miterlimit.value() as f32;
+        Some(Stroke {
+            color,
+            weight,
+            cap,
+            join,
+            miter_limit,
+        })
+    });
+    if fill.is_none() && stroke.is_none() {
+        return None;
+    }
+
+    let point = |x: f64, y: f64| -> Point2 {
+        let (x, y) = ts.apply(x, y);
+        pt2(x as f32, y as f32)
+    };
+    let mut builder = geom::path();
+    let mut open = false;
+    for segment in p.data.iter() {
+        builder = match *segment {
+            usvg::PathSegment::MoveTo { x, y } => {
+                open = true;
+                builder.move_to(point(x, y))
+            }
+            usvg::PathSegment::LineTo { x, y } => builder.line_to(point(x, y)),
+            usvg::PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => builder.cubic_bezier_to(point(x1, y1), point(x2, y2), point(x, y)),
+            usvg::PathSegment::ClosePath if open => {
+                open = false;
+                builder.close()
+            }
+            usvg::PathSegment::ClosePath => builder,
+        };
+    }
+
+    Some(Path {
+        id: p.id.clone(),
+        path: builder.build(),
+        fill,
+        stroke,
+    })
+}
+
+// The color of the given paint, or `None` if it cannot be represented with a single color.
+fn paint_color(tree: &usvg::Tree, paint: &usvg::Paint, opacity: f64) -> Option<Srgba> {
+    let (r, g, b, a) = match *paint {
+        usvg::Paint::Color(c) => (
+            c.red as f64 / 255.0,
+            c.green as f64 / 255.0,
+            c.blue as f64 / 255.0,
+            1.0,
+        ),
+        usvg::Paint::Link(ref id) => {
+            let node = tree.defs_by_id(id)?;
+            let kind = node.borrow();
+            let stops = match *kind {
+                usvg::NodeKind::LinearGradient(ref g) => &g.base.stops,
+                usvg::NodeKind::RadialGradient(ref g) => &g.base.stops,
+                _ => return None,
+            };
+            if stops.is_empty() {
+                return None;
+            }
+            let n = stops.len() as f64;
+            stops
+                .iter()
+                .fold((0.0, 0.0, 0.0, 0.0), |(r, g, b, a), stop| {
+                    (
+                        r + stop.color.red as f64 / 255.0 / n,
+                        g + stop.color.green as f64 / 255.0 / n,
+                        b + stop.color.blue as f64 / 255.0 / n,
+                        a + stop.opacity.value() / n,
+                    )
+                })
+        }
+    };
+    let a = a * opacity;
+    Some(color::srgba(r as f32, g as f32, b as f32, a as f32))
+}
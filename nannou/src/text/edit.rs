@@ -0,0 +1,673 @@
+//! A model for editable text, combining the `text::cursor` logic with keyboard input.
+//!
+//! The **TextEdit** type tracks some text along with a caret, an optional selection and any
+//! in-progress IME composition (preedit). Feed it window events via `raw_window_event` (or the
+//! lower-level `key_pressed` and `received_character` methods) and draw it via
+//! `draw.text_edit(&edit)`.
+
+use crate::event::{Key, ModifiersState};
+use crate::geom::{self, Point2, Range};
+use crate::glam::Vec2;
+use crate::text::{self, cursor, Layout, Scalar};
+use crate::window::Window;
+use std::borrow::Cow;
+
+/// Editable text along with a caret, selection and IME composition state.
+#[derive(Clone, Debug)]
+pub struct TextEdit {
+    text: String,
+    // The byte index of the end of the selection that does not move with the caret.
+    anchor: usize,
+    // The byte index of the caret.
+    caret: usize,
+    preedit: Option<Preedit>,
+    layout: Layout,
+    wh: Vec2,
+    multiline: bool,
+    modifiers: ModifiersState,
+    // The *x* position the caret attempts to return to when moving between lines.
+    goal_x: Option<Scalar>,
+}
+
+/// Text that is currently being composed via an input method editor (IME).
+///
+/// The preedit is displayed at the caret but is not part of the text until committed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Preedit {
+    /// The text currently being composed.
+    pub text: String,
+    /// The byte index of the IME's cursor within the preedit `text`, if any.
+    pub cursor: Option<usize>,
+}
+
+impl TextEdit {
+    /// The dimensions of the text's layout rectangle used by default.
+    pub const DEFAULT_WH: [Scalar; 2] = [200.0, 200.0];
+
+    /// Create a new **TextEdit** with the given initial text.
+    ///
+    /// The caret is placed at the end of the text.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let text = text.into();
+        let caret = text.len();
+        TextEdit {
+            text,
+            anchor: caret,
+            caret,
+            preedit: None,
+            layout: Default::default(),
+            wh: Self::DEFAULT_WH.into(),
+            multiline: true,
+            modifiers: Default::default(),
+            goal_x: None,
+        }
+    }
+
+    /// The text that has been entered so far, excluding any preedit.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text, placing the caret at the end and clearing the selection and preedit.
+    pub fn set_text<S>(&mut self, text: S)
+    where
+        S: Into<String>,
+    {
+        self.text = text.into();
+        self.preedit = None;
+        self.set_caret(self.text.len(), false);
+    }
+
+    /// Consume the **TextEdit** and return the inner text.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// The layout used to position the text.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Specify the layout used to position the text.
+    ///
+    /// This should match the layout used when drawing the text.
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+        self.goal_x = None;
+    }
+
+    /// The dimensions of the rectangle in which the text is laid out.
+    pub fn wh(&self) -> Vec2 {
+        self.wh
+    }
+
+    /// Specify the dimensions of the rectangle in which the text is laid out.
+    pub fn set_wh(&mut self, wh: Vec2) {
+        self.wh = wh;
+        self.goal_x = None;
+    }
+
+    /// Whether or not the `Return` key inserts a new line.
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    /// Specify whether or not the `Return` key inserts a new line.
+    ///
+    /// By default, this is `true`.
+    pub fn set_multiline(&mut self, multiline: bool) {
+        self.multiline = multiline;
+    }
+
+    /// The byte index of the caret within the text.
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Move the caret to the given byte index.
+    ///
+    /// The index is clamped to the text and rounded down to the nearest `char` boundary. If
+    /// `extend_selection` is `true`, the selection is extended to the new position.
+    pub fn set_caret(&mut self, index: usize, extend_selection: bool) {
+        let mut index = index.min(self.text.len());
+        while !self.text.is_char_boundary(index) {
+            index -= 1;
+        }
+        self.caret = index;
+        if !extend_selection {
+            self.anchor = index;
+        }
+        self.goal_x = None;
+    }
+
+    /// The byte range of the current selection, or `None` if nothing is selected.
+    pub fn selection(&self) -> Option<std::ops::Range<usize>> {
+        if self.anchor == self.caret {
+            None
+        } else {
+            let start = self.anchor.min(self.caret);
+            let end = self.anchor.max(self.caret);
+            Some(start..end)
+        }
+    }
+
+    /// The currently selected text, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.text[range])
+    }
+
+    /// Select the given byte range of the text, placing the caret at the end of the range.
+    pub fn select(&mut self, range: std::ops::Range<usize>) {
+        self.set_caret(range.start, false);
+        self.set_caret(range.end, true);
+    }
+
+    /// Select all text.
+    pub fn select_all(&mut self) {
+        self.select(0..self.text.len());
+    }
+
+    /// Collapse the selection to the caret.
+    pub fn clear_selection(&mut self) {
+        self.anchor = self.caret;
+    }
+
+    /// Remove the selected text, returning it.
+    ///
+    /// This is useful for implementing "cut" in combination with a clipboard.
+    pub fn cut_selection(&mut self) -> Option<String> {
+        let range = self.selection()?;
+        let cut = self.text[range.clone()].to_string();
+        self.text.replace_range(range.clone(), "");
+        self.set_caret(range.start, false);
+        Some(cut)
+    }
+
+    /// Insert the given string at the caret, replacing the selection if there is one.
+    pub fn insert_str(&mut self, s: &str) {
+        let range = self.selection().unwrap_or(self.caret..self.caret);
+        self.text.replace_range(range.clone(), s);
+        self.set_caret(range.start + s.len(), false);
+    }
+
+    /// Insert the given character at the caret, replacing the selection if there is one.
+    pub fn insert_char(&mut self, ch: char) {
+        let mut buf = [0u8; 4];
+        self.insert_str(ch.encode_utf8(&mut buf));
+    }
+
+    /// Delete the selection or the character before the caret.
+    ///
+    /// Returns `false` if there was nothing to delete.
+    pub fn backspace(&mut self) -> bool {
+        let start = prev_char_boundary(&self.text, self.caret);
+        self.delete_selection_or(start..self.caret)
+    }
+
+    /// Delete the selection or the character after the caret.
+    ///
+    /// Returns `false` if there was nothing to delete.
+    pub fn delete(&mut self) -> bool {
+        let end = next_char_boundary(&self.text, self.caret);
+        self.delete_selection_or(self.caret..end)
+    }
+
+    /// Delete the selection or everything from the start of the word before the caret.
+    ///
+    /// Returns `false` if there was nothing to delete.
+    pub fn delete_word_backward(&mut self) -> bool {
+        let start = prev_word_start(&self.text, self.caret);
+        self.delete_selection_or(start..self.caret)
+    }
+
+    /// Delete the selection or everything up to the end of the word after the caret.
+    ///
+    /// Returns `false` if there was nothing to delete.
+    pub fn delete_word_forward(&mut self) -> bool {
+        let end = next_word_end(&self.text, self.caret);
+        self.delete_selection_or(self.caret..end)
+    }
+
+    /// Move the caret one character to the left.
+    ///
+    /// If there is a selection and `extend_selection` is `false`, the selection is collapsed to
+    /// its start instead.
+    pub fn move_left(&mut self, extend_selection: bool) {
+        let index = match self.selection() {
+            Some(range) if !extend_selection => range.start,
+            _ => prev_char_boundary(&self.text, self.caret),
+        };
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret one character to the right.
+    ///
+    /// If there is a selection and `extend_selection` is `false`, the selection is collapsed to
+    /// its end instead.
+    pub fn move_right(&mut self, extend_selection: bool) {
+        let index = match self.selection() {
+            Some(range) if !extend_selection => range.end,
+            _ => next_char_boundary(&self.text, self.caret),
+        };
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret to the start of the word before the caret.
+    pub fn move_word_left(&mut self, extend_selection: bool) {
+        let index = prev_word_start(&self.text, self.caret);
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret to the end of the word after the caret.
+    pub fn move_word_right(&mut self, extend_selection: bool) {
+        let index = next_word_end(&self.text, self.caret);
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret to the start of the current line.
+    ///
+    /// Lines are separated by newline characters, rather than by wrapping.
+    pub fn move_line_start(&mut self, extend_selection: bool) {
+        let index = self.text[..self.caret]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret to the end of the current line.
+    ///
+    /// Lines are separated by newline characters, rather than by wrapping.
+    pub fn move_line_end(&mut self, extend_selection: bool) {
+        let index = self.text[self.caret..]
+            .find('\n')
+            .map(|i| self.caret + i)
+            .unwrap_or(self.text.len());
+        self.set_caret(index, extend_selection);
+    }
+
+    /// Move the caret to the closest position on the line above, as laid out.
+    pub fn move_up(&mut self, extend_selection: bool) {
+        self.move_vertically(-1, extend_selection);
+    }
+
+    /// Move the caret to the closest position on the line below, as laid out.
+    pub fn move_down(&mut self, extend_selection: bool) {
+        self.move_vertically(1, extend_selection);
+    }
+
+    /// The text currently being composed via the IME, if any.
+    pub fn preedit(&self) -> Option<&Preedit> {
+        self.preedit.as_ref()
+    }
+
+    /// Update the text being composed via the IME.
+    ///
+    /// The selection is replaced by the composition. Passing an empty `text` clears the preedit.
+    pub fn set_preedit<S>(&mut self, text: S, cursor: Option<usize>)
+    where
+        S: Into<String>,
+    {
+        let text = text.into();
+        if text.is_empty() {
+            self.preedit = None;
+            return;
+        }
+        if self.selection().is_some() {
+            self.delete_selection_or(self.caret..self.caret);
+        }
+        let cursor = cursor.map(|c| c.min(text.len()));
+        self.preedit = Some(Preedit { text, cursor });
+    }
+
+    /// Insert the composed text at the caret and clear the preedit.
+    pub fn commit_preedit(&mut self) {
+        if let Some(preedit) = self.preedit.take() {
+            self.insert_str(&preedit.text);
+        }
+    }
+
+    /// Discard the text being composed.
+    pub fn clear_preedit(&mut self) {
+        self.preedit = None;
+    }
+
+    /// The text as it should be displayed, with any preedit inserted at the caret.
+    pub fn display_text(&self) -> Cow<str> {
+        match self.preedit {
+            None => Cow::Borrowed(&self.text),
+            Some(ref preedit) => {
+                let mut s = String::with_capacity(self.text.len() + preedit.text.len());
+                s.push_str(&self.text[..self.caret]);
+                s.push_str(&preedit.text);
+                s.push_str(&self.text[self.caret..]);
+                Cow::Owned(s)
+            }
+        }
+    }
+
+    /// The byte index of the caret within the `display_text`.
+    ///
+    /// While composing, this is the position of the IME's cursor within the preedit.
+    pub fn display_caret(&self) -> usize {
+        match self.preedit {
+            None => self.caret,
+            Some(ref p) => self.caret + p.cursor.unwrap_or(p.text.len()),
+        }
+    }
+
+    /// The byte range occupied by the preedit within the `display_text`.
+    pub fn display_preedit(&self) -> Option<std::ops::Range<usize>> {
+        self.preedit
+            .as_ref()
+            .map(|p| self.caret..self.caret + p.text.len())
+    }
+
+    /// Lay out the `display_text` with the edit's `layout` and `wh`.
+    ///
+    /// The text is laid out relative to the centre of its rectangle, matching `draw.text(..)`.
+    pub fn layout_text(&self) -> text::Text<'static> {
+        let rect = geom::Rect::from_wh(self.wh);
+        text::text(&self.display_text())
+            .layout(&self.layout)
+            .build(rect)
+            .into_owned()
+    }
+
+    /// The rectangle occupied by the caret, relative to the centre of the text.
+    ///
+    /// The rectangle has a width of zero and spans the height of the caret's line.
+    pub fn caret_rect(&self) -> geom::Rect {
+        let text = self.layout_text();
+        caret_rect(&text, self.display_caret())
+    }
+
+    /// The cursor **Index** of the caret within the laid out `display_text`.
+    pub fn cursor_index(&self) -> cursor::Index {
+        let text = self.layout_text();
+        cursor_index(&text, self.display_caret())
+    }
+
+    /// Place the caret at the text position closest to the given point.
+    ///
+    /// The point is relative to the centre of the text. Any preedit is committed first.
+    pub fn click(&mut self, point: Point2, extend_selection: bool) {
+        self.commit_preedit();
+        let text = self.layout_text();
+        if let Some((index, _)) = cursor::closest_cursor_index_and_xy(point, xys_per_line(&text)) {
+            let index = byte_index(&text, index);
+            self.set_caret(index, extend_selection);
+        }
+    }
+
+    /// Move the window's IME candidate box to the caret.
+    ///
+    /// `position` is the position at which the centre of the text is drawn in window
+    /// coordinates.
+    pub fn set_ime_position(&self, window: &Window, position: Point2) {
+        let caret = self.caret_rect();
+        let (w, h) = window.inner_size_points();
+        let x = position.x + caret.x() + w / 2.0;
+        let y = h / 2.0 - (position.y + caret.bottom());
+        window.set_ime_position_points(x, y);
+    }
+
+    /// Handle a key press with the given modifiers.
+    ///
+    /// Returns `true` if the key was handled. Keys are ignored while composing via the IME.
+    pub fn key_pressed(&mut self, key: Key, mods: ModifiersState) -> bool {
+        if self.preedit.is_some() {
+            return false;
+        }
+        let shift = mods.shift();
+        let word = mods.ctrl() || mods.alt();
+        let command = mods.ctrl() || mods.logo();
+        match key {
+            Key::Left if word => self.move_word_left(shift),
+            Key::Left => self.move_left(shift),
+            Key::Right if word => self.move_word_right(shift),
+            Key::Right => self.move_right(shift),
+            Key::Up => self.move_up(shift),
+            Key::Down => self.move_down(shift),
+            Key::Home if command => self.set_caret(0, shift),
+            Key::Home => self.move_line_start(shift),
+            Key::End if command => self.set_caret(self.text.len(), shift),
+            Key::End => self.move_line_end(shift),
+            Key::Back if word => {
+                self.delete_word_backward();
+            }
+            Key::Back => {
+                self.backspace();
+            }
+            Key::Delete if word => {
+                self.delete_word_forward();
+            }
+            Key::Delete => {
+                self.delete();
+            }
+            Key::Return | Key::NumpadEnter if self.multiline => self.insert_char('\n'),
+            Key::A if command => self.select_all(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handle a character received from the window.
+    ///
+    /// Control characters and characters entered alongside a command modifier are ignored, as
+    /// they are handled by `key_pressed`. Characters entered with both `Ctrl` and `Alt` held are
+    /// inserted, as this is how Windows reports the `AltGr` key. Returns `true` if the character
+    /// was inserted.
+    pub fn received_character(&mut self, ch: char) -> bool {
+        let mods = self.modifiers;
+        let command = (mods.ctrl() && !mods.alt()) || mods.logo();
+        if ch.is_control() || command {
+            return false;
+        }
+        self.preedit = None;
+        self.insert_char(ch);
+        true
+    }
+
+    /// Update the **TextEdit** from a raw window event.
+    ///
+    /// Handles modifier changes, key presses and received characters. This is designed to be
+    /// called from a window's `raw_event` function. Returns `true` if the event was handled.
+    pub fn raw_window_event(&mut self, event: &winit::event::WindowEvent) -> bool {
+        match *event {
+            winit::event::WindowEvent::ModifiersChanged(mods) => {
+                self.modifiers = mods;
+                false
+            }
+            winit::event::WindowEvent::KeyboardInput {
+                input:
+                    winit::event::KeyboardInput {
+                        state: winit::event::ElementState::Pressed,
+                        virtual_keycode: Some(key),
+                        ..
+                    },
+                ..
+            } => self.key_pressed(key, self.modifiers),
+            winit::event::WindowEvent::ReceivedCharacter(ch) => self.received_character(ch),
+            _ => false,
+        }
+    }
+
+    // Delete the selection if there is one, otherwise the given range.
+    fn delete_selection_or(&mut self, range: std::ops::Range<usize>) -> bool {
+        let range = self.selection().unwrap_or(range);
+        if range.start == range.end {
+            return false;
+        }
+        self.text.replace_range(range.clone(), "");
+        self.set_caret(range.start, false);
+        true
+    }
+
+    // Move the caret up (negative) or down (positive) by the given number of laid out lines.
+    fn move_vertically(&mut self, lines: isize, extend_selection: bool) {
+        let text = self.layout_text();
+        let index = cursor_index(&text, self.caret);
+        let goal_x = match self.goal_x {
+            Some(x) => x,
+            None => caret_rect(&text, self.caret).x(),
+        };
+        let line = index.line as isize + lines;
+        let new_index = if line < 0 {
+            0
+        } else if line as usize >= text.num_lines() {
+            self.text.len()
+        } else {
+            let (xs, _) = match xys_per_line(&text).nth(line as usize) {
+                Some(line_xys) => line_xys,
+                None => return,
+            };
+            let (char, _) = cursor::closest_cursor_index_on_line(goal_x, xs);
+            let line = line as usize;
+            byte_index(&text, cursor::Index { line, char })
+        };
+        self.set_caret(new_index, extend_selection);
+        self.goal_x = Some(goal_x);
+    }
+}
+
+impl Default for TextEdit {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+impl From<String> for TextEdit {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<'a> From<&'a str> for TextEdit {
+    fn from(s: &'a str) -> Self {
+        Self::new(s)
+    }
+}
+
+/// Every possible cursor position within each line of the given laid out text.
+///
+/// Unlike `cursor::xys_per_line_from_text`, positions account for the text's *y* alignment
+/// within its layout rectangle.
+pub fn xys_per_line<'a, 'b: 'a>(
+    text: &'a text::Text<'b>,
+) -> impl 'a + Iterator<Item = (cursor::Xs<'a, 'a>, Range)> {
+    let lines_with_rects = text.line_infos().iter().cloned().zip(text.line_rects());
+    cursor::xys_per_line(
+        lines_with_rects,
+        text.font(),
+        text.text(),
+        text.layout().font_size,
+    )
+}
+
+/// Convert the given byte index into the laid out text into a cursor **Index**.
+pub fn cursor_index(text: &text::Text, byte_index: usize) -> cursor::Index {
+    let char_index = text.text()[..byte_index].chars().count();
+    cursor::index_before_char(text.line_infos().iter().cloned(), char_index)
+        .unwrap_or(cursor::Index { line: 0, char: 0 })
+}
+
+/// Convert the given cursor **Index** into a byte index into the laid out text.
+pub fn byte_index(text: &text::Text, index: cursor::Index) -> usize {
+    let info = match text.line_infos().get(index.line) {
+        None => return text.text().len(),
+        Some(info) => info,
+    };
+    let line = &text.text()[info.byte_range()];
+    let offset = line
+        .char_indices()
+        .nth(index.char)
+        .map(|(i, _)| i)
+        .unwrap_or(line.len());
+    info.start_byte + offset
+}
+
+/// The zero-width rectangle spanning the line at the given byte index into the laid out text.
+pub fn caret_rect(text: &text::Text, byte_index: usize) -> geom::Rect {
+    let index = cursor_index(text, byte_index);
+    match cursor::xy_at(xys_per_line(text), index) {
+        Some((x, y)) => geom::Rect {
+            x: Range::new(x, x),
+            y,
+        },
+        None => geom::Rect::from_w_h(0.0, text.layout().font_size as Scalar),
+    }
+}
+
+/// The rectangles covering the given byte range of the laid out text, one per line.
+pub fn range_rects(text: &text::Text, range: std::ops::Range<usize>) -> Vec<geom::Rect> {
+    let start = cursor_index(text, range.start);
+    let end = cursor_index(text, range.end);
+    xys_per_line(text)
+        .enumerate()
+        .skip(start.line)
+        .take(end.line + 1 - start.line)
+        .filter_map(|(i, (xs, y))| {
+            let xs: Vec<Scalar> = xs.collect();
+            let start_char = if i == start.line { start.char } else { 0 };
+            let end_char = if i == end.line {
+                end.char
+            } else {
+                xs.len() - 1
+            };
+            let x_start = *xs.get(start_char)?;
+            let x_end = *xs.get(end_char).or(xs.last())?;
+            Some(geom::Rect {
+                x: Range::new(x_start, x_end),
+                y,
+            })
+        })
+        .collect()
+}
+
+// The byte index of the `char` boundary before the given index.
+fn prev_char_boundary(s: &str, index: usize) -> usize {
+    s[..index]
+        .char_indices()
+        .next_back()
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+// The byte index of the `char` boundary after the given index.
+fn next_char_boundary(s: &str, index: usize) -> usize {
+    s[index..]
+        .chars()
+        .next()
+        .map(|ch| index + ch.len_utf8())
+        .unwrap_or(index)
+}
+
+// The start of the word before the given index, skipping any whitespace in between.
+fn prev_word_start(s: &str, index: usize) -> usize {
+    let mut hit_non_whitespace = false;
+    for (i, ch) in s[..index].char_indices().rev() {
+        if !ch.is_whitespace() {
+            hit_non_whitespace = true;
+        } else if hit_non_whitespace {
+            return i + ch.len_utf8();
+        }
+    }
+    0
+}
+
+// The end of the word after the given index, skipping any whitespace in between.
+fn next_word_end(s: &str, index: usize) -> usize {
+    let mut hit_non_whitespace = false;
+    for (i, ch) in s[index..].char_indices() {
+        if !ch.is_whitespace() {
+            hit_non_whitespace = true;
+        } else if hit_non_whitespace {
+            return index + i;
+        }
+    }
+    s.len()
+}
@@ -4,16 +4,19 @@
 //! important role in future GUI work.
 
 pub mod cursor;
+pub mod edit;
 pub mod font;
 pub mod glyph;
 pub mod layout;
 pub mod line;
+pub mod sdf;
 pub mod rt {
     //! Re-exported RustType geometric types.
     pub use rusttype::{gpu_cache, point, vector, Point, Rect, Vector};
 }
 
 // Re-export all relevant rusttype types here.
+pub use self::edit::TextEdit;
 pub use self::layout::Layout;
 pub use rusttype::gpu_cache::Cache as GlyphCache;
 pub use rusttype::{Glyph, GlyphId, GlyphIter, LayoutIter, Scale, ScaledGlyph};
@@ -0,0 +1,413 @@
+//! Signed distance field (SDF) glyph rasterization and caching.
+//!
+//! Rather than rasterizing each glyph at the exact size at which it appears on screen, glyphs are
+//! rasterized once at a fixed reference scale and stored as a signed distance field. The distance
+//! field may then be sampled at any scale, rotation or zoom while retaining a crisp edge. As the
+//! distance to the glyph's edge is known for every texel within the `spread`, the same atlas can
+//! also be used to render outlines and glows.
+//!
+//! The **Cache** API loosely mirrors that of the rusttype `gpu_cache::Cache`: glyphs are first
+//! queued, then written to the atlas via `cache_queued`, after which their texture coordinates
+//! may be retrieved via `rect_for`.
+
+use crate::text::{rt, Font, GlyphId, PositionedGlyph, Scale};
+use std::collections::HashMap;
+
+pub use rusttype::gpu_cache::CacheWriteErr;
+
+/// A cache of signed distance field glyphs packed into a single-channel atlas texture.
+#[derive(Debug)]
+pub struct Cache {
+    width: u32,
+    height: u32,
+    glyph_scale: f32,
+    spread: u32,
+    glyphs: HashMap<(usize, GlyphId), Option<Entry>>,
+    queue: Vec<(usize, Font, GlyphId)>,
+    shelves: Vec<Shelf>,
+}
+
+// The location of a cached glyph within the atlas.
+#[derive(Copy, Clone, Debug)]
+struct Entry {
+    // The glyph's pixel rect within the atlas.
+    atlas_rect: rt::Rect<u32>,
+    // The bounds of the padded glyph relative to its origin at `glyph_scale`, y down.
+    bounds: rt::Rect<f32>,
+}
+
+// A single row within the atlas into which glyphs of a similar height are packed.
+#[derive(Copy, Clone, Debug)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    x: u32,
+}
+
+impl Cache {
+    /// The default scale in pixels at which glyphs are rasterized before being converted to a
+    /// distance field.
+    pub const DEFAULT_GLYPH_SCALE: f32 = 48.0;
+    /// The default distance in pixels (at `glyph_scale`) over which the field is encoded either
+    /// side of the glyph's edge.
+    pub const DEFAULT_SPREAD: u32 = 6;
+
+    /// Create a new, empty SDF glyph cache with an atlas of the given dimensions.
+    ///
+    /// `glyph_scale` is the scale in pixels at which glyphs are rasterized and `spread` is the
+    /// distance in pixels either side of the glyph's edge that is encoded within the field. A
+    /// larger `spread` allows for wider outlines and glows at the cost of atlas space.
+    pub fn new([width, height]: [u32; 2], glyph_scale: f32, spread: u32) -> Self {
+        Cache {
+            width,
+            height,
+            glyph_scale,
+            spread,
+            glyphs: Default::default(),
+            queue: vec![],
+            shelves: vec![],
+        }
+    }
+
+    /// The dimensions of the atlas in pixels.
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// The scale in pixels at which glyphs are rasterized.
+    pub fn glyph_scale(&self) -> f32 {
+        self.glyph_scale
+    }
+
+    /// The distance in pixels (at `glyph_scale`) encoded either side of each glyph's edge.
+    pub fn spread(&self) -> u32 {
+        self.spread
+    }
+
+    /// The number of glyphs currently stored within the atlas.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Whether or not the atlas is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// The fraction of the atlas area that is currently allocated to glyphs.
+    pub fn occupancy(&self) -> f32 {
+        let used: u64 = self
+            .shelves
+            .iter()
+            .map(|s| s.x as u64 * s.height as u64)
+            .sum();
+        let total = self.width as u64 * self.height as u64;
+        if total == 0 {
+            return 1.0;
+        }
+        used as f32 / total as f32
+    }
+
+    /// Resize the atlas.
+    ///
+    /// If the atlas grows along both axes, all cached glyphs retain their pixel position within
+    /// the atlas and the existing pixel data should be copied to the top-left of the new atlas.
+    /// Otherwise the cache is cleared.
+    ///
+    /// Note that texture coordinates are normalised, so rects for all glyphs should be
+    /// re-requested after resizing.
+    pub fn resize(&mut self, [width, height]: [u32; 2]) {
+        if width < self.width || height < self.height {
+            self.clear();
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Converts a distance in pixels at the given glyph scale to the normalised distance units
+    /// stored within the atlas, where `0.5` represents the glyph's edge.
+    ///
+    /// This is useful for determining the threshold offsets for outline and glow effects.
+    pub fn normalised_distance(&self, distance: f32, scale: Scale) -> f32 {
+        let scale = scale.y.max(f32::EPSILON);
+        let px_at_glyph_scale = distance * self.glyph_scale / scale;
+        px_at_glyph_scale / (2.0 * self.spread.max(1) as f32)
+    }
+
+    /// Queue the glyph with the given ID to be cached during the next call to `cache_queued`.
+    ///
+    /// Unlike the rusttype cache, glyphs are cached independently of scale and sub-pixel
+    /// position, so only the font and glyph ID are required.
+    pub fn queue_glyph(&mut self, font_id: usize, font: &Font, glyph_id: GlyphId) {
+        if self.glyphs.contains_key(&(font_id, glyph_id)) {
+            return;
+        }
+        if self
+            .queue
+            .iter()
+            .any(|&(f_id, _, g_id)| f_id == font_id && g_id == glyph_id)
+        {
+            return;
+        }
+        self.queue.push((font_id, font.clone(), glyph_id));
+    }
+
+    /// Clear all glyphs from the cache.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.shelves.clear();
+    }
+
+    /// Clear the queue of glyphs pending caching.
+    pub fn clear_queue(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Rasterize all queued glyphs into distance fields and write them to the atlas.
+    ///
+    /// The `uploader` is called with the pixel rect within the atlas and the single-channel
+    /// distance field data for each newly cached glyph.
+    ///
+    /// If the atlas has no room remaining for a glyph, the glyph and all following glyphs remain
+    /// queued and an error is returned. At this point the atlas may either be `resize`d or
+    /// `clear`ed before trying again.
+    pub fn cache_queued<F>(&mut self, mut uploader: F) -> Result<(), CacheWriteErr>
+    where
+        F: FnMut(rt::Rect<u32>, &[u8]),
+    {
+        let mut queue = std::mem::take(&mut self.queue).into_iter();
+        while let Some((font_id, font, glyph_id)) = queue.next() {
+            let key = (font_id, glyph_id);
+            if self.glyphs.contains_key(&key) {
+                continue;
+            }
+            let glyph = font
+                .glyph(glyph_id)
+                .scaled(Scale::uniform(self.glyph_scale))
+                .positioned(rt::point(0.0, 0.0));
+            let bb = match glyph.pixel_bounding_box() {
+                None => {
+                    self.glyphs.insert(key, None);
+                    continue;
+                }
+                Some(bb) => bb,
+            };
+
+            // The size of the glyph including padding for the spread.
+            let pad = self.spread;
+            let w = (bb.max.x - bb.min.x) as u32 + pad * 2;
+            let h = (bb.max.y - bb.min.y) as u32 + pad * 2;
+            if w > self.width || h > self.height {
+                self.queue.push((font_id, font, glyph_id));
+                self.queue.extend(queue);
+                return Err(CacheWriteErr::GlyphTooLarge);
+            }
+
+            // Find space within the atlas.
+            let [x, y] = match self.allocate(w, h) {
+                Some(pos) => pos,
+                None => {
+                    self.queue.push((font_id, font, glyph_id));
+                    self.queue.extend(queue);
+                    return Err(CacheWriteErr::NoRoomForWholeQueue);
+                }
+            };
+
+            // Rasterize the glyph coverage and convert it to a distance field.
+            let mut coverage = vec![0.0f32; w as usize * h as usize];
+            glyph.draw(|x, y, v| {
+                let ix = (y + pad) as usize * w as usize + (x + pad) as usize;
+                coverage[ix] = v;
+            });
+            let field = distance_field(&coverage, w as usize, h as usize, pad as f32);
+            let atlas_rect = rt::Rect {
+                min: rt::point(x, y),
+                max: rt::point(x + w, y + h),
+            };
+            let bounds = rt::Rect {
+                min: rt::point(bb.min.x as f32 - pad as f32, bb.min.y as f32 - pad as f32),
+                max: rt::point(bb.max.x as f32 + pad as f32, bb.max.y as f32 + pad as f32),
+            };
+            uploader(atlas_rect, &field);
+            self.glyphs.insert(key, Some(Entry { atlas_rect, bounds }));
+        }
+        Ok(())
+    }
+
+    /// Retrieve the texture coordinates and the screen rect for the given positioned glyph.
+    ///
+    /// The screen rect is in the same pixel space as the given glyph's position and includes the
+    /// padding required to render the full spread of the distance field.
+    ///
+    /// Returns `None` if the glyph has no outline (e.g. whitespace) or has not yet been cached.
+    pub fn rect_for(
+        &self,
+        font_id: usize,
+        glyph: &PositionedGlyph,
+    ) -> Option<(rt::Rect<f32>, rt::Rect<f32>)> {
+        let entry = match self.glyphs.get(&(font_id, glyph.id())) {
+            Some(&Some(entry)) => entry,
+            _ => return None,
+        };
+        let (w, h) = (self.width as f32, self.height as f32);
+        let uv_rect = rt::Rect {
+            min: rt::point(
+                entry.atlas_rect.min.x as f32 / w,
+                entry.atlas_rect.min.y as f32 / h,
+            ),
+            max: rt::point(
+                entry.atlas_rect.max.x as f32 / w,
+                entry.atlas_rect.max.y as f32 / h,
+            ),
+        };
+        let scale = glyph.scale();
+        let sx = scale.x / self.glyph_scale;
+        let sy = scale.y / self.glyph_scale;
+        let p = glyph.position();
+        let screen_rect = rt::Rect {
+            min: rt::point(p.x + entry.bounds.min.x * sx, p.y + entry.bounds.min.y * sy),
+            max: rt::point(p.x + entry.bounds.max.x * sx, p.y + entry.bounds.max.y * sy),
+        };
+        Some((uv_rect, screen_rect))
+    }
+
+    // Find a position for a rect of the given size within the atlas using a simple shelf packer.
+    fn allocate(&mut self, w: u32, h: u32) -> Option<[u32; 2]> {
+        let width = self.width;
+        // Prefer the existing shelf whose height best matches the glyph.
+        let best = self
+            .shelves
+            .iter_mut()
+            .filter(|s| s.height >= h && s.height <= h + h / 4 + 1 && width - s.x >= w)
+            .min_by_key(|s| s.height - h);
+        if let Some(shelf) = best {
+            let pos = [shelf.x, shelf.y];
+            shelf.x += w;
+            return Some(pos);
+        }
+        // Otherwise start a new shelf.
+        let y = self.shelves.last().map(|s| s.y + s.height).unwrap_or(0);
+        if y + h > self.height || w > width {
+            return None;
+        }
+        self.shelves.push(Shelf { y, height: h, x: w });
+        Some([0, y])
+    }
+}
+
+/// Produce a single-channel signed distance field from the given glyph coverage bitmap.
+///
+/// `coverage` values are expected within the range `0.0..=1.0`. The returned field stores `128`
+/// (roughly `0.5` when normalised) at the edge of the shape, increasing to `255` at `spread`
+/// pixels inside the shape and decreasing to `0` at `spread` pixels outside.
+///
+/// Distances are computed via an exact euclidean distance transform, using the coverage of
+/// anti-aliased edge pixels for sub-pixel accuracy.
+pub fn distance_field(coverage: &[f32], width: usize, height: usize, spread: f32) -> Vec<u8> {
+    const INF: f64 = 1e20;
+    assert_eq!(coverage.len(), width * height);
+    let len = width * height;
+    let mut outer = vec![0.0f64; len];
+    let mut inner = vec![0.0f64; len];
+    for (i, &a) in coverage.iter().enumerate() {
+        let a = a.clamp(0.0, 1.0) as f64;
+        if a >= 1.0 {
+            outer[i] = 0.0;
+            inner[i] = INF;
+        } else if a <= 0.0 {
+            outer[i] = INF;
+            inner[i] = 0.0;
+        } else {
+            let d = 0.5 - a;
+            outer[i] = if d > 0.0 { d * d } else { 0.0 };
+            inner[i] = if d < 0.0 { d * d } else { 0.0 };
+        }
+    }
+
+    let n = width.max(height);
+    let mut f = vec![0.0f64; n];
+    let mut z = vec![0.0f64; n + 1];
+    let mut v = vec![0usize; n];
+    edt(&mut outer, width, height, &mut f, &mut v, &mut z);
+    edt(&mut inner, width, height, &mut f, &mut v, &mut z);
+
+    let spread = spread.max(f32::EPSILON) as f64;
+    outer
+        .iter()
+        .zip(&inner)
+        .map(|(&o, &i)| {
+            let d = o.sqrt() - i.sqrt();
+            let value = 0.5 - d / (2.0 * spread);
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        })
+        .collect()
+}
+
+// A 2D squared euclidean distance transform performed in-place.
+//
+// See "Distance Transforms of Sampled Functions" by Felzenszwalb and Huttenlocher.
+fn edt(
+    grid: &mut [f64],
+    width: usize,
+    height: usize,
+    f: &mut [f64],
+    v: &mut [usize],
+    z: &mut [f64],
+) {
+    for x in 0..width {
+        edt_1d(grid, x, width, height, f, v, z);
+    }
+    for y in 0..height {
+        edt_1d(grid, y * width, 1, width, f, v, z);
+    }
+}
+
+fn edt_1d(
+    grid: &mut [f64],
+    offset: usize,
+    stride: usize,
+    length: usize,
+    f: &mut [f64],
+    v: &mut [usize],
+    z: &mut [f64],
+) {
+    const INF: f64 = 1e20;
+    if length == 0 {
+        return;
+    }
+    v[0] = 0;
+    z[0] = -INF;
+    z[1] = INF;
+    f[0] = grid[offset];
+    let mut k = 0usize;
+    for q in 1..length {
+        f[q] = grid[offset + q * stride];
+        let qf = q as f64;
+        let mut s;
+        loop {
+            let r = v[k];
+            let rf = r as f64;
+            s = (f[q] - f[r] + qf * qf - rf * rf) / (qf - rf) / 2.0;
+            if s <= z[k] && k > 0 {
+                k -= 1;
+            } else {
+                break;
+            }
+        }
+        if s > z[k] {
+            k += 1;
+        }
+        v[k] = q;
+        z[k] = s;
+        z[k + 1] = INF;
+    }
+    let mut k = 0;
+    for q in 0..length {
+        while z[k + 1] < q as f64 {
+            k += 1;
+        }
+        let r = v[k];
+        let qr = q as f64 - r as f64;
+        grid[offset + q * stride] = f[r] + qr * qr;
+    }
+}
@@ -10,17 +10,18 @@ use crate::frame::{self, Frame, RawFrame};
 use crate::geom;
 use crate::geom::Point2;
 use crate::glam::Vec2;
+use crate::image;
 use crate::wgpu;
 use crate::App;
 use std::any::Any;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicU64};
 use std::sync::Arc;
 use std::time::Duration;
 use std::{env, fmt};
 use winit::dpi::{LogicalSize, PhysicalSize};
 
 pub use winit::window::Fullscreen;
-pub use winit::window::WindowId as Id;
 
 #[cfg(target_os = "windows")]
 use winit::platform::windows::WindowBuilderExtWindows;
@@ -37,6 +38,22 @@ pub const MIN_SC_PIXELS: PhysicalSize<u32> = PhysicalSize {
     height: 2,
 };
 
+/// A unique identifier associated with a window.
+///
+/// Identifies either a window on the display or one of the offscreen windows of a headless
+/// **App**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(IdKind);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum IdKind {
+    Winit(winit::window::WindowId),
+    Headless(u64),
+}
+
+// The next ID assigned to a headless window. Unique across all apps within the process.
+static NEXT_HEADLESS_ID: AtomicU64 = AtomicU64::new(0);
+
 /// A context for building a window.
 pub struct Builder<'app> {
     app: &'app App,
@@ -230,13 +247,22 @@ fn_any!(ClosedFn<M>, ClosedFnAny);
 ///
 /// The **Window** acts as a wrapper around the `winit::window::Window` and the `wgpu::Surface`
 /// types. It also manages the associated swap chain, providing a more nannou-friendly API.
+///
+/// Windows of a headless **App** have neither a winit window nor a surface. Their frames are
+/// rendered to an offscreen texture instead and read back as an image available via
+/// `frame_image`. Methods that affect the platform window have no effect on a headless window.
 #[derive(Debug)]
 pub struct Window {
-    pub(crate) window: winit::window::Window,
-    pub(crate) surface: wgpu::Surface,
+    id: Id,
+    // `None` if the window is headless.
+    pub(crate) window: Option<winit::window::Window>,
+    // `None` if the window is headless.
+    pub(crate) surface: Option<wgpu::Surface>,
     pub(crate) device_queue_pair: Arc<wgpu::DeviceQueuePair>,
     msaa_samples: u32,
     pub(crate) swap_chain: WindowSwapChain,
+    // `Some` if the window is headless.
+    pub(crate) headless: Option<HeadlessTarget>,
     pub(crate) frame_data: Option<FrameData>,
     pub(crate) frame_count: u64,
     pub(crate) user_functions: UserFunctions,
@@ -263,13 +289,24 @@ pub(crate) struct TrackedState {
     pub(crate) physical_size: winit::dpi::PhysicalSize<u32>,
 }
 
+// The offscreen texture standing in for the swap chain of a headless window.
+#[derive(Debug)]
+pub(crate) struct HeadlessTarget {
+    pub(crate) texture: wgpu::Texture,
+    pub(crate) texture_view: wgpu::TextureView,
+    // Reads each frame rendered to the texture back to the CPU.
+    capturer: wgpu::TextureCapturer,
+    // The most recent frame read back from the texture.
+    image: Option<image::RgbaImage>,
+}
+
 /// A swap_chain and its images associated with a single window.
 pub(crate) struct WindowSwapChain {
     // The descriptor used to create the original swap chain. Useful for recreation.
     pub(crate) descriptor: wgpu::SwapChainDescriptor,
     // This is an `Option` in order to allow for separating ownership of the swapchain from the
     // window during a `RedrawRequest`. Other than during `RedrawRequest`, this should always be
-    // `Some`.
+    // `Some` unless the window is headless.
     pub(crate) swap_chain: Option<wgpu::SwapChain>,
 }
 
@@ -329,20 +366,25 @@ impl SwapChainBuilder {
         self,
         device: &wgpu::Device,
         surface: &wgpu::Surface,
-        [width_px, height_px]: [u32; 2],
+        dims_px: [u32; 2],
     ) -> (wgpu::SwapChain, wgpu::SwapChainDescriptor) {
+        let desc = self.descriptor(dims_px);
+        let swap_chain = device.create_swap_chain(surface, &desc);
+        (swap_chain, desc)
+    }
+
+    // The descriptor for a swap chain of the given dimensions.
+    pub(crate) fn descriptor(self, [width_px, height_px]: [u32; 2]) -> wgpu::SwapChainDescriptor {
         let usage = self.usage.unwrap_or(Self::DEFAULT_USAGE);
         let format = self.format.unwrap_or(Self::DEFAULT_FORMAT);
         let present_mode = self.present_mode.unwrap_or(Self::DEFAULT_PRESENT_MODE);
-        let desc = wgpu::SwapChainDescriptor {
+        wgpu::SwapChainDescriptor {
             usage,
             format,
             width: width_px,
             height: height_px,
             present_mode,
-        };
-        let swap_chain = device.create_swap_chain(surface, &desc);
-        (swap_chain, desc)
+        }
     }
 }
 
@@ -787,23 +829,27 @@ impl<'app> Builder<'app> {
             window.window.min_inner_size = Some(winit::dpi::Size::Physical(MIN_SC_PIXELS));
         }
 
-        // Build the window.
-        let window = {
+        // Build the window and its surface, unless the app is headless.
+        let (window, surface) = if app.is_headless() {
+            (None, None)
+        } else {
             let window_target = app
                 .event_loop_window_target
                 .as_ref()
                 .expect("unexpected invalid App.event_loop_window_target state - please report")
                 .as_ref();
-            window.build(window_target)?
+            let window = window.build(window_target)?;
+            let surface = unsafe { app.instance().create_surface(&window) };
+            (Some(window), Some(surface))
         };
 
-        // Build the wgpu surface.
-        let surface = unsafe { app.instance().create_surface(&window) };
-
         // Request the adapter.
+        //
+        // A headless window requires no compatible surface, in which case wgpu falls back to a
+        // software adapter if no hardware adapter is available.
         let request_adapter_opts = wgpu::RequestAdapterOptions {
             power_preference,
-            compatible_surface: Some(&surface),
+            compatible_surface: surface.as_ref(),
         };
         let adapter = app
             .wgpu_adapters()
@@ -814,12 +860,40 @@ impl<'app> Builder<'app> {
         let device_desc = device_desc.unwrap_or_else(wgpu::default_device_descriptor);
         let device_queue_pair = adapter.get_or_request_device(device_desc);
 
-        // Build the swapchain.
-        let win_physical_size = window.inner_size();
+        // Determine the window's ID and size. Headless windows are sized in pixels.
+        let (window_id, win_physical_size, scale_factor) = match window {
+            Some(ref window) => {
+                let id = Id::from(window.id());
+                (id, window.inner_size(), window.scale_factor())
+            }
+            None => {
+                let size = initial_window_size.to_physical::<u32>(1.0);
+                let size = PhysicalSize {
+                    width: size.width.max(MIN_SC_PIXELS.width),
+                    height: size.height.max(MIN_SC_PIXELS.height),
+                };
+                (Id::headless(), size, 1.0)
+            }
+        };
+
+        // Build the swapchain, or the offscreen texture standing in for it if headless.
         let win_dims_px: [u32; 2] = win_physical_size.into();
         let device = device_queue_pair.device();
-        let (swap_chain, swap_chain_desc) =
-            swap_chain_builder.build(&device, &surface, win_dims_px);
+        let (swap_chain, swap_chain_desc, headless) = match surface {
+            Some(ref surface) => {
+                let (swap_chain, swap_chain_desc) =
+                    swap_chain_builder.build(&device, surface, win_dims_px);
+                (Some(swap_chain), swap_chain_desc, None)
+            }
+            None => {
+                let format = swap_chain_builder
+                    .format
+                    .unwrap_or(HeadlessTarget::DEFAULT_FORMAT);
+                let swap_chain_desc = swap_chain_builder.format(format).descriptor(win_dims_px);
+                let headless = HeadlessTarget::new(&device, &swap_chain_desc);
+                (None, swap_chain_desc, Some(headless))
+            }
+        };
 
         // If we're using an intermediary image for rendering frames to swap_chain images, create
         // the necessary render data.
@@ -842,24 +916,25 @@ impl<'app> Builder<'app> {
             Some(View::WithModelRaw(_)) => (None, 1),
         };
 
-        let window_id = window.id();
         let frame_count = 0;
         let swap_chain = WindowSwapChain {
             descriptor: swap_chain_desc,
-            swap_chain: Some(swap_chain),
+            swap_chain,
         };
 
         let tracked_state = TrackedState {
-            scale_factor: window.scale_factor(),
+            scale_factor,
             physical_size: win_physical_size,
         };
 
         let window = Window {
+            id: window_id,
             window,
             surface,
             device_queue_pair,
             msaa_samples,
             swap_chain,
+            headless,
             frame_data,
             frame_count,
             user_functions,
@@ -1022,7 +1097,7 @@ impl Window {
 
     /// A unique identifier associated with this window.
     pub fn id(&self) -> Id {
-        self.window.id()
+        self.id
     }
 
     /// Returns the scale factor that can be used to map logical pixels to physical pixels and vice
@@ -1045,7 +1120,10 @@ impl Window {
     /// - **iOS:** Can only be called on the main thread. Returns the underlying `UiView`'s
     ///   `contentScaleFactor`.
     pub fn scale_factor(&self) -> geom::scalar::Default {
-        self.window.scale_factor() as _
+        match self.window {
+            Some(ref window) => window.scale_factor() as _,
+            None => self.tracked_state.scale_factor as _,
+        }
     }
 
     /// The position of the top-left hand corner of the window relative to the top-left hand corner
@@ -1063,8 +1141,12 @@ impl Window {
     /// - **iOS:** Can only be called on the main thread. Returns the top left coordinates of the
     /// window in the screen space coordinate system.
     /// - **Web:** Returns the top-left coordinates relative to the viewport.
+    /// - **Headless:** Always returns `(0, 0)`.
     pub fn outer_position_pixels(&self) -> Result<(i32, i32), winit::error::NotSupportedError> {
-        self.window.outer_position().map(Into::into)
+        match self.window {
+            Some(ref window) => window.outer_position().map(Into::into),
+            None => Ok((0, 0)),
+        }
     }
 
     /// Modifies the position of the window.
@@ -1078,15 +1160,16 @@ impl Window {
     ///   window in the screen space coordinate system.
     /// - **Web:** Sets the top-left coordinates relative to the viewport.
     pub fn set_outer_position_pixels(&self, x: i32, y: i32) {
-        self.window
-            .set_outer_position(winit::dpi::PhysicalPosition { x, y })
+        if let Some(ref window) = self.window {
+            window.set_outer_position(winit::dpi::PhysicalPosition { x, y })
+        }
     }
 
     /// The width and height in pixels of the client area of the window.
     ///
     /// The client area is the content of the window, excluding the title bar and borders.
     pub fn inner_size_pixels(&self) -> (u32, u32) {
-        self.window.inner_size().into()
+        self.physical_inner_size().into()
     }
 
     /// The width and height in points of the client area of the window.
@@ -1095,8 +1178,7 @@ impl Window {
     ///
     /// This is the same as dividing the result  of `inner_size_pixels()` by `scale_factor()`.
     pub fn inner_size_points(&self) -> (geom::scalar::Default, geom::scalar::Default) {
-        self.window
-            .inner_size()
+        self.physical_inner_size()
             .to_logical::<f32>(self.tracked_state.scale_factor)
             .into()
     }
@@ -1105,16 +1187,18 @@ impl Window {
     ///
     /// See the `inner_size` methods for more informations about the values.
     pub fn set_inner_size_pixels(&self, width: u32, height: u32) {
-        self.window
-            .set_inner_size(winit::dpi::PhysicalSize { width, height })
+        if let Some(ref window) = self.window {
+            window.set_inner_size(winit::dpi::PhysicalSize { width, height })
+        }
     }
 
     /// Modifies the inner size of the window using point values.
     ///
     /// See the `inner_size` methods for more informations about the values.
     pub fn set_inner_size_points(&self, width: f32, height: f32) {
-        self.window
-            .set_inner_size(winit::dpi::LogicalSize { width, height })
+        if let Some(ref window) = self.window {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height })
+        }
     }
 
     /// The width and height of the window in pixels.
@@ -1122,7 +1206,7 @@ impl Window {
     /// These dimensions include title bar and borders. If you don't want these, you should use
     /// `inner_size_pixels` instead.
     pub fn outer_size_pixels(&self) -> (u32, u32) {
-        self.window.outer_size().into()
+        self.physical_outer_size().into()
     }
 
     /// The width and height of the window in points.
@@ -1132,8 +1216,7 @@ impl Window {
     ///
     /// This is the same as dividing the result  of `outer_size_pixels()` by `scale_factor()`.
     pub fn outer_size_points(&self) -> (f32, f32) {
-        self.window
-            .outer_size()
+        self.physical_outer_size()
             .to_logical::<f32>(self.tracked_state.scale_factor)
             .into()
     }
@@ -1141,20 +1224,26 @@ impl Window {
     /// Sets a minimum size for the window.
     pub fn set_min_inner_size_points(&self, size: Option<(f32, f32)>) {
         let size = size.map(|(width, height)| winit::dpi::LogicalSize { width, height });
-        self.window.set_min_inner_size(size)
+        if let Some(ref window) = self.window {
+            window.set_min_inner_size(size);
+        }
     }
 
     /// Sets a maximum size for the window.
     pub fn set_max_inner_size_points(&self, size: Option<(f32, f32)>) {
         let size = size.map(|(width, height)| winit::dpi::LogicalSize { width, height });
-        self.window.set_max_inner_size(size)
+        if let Some(ref window) = self.window {
+            window.set_max_inner_size(size);
+        }
     }
 
     /// Modifies the title of the window.
     ///
     /// This is a no-op if the window has already been closed.
     pub fn set_title(&self, title: &str) {
-        self.window.set_title(title);
+        if let Some(ref window) = self.window {
+            window.set_title(title);
+        }
     }
 
     /// Set the visibility of the window.
@@ -1165,7 +1254,9 @@ impl Window {
     /// - iOS: Can only be called on the main thread.
     /// - Web: Has no effect.
     pub fn set_visible(&self, visible: bool) {
-        self.window.set_visible(visible)
+        if let Some(ref window) = self.window {
+            window.set_visible(visible);
+        }
     }
 
     /// Sets whether the window is resizable or not.
@@ -1173,17 +1264,23 @@ impl Window {
     /// Note that making the window unresizable doesn't exempt you from handling **Resized**, as
     /// that event can still be triggered by DPI scaling, entering fullscreen mode, etc.
     pub fn set_resizable(&self, resizable: bool) {
-        self.window.set_resizable(resizable)
+        if let Some(ref window) = self.window {
+            window.set_resizable(resizable);
+        }
     }
 
     /// Sets the window to minimized or back.
     pub fn set_minimized(&self, minimized: bool) {
-        self.window.set_minimized(minimized)
+        if let Some(ref window) = self.window {
+            window.set_minimized(minimized);
+        }
     }
 
     /// Sets the window to maximized or back.
     pub fn set_maximized(&self, maximized: bool) {
-        self.window.set_maximized(maximized)
+        if let Some(ref window) = self.window {
+            window.set_maximized(maximized);
+        }
     }
 
     /// Set the window to fullscreen on the primary monitor.
@@ -1223,7 +1320,9 @@ impl Window {
     /// - Wayland: Does not support exclusive fullscreen mode.
     /// - Windows: Screen saver is disabled in fullscreen mode.
     pub fn set_fullscreen_with(&self, fullscreen: Option<Fullscreen>) {
-        self.window.set_fullscreen(fullscreen)
+        if let Some(ref window) = self.window {
+            window.set_fullscreen(fullscreen);
+        }
     }
 
     /// Gets the window's current fullscreen state.
@@ -1232,7 +1331,7 @@ impl Window {
     ///
     /// - **iOS:** Can only be called on the main thread.
     pub fn fullscreen(&self) -> Option<Fullscreen> {
-        self.window.fullscreen()
+        self.window.as_ref().and_then(|window| window.fullscreen())
     }
 
     /// Turn window decorations on or off.
@@ -1243,12 +1342,16 @@ impl Window {
     ///   via `setPrefersStatusBarHidden`.
     /// - **Web:** Has no effect.
     pub fn set_decorations(&self, decorations: bool) {
-        self.window.set_decorations(decorations)
+        if let Some(ref window) = self.window {
+            window.set_decorations(decorations);
+        }
     }
 
     /// Change whether or not the window will always be on top of other windows.
     pub fn set_always_on_top(&self, always_on_top: bool) {
-        self.window.set_always_on_top(always_on_top)
+        if let Some(ref window) = self.window {
+            window.set_always_on_top(always_on_top);
+        }
     }
 
     /// Sets the window icon. On Windows and X11, this is typically the small icon in the top-left
@@ -1264,7 +1367,9 @@ impl Window {
     /// X11 has no universal guidelines for icon sizes, so you're at the whims of the WM. That
     /// said, it's usually in the same ballpark as on Windows.
     pub fn set_window_icon(&self, window_icon: Option<winit::window::Icon>) {
-        self.window.set_window_icon(window_icon)
+        if let Some(ref window) = self.window {
+            window.set_window_icon(window_icon);
+        }
     }
 
     /// Sets the location of IME candidate box in client area coordinates relative to the top left.
@@ -1274,8 +1379,9 @@ impl Window {
     /// - **iOS:** Has no effect.
     /// - **Web:** Has no effect.
     pub fn set_ime_position_points(&self, x: f32, y: f32) {
-        self.window
-            .set_ime_position(winit::dpi::LogicalPosition { x, y })
+        if let Some(ref window) = self.window {
+            window.set_ime_position(winit::dpi::LogicalPosition { x, y });
+        }
     }
 
     /// Modifies the mouse cursor of the window.
@@ -1285,7 +1391,9 @@ impl Window {
     /// - **iOS:** Has no effect.
     /// - **Android:** Has no effect.
     pub fn set_cursor_icon(&self, state: winit::window::CursorIcon) {
-        self.window.set_cursor_icon(state);
+        if let Some(ref window) = self.window {
+            window.set_cursor_icon(state);
+        }
     }
 
     /// Changes the position of the cursor in logical window coordinates.
@@ -1294,13 +1402,16 @@ impl Window {
     ///
     /// - **iOS:** Always returns an `Err`.
     /// - **Web:** Has no effect.
+    /// - **Headless:** Has no effect.
     pub fn set_cursor_position_points(
         &self,
         x: f32,
         y: f32,
     ) -> Result<(), winit::error::ExternalError> {
-        self.window
-            .set_cursor_position(winit::dpi::LogicalPosition { x, y })
+        match self.window {
+            Some(ref window) => window.set_cursor_position(winit::dpi::LogicalPosition { x, y }),
+            None => Ok(()),
+        }
     }
 
     /// Grabs the cursor, preventing it from leaving the window.
@@ -1312,8 +1423,12 @@ impl Window {
     /// - **Android:** Has no effect.
     /// - **iOS:** Always returns an Err.
     /// - **Web:** Has no effect.
+    /// - **Headless:** Has no effect.
     pub fn set_cursor_grab(&self, grab: bool) -> Result<(), winit::error::ExternalError> {
-        self.window.set_cursor_grab(grab)
+        match self.window {
+            Some(ref window) => window.set_cursor_grab(grab),
+            None => Ok(()),
+        }
     }
 
     /// Set the cursor's visibility.
@@ -1330,20 +1445,36 @@ impl Window {
     ///
     /// This has no effect on **Android** or **iOS**.
     pub fn set_cursor_visible(&self, visible: bool) {
-        self.window.set_cursor_visible(visible)
+        if let Some(ref window) = self.window {
+            window.set_cursor_visible(visible);
+        }
     }
 
     /// The current monitor that the window is, on or the primary monitor if nothing matches.
     /// If there's neither a current nor a primary monitor, returns none.
+    ///
+    /// Always returns `None` for a headless window.
     pub fn current_monitor(&self) -> Option<winit::monitor::MonitorHandle> {
-        self.window.current_monitor()
+        self.window
+            .as_ref()
+            .and_then(|window| window.current_monitor())
     }
 
     // Access to wgpu API.
 
     /// Returns a reference to the window's wgpu swap chain surface.
+    ///
+    /// **Panics** if the window is headless. See `try_surface` for a non-panicking alternative.
     pub fn surface(&self) -> &wgpu::Surface {
-        &self.surface
+        self.try_surface()
+            .expect("a headless window has no surface")
+    }
+
+    /// Returns a reference to the window's wgpu swap chain surface.
+    ///
+    /// Returns `None` if the window is headless.
+    pub fn try_surface(&self) -> Option<&wgpu::Surface> {
+        self.surface.as_ref()
     }
 
     /// The descriptor for the swap chain associated with this window's wgpu surface.
@@ -1399,10 +1530,17 @@ impl Window {
 
         self.swap_chain.descriptor.width = self.tracked_state.physical_size.width;
         self.swap_chain.descriptor.height = self.tracked_state.physical_size.height;
-        self.swap_chain.swap_chain = Some(
-            self.swap_chain_device()
-                .create_swap_chain(&self.surface, &self.swap_chain.descriptor),
-        );
+        let device = self.device_queue_pair.device();
+        match self.surface {
+            Some(ref surface) => {
+                let swap_chain = device.create_swap_chain(surface, &self.swap_chain.descriptor);
+                self.swap_chain.swap_chain = Some(swap_chain);
+            }
+            None => {
+                let headless = HeadlessTarget::new(device, &self.swap_chain.descriptor);
+                self.headless = Some(headless);
+            }
+        }
         if self.frame_data.is_some() {
             let render_data = frame::RenderData::new(
                 self.swap_chain_device(),
@@ -1414,6 +1552,60 @@ impl Window {
         }
     }
 
+    // The inner size of the window in pixels.
+    fn physical_inner_size(&self) -> PhysicalSize<u32> {
+        match self.window {
+            Some(ref window) => window.inner_size(),
+            None => self.tracked_state.physical_size,
+        }
+    }
+
+    // The outer size of the window in pixels. A headless window has no decorations.
+    fn physical_outer_size(&self) -> PhysicalSize<u32> {
+        match self.window {
+            Some(ref window) => window.outer_size(),
+            None => self.tracked_state.physical_size,
+        }
+    }
+
+    /// Whether or not the window belongs to a headless **App**, rendering its frames to an
+    /// offscreen texture rather than to the display.
+    pub fn is_headless(&self) -> bool {
+        self.headless.is_some()
+    }
+
+    /// The most recent frame rendered to a headless window, read back as a non-linear sRGBA image.
+    ///
+    /// The image is updated each time the window's **view** function returns. Returns `None` if
+    /// the window is not headless or if no frame has been rendered yet.
+    pub fn frame_image(&self) -> Option<&image::RgbaImage> {
+        self.headless.as_ref().and_then(|h| h.image.as_ref())
+    }
+
+    // Read the frame most recently rendered to a headless window back to the CPU.
+    pub(crate) fn read_headless_frame(&mut self) {
+        let headless = match self.headless {
+            Some(ref mut headless) => headless,
+            None => return,
+        };
+        let device = self.device_queue_pair.device();
+        let ce_desc = wgpu::CommandEncoderDescriptor {
+            label: Some("nannou_headless_frame_read"),
+        };
+        let mut encoder = device.create_command_encoder(&ce_desc);
+        let snapshot = headless
+            .capturer
+            .capture(device, &mut encoder, &headless.texture);
+        let queue = self.device_queue_pair.queue();
+        queue.submit(std::iter::once(encoder.finish()));
+
+        match frame::read_snapshot_blocking(device, &snapshot) {
+            Ok(image) => headless.image = Some(image),
+            // TODO: Log errors, don't print to stderr.
+            Err(e) => eprintln!("failed to read headless frame: {:?}", e),
+        }
+    }
+
     /// Attempts to determine whether or not the window is currently fullscreen.
     pub fn is_fullscreen(&self) -> bool {
         self.fullscreen().is_some()
@@ -1452,8 +1644,19 @@ impl Window {
     /// Produces a reference to the inner winit window.
     ///
     /// This is sometimes useful for integration with other winit-aware libraries (e.g. UI).
+    ///
+    /// **Panics** if the window is headless. See `try_winit_window` for a non-panicking
+    /// alternative.
     pub fn winit_window(&self) -> &winit::window::Window {
-        &self.window
+        self.try_winit_window()
+            .expect("a headless window has no winit window")
+    }
+
+    /// Produces a reference to the inner winit window.
+    ///
+    /// Returns `None` if the window is headless.
+    pub fn try_winit_window(&self) -> Option<&winit::window::Window> {
+        self.window.as_ref()
     }
 
     fn capture_frame_inner(&self, path: &Path) {
@@ -1490,6 +1693,27 @@ impl Window {
     }
 }
 
+impl HeadlessTarget {
+    // The texture format used by default, matching that of the images read back to the CPU.
+    const DEFAULT_FORMAT: wgpu::TextureFormat = wgpu::TextureCapturer::DST_FORMAT;
+
+    // Create the offscreen texture described by the given swap chain descriptor.
+    fn new(device: &wgpu::Device, desc: &wgpu::SwapChainDescriptor) -> Self {
+        let texture = wgpu::TextureBuilder::new()
+            .size([desc.width, desc.height])
+            .format(desc.format)
+            .usage(desc.usage | wgpu::TextureUsage::COPY_SRC | wgpu::TextureUsage::SAMPLED)
+            .build(device);
+        let texture_view = texture.view().build();
+        HeadlessTarget {
+            texture,
+            texture_view,
+            capturer: Default::default(),
+            image: None,
+        }
+    }
+}
+
 // Drop implementations.
 
 impl Drop for Window {
@@ -1537,6 +1761,20 @@ impl fmt::Display for BuildError {
     }
 }
 
+impl Id {
+    // A new, unique ID for a headless window.
+    fn headless() -> Self {
+        let id = NEXT_HEADLESS_ID.fetch_add(1, atomic::Ordering::Relaxed);
+        Id(IdKind::Headless(id))
+    }
+}
+
+impl From<winit::window::WindowId> for Id {
+    fn from(id: winit::window::WindowId) -> Self {
+        Id(IdKind::Winit(id))
+    }
+}
+
 impl From<winit::error::OsError> for BuildError {
     fn from(e: winit::error::OsError) -> Self {
         BuildError::WinitOsError(e)
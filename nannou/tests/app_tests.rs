@@ -0,0 +1,193 @@
+use nannou::prelude::*;
+use nannou::window::BuildError;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+// The test requires a wgpu adapter, software or otherwise, and is skipped if there is none.
+static HAS_ADAPTER: AtomicBool = AtomicBool::new(false);
+static UPDATES: AtomicU64 = AtomicU64::new(0);
+static VIEWS: AtomicU64 = AtomicU64::new(0);
+static EXITED: AtomicBool = AtomicBool::new(false);
+
+static MULTI_HAS_ADAPTER: AtomicBool = AtomicBool::new(false);
+static MULTI_EXITED: AtomicBool = AtomicBool::new(false);
+
+static OFFLINE_HAS_ADAPTER: AtomicBool = AtomicBool::new(false);
+// The `since_start`, `since_last` and `app.time` of each update in `Offline` mode.
+static OFFLINE_UPDATES: Mutex<Vec<(f64, f64, f32)>> = Mutex::new(Vec::new());
+// The `nth` of each frame in `Offline` mode.
+static OFFLINE_FRAMES: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    // Headless apps apply a finite number of updates by default.
+    assert!(app.is_headless());
+    assert_eq!(app.loop_mode(), LoopMode::loop_once());
+    match app.new_window().size(16, 8).view(view).build() {
+        Ok(_) => HAS_ADAPTER.store(true, Ordering::SeqCst),
+        Err(BuildError::NoAvailableAdapter) => return Model,
+        Err(err) => panic!("failed to build headless window: {}", err),
+    }
+    app.set_loop_mode(LoopMode::loop_ntimes(3));
+    Model
+}
+
+fn update(_app: &App, _model: &mut Model, _update: Update) {
+    UPDATES.fetch_add(1, Ordering::SeqCst);
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    VIEWS.fetch_add(1, Ordering::SeqCst);
+    let draw = app.draw();
+    draw.background().color(RED);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn exit(app: &App, _model: Model) {
+    EXITED.store(true, Ordering::SeqCst);
+    if !HAS_ADAPTER.load(Ordering::SeqCst) {
+        return;
+    }
+    let window = app.main_window();
+    assert!(window.is_headless());
+    assert!(window.try_surface().is_none());
+    assert!(window.try_winit_window().is_none());
+    assert_eq!(window.inner_size_pixels(), (16, 8));
+    let image = window.frame_image().expect("no frame was read back");
+    assert_eq!(image.dimensions(), (16, 8));
+    assert!(image.pixels().all(|p| p.0 == [255, 0, 0, 255]));
+}
+
+#[test]
+fn headless_app_test() {
+    // The loop ends once the `NTimes` mode has applied its updates.
+    nannou::app(model)
+        .update(update)
+        .exit(exit)
+        .headless(32, 32)
+        .run();
+    assert!(EXITED.load(Ordering::SeqCst));
+    if HAS_ADAPTER.load(Ordering::SeqCst) {
+        assert_eq!(UPDATES.load(Ordering::SeqCst), 3);
+        assert_eq!(VIEWS.load(Ordering::SeqCst), 3);
+    }
+}
+
+struct MultiModel {
+    windows: Vec<window::Id>,
+}
+
+fn multi_model(app: &App) -> MultiModel {
+    let a = match app.new_window().size(8, 4).view(multi_view_a).build() {
+        Ok(id) => id,
+        Err(BuildError::NoAvailableAdapter) => return MultiModel { windows: vec![] },
+        Err(err) => panic!("failed to build headless window: {}", err),
+    };
+    let b = app
+        .new_window()
+        .size(4, 8)
+        .view(multi_view_b)
+        .build()
+        .expect("failed to build second headless window");
+    MULTI_HAS_ADAPTER.store(true, Ordering::SeqCst);
+    MultiModel {
+        windows: vec![a, b],
+    }
+}
+
+fn multi_view_a(app: &App, _model: &MultiModel, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(RED);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn multi_view_b(app: &App, _model: &MultiModel, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLUE);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn multi_exit(app: &App, model: MultiModel) {
+    MULTI_EXITED.store(true, Ordering::SeqCst);
+    if !MULTI_HAS_ADAPTER.load(Ordering::SeqCst) {
+        return;
+    }
+    let (a, b) = (model.windows[0], model.windows[1]);
+    assert_ne!(a, b);
+    assert_eq!(app.window_ids().len(), 2);
+
+    // Each window renders its own view to its own offscreen texture.
+    let expected = [(a, (8, 4), [255, 0, 0, 255]), (b, (4, 8), [0, 0, 255, 255])];
+    for &(id, size, color) in expected.iter() {
+        let window = app.window(id).expect("no window for ID");
+        assert!(window.is_headless());
+        assert_eq!(window.id(), id);
+        assert_eq!(window.elapsed_frames(), 1);
+        let image = window.frame_image().expect("no frame was read back");
+        assert_eq!(image.dimensions(), size);
+        assert!(image.pixels().all(|p| p.0 == color));
+    }
+}
+
+#[test]
+fn headless_multi_window_test() {
+    nannou::app(multi_model)
+        .exit(multi_exit)
+        .headless(32, 32)
+        .run();
+    assert!(MULTI_EXITED.load(Ordering::SeqCst));
+}
+
+fn offline_model(app: &App) -> Model {
+    app.set_loop_mode(LoopMode::offline(10.0));
+    match app.new_window().size(8, 8).view(offline_view).build() {
+        Ok(_) => OFFLINE_HAS_ADAPTER.store(true, Ordering::SeqCst),
+        Err(BuildError::NoAvailableAdapter) => (),
+        Err(err) => panic!("failed to build headless window: {}", err),
+    }
+    Model
+}
+
+fn offline_update(app: &App, _model: &mut Model, update: Update) {
+    let mut updates = OFFLINE_UPDATES.lock().unwrap();
+    let since_start = update.since_start.secs();
+    let since_last = update.since_last.secs();
+    updates.push((since_start, since_last, app.time));
+    if updates.len() == 5 {
+        app.quit();
+    }
+}
+
+fn offline_view(app: &App, _model: &Model, frame: Frame) {
+    OFFLINE_FRAMES.lock().unwrap().push(frame.nth());
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+#[test]
+fn offline_loop_mode_test() {
+    nannou::app(offline_model)
+        .update(offline_update)
+        .headless(8, 8)
+        .run();
+    if !OFFLINE_HAS_ADAPTER.load(Ordering::SeqCst) {
+        return;
+    }
+
+    // The virtual clock starts at zero and advances by exactly `1 / fps` per update, regardless of
+    // how long each update and frame takes.
+    let updates = OFFLINE_UPDATES.lock().unwrap();
+    assert_eq!(updates.len(), 5);
+    for (i, &(since_start, since_last, time)) in updates.iter().enumerate() {
+        let expected = i as f64 / 10.0;
+        assert!((since_start - expected).abs() < 1e-9);
+        assert_eq!(time, since_start as f32);
+        let expected_since_last = if i == 0 { 0.0 } else { 0.1 };
+        assert!((since_last - expected_since_last).abs() < 1e-6);
+    }
+
+    // Each update is viewed exactly once. The app quits during the fifth update.
+    assert_eq!(*OFFLINE_FRAMES.lock().unwrap(), vec![0, 1, 2, 3]);
+}
@@ -0,0 +1,327 @@
+use nannou::camera::{Camera, FlyCamera, OrbitCamera, PanZoom};
+use nannou::event::{Key, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent};
+use nannou::geom::{pt2, pt3, Point2, Point3, Ray3, Rect};
+use nannou::glam::Vec3;
+
+fn window() -> Rect {
+    Rect::from_w_h(800.0, 600.0)
+}
+
+// Drag the mouse with the given button from `start` to `end`.
+fn drag(camera: &mut impl Camera, button: MouseButton, start: Point2, end: Point2) {
+    camera.event(&WindowEvent::MouseMoved(start));
+    camera.event(&WindowEvent::MousePressed(button));
+    camera.event(&WindowEvent::MouseMoved(end));
+    camera.event(&WindowEvent::MouseReleased(button));
+}
+
+fn scroll(camera: &mut impl Camera, lines: f32) {
+    let delta = MouseScrollDelta::LineDelta(0.0, lines);
+    camera.event(&WindowEvent::MouseWheel(delta, TouchPhase::Moved));
+}
+
+// The distance between the point and the line along the ray.
+fn distance_to_ray(ray: Ray3, point: Point3) -> f32 {
+    (point - ray.origin).cross(ray.direction).length()
+}
+
+fn assert_approx(a: f32, b: f32, epsilon: f32) {
+    assert!((a - b).abs() <= epsilon, "{} != {}", a, b);
+}
+
+#[test]
+fn orbit_pitch_test() {
+    let mut camera = OrbitCamera::new(Vec3::ZERO, 100.0).damping(0.0);
+    assert!(camera.eye().abs_diff_eq(pt3(0.0, 0.0, 100.0), 1e-4));
+
+    // Dragging downwards raises the camera, stopping just short of the pole.
+    drag(
+        &mut camera,
+        MouseButton::Left,
+        pt2(0.0, 0.0),
+        pt2(0.0, -10_000.0),
+    );
+    camera.update(1.0 / 60.0);
+    let eye = camera.eye();
+    assert!(eye.y > 99.99 && eye.y < 100.0, "{:?}", eye);
+    assert!(eye.z > 0.0, "the camera passed over the pole: {:?}", eye);
+    assert!(camera.view().is_finite());
+
+    drag(
+        &mut camera,
+        MouseButton::Left,
+        pt2(0.0, 0.0),
+        pt2(0.0, 20_000.0),
+    );
+    camera.update(1.0 / 60.0);
+    let eye = camera.eye();
+    assert!(eye.y < -99.99 && eye.z > 0.0, "{:?}", eye);
+
+    let camera = OrbitCamera::new(Vec3::ZERO, 100.0).pitch(10.0);
+    assert!(camera.eye().y < 100.0 && camera.eye().z > 0.0);
+
+    // Without the rotate button held, mouse movement does not rotate the camera.
+    let mut camera = OrbitCamera::new(Vec3::ZERO, 100.0).damping(0.0);
+    camera.event(&WindowEvent::MouseMoved(pt2(0.0, 0.0)));
+    camera.event(&WindowEvent::MouseMoved(pt2(300.0, 300.0)));
+    camera.event(&WindowEvent::MousePressed(MouseButton::Left));
+    camera.event(&WindowEvent::Unfocused);
+    camera.event(&WindowEvent::MouseMoved(pt2(0.0, 0.0)));
+    camera.update(1.0 / 60.0);
+    assert!(camera.eye().abs_diff_eq(pt3(0.0, 0.0, 100.0), 1e-4));
+}
+
+#[test]
+fn orbit_distance_test() {
+    let mut camera = OrbitCamera::new(Vec3::ZERO, 100.0)
+        .distance_limits(50.0, 200.0)
+        .damping(0.0);
+    scroll(&mut camera, 1.0);
+    camera.update(1.0 / 60.0);
+    assert!(camera.distance() < 100.0);
+
+    scroll(&mut camera, 100.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.distance(), 50.0);
+    scroll(&mut camera, -100.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.distance(), 200.0);
+    assert_approx(camera.eye().length(), 200.0, 1e-3);
+
+    camera.set_distance(1_000.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.distance(), 200.0);
+    camera.set_distance(0.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.distance(), 50.0);
+}
+
+#[test]
+#[should_panic]
+fn orbit_invalid_distance_limits_test() {
+    OrbitCamera::new(Vec3::ZERO, 100.0).distance_limits(200.0, 50.0);
+}
+
+#[test]
+fn orbit_damping_test() {
+    let damping = OrbitCamera::DEFAULT_DAMPING;
+    let mut once = OrbitCamera::new(Vec3::ZERO, 100.0);
+    let mut often = once.clone();
+    once.set_distance(200.0);
+    often.set_distance(200.0);
+
+    // The camera covers the same distance regardless of how often it is updated.
+    once.update(damping);
+    for _ in 0..10 {
+        often.update(damping / 10.0);
+    }
+    let expected = 200.0 - 100.0 * (-1.0f32).exp();
+    assert_approx(once.distance(), expected, 1e-3);
+    assert_approx(often.distance(), expected, 1e-3);
+
+    // And settles on the target without overshooting.
+    let mut last = once.distance();
+    for _ in 0..120 {
+        once.update(1.0 / 60.0);
+        assert!(once.distance() >= last && once.distance() <= 200.0);
+        last = once.distance();
+    }
+    assert_approx(once.distance(), 200.0, 1e-3);
+
+    // Damping continues to ease the camera after the input has stopped.
+    let mut camera = OrbitCamera::new(Vec3::ZERO, 100.0);
+    drag(
+        &mut camera,
+        MouseButton::Left,
+        pt2(0.0, 0.0),
+        pt2(100.0, 0.0),
+    );
+    camera.update(1.0 / 60.0);
+    let partway = camera.eye();
+    assert!(partway.x < 0.0);
+    for _ in 0..120 {
+        camera.update(1.0 / 60.0);
+    }
+    let settled = camera.eye();
+    assert!(settled.x < partway.x);
+    let yaw = -100.0 * OrbitCamera::DEFAULT_ROTATE_SPEED;
+    assert!(settled.abs_diff_eq(pt3(yaw.sin(), 0.0, yaw.cos()) * 100.0, 1e-2));
+}
+
+#[test]
+fn orbit_ray_test() {
+    let center = pt3(10.0, 20.0, 30.0);
+    let camera = OrbitCamera::new(center, 500.0).yaw(0.7).pitch(0.3);
+
+    // The ray through the centre of the window passes through the center of the orbit.
+    let ray = camera.ray(Point2::ZERO, window());
+    assert!(distance_to_ray(ray, center) < 1e-2);
+    assert!(ray.direction.dot(center - camera.eye()) > 0.0);
+    assert_approx(ray.direction.length(), 1.0, 1e-5);
+
+    // Rays through other points miss it.
+    let ray = camera.ray(pt2(200.0, 0.0), window());
+    assert!(distance_to_ray(ray, center) > 10.0);
+
+    // Panning moves the center across the view, remaining beneath the centre of the window.
+    let mut camera = camera.damping(0.0);
+    drag(
+        &mut camera,
+        MouseButton::Right,
+        pt2(0.0, 0.0),
+        pt2(50.0, 30.0),
+    );
+    camera.update(1.0 / 60.0);
+    assert!(camera.center().distance(center) > 1.0);
+    assert_approx(camera.distance(), 500.0, 1e-3);
+    let ray = camera.ray(Point2::ZERO, window());
+    assert!(distance_to_ray(ray, camera.center()) < 1e-2);
+    assert!(distance_to_ray(ray, center) > 1.0);
+}
+
+#[test]
+fn fly_look_test() {
+    let mut camera = FlyCamera::new(Vec3::ZERO).damping(0.0);
+    assert!(camera.direction().abs_diff_eq(-Vec3::Z, 1e-6));
+
+    // Without the look button held, moving the mouse does not turn the camera.
+    camera.event(&WindowEvent::MouseMoved(pt2(0.0, 0.0)));
+    camera.event(&WindowEvent::MouseMoved(pt2(100.0, 100.0)));
+    camera.update(1.0 / 60.0);
+    assert!(camera.direction().abs_diff_eq(-Vec3::Z, 1e-6));
+
+    // Looking upwards stops just short of straight up.
+    drag(
+        &mut camera,
+        MouseButton::Right,
+        pt2(0.0, 0.0),
+        pt2(0.0, 10_000.0),
+    );
+    camera.update(1.0 / 60.0);
+    let direction = camera.direction();
+    assert!(direction.y > 0.999 && direction.y < 1.0, "{:?}", direction);
+    assert!(direction.z < 0.0, "the camera turned over: {:?}", direction);
+    camera.look(pt2(0.0, -20_000.0));
+    camera.update(1.0 / 60.0);
+    let direction = camera.direction();
+    assert!(direction.y < -0.999 && direction.z < 0.0, "{:?}", direction);
+
+    let camera = FlyCamera::new(Vec3::ZERO).pitch(-10.0);
+    assert!(camera.direction().z < 0.0);
+
+    // The ray through the centre of the window follows the direction of the camera.
+    let camera = FlyCamera::new(pt3(1.0, 2.0, 3.0)).look_at(pt3(-5.0, 4.0, -20.0));
+    let ray = camera.ray(Point2::ZERO, window());
+    assert!(ray.direction.abs_diff_eq(camera.direction(), 1e-4));
+    assert!(distance_to_ray(ray, camera.eye()) < 1e-3);
+    assert!(distance_to_ray(ray, pt3(-5.0, 4.0, -20.0)) < 1e-2);
+}
+
+#[test]
+fn fly_move_test() {
+    let mut camera = FlyCamera::new(Vec3::ZERO);
+    camera.event(&WindowEvent::KeyPressed(Key::W));
+    // Repeated presses of a held key do not change the speed.
+    camera.event(&WindowEvent::KeyPressed(Key::W));
+    for _ in 0..120 {
+        camera.update(1.0 / 60.0);
+    }
+
+    // The camera accelerates towards its speed.
+    let before = camera.eye();
+    camera.update(0.5);
+    let moved = camera.eye() - before;
+    assert!(moved.abs_diff_eq(-Vec3::Z * FlyCamera::DEFAULT_SPEED * 0.5, 1e-2));
+
+    camera.event(&WindowEvent::KeyPressed(Key::LShift));
+    for _ in 0..120 {
+        camera.update(1.0 / 60.0);
+    }
+    let before = camera.eye();
+    camera.update(0.5);
+    let speed = (camera.eye() - before).length() / 0.5;
+    assert_approx(speed, FlyCamera::DEFAULT_SPEED * 4.0, 1e-1);
+
+    // And decelerates to a stop once released.
+    camera.event(&WindowEvent::Unfocused);
+    for _ in 0..120 {
+        camera.update(1.0 / 60.0);
+    }
+    let before = camera.eye();
+    camera.update(1.0);
+    assert!(camera.eye().distance(before) < 1e-3);
+
+    // Opposing keys cancel out, while diagonal movement is no faster than straight movement.
+    let mut camera = FlyCamera::new(Vec3::ZERO).damping(0.0);
+    camera.event(&WindowEvent::KeyPressed(Key::A));
+    camera.event(&WindowEvent::KeyPressed(Key::D));
+    camera.update(1.0);
+    assert_eq!(camera.eye(), Vec3::ZERO);
+    camera.event(&WindowEvent::KeyReleased(Key::A));
+    camera.event(&WindowEvent::KeyPressed(Key::E));
+    camera.update(1.0);
+    let eye = camera.eye();
+    assert_approx(eye.length(), FlyCamera::DEFAULT_SPEED, 1e-3);
+    assert!(eye.x > 0.0 && eye.y > 0.0);
+}
+
+#[test]
+fn pan_zoom_test() {
+    let mut camera = PanZoom::new(pt2(0.0, 0.0), 2.0).damping(0.0);
+
+    // Dragging moves the scene with the mouse.
+    drag(
+        &mut camera,
+        MouseButton::Left,
+        pt2(0.0, 0.0),
+        pt2(10.0, -4.0),
+    );
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.center(), pt2(-5.0, 2.0));
+    let p = pt2(30.0, -70.0);
+    assert!(camera
+        .window_point(camera.scene_point(p))
+        .abs_diff_eq(p, 1e-4));
+
+    // Zooming keeps the point beneath the cursor in place.
+    let cursor = pt2(100.0, 50.0);
+    camera.event(&WindowEvent::MouseMoved(cursor));
+    let beneath = camera.scene_point(cursor);
+    scroll(&mut camera, 3.0);
+    camera.update(1.0 / 60.0);
+    assert!(camera.zoom() > 2.0);
+    assert!(camera.scene_point(cursor).abs_diff_eq(beneath, 1e-3));
+
+    // The ray through the centre of the window points into the scene at its center.
+    let ray = camera.ray(Point2::ZERO, window());
+    assert!(ray.origin.truncate().abs_diff_eq(camera.center(), 1e-3));
+    assert!(ray.direction.abs_diff_eq(-Vec3::Z, 1e-4));
+    let ray = camera.ray(cursor, window());
+    assert!(ray.origin.truncate().abs_diff_eq(beneath, 1e-2));
+}
+
+#[test]
+fn pan_zoom_limits_test() {
+    let mut camera = PanZoom::new(pt2(0.0, 0.0), 1.0)
+        .zoom_limits(0.5, 4.0)
+        .damping(0.0);
+    scroll(&mut camera, 100.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.zoom(), 4.0);
+    scroll(&mut camera, -100.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.zoom(), 0.5);
+    camera.set_zoom(10.0);
+    camera.update(1.0 / 60.0);
+    assert_eq!(camera.zoom(), 4.0);
+
+    // Without damping the view changes immediately, while with damping it settles over time.
+    let mut camera = PanZoom::default();
+    camera.set_center(pt2(100.0, 0.0));
+    camera.update(PanZoom::DEFAULT_DAMPING);
+    assert_approx(camera.center().x, 100.0 * (1.0 - (-1.0f32).exp()), 1e-3);
+    for _ in 0..60 {
+        camera.update(1.0 / 60.0);
+    }
+    assert_approx(camera.center().x, 100.0, 1e-3);
+}
@@ -0,0 +1,255 @@
+use nannou::ease::{self, Animation, BezierEasing, Easing, Lerp, Tween};
+use nannou::glam::{Mat4, Quat, Vec3};
+
+fn approx(a: f32, b: f32, epsilon: f32) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+fn assert_approx(a: f32, b: f32) {
+    assert!(approx(a, b, 1e-4), "{} != {}", a, b);
+}
+
+fn unit() -> Tween<f32> {
+    Tween::new(0.0, 1.0, 1.0)
+}
+
+#[test]
+fn repeat_test() {
+    let anim = unit().repeat(3);
+    assert_eq!(anim.duration(), 3.0);
+    assert_eq!(anim.sample(-1.0), 0.0);
+    assert_eq!(anim.sample(0.0), 0.0);
+    assert_approx(anim.sample(0.25), 0.25);
+    // Each repetition restarts from the beginning.
+    assert_approx(anim.sample(1.25), 0.25);
+    assert_eq!(anim.sample(1.0), 0.0);
+    assert_approx(anim.sample(2.999), 0.999);
+    // The final value holds once every repetition has played.
+    assert_eq!(anim.sample(3.0), 1.0);
+    assert_eq!(anim.sample(10.0), 1.0);
+    assert!(!anim.is_finished(2.999));
+    assert!(anim.is_finished(3.0));
+
+    let once = unit().repeat(1);
+    assert_eq!(once.duration(), 1.0);
+    assert_eq!(once.sample(1.0), 1.0);
+    assert_eq!(once.sample(5.0), 1.0);
+
+    let none = unit().repeat(0);
+    assert_eq!(none.duration(), 0.0);
+    assert!(none.is_finished(0.0));
+    assert_eq!(none.sample(0.5), 0.0);
+    assert_eq!(none.sample(5.0), 0.0);
+
+    let looped = unit().looped();
+    assert_eq!(looped.duration(), f32::INFINITY);
+    assert!(!looped.is_finished(1e9));
+    assert_approx(looped.sample(100.25), 0.25);
+}
+
+#[test]
+fn repeat_yoyo_test() {
+    let anim = unit().repeat(3).yoyo();
+    assert_approx(anim.sample(0.25), 0.25);
+    // The direction alternates with each repetition without jumping between them.
+    assert_eq!(anim.sample(1.0), 1.0);
+    assert_approx(anim.sample(1.25), 0.75);
+    assert_eq!(anim.sample(2.0), 0.0);
+    assert_approx(anim.sample(2.25), 0.25);
+    // An odd number of repetitions ends at the end.
+    assert_eq!(anim.sample(3.0), 1.0);
+    assert_eq!(anim.sample(4.5), 1.0);
+
+    // An even number ends back at the start.
+    let anim = unit().repeat(2).yoyo();
+    assert_approx(anim.sample(1.75), 0.25);
+    assert_eq!(anim.sample(2.0), 0.0);
+    assert_eq!(anim.sample(3.0), 0.0);
+
+    let looped = unit().looped().yoyo();
+    assert_approx(looped.sample(101.25), 0.75);
+    assert_approx(looped.sample(102.25), 0.25);
+}
+
+#[test]
+fn sequence_test() {
+    let seq = ease::sequence(vec![
+        Tween::new(0.0, 1.0, 1.0),
+        Tween::new(5.0, 5.0, 0.0),
+        Tween::new(1.0, 3.0, 2.0),
+    ]);
+    assert_eq!(seq.duration(), 3.0);
+    assert_eq!(seq.sample(-1.0), 0.0);
+    assert_eq!(seq.sample(0.0), 0.0);
+    assert_approx(seq.sample(0.5), 0.5);
+    assert_approx(seq.sample(0.999), 0.999);
+    // Each animation starts as the previous ends, skipping those without duration.
+    assert_eq!(seq.sample(1.0), 1.0);
+    assert_approx(seq.sample(2.0), 2.0);
+    assert_eq!(seq.sample(3.0), 3.0);
+    assert_eq!(seq.sample(10.0), 3.0);
+    assert!(!seq.is_finished(2.999));
+    assert!(seq.is_finished(3.0));
+    assert_eq!(seq.anims().len(), 3);
+
+    // `then` agrees with a sequence of the same animations.
+    let then = unit().then(Tween::new(1.0, 3.0, 2.0));
+    for &t in &[-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 10.0] {
+        assert_eq!(then.sample(t), seq.sample(t));
+    }
+}
+
+#[test]
+#[should_panic]
+fn empty_sequence_test() {
+    ease::sequence(Vec::<Tween<f32>>::new());
+}
+
+#[test]
+fn stagger_test() {
+    let anims = ease::stagger(vec![unit(), unit(), unit()], 0.5);
+    assert_eq!(anims.offset(), 0.5);
+    assert_eq!(anims.duration(), 2.0);
+    assert_eq!(anims.sample(0.0), vec![0.0, 0.0, 0.0]);
+    assert_eq!(anims.sample(0.5), vec![0.5, 0.0, 0.0]);
+    assert_eq!(anims.sample(1.0), vec![1.0, 0.5, 0.0]);
+    assert_eq!(anims.sample(1.5), vec![1.0, 1.0, 0.5]);
+    assert_eq!(anims.sample(2.0), vec![1.0, 1.0, 1.0]);
+    assert!(!anims.is_finished(1.999));
+    assert!(anims.is_finished(2.0));
+
+    // Staggering by nothing plays every animation in parallel.
+    let anims = || vec![unit().boxed(), unit().delay(1.0).boxed()];
+    let together = ease::stagger(anims(), 0.0);
+    let parallel = ease::parallel(anims());
+    assert_eq!(together.duration(), parallel.duration());
+    for &t in &[0.0, 0.5, 1.0, 1.5, 2.0] {
+        assert_eq!(together.sample(t), parallel.sample(t));
+    }
+}
+
+// Values of CSS's timing functions calculated to a high precision by bisection.
+#[test]
+fn bezier_easing_test() {
+    let ts = [0.1, 0.25, 0.5, 0.75, 0.9];
+    let curves = [
+        (
+            BezierEasing::EASE,
+            [0.094796, 0.408511, 0.802403, 0.960459, 0.994316],
+        ),
+        (
+            BezierEasing::EASE_IN,
+            [0.017027, 0.093465, 0.315357, 0.621862, 0.839428],
+        ),
+        (
+            BezierEasing::EASE_OUT,
+            [0.160572, 0.378138, 0.684643, 0.906535, 0.982973],
+        ),
+        (
+            BezierEasing::EASE_IN_OUT,
+            [0.019722, 0.129162, 0.5, 0.870838, 0.980278],
+        ),
+        // Overshoots in both directions.
+        (
+            BezierEasing::new(0.68, -0.55, 0.265, 1.55),
+            [-0.066291, -0.082807, 0.60668, 1.089166, 1.062373],
+        ),
+        // Flat at the end, where Newton-Raphson struggles.
+        (
+            BezierEasing::new(0.0, 0.0, 1.0, 0.0),
+            [0.007507, 0.034758, 0.125, 0.305703, 0.520106],
+        ),
+    ];
+    for (curve, expected) in curves.iter() {
+        for (&t, &y) in ts.iter().zip(expected) {
+            let eased = curve.apply(t);
+            assert!(
+                approx(eased, y, 1e-4),
+                "{:?} at {}: {} != {}",
+                curve,
+                t,
+                eased,
+                y
+            );
+            assert_eq!(Easing::CubicBezier(*curve).apply(t), eased);
+        }
+        assert_eq!(curve.apply(0.0), 0.0);
+        assert_eq!(curve.apply(1.0), 1.0);
+        assert_eq!(curve.apply(-1.0), 0.0);
+        assert_eq!(curve.apply(2.0), 1.0);
+    }
+
+    // The slope is zero halfway, so Newton-Raphson falls back to bisection.
+    let flat = BezierEasing::new(1.0, 0.0, 0.0, 1.0);
+    assert_approx(flat.apply(0.5), 0.5);
+    let mut last = 0.0;
+    for i in 0..=100 {
+        let y = flat.apply(i as f32 / 100.0);
+        assert!(y >= last - 1e-5);
+        last = y;
+    }
+
+    // Control point `x` coordinates are clamped so that the curve remains a function of time.
+    let clamped = BezierEasing::new(-1.0, 0.5, 2.0, 0.5);
+    assert_eq!(clamped.points(), [0.0, 0.5, 1.0, 0.5]);
+    let linear = Easing::cubic_bezier(0.25, 0.25, 0.75, 0.75);
+    for i in 0..=10 {
+        let t = i as f32 / 10.0;
+        assert_approx(linear.apply(t), t);
+    }
+}
+
+#[test]
+fn easing_test() {
+    let easings = [
+        Easing::Linear,
+        Easing::SineInOut,
+        Easing::QuadIn,
+        Easing::CubicOut,
+        Easing::ExpoInOut,
+        Easing::BackIn,
+        Easing::ElasticOut,
+        Easing::BounceInOut,
+        Easing::Steps(4),
+    ];
+    for easing in easings.iter() {
+        assert_approx(easing.apply(0.0), 0.0);
+        assert_approx(easing.apply(1.0), 1.0);
+        assert_approx(easing.apply(-1.0), 0.0);
+        assert_approx(easing.apply(2.0), 1.0);
+    }
+    assert_eq!(Easing::Steps(4).apply(0.24), 0.0);
+    assert_eq!(Easing::Steps(4).apply(0.25), 0.25);
+    assert_eq!(Easing::Steps(4).apply(0.99), 0.75);
+    assert_eq!(Easing::Steps(0).apply(0.5), 0.0);
+    assert_eq!(Easing::Custom(|t| t * t).apply(0.5), 0.25);
+}
+
+#[test]
+fn mat4_lerp_test() {
+    let a = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.0, 1.0, 1.0),
+        Quat::IDENTITY,
+        Vec3::new(0.0, 0.0, 0.0),
+    );
+    let b = Mat4::from_scale_rotation_translation(
+        Vec3::new(3.0, 2.0, 1.0),
+        Quat::from_rotation_z(std::f32::consts::PI),
+        Vec3::new(10.0, -4.0, 2.0),
+    );
+    assert!(a.lerp(&b, 0.0).abs_diff_eq(a, 1e-5));
+    assert!(a.lerp(&b, 1.0).abs_diff_eq(b, 1e-5));
+
+    // Scale, rotation and translation are each interpolated rather than the matrix elements,
+    // which would collapse a half turn to nothing halfway.
+    let (scale, rotation, translation) = a.lerp(&b, 0.5).to_scale_rotation_translation();
+    assert!(scale.abs_diff_eq(Vec3::new(2.0, 1.5, 1.0), 1e-4));
+    let expected = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+    assert!(rotation.abs_diff_eq(expected, 1e-4) || rotation.abs_diff_eq(-expected, 1e-4));
+    assert!(translation.abs_diff_eq(Vec3::new(5.0, -2.0, 1.0), 1e-4));
+
+    // Extrapolation continues along each component.
+    let (scale, _, translation) = a.lerp(&b, 2.0).to_scale_rotation_translation();
+    assert!(scale.abs_diff_eq(Vec3::new(5.0, 3.0, 1.0), 1e-4));
+    assert!(translation.abs_diff_eq(Vec3::new(20.0, -8.0, 4.0), 1e-4));
+}
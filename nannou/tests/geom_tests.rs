@@ -13,3 +13,460 @@ fn angle_test() {
     let vector = vec2(70.7, -60.8);
     assert_eq!(vector.angle(), -0.7102547457375739);
 }
+
+#[test]
+fn path_measure_test() {
+    let path = nannou::geom::path()
+        .move_to(pt2(0.0, 0.0))
+        .line_to(pt2(1.0, 0.0))
+        .line_to(pt2(1.0, 1.0))
+        .line_to(pt2(0.0, 1.0))
+        .close()
+        .build();
+    let measure = path.measure(0.01);
+    assert_eq!(measure.length(), 4.0);
+    assert_eq!(measure.point_at_length(1.5), Some(pt2(1.0, 0.5)));
+    assert_eq!(measure.tangent_at_length(1.5), Some(vec2(0.0, 1.0)));
+    let points = measure.resample(5);
+    assert_eq!(points.first(), Some(&pt2(0.0, 0.0)));
+    assert_eq!(points[2], pt2(1.0, 1.0));
+    let (before, after) = measure.split_at_length(1.5);
+    assert_eq!(before.length(), 1.5);
+    assert_eq!(after.length(), 2.5);
+}
+
+#[test]
+fn polygon_boolean_test() {
+    use nannou::geom::polygon::{self, OffsetOptions};
+    let area = |contours: &[Vec<Point2>]| -> f32 {
+        contours
+            .iter()
+            .map(|c| polygon::boolean::signed_area(c))
+            .sum()
+    };
+    let square = |x: f32, y: f32| {
+        vec![
+            pt2(x, y),
+            pt2(x + 2.0, y),
+            pt2(x + 2.0, y + 2.0),
+            pt2(x, y + 2.0),
+        ]
+    };
+    let a = vec![square(0.0, 0.0)];
+    let b = vec![square(1.0, 1.0)];
+    assert_eq!(area(&polygon::union(a.clone(), b.clone())), 7.0);
+    assert_eq!(area(&polygon::intersection(a.clone(), b.clone())), 1.0);
+    assert_eq!(area(&polygon::difference(a.clone(), b.clone())), 3.0);
+    assert_eq!(area(&polygon::xor(a.clone(), b.clone())), 6.0);
+    let options = OffsetOptions::default();
+    assert_eq!(area(&polygon::offset(a.clone(), 1.0, &options)), 16.0);
+    assert_eq!(area(&polygon::offset(a, -0.5, &options)), 1.0);
+}
+
+#[test]
+fn polygon_triangulate_test() {
+    use nannou::geom::polygon::{self, boolean::signed_area};
+    // A "C" shape whose notch is not part of the polygon.
+    let c = [
+        pt2(0.0, 0.0),
+        pt2(3.0, 0.0),
+        pt2(3.0, 1.0),
+        pt2(1.0, 1.0),
+        pt2(1.0, 2.0),
+        pt2(3.0, 2.0),
+        pt2(3.0, 3.0),
+        pt2(0.0, 3.0),
+    ];
+    let triangles: Vec<_> = polygon::triangles(c.iter().cloned()).unwrap().collect();
+    assert_eq!(triangles.len(), c.len() - 2);
+    let area: f32 = triangles.iter().map(|tri| signed_area(&tri.0)).sum();
+    assert_eq!(area, 7.0);
+    let notch = pt2(2.0, 1.5);
+    assert!(triangles.iter().all(|tri| !tri.contains(&notch)));
+    assert!(triangles.iter().any(|tri| tri.contains(&pt2(2.0, 2.5))));
+    let indices: Vec<usize> = polygon::triangle_indices(c.iter().cloned()).collect();
+    assert_eq!(indices.len(), triangles.len() * 3);
+    assert!(indices
+        .chunks(3)
+        .zip(&triangles)
+        .all(|(i, tri)| { tri.0 == [c[i[0]], c[i[1]], c[i[2]]] }));
+
+    // `contains` is correct for concave polygons, including points on the fan's inner edges.
+    let polygon = || polygon::Polygon::new(c.iter().cloned());
+    assert!(polygon().contains(&notch).is_none());
+    assert!(polygon().contains(&pt2(2.0, 2.5)).is_some());
+    assert!(polygon().contains(&pt2(0.5, 0.5)).is_some());
+    assert!(polygon().contains(&pt2(4.0, 1.5)).is_none());
+
+    // Vertices with more dimensions are triangulated within the *xy* plane.
+    let tri = [pt3(0.0, 0.0, 1.0), pt3(1.0, 0.0, 2.0), pt3(1.0, 1.0, 3.0)];
+    let triangles: Vec<_> = polygon::triangles(tri.iter().cloned()).unwrap().collect();
+    assert_eq!(triangles.len(), 1);
+    assert!(tri.iter().all(|p| triangles[0].0.contains(p)));
+    assert!(polygon::triangles(tri[..1].iter().cloned()).is_none());
+
+    // A square with a square hole.
+    let outer = vec![pt2(0.0, 0.0), pt2(4.0, 0.0), pt2(4.0, 4.0), pt2(0.0, 4.0)];
+    let hole = vec![pt2(1.0, 1.0), pt2(3.0, 1.0), pt2(3.0, 3.0), pt2(1.0, 3.0)];
+    let contours = [outer, hole];
+    let points: Vec<Point2> = contours.iter().flatten().cloned().collect();
+    let triangles = polygon::triangulate(&contours).unwrap();
+    assert_eq!(triangles.len(), 8);
+    let area: f32 = triangles
+        .iter()
+        .map(|t| signed_area(&[points[t[0]], points[t[1]], points[t[2]]]))
+        .sum();
+    assert_eq!(area, 12.0);
+
+    let bowtie = [pt2(0.0, 0.0), pt2(2.0, 2.0), pt2(2.0, 0.0), pt2(0.0, 2.0)];
+    let err = polygon::triangulate(&[bowtie]).unwrap_err();
+    assert_eq!(err.crossings.len(), 1);
+    assert_eq!(err.crossings[0].edges, [0, 2]);
+    assert_eq!(err.crossings[0].point, pt2(1.0, 1.0));
+}
+
+#[test]
+fn delaunay_voronoi_test() {
+    use nannou::geom::{convex_hull, polygon::boolean::signed_area, Delaunay};
+    let points = vec![
+        pt2(0.0, 0.0),
+        pt2(2.0, 0.0),
+        pt2(2.0, 2.0),
+        pt2(0.0, 2.0),
+        pt2(1.0, 1.0),
+    ];
+    assert_eq!(convex_hull(points.clone()).len(), 4);
+    let delaunay = Delaunay::new(points);
+    assert_eq!(delaunay.len(), 4);
+    assert_eq!(delaunay.hull().len(), 4);
+    assert_eq!(delaunay.edges().count(), 8);
+    for t in 0..delaunay.len() {
+        let adjacent = delaunay.adjacent(t);
+        assert_eq!(adjacent.iter().filter(|a| a.is_some()).count(), 2);
+    }
+    let rect = Rect::from_corners(pt2(0.0, 0.0), pt2(2.0, 2.0));
+    let voronoi = delaunay.voronoi(rect);
+    assert_eq!(voronoi.len(), 5);
+    assert_eq!(signed_area(voronoi.cell(4)), 2.0);
+    let total: f32 = voronoi.cells().map(signed_area).sum();
+    assert_eq!(total, 4.0);
+}
+
+#[test]
+fn intersect_test() {
+    use nannou::geom::intersect::{self, Ray2, Segment};
+    let a = Segment::new(pt2(0.0, 0.0), pt2(2.0, 2.0));
+    let b = Segment::new(pt2(0.0, 2.0), pt2(2.0, 0.0));
+    let hit = intersect::segment_segment(a, b).unwrap();
+    assert_eq!(hit.point, pt2(1.0, 1.0));
+    assert_eq!(hit.t, 0.5);
+    assert!(hit.normal.dot(a.vector()) < 0.0);
+
+    let ray = Ray2::new(pt2(-5.0, 0.0), vec2(1.0, 0.0));
+    let hit = intersect::ray_circle(ray, pt2(0.0, 0.0), 1.0).unwrap();
+    assert_eq!(hit.point, pt2(-1.0, 0.0));
+    assert_eq!(hit.normal, vec2(-1.0, 0.0));
+
+    let rect = Rect::from_w_h(2.0, 2.0);
+    let other = Rect::from_x_y_w_h(1.5, 0.0, 2.0, 2.0);
+    assert_eq!(rect.overlap_area(other), 1.0);
+    let contact = intersect::rect_rect(rect, other).unwrap();
+    assert_eq!(contact.normal, vec2(1.0, 0.0));
+    assert_eq!(contact.depth, 0.5);
+
+    let square = [
+        pt2(-1.0, -1.0),
+        pt2(1.0, -1.0),
+        pt2(1.0, 1.0),
+        pt2(-1.0, 1.0),
+    ];
+    let tri = [pt2(0.0, 0.5), pt2(0.5, 3.0), pt2(-0.5, 3.0)];
+    let contact = intersect::polygon_polygon(&square, &tri).unwrap();
+    assert_eq!(contact.normal, vec2(0.0, 1.0));
+    assert_eq!(contact.depth, 0.5);
+    assert_eq!(contact.point, pt2(0.0, 0.5));
+}
+
+#[test]
+fn ray3_test() {
+    use nannou::geom::intersect::{self, Ray3};
+    use nannou::geom::{Cuboid, Tri};
+    use nannou::glam::Mat4;
+
+    let ray = Ray3::new(pt3(0.0, 0.0, 5.0), vec3(0.0, 0.0, -1.0));
+    let hit = intersect::ray_sphere(ray, pt3(0.0, 0.0, 0.0), 1.0).unwrap();
+    assert_eq!(hit.point, pt3(0.0, 0.0, 1.0));
+    assert_eq!(hit.normal, vec3(0.0, 0.0, 1.0));
+    assert_eq!(hit.t, 4.0);
+
+    let hit = intersect::ray_plane(ray, pt3(0.0, 0.0, -2.0), vec3(0.0, 0.0, -1.0)).unwrap();
+    assert_eq!(hit.t, 7.0);
+    assert_eq!(hit.normal, vec3(0.0, 0.0, 1.0));
+
+    let cuboid = Cuboid::from_xyz_whd(pt3(0.0, 0.0, 0.0), vec3(2.0, 2.0, 2.0));
+    let hit = intersect::ray_cuboid(ray, cuboid).unwrap();
+    assert_eq!(hit.point, pt3(0.0, 0.0, 1.0));
+    assert_eq!(hit.normal, vec3(0.0, 0.0, 1.0));
+    let inside = Ray3::new(pt3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0));
+    assert_eq!(intersect::ray_cuboid(inside, cuboid).unwrap().t, 1.0);
+
+    let tri = Tri([
+        pt3(-1.0, -1.0, 0.0),
+        pt3(1.0, -1.0, 0.0),
+        pt3(0.0, 1.0, 0.0),
+    ]);
+    let hit = intersect::ray_tri(ray, tri).unwrap();
+    assert_eq!(hit.point, pt3(0.0, 0.0, 0.0));
+    assert_eq!(hit.normal, vec3(0.0, 0.0, 1.0));
+    let miss = Ray3::new(pt3(2.0, 0.0, 5.0), vec3(0.0, 0.0, -1.0));
+    assert!(intersect::ray_tri(miss, tri).is_none());
+    let (i, hit) = intersect::ray_tris(ray, cuboid.triangles_iter()).unwrap();
+    assert_eq!(hit.point, pt3(0.0, 0.0, 1.0));
+    assert!(i < 12);
+
+    // A ray cast through the centre of the window travels along the camera's line of sight.
+    let window = Rect::from_w_h(800.0, 600.0);
+    let view = Mat4::look_at_rh(pt3(0.0, 0.0, 10.0), pt3(0.0, 0.0, 0.0), Vec3::Y);
+    let proj = Mat4::perspective_rh(1.0, 800.0 / 600.0, 0.1, 100.0);
+    let view_proj = proj * view;
+    let ray = Ray3::from_window_point(pt2(0.0, 0.0), window, view_proj);
+    assert!(ray.direction.abs_diff_eq(vec3(0.0, 0.0, -1.0), 1e-5));
+    let hit = intersect::ray_sphere(ray, pt3(0.0, 0.0, 0.0), 1.0).unwrap();
+    assert!(hit.point.abs_diff_eq(pt3(0.0, 0.0, 1.0), 1e-3));
+    let p = pt3(1.0, 2.0, -3.0);
+    let projected = intersect::project(p, window, view_proj);
+    assert!(intersect::unproject(projected, window, view_proj).abs_diff_eq(p, 1e-3));
+
+    // Rays remain precise for distant far planes.
+    let eye = pt3(300.0, 150.0, 400.0);
+    let target = pt3(10.0, 20.0, 30.0);
+    let view = Mat4::look_at_rh(eye, target, Vec3::Y);
+    let proj = Mat4::perspective_rh(1.0, 800.0 / 600.0, 0.1, 10_000.0);
+    let ray = Ray3::from_window_point(pt2(0.0, 0.0), window, proj * view);
+    assert!(ray.direction.abs_diff_eq((target - eye).normalize(), 1e-5));
+    assert!((target - ray.origin).cross(ray.direction).length() < 1e-2);
+}
+
+#[test]
+fn spatial_test() {
+    use nannou::geom::spatial::{HashGrid, KdTree, Quadtree};
+    let points: Vec<Point2> = (0..100)
+        .map(|i| pt2((i % 10) as f32 * 10.0, (i / 10) as f32 * 10.0))
+        .collect();
+    let mut quadtree = Quadtree::new(Rect::from_corners(pt2(0.0, 0.0), pt2(100.0, 100.0)));
+    let mut kdtree = KdTree::new();
+    let mut grid = HashGrid::new(15.0);
+    for (i, &p) in points.iter().enumerate() {
+        assert_eq!(quadtree.insert(p, i), i);
+        assert_eq!(kdtree.insert(p, i), i);
+        assert_eq!(grid.insert(p, i), i);
+    }
+
+    let keys = |items: Vec<nannou::geom::spatial::Item<Point2, usize>>| {
+        let mut keys: Vec<usize> = items.iter().map(|item| *item.value).collect();
+        keys.sort();
+        keys
+    };
+    let center = pt2(45.0, 45.0);
+    let expected = vec![44, 45, 54, 55];
+    assert_eq!(keys(quadtree.within_radius(center, 8.0)), expected);
+    assert_eq!(keys(kdtree.within_radius(center, 8.0)), expected);
+    assert_eq!(keys(grid.within_radius(center, 8.0)), expected);
+    let bounds = Rect::from_corners(pt2(35.0, 35.0), pt2(55.0, 55.0));
+    assert_eq!(keys(quadtree.within_bounds(bounds)), expected);
+    assert_eq!(keys(kdtree.within_bounds(bounds)), expected);
+    assert_eq!(keys(grid.within_bounds(bounds)), expected);
+
+    assert_eq!(quadtree.remove(44), Some(44));
+    assert_eq!(kdtree.remove(44), Some(44));
+    assert_eq!(grid.remove(44), Some(44));
+    assert!(quadtree.update(0, pt2(46.0, 44.0)));
+    assert!(kdtree.update(0, pt2(46.0, 44.0)));
+    assert!(grid.update(0, pt2(46.0, 44.0)));
+    let point = pt2(47.0, 46.0);
+    let nearest = vec![0, 55, 45];
+    let values = |items: Vec<nannou::geom::spatial::Item<Point2, usize>>| {
+        items.iter().map(|item| *item.value).collect::<Vec<_>>()
+    };
+    assert_eq!(values(quadtree.nearest(point, 3)), nearest);
+    assert_eq!(values(kdtree.nearest(point, 3)), nearest);
+    assert_eq!(values(grid.nearest(point, 3)), nearest);
+    assert_eq!(grid.len(), 99);
+}
+
+#[test]
+fn curve_test() {
+    use nannou::geom::curve::{BSpline, CatmullRom, CubicBezier, Curve};
+    let cubic = CubicBezier::new(pt2(0.0, 0.0), pt2(0.0, 1.0), pt2(1.0, 1.0), pt2(1.0, 0.0));
+    assert_eq!(cubic.point(0.5), pt2(0.5, 0.75));
+    let (a, b) = cubic.split(0.5);
+    assert_eq!(a.to, b.from);
+    assert_eq!(a.point(1.0), cubic.point(0.5));
+    let bounds = cubic.bounding_box();
+    assert_eq!(bounds.top(), 0.75);
+    assert_eq!((bounds.left(), bounds.right()), (0.0, 1.0));
+
+    let line = CubicBezier::new(pt2(0.0, 0.0), pt2(1.0, 0.0), pt2(2.0, 0.0), pt2(3.0, 0.0));
+    assert!((line.length() - 3.0).abs() < 1e-4);
+    assert!((line.point_at_length(1.5).x - 1.5).abs() < 1e-3);
+    assert_eq!(line.flattened(0.01).count(), 2);
+
+    let points = [pt2(0.0, 0.0), pt2(1.0, 1.0), pt2(2.0, 0.0), pt2(3.0, 1.0)];
+    let spline = CatmullRom::new(points.iter().cloned());
+    assert_eq!(spline.len(), 3);
+    for (i, &p) in points.iter().enumerate() {
+        assert!((spline.point(i as f32 / 3.0) - p).length() < 1e-5);
+    }
+
+    let bspline = BSpline::clamped(3, points.iter().cloned()).unwrap();
+    assert_eq!(bspline.start(), points[0]);
+    assert_eq!(bspline.end(), points[3]);
+    // A clamped cubic with four points is a single cubic bézier.
+    assert_eq!(bspline.bezier_points(), points.to_vec());
+    assert!(BSpline::uniform(3, points[..3].iter().cloned()).is_none());
+}
+
+#[test]
+fn polyline_test() {
+    use nannou::geom::{polyline, CubicBezier};
+
+    // Ramer–Douglas–Peucker keeps the end points of an open polyline and any significant corners.
+    let open = [
+        pt2(0.0, 0.0),
+        pt2(1.0, 0.01),
+        pt2(2.0, 0.0),
+        pt2(3.0, 1.0),
+        pt2(4.0, 0.0),
+    ];
+    let expected = vec![pt2(0.0, 0.0), pt2(2.0, 0.0), pt2(3.0, 1.0), pt2(4.0, 0.0)];
+    assert_eq!(polyline::rdp(open.iter().cloned(), 0.1, false), expected);
+    // The mid-points of a closed square's edges are removed.
+    let square = [
+        pt2(0.0, 0.0),
+        pt2(1.0, 0.0),
+        pt2(2.0, 0.0),
+        pt2(2.0, 1.0),
+        pt2(2.0, 2.0),
+        pt2(1.0, 2.0),
+        pt2(0.0, 2.0),
+        pt2(0.0, 1.0),
+    ];
+    let corners = vec![pt2(0.0, 0.0), pt2(2.0, 0.0), pt2(2.0, 2.0), pt2(0.0, 2.0)];
+    assert_eq!(polyline::rdp(square.iter().cloned(), 0.1, true), corners);
+    assert!(polyline::rdp(vec![], 0.1, false).is_empty());
+    let pair = vec![pt2(0.0, 0.0), pt2(1.0, 1.0)];
+    assert_eq!(polyline::rdp(pair.clone(), 0.1, true), pair);
+
+    // Visvalingam–Whyatt removes the least significant points first.
+    assert_eq!(
+        polyline::visvalingam(square.iter().cloned(), 0.5, true),
+        corners
+    );
+    let line: Vec<Point2> = (0..5).map(|i| pt2(i as f32, 0.0)).collect();
+    let ends = vec![pt2(0.0, 0.0), pt2(4.0, 0.0)];
+    assert_eq!(polyline::visvalingam(line.clone(), 1.0, false), ends);
+    // Closed polylines keep at least three points, regardless of area.
+    assert_eq!(polyline::visvalingam(corners.clone(), 100.0, true).len(), 3);
+    assert_eq!(polyline::visvalingam(pair.clone(), 1.0, false), pair);
+
+    // Chaikin cuts each corner, keeping the end points of open polylines.
+    let corner = [pt2(0.0, 0.0), pt2(4.0, 0.0), pt2(4.0, 4.0)];
+    let smoothed = polyline::chaikin(corner.iter().cloned(), 1, false);
+    let expected = vec![pt2(0.0, 0.0), pt2(3.0, 0.0), pt2(4.0, 1.0), pt2(4.0, 4.0)];
+    assert_eq!(smoothed, expected);
+    let smoothed = polyline::chaikin(corners.iter().cloned(), 2, true);
+    assert_eq!(smoothed.len(), 16);
+    assert_eq!(smoothed[0], pt2(0.75, 0.0));
+    assert_eq!(polyline::chaikin(pair.clone(), 3, false), pair);
+
+    // Fitted cubics pass within the tolerance of every point.
+    let within = |points: &[Point2], cubics: &[CubicBezier<Point2>], tolerance: f32| {
+        let samples = polyline::sample_cubics(cubics, 100);
+        points
+            .iter()
+            .all(|p| samples.iter().any(|s| s.distance(*p) <= tolerance + 0.01))
+    };
+    let wave: Vec<Point2> = (0..=50)
+        .map(|i| {
+            let x = i as f32 / 50.0 * TAU;
+            pt2(x, x.sin())
+        })
+        .collect();
+    let cubics = polyline::fit_cubics(wave.clone(), 0.05, false);
+    assert_eq!(cubics.first().unwrap().from, wave[0]);
+    assert_eq!(cubics.last().unwrap().to, wave[50]);
+    assert!(within(&wave, &cubics, 0.05));
+    // Neighbouring segments are joined.
+    assert!(cubics.windows(2).all(|w| w[0].to == w[1].from));
+
+    let circle: Vec<Point2> = (0..64)
+        .map(|i| {
+            let angle = i as f32 / 64.0 * TAU;
+            pt2(angle.cos(), angle.sin())
+        })
+        .collect();
+    let cubics = polyline::fit_cubics(circle.clone(), 0.01, true);
+    assert_eq!(cubics.first().unwrap().from, circle[0]);
+    assert_eq!(cubics.last().unwrap().to, circle[0]);
+    assert!(within(&circle, &cubics, 0.01));
+    // The closed curve is smooth where it joins.
+    let start = (cubics[0].ctrl1 - cubics[0].from).normalize();
+    let end = (cubics.last().unwrap().to - cubics.last().unwrap().ctrl2).normalize();
+    assert!(start.distance(end) < 1e-4);
+
+    // Degenerate polylines.
+    assert!(polyline::fit_cubics(vec![], 0.1, false).is_empty());
+    assert!(polyline::fit_cubics(vec![pt2(1.0, 1.0); 4], 0.1, true).is_empty());
+    let cubics = polyline::fit_cubics(pair.clone(), 0.1, false);
+    assert_eq!(cubics.len(), 1);
+    assert_eq!((cubics[0].from, cubics[0].to), (pair[0], pair[1]));
+}
+
+#[test]
+fn path_polyline_test() {
+    let path = nannou::geom::path()
+        .move_to(pt2(0.0, 0.0))
+        .line_to(pt2(1.0, 0.0))
+        .line_to(pt2(2.0, 0.0))
+        .line_to(pt2(2.0, 2.0))
+        .close()
+        .move_to(pt2(5.0, 0.0))
+        .line_to(pt2(6.0, 0.01))
+        .line_to(pt2(7.0, 0.0))
+        .build();
+    let polylines = nannou::geom::path::polyline::polylines(&path.rdp(0.1), 0.01);
+    let expected = vec![
+        (vec![pt2(0.0, 0.0), pt2(2.0, 0.0), pt2(2.0, 2.0)], true),
+        (vec![pt2(5.0, 0.0), pt2(7.0, 0.0)], false),
+    ];
+    assert_eq!(polylines, expected);
+    let smoothed = nannou::geom::path::polyline::polylines(&path.chaikin(1), 0.01);
+    assert_eq!(smoothed.len(), 2);
+    assert!(smoothed[0].1 && !smoothed[1].1);
+
+    // The Catmull-Rom spline passes through each point, matching the path of the equivalent
+    // spline.
+    let splined = path.catmull_rom(0.5);
+    let ends: Vec<Point2> = splined
+        .iter()
+        .filter_map(|event| match event {
+            nannou::lyon::path::PathEvent::Cubic { to, .. } => Some(pt2(to.x, to.y)),
+            _ => None,
+        })
+        .collect();
+    let expected = vec![
+        pt2(1.0, 0.0),
+        pt2(2.0, 0.0),
+        pt2(2.0, 2.0),
+        pt2(0.0, 0.0),
+        pt2(6.0, 0.01),
+        pt2(7.0, 0.0),
+    ];
+    assert_eq!(ends, expected);
+    let square = vec![pt2(0.0, 0.0), pt2(1.0, 0.0), pt2(2.0, 0.0), pt2(2.0, 2.0)];
+    let square = nannou::geom::CatmullRom::new(square).closed(true);
+    let line = nannou::geom::CatmullRom::new(vec![pt2(5.0, 0.0), pt2(6.0, 0.01), pt2(7.0, 0.0)]);
+    let length =
+        nannou::geom::Path::from(&square).length() + nannou::geom::Path::from(&line).length();
+    assert!((splined.length() - length).abs() < 1e-3);
+}
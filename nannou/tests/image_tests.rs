@@ -0,0 +1,144 @@
+use nannou::color::Srgb;
+use nannou::image::{
+    self, DynamicImage, Extractor, GenericImageView, Method, Rgba, RgbaImage, SortBy,
+};
+
+const RED: Srgb<u8> = Srgb {
+    red: 255,
+    green: 0,
+    blue: 0,
+    standard: std::marker::PhantomData,
+};
+const BLUE: Srgb<u8> = Srgb {
+    red: 0,
+    green: 0,
+    blue: 255,
+    standard: std::marker::PhantomData,
+};
+
+// A 10x10 image, 60% red and 40% blue, with a transparent green column.
+fn two_color_image() -> DynamicImage {
+    let image = RgbaImage::from_fn(11, 10, |x, _| match x {
+        0..=5 => Rgba([255, 0, 0, 255]),
+        6..=9 => Rgba([0, 0, 255, 255]),
+        _ => Rgba([0, 255, 0, 100]),
+    });
+    DynamicImage::ImageRgba8(image)
+}
+
+fn gradient_image() -> DynamicImage {
+    let image = RgbaImage::from_fn(64, 16, |x, y| {
+        let v = (x * 4) as u8;
+        Rgba([v, v / 2, 255 - v, 100 + y as u8])
+    });
+    DynamicImage::ImageRgba8(image)
+}
+
+fn close(a: Srgb<u8>, b: Srgb<u8>) -> bool {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).abs() <= 1;
+    d(a.red, b.red) && d(a.green, b.green) && d(a.blue, b.blue)
+}
+
+#[test]
+fn extract_two_colors_test() {
+    let image = two_color_image();
+    for &method in &[Method::KMeans, Method::MedianCut] {
+        let extractor = Extractor::new().method(method);
+        let swatches = extractor.extract(&image, 2);
+        assert_eq!(swatches.len(), 2, "{:?}", method);
+        assert!(close(swatches[0].color, RED), "{:?}", swatches);
+        assert!(close(swatches[1].color, BLUE), "{:?}", swatches);
+        assert!((swatches[0].population - 0.6).abs() < 1e-6);
+        assert!((swatches[1].population - 0.4).abs() < 1e-6);
+
+        // No more colors are produced than the image contains.
+        let swatches = extractor.extract(&image, 5);
+        assert_eq!(swatches.len(), 2, "{:?}: {:?}", method, swatches);
+        let total: f32 = swatches.iter().map(|s| s.population).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+
+        assert!(extractor.extract(&image, 0).is_empty());
+        let transparent = DynamicImage::ImageRgba8(RgbaImage::new(4, 4));
+        assert!(extractor.extract(&transparent, 2).is_empty());
+    }
+
+    // Sampling a subset of a large image preserves its proportions.
+    let swatches = Extractor::new().max_samples(10).extract(&image, 2);
+    assert_eq!(swatches.len(), 2);
+    assert!(close(swatches[0].color, RED) && close(swatches[1].color, BLUE));
+
+    // The default seed produces the same palette each time.
+    let gradient = gradient_image();
+    assert_eq!(
+        image::extract_palette(&gradient, 4),
+        image::extract_palette(&gradient, 4)
+    );
+}
+
+#[test]
+fn quantize_test() {
+    let image = gradient_image();
+    let palette = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255), RED, BLUE];
+    for &dither in &[image::Dither::None, image::Dither::FloydSteinberg] {
+        let quantized = image::quantize(&image, &palette, dither);
+        assert_eq!(quantized.dimensions(), image.dimensions());
+        for (x, y, pixel) in quantized.enumerate_pixels() {
+            let [r, g, b, a] = pixel.0;
+            assert!(
+                palette.contains(&Srgb::new(r, g, b)),
+                "{:?}: {:?} is not within the palette",
+                dither,
+                pixel
+            );
+            assert_eq!(a, image.get_pixel(x, y).0[3]);
+        }
+    }
+
+    // Without dithering, each pixel takes its nearest color.
+    let quantized = image::quantize(&two_color_image(), &palette, image::Dither::None);
+    assert_eq!(quantized.get_pixel(0, 0).0, [255, 0, 0, 255]);
+    assert_eq!(quantized.get_pixel(9, 9).0, [0, 0, 255, 255]);
+
+    // Dithering a gray between black and white mixes the two, rather than rounding to one.
+    let gray = DynamicImage::ImageRgba8(RgbaImage::from_pixel(16, 16, Rgba([128, 128, 128, 255])));
+    let black_and_white = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    let flat = image::quantize(&gray, &black_and_white, image::Dither::None);
+    let first = flat.get_pixel(0, 0).0;
+    assert!(flat.pixels().all(|p| p.0 == first));
+    let dithered = image::quantize(&gray, &black_and_white, image::Dither::FloydSteinberg);
+    let white = dithered.pixels().filter(|p| p.0[0] == 255).count();
+    assert!(white > 0 && white < 256, "{} white pixels", white);
+}
+
+#[test]
+#[should_panic]
+fn quantize_empty_palette_test() {
+    image::quantize(&two_color_image(), &[], image::Dither::None);
+}
+
+#[test]
+fn sort_colors_test() {
+    let black = Srgb::new(0, 0, 0);
+    let gray = Srgb::new(128, 128, 128);
+    let white = Srgb::new(255, 255, 255);
+    let green = Srgb::new(0, 200, 0);
+    let pale_blue = Srgb::new(150, 150, 255);
+    let mut colors = vec![BLUE, gray, RED, white, green, black];
+
+    // Grays have no hue and are placed first, in their original order.
+    image::sort_colors(&mut colors, SortBy::Hue);
+    assert_eq!(colors, vec![gray, white, black, RED, green, BLUE]);
+
+    image::sort_colors(&mut colors, SortBy::Lightness);
+    assert_eq!(colors[0], black);
+    assert_eq!(colors[colors.len() - 1], white);
+    assert!(colors.iter().position(|&c| c == gray) > colors.iter().position(|&c| c == BLUE));
+
+    let mut colors = vec![RED, pale_blue, gray, green];
+    image::sort_colors(&mut colors, SortBy::Saturation);
+    assert_eq!(colors, vec![gray, pale_blue, RED, green]);
+
+    let mut swatches = image::extract_palette(&two_color_image(), 2);
+    image::sort_colors(&mut swatches, SortBy::Hue);
+    assert!(close(swatches[0].color, RED) && close(swatches[1].color, BLUE));
+}
@@ -0,0 +1,126 @@
+use nannou::glam::{Vec2, Vec3, Vec4};
+use nannou::noise::core::{
+    Feature, Metric, NoiseFn, OpenSimplex, Perlin, Simplex, Value, Worley, DEFAULT_SEED,
+};
+use nannou::rand::SeededRng;
+
+const SAMPLES: usize = 1_000;
+
+// Points scattered across a range wide enough to span many lattice cells.
+fn points(seed: u64) -> Vec<Vec4> {
+    let mut rng = SeededRng::new(seed);
+    let mut coord = || rng.random_range(-100.0, 100.0);
+    (0..SAMPLES)
+        .map(|_| Vec4::new(coord(), coord(), coord(), coord()))
+        .collect()
+}
+
+// The noise at each point in 1 to 4 dimensions.
+fn sample<N>(noise: &N, points: &[Vec4]) -> Vec<[f32; 4]>
+where
+    N: NoiseFn<f32> + NoiseFn<Vec2> + NoiseFn<Vec3> + NoiseFn<Vec4>,
+{
+    points
+        .iter()
+        .map(|&p| {
+            [
+                noise.get(p.x),
+                noise.get(p.truncate().truncate()),
+                noise.get(p.truncate()),
+                noise.get(p),
+            ]
+        })
+        .collect()
+}
+
+// Check the noise produced by each of the given generators, created with the given seed.
+fn check<N, F>(name: &str, new: F)
+where
+    N: NoiseFn<f32> + NoiseFn<Vec2> + NoiseFn<Vec3> + NoiseFn<Vec4>,
+    F: Fn(u32) -> N,
+{
+    let points = points(1);
+    let a = sample(&new(1), &points);
+
+    // The same seed always produces the same noise.
+    assert_eq!(a, sample(&new(1), &points), "{}", name);
+
+    for dim in 0..4 {
+        let values = a.iter().map(|v| v[dim]);
+        // Values lie within range and are not constant.
+        let (min, max) = values.fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        assert!(
+            min >= -1.0 && max <= 1.0,
+            "{} {}D: {}..{}",
+            name,
+            dim + 1,
+            min,
+            max
+        );
+        assert!(max - min > 0.5, "{} {}D: {}..{}", name, dim + 1, min, max);
+    }
+
+    // Different seeds produce unrelated noise.
+    let b = sample(&new(2), &points);
+    for dim in 0..4 {
+        let corr = correlation(a.iter().map(|v| v[dim]), b.iter().map(|v| v[dim]));
+        assert!(
+            corr.abs() < 0.25,
+            "{} {}D: correlation {}",
+            name,
+            dim + 1,
+            corr
+        );
+    }
+}
+
+// The pearson correlation coefficient of the given values.
+fn correlation(a: impl Iterator<Item = f32>, b: impl Iterator<Item = f32>) -> f64 {
+    let pairs: Vec<(f64, f64)> = a.zip(b).map(|(a, b)| (a as f64, b as f64)).collect();
+    let n = pairs.len() as f64;
+    let (ma, mb) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(sa, sb), (a, b)| (sa + a / n, sb + b / n));
+    let (mut cov, mut va, mut vb) = (0.0, 0.0, 0.0);
+    for (a, b) in pairs {
+        cov += (a - ma) * (b - mb);
+        va += (a - ma) * (a - ma);
+        vb += (b - mb) * (b - mb);
+    }
+    cov / (va * vb).sqrt()
+}
+
+#[test]
+fn noise_test() {
+    check("Perlin", Perlin::new);
+    check("Simplex", Simplex::new);
+    check("OpenSimplex", OpenSimplex::new);
+    check("Value", Value::new);
+    check("Worley", Worley::new);
+}
+
+#[test]
+fn worley_noise_test() {
+    let metrics = [Metric::Euclidean, Metric::Manhattan, Metric::Chebyshev];
+    let features = [
+        Feature::Distance,
+        Feature::SecondDistance,
+        Feature::Difference,
+        Feature::Value,
+    ];
+    for &metric in &metrics {
+        for &feature in &features {
+            let name = format!("Worley {:?} {:?}", metric, feature);
+            check(&name, |seed| {
+                Worley::new(seed).metric(metric).feature(feature)
+            });
+        }
+    }
+
+    // Feature points lie at the centre of each cell without jitter.
+    let grid = Worley::new(DEFAULT_SEED).jitter(0.0);
+    assert_eq!(grid.get(Vec2::new(0.5, 0.5)), -1.0);
+    assert_eq!(grid.get(Vec2::new(3.5, -7.5)), -1.0);
+    assert_eq!(grid.get(Vec2::new(1.0, 0.5)), 0.0);
+    assert_eq!(Worley::default(), Worley::new(DEFAULT_SEED));
+}
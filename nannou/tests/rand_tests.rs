@@ -0,0 +1,27 @@
+use nannou::prelude::*;
+use nannou::rand::{InPolygon, PoissonDisk, SeededRng};
+
+#[test]
+fn poisson_disk_polygon_hole_test() {
+    let square =
+        |x: f32, y: f32, s: f32| vec![pt2(x, y), pt2(x + s, y), pt2(x + s, y + s), pt2(x, y + s)];
+    let outer = square(0.0, 0.0, 20.0);
+    let hole = square(5.0, 5.0, 10.0);
+    let in_hole = |p: &Point2| p.x > 5.0 && p.x < 15.0 && p.y > 5.0 && p.y < 15.0;
+    let in_outer = |p: &Point2| p.x >= 0.0 && p.x <= 20.0 && p.y >= 0.0 && p.y <= 20.0;
+
+    // Holes are respected whether they are wound with or against the outline.
+    let mut reversed = hole.clone();
+    reversed.reverse();
+    for hole in [hole, reversed].iter() {
+        let contours = [outer.clone(), hole.clone()];
+        assert_eq!(InPolygon::new(&contours).unwrap().area(), 300.0);
+        let mut rng = SeededRng::new(7);
+        let points = PoissonDisk::new(1.0).sample_polygon(&mut rng, &contours);
+        assert!(points.len() > 100);
+        assert!(points.iter().all(|p| in_outer(p) && !in_hole(p)));
+        for (i, a) in points.iter().enumerate() {
+            assert!(points[i + 1..].iter().all(|b| a.distance(*b) >= 1.0));
+        }
+    }
+}
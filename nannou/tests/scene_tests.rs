@@ -0,0 +1,127 @@
+use nannou::glam::{Mat4, Vec3};
+use nannou::scene::{Node, Scene};
+
+fn translation(scene: &Scene, id: nannou::scene::NodeId) -> Vec3 {
+    scene.world_transform(id).unwrap().w_axis.truncate()
+}
+
+#[test]
+fn scene_world_transform_test() {
+    let mut scene = Scene::new();
+    let parent = scene.add(Node::new().translate(Vec3::new(1.0, 0.0, 0.0)));
+    let child = scene.add_child(parent, Node::new().translate(Vec3::new(0.0, 2.0, 0.0)));
+    let grandchild = scene.add_child(child, Node::new().scale(2.0));
+    assert_eq!(translation(&scene, child), Vec3::new(1.0, 2.0, 0.0));
+    assert_eq!(translation(&scene, grandchild), Vec3::new(1.0, 2.0, 0.0));
+
+    // Cached world transforms are recalculated after the transform of an ancestor changes.
+    assert!(scene.set_transform(parent, Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0))));
+    assert_eq!(translation(&scene, grandchild), Vec3::new(5.0, 2.0, 0.0));
+    assert_eq!(translation(&scene, child), Vec3::new(5.0, 2.0, 0.0));
+    let scaled = scene.add(Node::new().scale(3.0));
+    let point = Vec3::new(0.0, 0.0, 1.0);
+    let world = scene.world_transform(grandchild).unwrap();
+    assert_eq!(world.transform_point3(point), Vec3::new(5.0, 2.0, 2.0));
+
+    // And after a node is moved to a new parent.
+    assert!(scene.set_parent(child, Some(scaled)));
+    assert_eq!(translation(&scene, child), Vec3::new(0.0, 6.0, 0.0));
+    let world = scene.world_transform(grandchild).unwrap();
+    assert_eq!(world.transform_point3(point), Vec3::new(0.0, 6.0, 6.0));
+    assert_eq!(scene.node(parent).unwrap().children(), &[]);
+    assert_eq!(scene.node(scaled).unwrap().children(), &[child]);
+    assert!(scene.set_parent(child, None));
+    assert_eq!(translation(&scene, child), Vec3::new(0.0, 2.0, 0.0));
+    assert_eq!(scene.roots(), &[parent, scaled, child]);
+
+    // A node may not become its own descendant.
+    assert!(!scene.set_parent(child, Some(grandchild)));
+    assert!(!scene.set_parent(child, Some(child)));
+    let ancestors: Vec<_> = scene.ancestors(grandchild).collect();
+    assert_eq!(ancestors, vec![grandchild, child]);
+}
+
+#[test]
+fn scene_deep_hierarchy_test() {
+    // World transforms are found without recursing once per ancestor.
+    let mut scene = Scene::new();
+    let step = Node::new().translate(Vec3::new(1.0, 0.0, 0.0));
+    let root = scene.add(Node::new());
+    let mut leaf = root;
+    for _ in 0..100_000 {
+        leaf = scene.add_child(leaf, Node::new().transform(step.local_transform()));
+    }
+    assert_eq!(translation(&scene, leaf).x, 100_000.0);
+    scene.set_transform(root, Mat4::from_translation(Vec3::new(-1.0, 0.0, 0.0)));
+    assert_eq!(translation(&scene, leaf).x, 99_999.0);
+    assert!(scene.remove(root).is_some());
+    assert!(scene.is_empty());
+}
+
+#[test]
+fn scene_remove_test() {
+    let mut scene = Scene::new();
+    let a = scene.add(Node::new());
+    let b = scene.add_child(a, Node::new());
+    let c = scene.add_child(b, Node::new());
+    let d = scene.add_child(a, Node::new());
+    let e = scene.add(Node::new());
+    assert_eq!(scene.len(), 5);
+
+    // Removing a node removes its descendants and detaches it from its parent.
+    let removed = scene.remove(b).unwrap();
+    assert_eq!(removed.parent(), None);
+    assert_eq!(scene.len(), 3);
+    assert!(!scene.contains(b) && !scene.contains(c));
+    assert_eq!(scene.node(a).unwrap().children(), &[d]);
+    assert!(scene.remove(b).is_none());
+    let ids: Vec<_> = scene.iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![a, d, e]);
+
+    // The ids of removed nodes never refer to nodes added afterwards.
+    let f = scene.add(Node::new());
+    let g = scene.add(Node::new());
+    assert!(scene.contains(f) && scene.contains(g));
+    for &stale in &[b, c] {
+        assert!(scene.node(stale).is_none());
+        assert!(!scene.set_transform(stale, Mat4::IDENTITY));
+        assert!(!scene.set_visible(stale, false));
+        assert!(!scene.set_parent(stale, None));
+        assert!(scene.world_transform(stale).is_none());
+        assert_eq!(scene.ancestors(stale).count(), 0);
+    }
+    assert_eq!(scene.len(), 5);
+
+    scene.clear();
+    assert!(scene.is_empty());
+    let h = scene.add(Node::new());
+    assert!(scene.contains(h));
+    assert!(!scene.contains(a) && !scene.contains(f));
+    assert_eq!(scene.roots(), &[h]);
+}
+
+#[test]
+fn scene_visibility_test() {
+    let mut scene = Scene::new();
+    let parent = scene.add(Node::new());
+    let child = scene.add_child(parent, Node::new());
+    let hidden = scene.add_child(child, Node::new().visible(false));
+    let grandchild = scene.add_child(hidden, Node::new());
+    assert!(scene.is_visible(child));
+    assert!(!scene.is_visible(hidden));
+    assert!(!scene.is_visible(grandchild));
+    assert!(scene.node(grandchild).unwrap().is_visible());
+
+    // Hiding a node hides all of its descendants without changing their own visibility.
+    assert!(scene.set_visible(parent, false));
+    assert!(!scene.is_visible(child));
+    assert!(scene.node(child).unwrap().is_visible());
+    assert!(scene.set_visible(parent, true));
+    assert!(scene.set_visible(hidden, true));
+    assert!(scene.is_visible(grandchild));
+
+    // Visibility follows the new parent when moved.
+    let other = scene.add(Node::new().visible(false));
+    assert!(scene.set_parent(hidden, Some(other)));
+    assert!(!scene.is_visible(grandchild));
+}
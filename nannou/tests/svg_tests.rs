@@ -0,0 +1,96 @@
+use nannou::color::srgba;
+use nannou::lyon::path::PathEvent;
+use nannou::prelude::*;
+use nannou::svg::{Document, Node};
+
+// The start and end points of the line segments of each sub-path.
+fn polygon_points(path: &nannou::geom::Path) -> Vec<Point2> {
+    path.iter()
+        .filter_map(|event| match event {
+            PathEvent::Begin { at } => Some(pt2(at.x, at.y)),
+            PathEvent::Line { to, .. } => Some(pt2(to.x, to.y)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn svg_path_test() {
+    let text = r##"
+        <svg xmlns="http://www.w3.org/2000/svg" width="100" height="50" viewBox="0 0 200 100">
+            <rect id="r" x="0" y="0" width="20" height="10"
+                  fill="#ff0000" stroke="#00ff00" stroke-width="2"/>
+            <path id="p" d="M 100 50 L 200 100" stroke="#0000ff" fill="none"/>
+        </svg>
+    "##;
+    let doc: Document = text.parse().unwrap();
+    assert_eq!(doc.size, vec2(100.0, 50.0));
+    assert_eq!(doc.paths().count(), 2);
+
+    // The view box is scaled to the document size, centred at the origin with y pointing up.
+    let rect = match doc.root.find("r") {
+        Some(Node::Path(path)) => path,
+        _ => panic!("no path with id \"r\""),
+    };
+    let points = polygon_points(&rect.path);
+    let expected = [
+        pt2(-50.0, 25.0),
+        pt2(-40.0, 25.0),
+        pt2(-40.0, 20.0),
+        pt2(-50.0, 20.0),
+    ];
+    assert_eq!(points, expected);
+    let fill = rect.fill.unwrap();
+    assert_eq!(fill.color, srgba(1.0, 0.0, 0.0, 1.0));
+    let stroke = rect.stroke.unwrap();
+    assert_eq!(stroke.color, srgba(0.0, 1.0, 0.0, 1.0));
+    assert_eq!(stroke.weight, 1.0);
+
+    // Paths with `fill="none"` have no fill.
+    let line = doc.paths().nth(1).unwrap();
+    assert_eq!(line.id, "p");
+    assert!(line.fill.is_none());
+    assert_eq!(
+        polygon_points(&line.path),
+        vec![pt2(0.0, 0.0), pt2(50.0, -25.0)]
+    );
+}
+
+#[test]
+fn svg_group_opacity_test() {
+    let text = r##"
+        <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+            <g id="outer" opacity="0.5">
+                <g id="inner" opacity="0.5">
+                    <circle id="c" cx="50" cy="50" r="10" fill="#0000ff" fill-opacity="0.5"/>
+                </g>
+            </g>
+        </svg>
+    "##;
+    let doc: Document = text.parse().unwrap();
+    assert!(matches!(doc.root.find("outer"), Some(Node::Group(_))));
+    assert!(matches!(doc.root.find("inner"), Some(Node::Group(_))));
+    let circle = doc.paths().next().unwrap();
+    assert_eq!(circle.id, "c");
+    // The opacity of each ancestor group is multiplied into the fill's alpha.
+    assert_eq!(circle.fill.unwrap().color, srgba(0.0, 0.0, 1.0, 0.125));
+}
+
+#[test]
+fn svg_gradient_test() {
+    let text = r##"
+        <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+            <defs>
+                <linearGradient id="g">
+                    <stop offset="0" stop-color="#ff0000"/>
+                    <stop offset="1" stop-color="#0000ff" stop-opacity="0.5"/>
+                </linearGradient>
+            </defs>
+            <rect x="10" y="10" width="80" height="80" fill="url(#g)"/>
+        </svg>
+    "##;
+    let doc: Document = text.parse().unwrap();
+    let rect = doc.paths().next().unwrap();
+    // Gradients are replaced by the average of their stops.
+    assert_eq!(rect.fill.unwrap().color, srgba(0.5, 0.0, 0.5, 0.75));
+}
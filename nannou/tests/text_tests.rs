@@ -0,0 +1,423 @@
+use nannou::draw::renderer::{GlyphCache, GlyphCacheStats};
+use nannou::text::rt::gpu_cache::{CacheWriteErr, CachedBy};
+use nannou::text::{self, cursor, font, rt, sdf, Scale, TextEdit};
+
+#[test]
+fn sdf_distance_field_test() {
+    // A 7x1 bitmap whose left three pixels are fully covered.
+    let coverage = [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    let field = sdf::distance_field(&coverage, 7, 1, 4.0);
+    // `0.5 -/+ distance / (2 * spread)`, scaled to `0..=255`.
+    assert_eq!(field, vec![223, 191, 159, 96, 64, 32, 0]);
+    // Either side of the edge is equidistant from it.
+    assert_eq!(field[2] as u32 + field[3] as u32, 255);
+
+    // A half-covered pixel lies exactly on the edge.
+    let field = sdf::distance_field(&[0.5], 1, 1, 4.0);
+    assert_eq!(field, vec![128]);
+
+    // Distances are euclidean rather than manhattan.
+    let mut coverage = [0.0; 25];
+    coverage[12] = 1.0;
+    let field = sdf::distance_field(&coverage, 5, 5, 4.0);
+    assert_eq!(field[12], 159);
+    assert_eq!(field[13], 96);
+    assert_eq!(field[18], 82);
+    assert_eq!(field[0], 37);
+}
+
+#[test]
+fn sdf_cache_overflow_test() {
+    let font = font::default_notosans();
+    let glyph_ids: Vec<_> = "ABCDEFGH".chars().map(|c| font.glyph(c).id()).collect();
+    let spread = sdf::Cache::DEFAULT_SPREAD;
+
+    // An atlas with room for roughly one glyph.
+    let mut cache = sdf::Cache::new([64, 64], sdf::Cache::DEFAULT_GLYPH_SCALE, spread);
+    for &id in &glyph_ids {
+        cache.queue_glyph(0, &font, id);
+    }
+    let mut uploads = 0;
+    let result = cache.cache_queued(|_, _| uploads += 1);
+    assert_eq!(result, Err(sdf::CacheWriteErr::NoRoomForWholeQueue));
+    assert_eq!(cache.len(), uploads);
+    assert!(uploads > 0 && uploads < glyph_ids.len());
+
+    // The remaining glyphs stay queued and are cached once there is room.
+    cache.resize([512, 512]);
+    assert_eq!(cache.len(), uploads);
+    assert_eq!(cache.cache_queued(|_, _| uploads += 1), Ok(()));
+    assert_eq!(cache.len(), glyph_ids.len());
+    assert_eq!(uploads, glyph_ids.len());
+
+    // Rects never overlap.
+    let mut rects = vec![];
+    let mut cache = sdf::Cache::new([512, 512], sdf::Cache::DEFAULT_GLYPH_SCALE, spread);
+    for &id in &glyph_ids {
+        cache.queue_glyph(0, &font, id);
+    }
+    cache.cache_queued(|rect, _| rects.push(rect)).unwrap();
+    for (i, a) in rects.iter().enumerate() {
+        for b in &rects[i + 1..] {
+            let overlap_x = a.min.x < b.max.x && b.min.x < a.max.x;
+            let overlap_y = a.min.y < b.max.y && b.min.y < a.max.y;
+            assert!(!(overlap_x && overlap_y));
+        }
+    }
+
+    // A glyph larger than the whole atlas is rejected.
+    let mut cache = sdf::Cache::new([8, 8], sdf::Cache::DEFAULT_GLYPH_SCALE, spread);
+    cache.queue_glyph(0, &font, glyph_ids[0]);
+    let result = cache.cache_queued(|_, _| ());
+    assert_eq!(result, Err(sdf::CacheWriteErr::GlyphTooLarge));
+    assert!(cache.is_empty());
+}
+
+// Lay out the given text as a row of glyphs at the given scale.
+fn positioned_glyphs(font: &text::Font, s: &str, scale: f32) -> Vec<text::PositionedGlyph> {
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let position = rt::point(i as f32 * scale, scale);
+            font.glyph(c)
+                .scaled(Scale::uniform(scale))
+                .positioned(position)
+        })
+        .collect()
+}
+
+fn glyph_cache(size: [u32; 2], max_size: [u32; 2]) -> GlyphCache {
+    GlyphCache::new(size, 0.1, 0.1, size, 48.0, 6, max_size)
+}
+
+fn cache_glyphs(
+    cache: &mut GlyphCache,
+    glyphs: &[text::PositionedGlyph],
+) -> Result<CachedBy, CacheWriteErr> {
+    for g in glyphs {
+        cache.queue_glyph(0, g.clone());
+    }
+    cache.cache_queued_glyphs()
+}
+
+#[test]
+fn glyph_cache_growth_test() {
+    let font = font::default_notosans();
+    let big = positioned_glyphs(&font, "ABCDEFGHIJ", 64.0);
+
+    // The first batch of a frame grows the cache immediately.
+    let mut cache = glyph_cache([32, 32], [512, 512]);
+    cache.begin_frame();
+    assert!(cache_glyphs(&mut cache, &big).is_ok());
+    assert!(cache.dimensions().0 > 32);
+    assert!(cache.stats.resizes > 0);
+    assert_eq!(cache.stats.failures, 0);
+
+    // Later batches must not invalidate the texture coordinates of the earlier ones, so growth
+    // is deferred until the start of the next frame.
+    let mut cache = glyph_cache([64, 64], [512, 512]);
+    let small = positioned_glyphs(&font, "A", 16.0);
+    cache.begin_frame();
+    assert!(cache_glyphs(&mut cache, &small).is_ok());
+    assert!(cache.rect_for(0, &small[0]).unwrap().is_some());
+    let pixels = cache.pixel_buffer.clone();
+    assert!(cache_glyphs(&mut cache, &big).is_err());
+    assert_eq!(cache.stats.failures, 1);
+    assert_eq!(cache.stats.resizes, 0);
+    assert_eq!(cache.dimensions(), (64, 64));
+    assert_eq!(cache.pixel_buffer, pixels);
+    cache.end_frame();
+    assert_eq!(cache.dimensions(), (64, 64));
+    cache.begin_frame();
+    assert_eq!(cache.dimensions(), (128, 128));
+    assert_eq!(cache.stats.resizes, 1);
+
+    // Growth doubles each axis, limited to the maximum size.
+    let mut cache = glyph_cache([64, 64], [128, 64]);
+    cache.begin_frame();
+    assert!(cache_glyphs(&mut cache, &big).is_err());
+    assert_eq!(cache.dimensions(), (128, 64));
+    assert_eq!(cache.stats.resizes, 1);
+    cache.end_frame();
+    cache.begin_frame();
+    assert_eq!(cache.dimensions(), (128, 64));
+    assert_eq!(cache.stats.resizes, 1);
+
+    // The same applies to the SDF cache.
+    let mut cache = glyph_cache([64, 64], [128, 128]);
+    cache.begin_frame();
+    cache.queue_sdf_glyph(0, &font, font.glyph('A').id());
+    assert!(cache.cache_queued_sdf().is_ok());
+    for c in "BCDEFGHIJ".chars() {
+        cache.queue_sdf_glyph(0, &font, font.glyph(c).id());
+    }
+    assert!(cache.cache_queued_sdf().is_err());
+    assert_eq!(cache.sdf_cache.dimensions(), (64, 64));
+    assert_eq!(cache.stats.sdf_failures, 1);
+    cache.end_frame();
+    cache.begin_frame();
+    assert_eq!(cache.sdf_cache.dimensions(), (128, 128));
+    assert_eq!(cache.stats.sdf_resizes, 1);
+    assert_eq!(cache.sdf_cache.len(), 1);
+}
+
+#[test]
+fn glyph_cache_stats_test() {
+    let font = font::default_notosans();
+    let a = positioned_glyphs(&font, "A", 40.0);
+    let b = positioned_glyphs(&font, "B", 40.0);
+
+    // Pressure is the padded area of the frame's unique glyphs relative to the cache area.
+    let mut cache = glyph_cache([256, 256], [256, 256]);
+    cache.begin_frame();
+    cache_glyphs(&mut cache, &a).unwrap();
+    cache_glyphs(&mut cache, &a).unwrap();
+    cache.end_frame();
+    let bb = a[0].pixel_bounding_box().unwrap();
+    let area = (bb.width() + 2) * (bb.height() + 2);
+    assert_eq!(cache.stats.pressure, area as f32 / (256.0 * 256.0));
+    assert_eq!(cache.stats.queued, 2);
+    assert_eq!(cache.stats.uploads, 1);
+
+    // A glyph that is evicted and then drawn again is counted as an eviction. Evicting a glyph
+    // that is still in use within the frame grows the cache before the next frame.
+    let mut cache = glyph_cache([32, 32], [64, 64]);
+    cache.begin_frame();
+    cache_glyphs(&mut cache, &a).unwrap();
+    cache_glyphs(&mut cache, &b).unwrap();
+    assert_eq!(cache.stats.evictions, 0);
+    cache_glyphs(&mut cache, &a).unwrap();
+    assert_eq!(cache.stats.evictions, 1);
+    assert_eq!(cache.stats.uploads, 3);
+    cache.end_frame();
+    cache.begin_frame();
+    assert_eq!(cache.dimensions(), (64, 64));
+
+    // Resetting clears all counts.
+    cache.reset_stats();
+    assert_eq!(cache.stats, GlyphCacheStats::default());
+}
+
+#[test]
+fn text_edit_caret_and_selection_test() {
+    let mut edit = TextEdit::new("hello world");
+    assert_eq!(edit.caret(), 11);
+    assert_eq!(edit.selection(), None);
+    edit.set_caret(100, false);
+    assert_eq!(edit.caret(), 11);
+
+    edit.select(0..5);
+    assert_eq!(edit.selected_text(), Some("hello"));
+    assert_eq!(edit.caret(), 5);
+    // Moving without extending collapses the selection to the side moved towards.
+    edit.move_left(false);
+    assert_eq!((edit.caret(), edit.selection()), (0, None));
+    edit.select(0..5);
+    edit.move_right(false);
+    assert_eq!((edit.caret(), edit.selection()), (5, None));
+    edit.move_right(true);
+    edit.move_right(true);
+    assert_eq!(edit.selection(), Some(5..7));
+    edit.move_left(true);
+    assert_eq!(edit.selected_text(), Some(" "));
+    edit.clear_selection();
+    assert_eq!((edit.caret(), edit.selection()), (6, None));
+
+    // Selections may extend backwards from the anchor.
+    edit.set_caret(3, true);
+    assert_eq!(edit.selection(), Some(3..6));
+    assert_eq!(edit.caret(), 3);
+
+    edit.select_all();
+    assert_eq!(edit.cut_selection(), Some("hello world".to_string()));
+    assert_eq!((edit.text(), edit.caret()), ("", 0));
+    assert_eq!(edit.cut_selection(), None);
+}
+
+#[test]
+fn text_edit_insert_and_delete_test() {
+    let mut edit = TextEdit::new("abc");
+    edit.select(1..2);
+    edit.insert_str("XY");
+    assert_eq!((edit.text(), edit.caret()), ("aXYc", 3));
+    edit.insert_char('é');
+    assert_eq!((edit.text(), edit.caret()), ("aXYéc", 5));
+
+    assert!(edit.backspace());
+    assert_eq!((edit.text(), edit.caret()), ("aXYc", 3));
+    assert!(edit.delete());
+    assert_eq!((edit.text(), edit.caret()), ("aXY", 3));
+    assert!(!edit.delete());
+    edit.set_caret(0, false);
+    assert!(!edit.backspace());
+
+    // The selection is deleted in place of a character.
+    edit.select(1..3);
+    assert!(edit.backspace());
+    assert_eq!((edit.text(), edit.caret()), ("a", 1));
+
+    edit.set_text("new");
+    assert_eq!(
+        (edit.text(), edit.caret(), edit.selection()),
+        ("new", 3, None)
+    );
+    assert_eq!(edit.into_string(), "new");
+}
+
+#[test]
+fn text_edit_utf8_test() {
+    // `a` is 1 byte, `é` is 2, `😀` is 4 and `b` is 1.
+    let mut edit = TextEdit::new("aé😀b");
+    assert_eq!(edit.caret(), 8);
+    edit.set_caret(2, false);
+    assert_eq!(edit.caret(), 1);
+    edit.set_caret(6, false);
+    assert_eq!(edit.caret(), 3);
+
+    edit.set_caret(8, false);
+    edit.move_left(false);
+    assert_eq!(edit.caret(), 7);
+    edit.move_left(false);
+    assert_eq!(edit.caret(), 3);
+    edit.move_right(true);
+    assert_eq!(edit.selected_text(), Some("😀"));
+
+    edit.set_caret(3, false);
+    assert!(edit.backspace());
+    assert_eq!((edit.text(), edit.caret()), ("a😀b", 1));
+    assert!(edit.delete());
+    assert_eq!((edit.text(), edit.caret()), ("ab", 1));
+}
+
+#[test]
+fn text_edit_word_and_line_navigation_test() {
+    let mut edit = TextEdit::new("one  two three");
+    edit.move_word_left(false);
+    assert_eq!(edit.caret(), 9);
+    edit.move_word_left(false);
+    assert_eq!(edit.caret(), 5);
+    edit.move_word_left(true);
+    assert_eq!(edit.selected_text(), Some("one  "));
+    edit.move_word_left(false);
+    assert_eq!(edit.caret(), 0);
+    edit.move_word_right(false);
+    assert_eq!(edit.caret(), 3);
+    edit.move_word_right(false);
+    assert_eq!(edit.caret(), 8);
+    edit.move_word_right(false);
+    assert_eq!(edit.caret(), 14);
+
+    assert!(edit.delete_word_backward());
+    assert_eq!((edit.text(), edit.caret()), ("one  two ", 9));
+    edit.set_caret(3, false);
+    assert!(edit.delete_word_forward());
+    assert_eq!((edit.text(), edit.caret()), ("one ", 3));
+
+    // Line navigation is relative to newlines.
+    let mut edit = TextEdit::new("ab\ncd");
+    edit.move_line_start(false);
+    assert_eq!(edit.caret(), 3);
+    edit.move_line_end(false);
+    assert_eq!(edit.caret(), 5);
+    edit.set_caret(1, false);
+    edit.move_line_end(true);
+    assert_eq!(edit.selected_text(), Some("b"));
+    edit.move_line_start(false);
+    assert_eq!(edit.caret(), 0);
+}
+
+#[test]
+fn text_edit_vertical_movement_test() {
+    let mut edit = TextEdit::new("abc\nabc\nabc");
+    edit.set_caret(1, false);
+    edit.move_down(false);
+    assert_eq!(edit.caret(), 5);
+    assert_eq!(edit.cursor_index(), cursor::Index { line: 1, char: 1 });
+    edit.move_down(true);
+    assert_eq!(edit.selection(), Some(5..9));
+    // Moving below the last line moves to the end of the text.
+    edit.move_down(false);
+    assert_eq!(edit.caret(), 11);
+    // The caret returns to the column it started from.
+    edit.move_up(false);
+    assert_eq!(edit.caret(), 5);
+    edit.move_up(false);
+    edit.move_up(false);
+    assert_eq!(edit.caret(), 0);
+}
+
+#[test]
+fn text_edit_keys_and_characters_test() {
+    use nannou::event::{Key, ModifiersState};
+    use nannou::winit::event::WindowEvent;
+
+    let mut edit = TextEdit::new("one two");
+    let none = ModifiersState::empty();
+    assert!(edit.key_pressed(Key::Home, ModifiersState::CTRL));
+    assert_eq!(edit.caret(), 0);
+    assert!(edit.key_pressed(Key::Right, ModifiersState::ALT));
+    assert_eq!(edit.caret(), 3);
+    assert!(edit.key_pressed(Key::End, ModifiersState::SHIFT));
+    assert_eq!(edit.selected_text(), Some(" two"));
+    assert!(edit.key_pressed(Key::A, ModifiersState::LOGO));
+    assert_eq!(edit.selection(), Some(0..7));
+    assert!(edit.key_pressed(Key::Back, ModifiersState::CTRL));
+    assert_eq!(edit.text(), "");
+    assert!(edit.key_pressed(Key::Return, none));
+    assert_eq!(edit.text(), "\n");
+    edit.set_multiline(false);
+    assert!(!edit.key_pressed(Key::Return, none));
+    assert!(!edit.key_pressed(Key::Escape, none));
+
+    let mut edit = TextEdit::default();
+    assert!(edit.received_character('a'));
+    assert!(!edit.received_character('\u{8}'));
+    let mods = |m: ModifiersState| WindowEvent::ModifiersChanged(m);
+    edit.raw_window_event(&mods(ModifiersState::CTRL));
+    assert!(!edit.received_character('b'));
+    edit.raw_window_event(&mods(ModifiersState::LOGO));
+    assert!(!edit.received_character('b'));
+    // Windows reports `AltGr` as `Ctrl` + `Alt`.
+    edit.raw_window_event(&mods(ModifiersState::CTRL | ModifiersState::ALT));
+    assert!(edit.raw_window_event(&WindowEvent::ReceivedCharacter('@')));
+    edit.raw_window_event(&mods(ModifiersState::SHIFT));
+    assert!(edit.received_character('€'));
+    assert_eq!(edit.text(), "a@€");
+}
+
+#[test]
+fn text_edit_preedit_test() {
+    let mut edit = TextEdit::new("ab");
+    edit.set_caret(1, false);
+    edit.set_preedit("かな", Some(3));
+    assert_eq!(edit.text(), "ab");
+    assert_eq!(edit.display_text(), "aかなb");
+    assert_eq!(edit.display_caret(), 4);
+    assert_eq!(edit.display_preedit(), Some(1..7));
+    // Keys are left to the IME while composing.
+    assert!(!edit.key_pressed(nannou::event::Key::Back, Default::default()));
+    assert_eq!(edit.text(), "ab");
+
+    // A received character replaces the composition.
+    assert!(edit.received_character('x'));
+    assert_eq!(
+        (edit.text(), edit.caret(), edit.preedit()),
+        ("axb", 2, None)
+    );
+
+    edit.set_preedit("日本", None);
+    assert_eq!(edit.display_caret(), 8);
+    edit.commit_preedit();
+    assert_eq!((edit.text(), edit.caret()), ("ax日本b", 8));
+
+    // Composition replaces the selection.
+    edit.select(0..1);
+    edit.set_preedit("z", None);
+    assert_eq!(edit.text(), "x日本b");
+    assert_eq!(edit.display_text(), "zx日本b");
+    edit.set_preedit("", None);
+    assert_eq!(edit.preedit(), None);
+    edit.set_preedit("y", None);
+    edit.clear_preedit();
+    assert_eq!(edit.display_text(), "x日本b");
+}
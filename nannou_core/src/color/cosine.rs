@@ -0,0 +1,100 @@
+//! Procedural palettes defined by a cosine wave per color channel.
+//!
+//! This technique and the provided presets are described by Inigo Quilez in his article
+//! ["palettes"](https://iquilezles.org/articles/palettes/).
+
+use crate::color::{Alpha, LinSrgba, Srgb};
+use crate::math::num_traits::Float;
+
+/// A palette producing a color for each position `t` where each sRGB channel is given by
+/// `a + b * cos(2π * (c * t + d))`.
+///
+/// Here `a` is the offset, `b` the amplitude, `c` the frequency and `d` the phase of each channel.
+/// Positions within `0.0..=1.0` span a single cycle of the palette when `c` is `1.0`.
+///
+/// ```
+/// use nannou_core::color::CosinePalette;
+///
+/// let colors: Vec<_> = (0..8).map(|i| CosinePalette::RAINBOW.get(i as f32 / 8.0)).collect();
+/// assert_eq!(colors.len(), 8);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CosinePalette {
+    /// The offset of each channel.
+    pub a: [f32; 3],
+    /// The amplitude of each channel.
+    pub b: [f32; 3],
+    /// The frequency of each channel.
+    pub c: [f32; 3],
+    /// The phase of each channel.
+    pub d: [f32; 3],
+}
+
+impl CosinePalette {
+    /// Cycles through the full spectrum of hues.
+    pub const RAINBOW: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 1.0],
+        [0.0, 0.33, 0.67],
+    );
+    /// Cream through brown to black then pale blue.
+    pub const TWILIGHT: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 1.0],
+        [0.0, 0.1, 0.2],
+    );
+    /// Teal through black and maroon to pink and white.
+    pub const TIDE: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 1.0],
+        [0.3, 0.2, 0.2],
+    );
+    /// Lime and yellow through brown to dark teal.
+    pub const LIME: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 0.5],
+        [0.8, 0.9, 0.3],
+    );
+    /// Peach and mauve through dark brown.
+    pub const EMBER: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 0.7, 0.4],
+        [0.0, 0.15, 0.2],
+    );
+    /// Vivid green, magenta and purple.
+    pub const NEON: Self = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [2.0, 1.0, 0.0],
+        [0.5, 0.2, 0.25],
+    );
+    /// Coral and red through pale green.
+    pub const CORAL: Self = CosinePalette::new(
+        [0.8, 0.5, 0.4],
+        [0.2, 0.4, 0.2],
+        [2.0, 1.0, 1.0],
+        [0.0, 0.25, 0.25],
+    );
+
+    /// A palette with the given offset, amplitude, frequency and phase for each sRGB channel.
+    pub const fn new(a: [f32; 3], b: [f32; 3], c: [f32; 3], d: [f32; 3]) -> Self {
+        CosinePalette { a, b, c, d }
+    }
+
+    /// The color at the given position.
+    ///
+    /// Channels are clamped to `0.0..=1.0` before conversion to linear sRGB.
+    pub fn get(&self, t: f32) -> LinSrgba {
+        let channel = |i: usize| {
+            let phase = 2.0 * core::f32::consts::PI * (self.c[i] * t + self.d[i]);
+            (self.a[i] + self.b[i] * Float::cos(phase)).clamp(0.0, 1.0)
+        };
+        let color = Srgb::new(channel(0), channel(1), channel(2)).into_linear();
+        Alpha { color, alpha: 1.0 }
+    }
+}
@@ -0,0 +1,601 @@
+//! Parsing colors from hex, named and CSS Color Level 4 functional notation strings.
+//!
+//! The following syntaxes are supported, ignoring ASCII case:
+//!
+//! - Hex notation: `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`.
+//! - The 148 CSS named colors, e.g. `rebeccapurple`, along with `transparent`.
+//! - The `rgb()`, `rgba()`, `hsl()` and `hsla()` functions, with either the legacy comma-separated
+//!   syntax, e.g. `rgb(255, 136, 0)`, or the space-separated syntax, e.g. `rgb(255 136 0 / 50%)`.
+//! - The `hwb()`, `lab()`, `lch()`, `oklab()` and `oklch()` functions.
+//! - The `color()` function within the `srgb`, `srgb-linear`, `display-p3`, `xyz`, `xyz-d50` and
+//!   `xyz-d65` color spaces.
+//!
+//! Hues may be given in `deg`, `grad`, `rad` or `turn` units, and any component may be `none`.
+//! Colors outside of the sRGB gamut are mapped into it as described by **Oklch::gamut_map**.
+//!
+//! ```
+//! use nannou_core::color::{self, CssColor, Srgba};
+//!
+//! let orange = color::parse("#ff8800").unwrap();
+//! assert_eq!(orange, color::parse("rgb(255 136 0)").unwrap());
+//! assert_eq!(orange.into_format::<u8, u8>(), Srgba::new(255, 136, 0, 255));
+//!
+//! let translucent: CssColor = "hsl(120deg 100% 25% / 0.5)".parse().unwrap();
+//! assert_eq!(translucent.to_string(), "#00800080");
+//! assert!(color::parse("oklch(70% 0.1 200)").is_ok());
+//! ```
+
+use crate::color::{Alpha, Component, IntoLinSrgba, LinSrgb, LinSrgba, Oklab, Oklch, Srgb, Srgba};
+use crate::math::num_traits::Float;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
+
+mod named;
+
+/// An sRGBA color that is parsed from, displayed as and (de)serialized as a CSS color string.
+///
+/// Colors are displayed and serialized in hex notation, omitting the alpha component if the color
+/// is opaque. Any syntax accepted by **parse** may be deserialized.
+///
+/// To (de)serialize a plain **Srgba** field as a CSS color string, annotate it with
+/// `#[serde(with = "nannou_core::color::css")]`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CssColor(pub Srgba);
+
+/// The error returned when a string cannot be parsed as a color.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The string is empty or contains only whitespace.
+    Empty,
+    /// A `#` is not followed by 3, 4, 6 or 8 hex digits.
+    InvalidHex,
+    /// The string is not a known named color.
+    UnknownName,
+    /// The name before the `(` is not a known color function.
+    UnknownFunction,
+    /// The color space given to the `color()` function is not supported.
+    UnknownColorSpace,
+    /// The arguments of a color function are missing, in excess or incorrectly separated.
+    InvalidSyntax,
+    /// A component is not a valid number, percentage or angle.
+    InvalidValue,
+}
+
+// A single component of a color function.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Value {
+    Number(f32),
+    // The percentage as a fraction, such that `50%` is `0.5`.
+    Percentage(f32),
+    None,
+}
+
+// The arguments of a color function.
+struct Args<'a> {
+    components: [&'a str; 4],
+    len: usize,
+    alpha: Option<&'a str>,
+    legacy: bool,
+}
+
+/// Parse a color from a hex, named or CSS Color Level 4 functional notation string.
+///
+/// See the [module documentation](./index.html) for the supported syntaxes.
+pub fn parse(s: &str) -> Result<Srgba, ParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(open) = s.find('(') {
+        let name = s[..open].trim_end();
+        let args = s[open + 1..]
+            .strip_suffix(')')
+            .ok_or(ParseError::InvalidSyntax)?;
+        return parse_function(name, args);
+    }
+    parse_name(s)
+}
+
+/// Serialize an **Srgba** as a CSS hex color string.
+///
+/// For use with `#[serde(with = "nannou_core::color::css")]`.
+#[cfg(feature = "serde")]
+pub fn serialize<S>(color: &Srgba, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serde::Serialize::serialize(&CssColor(*color), serializer)
+}
+
+/// Deserialize an **Srgba** from any CSS color string accepted by **parse**.
+///
+/// For use with `#[serde(with = "nannou_core::color::css")]`.
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Srgba, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let color: CssColor = serde::Deserialize::deserialize(deserializer)?;
+    Ok(color.0)
+}
+
+impl<'a> Args<'a> {
+    // Split the arguments of a color function into its components and alpha.
+    fn parse(args: &'a str) -> Result<Self, ParseError> {
+        let mut components = [""; 4];
+        let mut len = 0;
+        let mut push = |c: &'a str| {
+            if c.is_empty() || len == components.len() {
+                return Err(ParseError::InvalidSyntax);
+            }
+            components[len] = c;
+            len += 1;
+            Ok(())
+        };
+        if args.contains(',') {
+            for c in args.split(',') {
+                push(c.trim())?;
+            }
+            let alpha = match len {
+                3 => None,
+                4 => {
+                    len = 3;
+                    Some(components[3])
+                }
+                _ => return Err(ParseError::InvalidSyntax),
+            };
+            return Ok(Args {
+                components,
+                len,
+                alpha,
+                legacy: true,
+            });
+        }
+        let (args, alpha) = match args.split_once('/') {
+            None => (args, None),
+            Some((args, alpha)) => {
+                let alpha = alpha.trim();
+                if alpha.is_empty() || alpha.contains(char::is_whitespace) {
+                    return Err(ParseError::InvalidSyntax);
+                }
+                (args, Some(alpha))
+            }
+        };
+        for c in args.split_whitespace() {
+            push(c)?;
+        }
+        Ok(Args {
+            components,
+            len,
+            alpha,
+            legacy: false,
+        })
+    }
+
+    // The three color components, as required by every color function.
+    fn three(&self) -> Result<[&'a str; 3], ParseError> {
+        match self.len {
+            3 => Ok([self.components[0], self.components[1], self.components[2]]),
+            _ => Err(ParseError::InvalidSyntax),
+        }
+    }
+
+    // The alpha component, clamped to `0.0..=1.0`.
+    fn alpha(&self) -> Result<f32, ParseError> {
+        match self.alpha {
+            None => Ok(1.0),
+            Some(alpha) => Ok(component(alpha, 1.0)?.clamp(0.0, 1.0)),
+        }
+    }
+}
+
+impl CssColor {
+    /// Parse a color from a hex, named or CSS Color Level 4 functional notation string.
+    ///
+    /// See **parse**.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        parse(s).map(CssColor)
+    }
+}
+
+impl Deref for CssColor {
+    type Target = Srgba;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CssColor {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Srgba> for CssColor {
+    fn from(color: Srgba) -> Self {
+        CssColor(color)
+    }
+}
+
+impl From<CssColor> for Srgba {
+    fn from(color: CssColor) -> Self {
+        color.0
+    }
+}
+
+impl FromStr for CssColor {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CssColor::parse(s)
+    }
+}
+
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c: Srgba<u8> = self.0.into_format();
+        write!(f, "#{:02x}{:02x}{:02x}", c.red, c.green, c.blue)?;
+        if c.alpha < u8::MAX {
+            write!(f, "{:02x}", c.alpha)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S> IntoLinSrgba<S> for CssColor
+where
+    S: Component + Float,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        self.0.into_lin_srgba()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CssColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CssColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = CssColor;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a CSS color string")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                CssColor::parse(s).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            ParseError::Empty => "the color string is empty",
+            ParseError::InvalidHex => "expected 3, 4, 6 or 8 hex digits after `#`",
+            ParseError::UnknownName => "unknown named color",
+            ParseError::UnknownFunction => "unknown color function",
+            ParseError::UnknownColorSpace => "unsupported color space",
+            ParseError::InvalidSyntax => "invalid color function arguments",
+            ParseError::InvalidValue => "invalid number, percentage or angle",
+        };
+        f.write_str(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+fn parse_hex(hex: &str) -> Result<Srgba, ParseError> {
+    let mut digits = [0u8; 8];
+    if hex.len() > digits.len() {
+        return Err(ParseError::InvalidHex);
+    }
+    for (d, c) in digits.iter_mut().zip(hex.chars()) {
+        *d = c.to_digit(16).ok_or(ParseError::InvalidHex)? as u8;
+    }
+    let [r, g, b, a] = match hex.len() {
+        3 | 4 => {
+            let a = if hex.len() == 4 { digits[3] } else { 0xf };
+            [digits[0], digits[1], digits[2], a].map(|d| d * 0x11)
+        }
+        6 | 8 => {
+            let a = if hex.len() == 8 {
+                [digits[6], digits[7]]
+            } else {
+                [0xf, 0xf]
+            };
+            [
+                digits[0] << 4 | digits[1],
+                digits[2] << 4 | digits[3],
+                digits[4] << 4 | digits[5],
+                a[0] << 4 | a[1],
+            ]
+        }
+        _ => return Err(ParseError::InvalidHex),
+    };
+    Ok(Srgba::<u8>::new(r, g, b, a).into_format())
+}
+
+fn parse_name(name: &str) -> Result<Srgba, ParseError> {
+    if name.eq_ignore_ascii_case("transparent") {
+        return Ok(Srgba::new(0.0, 0.0, 0.0, 0.0));
+    }
+    let lowercase = name.bytes().map(|b| b.to_ascii_lowercase());
+    let i = named::NAMED
+        .binary_search_by(|(n, _)| n.bytes().cmp(lowercase.clone()))
+        .map_err(|_| ParseError::UnknownName)?;
+    let color: Srgb = named::NAMED[i].1.into_format();
+    Ok(Alpha { color, alpha: 1.0 })
+}
+
+fn parse_function(name: &str, args: &str) -> Result<Srgba, ParseError> {
+    let args = Args::parse(args)?;
+    let alpha = args.alpha()?;
+    let is = |function: &str| name.eq_ignore_ascii_case(function);
+    let legacy_allowed = is("rgb") || is("rgba") || is("hsl") || is("hsla");
+    if args.legacy && !legacy_allowed {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    // Functions within the sRGB gamut.
+    if is("rgb") || is("rgba") {
+        let [r, g, b] = args.three()?;
+        let channel = |c| match value(c)? {
+            Value::Number(n) => Ok(n / 255.0),
+            Value::Percentage(p) => Ok(p),
+            Value::None => Ok(0.0),
+        };
+        let color = Srgb::new(channel(r)?, channel(g)?, channel(b)?);
+        return Ok(clip_srgba(color, alpha));
+    } else if is("hsl") || is("hsla") {
+        let [h, s, l] = args.three()?;
+        let s = (component(s, 100.0)? / 100.0).clamp(0.0, 1.0);
+        let l = (component(l, 100.0)? / 100.0).clamp(0.0, 1.0);
+        return Ok(clip_srgba(hsl_to_srgb(hue(h)?, s, l), alpha));
+    } else if is("hwb") {
+        let [h, w, b] = args.three()?;
+        let w = (component(w, 100.0)? / 100.0).clamp(0.0, 1.0);
+        let b = (component(b, 100.0)? / 100.0).clamp(0.0, 1.0);
+        return Ok(clip_srgba(hwb_to_srgb(hue(h)?, w, b), alpha));
+    }
+
+    // Functions that may exceed the sRGB gamut.
+    let color = if is("lab") {
+        let [l, a, b] = args.three()?;
+        let l = component(l, 100.0)?.max(0.0);
+        lab_to_lin_srgb(l, component(a, 1.25)?, component(b, 1.25)?)
+    } else if is("lch") {
+        let [l, c, h] = args.three()?;
+        let l = component(l, 100.0)?.max(0.0);
+        let c = component(c, 1.5)?.max(0.0);
+        let h = hue(h)?.to_radians();
+        lab_to_lin_srgb(l, c * Float::cos(h), c * Float::sin(h))
+    } else if is("oklab") {
+        let [l, a, b] = args.three()?;
+        let l = component(l, 1.0)?.clamp(0.0, 1.0);
+        Oklab::new(l, component(a, 0.4)?, component(b, 0.4)?).into()
+    } else if is("oklch") {
+        let [l, c, h] = args.three()?;
+        let l = component(l, 1.0)?.clamp(0.0, 1.0);
+        let c = component(c, 0.4)?.max(0.0);
+        Oklch::new(l, c, hue(h)?).into()
+    } else if is("color") {
+        if args.len != 4 {
+            return Err(ParseError::InvalidSyntax);
+        }
+        let space = args.components[0];
+        let c = [
+            component(args.components[1], 1.0)?,
+            component(args.components[2], 1.0)?,
+            component(args.components[3], 1.0)?,
+        ];
+        color_to_lin_srgb(space, c)?
+    } else {
+        return Err(ParseError::UnknownFunction);
+    };
+    Ok(map_lin_srgba(color, alpha))
+}
+
+// Parse a single component of a color function.
+fn value(s: &str) -> Result<Value, ParseError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(Value::None);
+    }
+    match s.strip_suffix('%') {
+        Some(p) => number(p).map(|p| Value::Percentage(p / 100.0)),
+        None => number(s).map(Value::Number),
+    }
+}
+
+// Parse a component, scaling percentages such that `100%` is equal to `percent_scale`.
+fn component(s: &str, percent_scale: f32) -> Result<f32, ParseError> {
+    match value(s)? {
+        Value::Number(n) => Ok(n),
+        Value::Percentage(p) => Ok(p * percent_scale),
+        Value::None => Ok(0.0),
+    }
+}
+
+// Parse a hue in degrees from a number or an angle in `deg`, `grad`, `rad` or `turn` units.
+fn hue(s: &str) -> Result<f32, ParseError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+    const UNITS: [(&str, f32); 4] = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+    for &(unit, degrees) in UNITS.iter() {
+        if s.len() > unit.len() && s.is_char_boundary(s.len() - unit.len()) {
+            let (n, suffix) = s.split_at(s.len() - unit.len());
+            if suffix.eq_ignore_ascii_case(unit) {
+                return number(n).map(|n| n * degrees);
+            }
+        }
+    }
+    number(s)
+}
+
+fn number(s: &str) -> Result<f32, ParseError> {
+    match s.parse::<f32>() {
+        Ok(n) if n.is_finite() => Ok(n),
+        _ => Err(ParseError::InvalidValue),
+    }
+}
+
+// Clip a color within the sRGB gamut to `0.0..=1.0`.
+fn clip_srgba(color: Srgb, alpha: f32) -> Srgba {
+    let color = Srgb::new(
+        color.red.clamp(0.0, 1.0),
+        color.green.clamp(0.0, 1.0),
+        color.blue.clamp(0.0, 1.0),
+    );
+    Alpha { color, alpha }
+}
+
+// Map a linear color into the sRGB gamut, preserving its lightness and hue.
+fn map_lin_srgba(color: LinSrgb, alpha: f32) -> Srgba {
+    let in_gamut = |c: f32| (0.0..=1.0).contains(&c);
+    let color = if in_gamut(color.red) && in_gamut(color.green) && in_gamut(color.blue) {
+        color
+    } else {
+        let mapped: LinSrgba = Oklch::from(color).into_lin_srgba();
+        mapped.color
+    };
+    Alpha {
+        color: Srgb::from_linear(color),
+        alpha,
+    }
+}
+
+// The CSS Color Level 4 conversion from HSL to gamma-encoded sRGB.
+fn hsl_to_srgb(hue: f32, saturation: f32, lightness: f32) -> Srgb {
+    let a = saturation * lightness.min(1.0 - lightness);
+    let f = |n: f32| {
+        let mut k = (n + hue / 30.0) % 12.0;
+        if k < 0.0 {
+            k += 12.0;
+        }
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    Srgb::new(f(0.0), f(8.0), f(4.0))
+}
+
+// The CSS Color Level 4 conversion from HWB to gamma-encoded sRGB.
+fn hwb_to_srgb(hue: f32, whiteness: f32, blackness: f32) -> Srgb {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return Srgb::new(gray, gray, gray);
+    }
+    let rgb = hsl_to_srgb(hue, 1.0, 0.5);
+    let f = |c: f32| c * (1.0 - whiteness - blackness) + whiteness;
+    Srgb::new(f(rgb.red), f(rgb.green), f(rgb.blue))
+}
+
+// Convert from CIE L*a*b* relative to the D50 white point, as used by CSS, to linear sRGB.
+fn lab_to_lin_srgb(l: f32, a: f32, b: f32) -> LinSrgb {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const WHITE_D50: [f32; 3] = [0.964_295_7, 1.0, 0.825_104_6];
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let f_inv = |f: f32| {
+        let f3 = f * f * f;
+        if f3 > EPSILON {
+            f3
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let y = if l > KAPPA * EPSILON {
+        fy * fy * fy
+    } else {
+        l / KAPPA
+    };
+    let xyz = [
+        f_inv(fx) * WHITE_D50[0],
+        y * WHITE_D50[1],
+        f_inv(fz) * WHITE_D50[2],
+    ];
+    xyz_d65_to_lin_srgb(mul(&D50_TO_D65, xyz))
+}
+
+// Convert from one of the color spaces supported by the `color()` function to linear sRGB.
+fn color_to_lin_srgb(space: &str, c: [f32; 3]) -> Result<LinSrgb, ParseError> {
+    let is = |s: &str| space.eq_ignore_ascii_case(s);
+    let color = if is("srgb") {
+        Srgb::new(c[0], c[1], c[2]).into_linear()
+    } else if is("srgb-linear") {
+        LinSrgb::new(c[0], c[1], c[2])
+    } else if is("display-p3") {
+        // Display P3 shares the sRGB transfer function.
+        let p3 = Srgb::new(c[0], c[1], c[2]).into_linear();
+        let rgb = mul(&P3_TO_SRGB, [p3.red, p3.green, p3.blue]);
+        LinSrgb::new(rgb[0], rgb[1], rgb[2])
+    } else if is("xyz") || is("xyz-d65") {
+        xyz_d65_to_lin_srgb(c)
+    } else if is("xyz-d50") {
+        xyz_d65_to_lin_srgb(mul(&D50_TO_D65, c))
+    } else {
+        return Err(ParseError::UnknownColorSpace);
+    };
+    Ok(color)
+}
+
+// The Bradford chromatic adaptation from the D50 to the D65 white point.
+const D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_473_4, -0.023_098_455, 0.063_259_24],
+    [-0.028_369_71, 1.009_995_4, 0.021_041_44],
+    [0.012_314_015, -0.020_507_65, 1.330_365_9],
+];
+
+// From CIE XYZ relative to the D65 white point to linear sRGB.
+const XYZ_D65_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+// From linear Display P3 to linear sRGB.
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_2, -0.224_940_18, 0.0],
+    [-0.042_056_955, 1.042_057, 0.0],
+    [-0.019_637_555, -0.078_636_05, 1.098_273_6],
+];
+
+fn xyz_d65_to_lin_srgb(xyz: [f32; 3]) -> LinSrgb {
+    let [r, g, b] = mul(&XYZ_D65_TO_SRGB, xyz);
+    LinSrgb::new(r, g, b)
+}
+
+fn mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    let row = |r: [f32; 3]| r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+    [row(m[0]), row(m[1]), row(m[2])]
+}
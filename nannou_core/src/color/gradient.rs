@@ -0,0 +1,296 @@
+//! Multi-stop color gradients interpolated within a chosen color space.
+
+use crate::color::{encoding, Alpha, IntoColor, IntoLinSrgba, LinSrgb, LinSrgba, Srgb};
+use crate::color::{Hsl, Lab, LabHue, Lch, Oklab, Oklch, RgbHue};
+use alloc::vec::Vec;
+
+/// A gradient through any number of color stops.
+///
+/// Each stop is a color at a position, typically within `0.0..=1.0`. Colors between stops are
+/// interpolated within the gradient's **Interpolation** space, while positions before the first
+/// stop or after the last produce the color of the nearest stop.
+///
+/// ```
+/// use nannou_core::color::{Gradient, Interpolation, BLUE, RED, WHITE};
+///
+/// let gradient = Gradient::new(vec![RED, WHITE, BLUE]).interpolation(Interpolation::Lch);
+/// let colors: Vec<_> = gradient.take(5).collect();
+/// assert_eq!(colors.len(), 5);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, LinSrgba)>,
+    interpolation: Interpolation,
+}
+
+/// The color space within which the colors of a **Gradient** are interpolated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Linear sRGB, producing physically accurate blends of light.
+    #[default]
+    LinearRgb,
+    /// Gamma-encoded sRGB, matching the gradients of most image editors and browsers.
+    Srgb,
+    /// HSL, interpolating the hue along the shortest path around the color wheel.
+    Hsl,
+    /// CIE L\*a\*b\*, producing perceptually even changes in lightness.
+    ///
+    /// Blends that fall outside of the sRGB gamut are clipped to it.
+    Lab,
+    /// CIE L\*C\*h°, interpolating the hue along the shortest path while preserving chroma.
+    ///
+    /// Blends that fall outside of the sRGB gamut are clipped to it.
+    Lch,
+    /// Oklab, producing perceptually even changes in lightness with fewer hue shifts than **Lab**.
+    ///
+    /// Blends that fall outside of the sRGB gamut are mapped to it as described by
+    /// **Oklch::gamut_map**.
+    Oklab,
+    /// Oklch, interpolating the hue along the shortest path while preserving chroma.
+    ///
+    /// Blends that fall outside of the sRGB gamut are mapped to it as described by
+    /// **Oklch::gamut_map**.
+    Oklch,
+}
+
+/// An iterator yielding evenly spaced colors from a **Gradient**.
+#[derive(Clone, Debug)]
+pub struct Take<'a> {
+    gradient: &'a Gradient,
+    range: core::ops::Range<usize>,
+    len: usize,
+}
+
+// Colors with a saturation or chroma below this threshold are considered to have no hue.
+const ACHROMATIC_EPSILON: f32 = 1e-4;
+
+impl Gradient {
+    /// A gradient through the given colors, evenly spaced from `0.0` to `1.0`.
+    ///
+    /// **Panics** if no colors are given.
+    pub fn new<I>(colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<f32>,
+    {
+        let colors: Vec<LinSrgba> = colors.into_iter().map(|c| c.into_lin_srgba()).collect();
+        assert!(!colors.is_empty(), "a gradient requires at least one color");
+        let last = (colors.len() - 1).max(1) as f32;
+        let stops = colors
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (i as f32 / last, c))
+            .collect();
+        Gradient {
+            stops,
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    /// A gradient through the given `(position, color)` stops.
+    ///
+    /// Stops may be given in any order. Stops at the same position produce a hard edge.
+    ///
+    /// **Panics** if no stops are given.
+    pub fn from_stops<I, C>(stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: IntoLinSrgba<f32>,
+    {
+        let mut stops: Vec<(f32, LinSrgba)> = stops
+            .into_iter()
+            .map(|(p, c)| (p, c.into_lin_srgba()))
+            .collect();
+        assert!(!stops.is_empty(), "a gradient requires at least one stop");
+        // A stable sort preserves the order of stops at the same position.
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+        Gradient {
+            stops,
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    /// Specify the color space within which colors are interpolated.
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Add a stop with the given color at the given position.
+    ///
+    /// A stop added at the same position as an existing stop is placed after it.
+    pub fn stop<C>(mut self, position: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        let i = self.stops.partition_point(|s| s.0 <= position);
+        self.stops.insert(i, (position, color.into_lin_srgba()));
+        self
+    }
+
+    /// The `(position, color)` of each stop, ordered by position.
+    pub fn stops(&self) -> &[(f32, LinSrgba)] {
+        &self.stops
+    }
+
+    /// The range of positions between the first and last stops.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.stops[0].0, self.stops[self.stops.len() - 1].0)
+    }
+
+    /// The color at the given position.
+    pub fn get(&self, position: f32) -> LinSrgba {
+        let i = self.stops.partition_point(|s| s.0 <= position);
+        if i == 0 {
+            return self.stops[0].1;
+        } else if i == self.stops.len() {
+            return self.stops[i - 1].1;
+        }
+        let (start, a) = self.stops[i - 1];
+        let (end, b) = self.stops[i];
+        let t = (position - start) / (end - start);
+        self.interpolation.mix(a, b, t)
+    }
+
+    /// An iterator yielding `n` evenly spaced colors from the start of the gradient to the end.
+    pub fn take(&self, n: usize) -> Take<'_> {
+        Take {
+            gradient: self,
+            range: 0..n,
+            len: n,
+        }
+    }
+}
+
+impl Interpolation {
+    /// Interpolate between the colors `a` and `b` within this color space, where a `t` of `0.0`
+    /// produces `a` and a `t` of `1.0` produces `b`.
+    pub fn mix<A, B>(&self, a: A, b: B, t: f32) -> LinSrgba
+    where
+        A: IntoLinSrgba<f32>,
+        B: IntoLinSrgba<f32>,
+    {
+        let (a, b) = (a.into_lin_srgba(), b.into_lin_srgba());
+        let alpha = lerp(a.alpha, b.alpha, t);
+        let (a, b) = (a.color, b.color);
+        let color: LinSrgb = match *self {
+            Interpolation::LinearRgb => LinSrgb::new(
+                lerp(a.red, b.red, t),
+                lerp(a.green, b.green, t),
+                lerp(a.blue, b.blue, t),
+            ),
+            Interpolation::Srgb => {
+                let (a, b) = (Srgb::from_linear(a), Srgb::from_linear(b));
+                Srgb::new(
+                    lerp(a.red, b.red, t),
+                    lerp(a.green, b.green, t),
+                    lerp(a.blue, b.blue, t),
+                )
+                .into_linear()
+            }
+            Interpolation::Hsl => {
+                let a: Hsl = a.into_hsl::<encoding::Srgb>();
+                let b: Hsl = b.into_hsl::<encoding::Srgb>();
+                let (ha, hb) = powerless_hues(
+                    a.hue.to_degrees(),
+                    a.saturation,
+                    b.hue.to_degrees(),
+                    b.saturation,
+                );
+                let hsl = Hsl::new(
+                    RgbHue::from_degrees(lerp_hue(ha, hb, t)),
+                    lerp(a.saturation, b.saturation, t),
+                    lerp(a.lightness, b.lightness, t),
+                );
+                hsl.into_rgb::<encoding::Srgb>()
+            }
+            Interpolation::Lab => {
+                let (a, b): (Lab, Lab) = (a.into_lab(), b.into_lab());
+                let lab = Lab::new(lerp(a.l, b.l, t), lerp(a.a, b.a, t), lerp(a.b, b.b, t));
+                clip(lab.into_rgb::<encoding::Srgb>())
+            }
+            Interpolation::Lch => {
+                let (a, b): (Lch, Lch) = (a.into_lch(), b.into_lch());
+                let (ha, hb) =
+                    powerless_hues(a.hue.to_degrees(), a.chroma, b.hue.to_degrees(), b.chroma);
+                let lch = Lch::new(
+                    lerp(a.l, b.l, t),
+                    lerp(a.chroma, b.chroma, t),
+                    LabHue::from_degrees(lerp_hue(ha, hb, t)),
+                );
+                clip(lch.into_rgb::<encoding::Srgb>())
+            }
+            Interpolation::Oklab => {
+                let (a, b) = (Oklab::from(a), Oklab::from(b));
+                let lab = Oklab::new(lerp(a.l, b.l, t), lerp(a.a, b.a, t), lerp(a.b, b.b, t));
+                IntoLinSrgba::<f32>::into_lin_srgba(lab).color
+            }
+            Interpolation::Oklch => {
+                let (a, b) = (Oklch::from(a), Oklch::from(b));
+                let (ha, hb) = powerless_hues(a.hue, a.chroma, b.hue, b.chroma);
+                let lch = Oklch::new(
+                    lerp(a.l, b.l, t),
+                    lerp(a.chroma, b.chroma, t),
+                    lerp_hue(ha, hb, t),
+                );
+                IntoLinSrgba::<f32>::into_lin_srgba(lch).color
+            }
+        };
+        Alpha { color, alpha }
+    }
+}
+
+impl<'a> Iterator for Take<'a> {
+    type Item = LinSrgba;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| {
+            let (start, end) = self.gradient.domain();
+            let t = if self.len > 1 {
+                i as f32 / (self.len - 1) as f32
+            } else {
+                0.0
+            };
+            self.gradient.get(start + (end - start) * t)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Take<'a> {}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+// Clip each channel of the color to the sRGB gamut.
+fn clip(color: LinSrgb) -> LinSrgb {
+    LinSrgb::new(
+        color.red.clamp(0.0, 1.0),
+        color.green.clamp(0.0, 1.0),
+        color.blue.clamp(0.0, 1.0),
+    )
+}
+
+// Interpolate between two hues in degrees along the shortest path around the color wheel.
+fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
+    let mut delta = (b - a) % 360.0;
+    if delta > 180.0 {
+        delta -= 360.0;
+    } else if delta < -180.0 {
+        delta += 360.0;
+    }
+    a + delta * t
+}
+
+// The hue of a color without saturation or chroma is meaningless, so such a color adopts the hue
+// of the color it is interpolated with to avoid sweeping through unrelated hues.
+fn powerless_hues(a: f32, a_chroma: f32, b: f32, b_chroma: f32) -> (f32, f32) {
+    match (a_chroma < ACHROMATIC_EPSILON, b_chroma < ACHROMATIC_EPSILON) {
+        (true, false) => (b, b),
+        (false, true) => (a, a),
+        _ => (a, b),
+    }
+}
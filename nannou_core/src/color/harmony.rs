@@ -0,0 +1,83 @@
+//! Palettes of colors in harmony with a base color, produced by rotating its hue.
+//!
+//! Hues are rotated within HSL, preserving the saturation, lightness and alpha of the base color.
+
+use crate::color::{encoding, Alpha, Hsl, IntoColor, IntoLinSrgba, LinSrgba};
+
+/// The base color with its hue rotated by the given number of degrees.
+pub fn rotate_hue<C>(color: C, degrees: f32) -> LinSrgba
+where
+    C: IntoLinSrgba<f32>,
+{
+    let Alpha { color, alpha } = color.into_lin_srgba();
+    let hsl: Hsl = color.into_hsl::<encoding::Srgb>();
+    let hsl = Hsl::new(hsl.hue + degrees, hsl.saturation, hsl.lightness);
+    Alpha {
+        color: hsl.into_rgb::<encoding::Srgb>(),
+        alpha,
+    }
+}
+
+/// The base color along with the color opposite it on the color wheel.
+pub fn complementary<C>(color: C) -> [LinSrgba; 2]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [0.0, 180.0])
+}
+
+/// The base color along with the two colors either side of its complement.
+pub fn split_complementary<C>(color: C) -> [LinSrgba; 3]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [0.0, 150.0, 210.0])
+}
+
+/// The base color between its two neighbours 30 degrees either side on the color wheel.
+pub fn analogous<C>(color: C) -> [LinSrgba; 3]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [-30.0, 0.0, 30.0])
+}
+
+/// The base color along with two others evenly spaced around the color wheel.
+pub fn triadic<C>(color: C) -> [LinSrgba; 3]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [0.0, 120.0, 240.0])
+}
+
+/// Two pairs of complementary colors forming a rectangle on the color wheel, starting with the
+/// base color.
+pub fn tetradic<C>(color: C) -> [LinSrgba; 4]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [0.0, 60.0, 180.0, 240.0])
+}
+
+/// The base color along with three others evenly spaced around the color wheel.
+pub fn square<C>(color: C) -> [LinSrgba; 4]
+where
+    C: IntoLinSrgba<f32>,
+{
+    rotations(color, [0.0, 90.0, 180.0, 270.0])
+}
+
+// The base color rotated by each of the given angles.
+fn rotations<C, const N: usize>(color: C, degrees: [f32; N]) -> [LinSrgba; N]
+where
+    C: IntoLinSrgba<f32>,
+{
+    let color = color.into_lin_srgba();
+    degrees.map(|d| {
+        if d == 0.0 {
+            color
+        } else {
+            rotate_hue(color, d)
+        }
+    })
+}
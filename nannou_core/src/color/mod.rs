@@ -4,9 +4,22 @@
 //! See the [**named**](./named/index.html) module for a set of provided color constants.
 
 pub mod conv;
+pub mod cosine;
+pub mod css;
+#[cfg(feature = "alloc")]
+pub mod gradient;
+pub mod harmony;
+pub mod oklab;
 
 pub use self::conv::IntoLinSrgba;
+pub use self::cosine::CosinePalette;
+pub use self::css::{parse, CssColor, ParseError};
+#[cfg(feature = "alloc")]
+pub use self::gradient::{Gradient, Interpolation};
 pub use self::named::*;
+pub use self::oklab::{Oklab, Oklaba, Oklch, Oklcha};
+#[doc(no_inline)]
+pub use palette;
 #[doc(inline)]
 pub use palette::*;
 
@@ -153,6 +166,38 @@ pub fn hsva(h: f32, s: f32, v: f32, a: f32) -> Hsva {
     Hsva::new(RgbHue::from_degrees(h * 360.0), s, v, a)
 }
 
+/// A short-hand constructor for `Oklab::new`.
+pub fn oklab(l: f32, a: f32, b: f32) -> Oklab {
+    Oklab::new(l, a, b)
+}
+
+/// A short-hand constructor for an `Oklab` color with an alpha component.
+pub fn oklaba(l: f32, a: f32, b: f32, alpha: f32) -> Oklaba {
+    Alpha {
+        color: oklab(l, a, b),
+        alpha,
+    }
+}
+
+/// A short-hand constructor for `Oklch::new(l, c, h * 360.0)`.
+///
+/// The given hue expects a value between `0.0` and `1.0` where `0.0` is 0 degress and `1.0` is
+/// 360 degrees (or 2 PI radians).
+pub fn oklch(l: f32, c: f32, h: f32) -> Oklch {
+    Oklch::new(l, c, h * 360.0)
+}
+
+/// A short-hand constructor for an `Oklch` color with an alpha component.
+///
+/// The given hue expects a value between `0.0` and `1.0` where `0.0` is 0 degress and `1.0` is
+/// 360 degrees (or 2 PI radians).
+pub fn oklcha(l: f32, c: f32, h: f32, alpha: f32) -> Oklcha {
+    Alpha {
+        color: oklch(l, c, h),
+        alpha,
+    }
+}
+
 /// A short-hand constructor for `Gray::new`.
 pub fn gray<T>(g: T) -> Gray<T>
 where
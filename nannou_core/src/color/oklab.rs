@@ -0,0 +1,269 @@
+//! The Oklab and Oklch perceptual color spaces.
+//!
+//! [Oklab](https://bottosson.github.io/posts/oklab/) predicts perceived lightness, chroma and hue
+//! more uniformly than HSL or CIE L\*a\*b\*, making it well suited to blending colors, generating
+//! gradients and adjusting lightness without shifting hue. **Oklch** is the cylindrical form of
+//! **Oklab**, describing the same colors by their lightness, chroma and hue.
+//!
+//! Not every Oklab color can be displayed in sRGB. Conversion via **IntoLinSrgba** maps colors
+//! outside of the sRGB gamut to the nearest displayable color of the same lightness and hue, while
+//! the `From` conversions to **LinSrgb** are exact and may produce channels outside of `0.0..=1.0`.
+//!
+//! ```
+//! use nannou_core::color::{IntoLinSrgba, LinSrgb, LinSrgba, Oklab, Oklch};
+//!
+//! let orange = Oklch::new(0.75, 0.12, 55.0);
+//! assert!(orange.is_in_gamut());
+//!
+//! let lighter = Oklch { l: 0.95, ..orange };
+//! assert!(!lighter.is_in_gamut());
+//! let rgba: LinSrgba = lighter.into_lin_srgba();
+//! assert!(rgba.red <= 1.0 && rgba.green <= 1.0 && rgba.blue <= 1.0);
+//!
+//! let white = Oklab::from(LinSrgb::new(1.0, 1.0, 1.0));
+//! assert!((white.l - 1.0).abs() < 1e-4);
+//! ```
+
+use crate::color::conv::IntoLinSrgba;
+use crate::color::{Alpha, Component, LinSrgb, LinSrgba, Srgb};
+use crate::math::num_traits::Float;
+
+/// A color within the Oklab color space.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// The perceived lightness, from `0.0` for black to `1.0` for white.
+    pub l: f32,
+    /// The position along the green (negative) to red (positive) axis.
+    pub a: f32,
+    /// The position along the blue (negative) to yellow (positive) axis.
+    pub b: f32,
+}
+
+/// A color within the Oklch color space, the cylindrical form of **Oklab**.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness, from `0.0` for black to `1.0` for white.
+    pub l: f32,
+    /// The colorfulness, from `0.0` for gray to approximately `0.37` for the most vivid sRGB
+    /// colors.
+    pub chroma: f32,
+    /// The hue in degrees.
+    pub hue: f32,
+}
+
+/// An **Oklab** color with an alpha component.
+pub type Oklaba = Alpha<Oklab, f32>;
+
+/// An **Oklch** color with an alpha component.
+pub type Oklcha = Alpha<Oklch, f32>;
+
+// The tolerance within which linear sRGB channels are considered within gamut.
+const GAMUT_EPSILON: f32 = 1e-4;
+
+// The color difference below which a clipped color is considered indistinguishable from the color
+// it was clipped from, as specified by CSS Color Level 4.
+const JUST_NOTICEABLE_DIFFERENCE: f32 = 0.02;
+
+// The precision with which the gamut mapping search locates the gamut boundary.
+const CHROMA_EPSILON: f32 = 1e-4;
+
+impl Oklab {
+    /// A color with the given lightness and `a` and `b` axis positions.
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Whether or not the color can be displayed in sRGB without clipping.
+    pub fn is_in_gamut(&self) -> bool {
+        in_gamut(LinSrgb::from(*self))
+    }
+
+    /// Map the color into the sRGB gamut, preserving its lightness and hue.
+    ///
+    /// See **Oklch::gamut_map** for details.
+    pub fn gamut_map(self) -> Self {
+        Oklch::from(self).gamut_map().into()
+    }
+}
+
+impl Oklch {
+    /// A color with the given lightness, chroma and hue in degrees.
+    pub const fn new(l: f32, chroma: f32, hue: f32) -> Self {
+        Oklch { l, chroma, hue }
+    }
+
+    /// Whether or not the color can be displayed in sRGB without clipping.
+    pub fn is_in_gamut(&self) -> bool {
+        Oklab::from(*self).is_in_gamut()
+    }
+
+    /// Map the color into the sRGB gamut, preserving its lightness and hue.
+    ///
+    /// Chroma is reduced until clipping the color to the gamut produces a visually
+    /// indistinguishable result, following the gamut mapping algorithm of CSS Color Level 4.
+    /// Colors lighter than white or darker than black are mapped to white and black respectively.
+    pub fn gamut_map(self) -> Self {
+        Oklch::from(Oklab::from(map_to_gamut(self)))
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let hue = lch.hue.to_radians();
+        Oklab {
+            l: lch.l,
+            a: lch.chroma * Float::cos(hue),
+            b: lch.chroma * Float::sin(hue),
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        let chroma = Float::sqrt(lab.a * lab.a + lab.b * lab.b);
+        let hue = Float::atan2(lab.b, lab.a).to_degrees();
+        Oklch {
+            l: lab.l,
+            chroma,
+            hue: if hue < 0.0 { hue + 360.0 } else { hue },
+        }
+    }
+}
+
+impl From<LinSrgb> for Oklab {
+    fn from(rgb: LinSrgb) -> Self {
+        let (r, g, b) = (rgb.red, rgb.green, rgb.blue);
+        let l = Float::cbrt(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = Float::cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = Float::cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+}
+
+impl From<Oklab> for LinSrgb {
+    fn from(lab: Oklab) -> Self {
+        let l = lab.l + 0.396_337_78 * lab.a + 0.215_803_76 * lab.b;
+        let m = lab.l - 0.105_561_346 * lab.a - 0.063_854_17 * lab.b;
+        let s = lab.l - 0.089_484_18 * lab.a - 1.291_485_5 * lab.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        LinSrgb::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+}
+
+impl From<LinSrgb> for Oklch {
+    fn from(rgb: LinSrgb) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklch> for LinSrgb {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+impl From<Srgb> for Oklab {
+    fn from(rgb: Srgb) -> Self {
+        rgb.into_linear().into()
+    }
+}
+
+impl From<Srgb> for Oklch {
+    fn from(rgb: Srgb) -> Self {
+        rgb.into_linear().into()
+    }
+}
+
+/// Colors outside of the sRGB gamut are mapped into it as described by **Oklch::gamut_map**.
+impl<S> IntoLinSrgba<S> for Oklab
+where
+    S: Component,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        Oklch::from(self).into_lin_srgba()
+    }
+}
+
+/// Colors outside of the sRGB gamut are mapped into it as described by **Oklch::gamut_map**.
+impl<S> IntoLinSrgba<S> for Oklch
+where
+    S: Component,
+{
+    fn into_lin_srgba(self) -> LinSrgba<S> {
+        let color = map_to_gamut(self).into_format();
+        let alpha = S::max_intensity();
+        Alpha { color, alpha }
+    }
+}
+
+// Whether or not each channel lies within `0.0..=1.0`, give or take `GAMUT_EPSILON`.
+fn in_gamut(rgb: LinSrgb) -> bool {
+    let range = -GAMUT_EPSILON..=1.0 + GAMUT_EPSILON;
+    range.contains(&rgb.red) && range.contains(&rgb.green) && range.contains(&rgb.blue)
+}
+
+// Clip each channel to `0.0..=1.0`.
+fn clip(rgb: LinSrgb) -> LinSrgb {
+    LinSrgb::new(
+        rgb.red.clamp(0.0, 1.0),
+        rgb.green.clamp(0.0, 1.0),
+        rgb.blue.clamp(0.0, 1.0),
+    )
+}
+
+// The Euclidean distance between two colors within Oklab.
+fn delta_e(a: Oklab, b: Oklab) -> f32 {
+    let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+    Float::sqrt(dl * dl + da * da + db * db)
+}
+
+// The CSS Color Level 4 gamut mapping algorithm, a binary search for the greatest chroma at which
+// the clipped color is indistinguishable from the unclipped color.
+fn map_to_gamut(color: Oklch) -> LinSrgb {
+    if color.l >= 1.0 {
+        return LinSrgb::new(1.0, 1.0, 1.0);
+    } else if color.l <= 0.0 {
+        return LinSrgb::new(0.0, 0.0, 0.0);
+    }
+    let rgb = LinSrgb::from(color);
+    if in_gamut(rgb) {
+        return clip(rgb);
+    }
+    let mut clipped = clip(rgb);
+    if delta_e(Oklab::from(clipped), color.into()) < JUST_NOTICEABLE_DIFFERENCE {
+        return clipped;
+    }
+    let (mut min, mut max) = (0.0, color.chroma);
+    let mut min_in_gamut = true;
+    while max - min > CHROMA_EPSILON {
+        let current = Oklch {
+            chroma: (min + max) * 0.5,
+            ..color
+        };
+        let rgb = LinSrgb::from(current);
+        if min_in_gamut && in_gamut(rgb) {
+            min = current.chroma;
+            continue;
+        }
+        clipped = clip(rgb);
+        let e = delta_e(Oklab::from(clipped), current.into());
+        if e < JUST_NOTICEABLE_DIFFERENCE {
+            if JUST_NOTICEABLE_DIFFERENCE - e < CHROMA_EPSILON {
+                break;
+            }
+            min_in_gamut = false;
+            min = current.chroma;
+        } else {
+            max = current.chroma;
+        }
+    }
+    clipped
+}
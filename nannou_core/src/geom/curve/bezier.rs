@@ -0,0 +1,225 @@
+//! Quadratic and cubic bézier curves.
+
+use super::{quadratic_roots, Curve, Point};
+
+/// A quadratic bézier curve described by its start point, control point and end point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QuadraticBezier<P> {
+    /// The start of the curve.
+    pub from: P,
+    /// The control point.
+    pub ctrl: P,
+    /// The end of the curve.
+    pub to: P,
+}
+
+/// A cubic bézier curve described by its start point, two control points and end point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CubicBezier<P> {
+    /// The start of the curve.
+    pub from: P,
+    /// The first control point.
+    pub ctrl1: P,
+    /// The second control point.
+    pub ctrl2: P,
+    /// The end of the curve.
+    pub to: P,
+}
+
+impl<P: Point> QuadraticBezier<P> {
+    /// Construct a quadratic bézier curve from its start point, control point and end point.
+    pub fn new(from: P, ctrl: P, to: P) -> Self {
+        QuadraticBezier { from, ctrl, to }
+    }
+
+    /// Split the curve at `t`, producing the curves before and after the split.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.from, self.ctrl, t);
+        let b = lerp(self.ctrl, self.to, t);
+        let mid = lerp(a, b, t);
+        (Self::new(self.from, a, mid), Self::new(mid, b, self.to))
+    }
+
+    /// The same curve travelling in the opposite direction.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.to, self.ctrl, self.from)
+    }
+
+    /// The cubic bézier curve describing the exact same curve.
+    pub fn to_cubic(&self) -> CubicBezier<P> {
+        let ctrl1 = self.from + (self.ctrl - self.from) * (2.0 / 3.0);
+        let ctrl2 = self.to + (self.ctrl - self.to) * (2.0 / 3.0);
+        CubicBezier::new(self.from, ctrl1, ctrl2, self.to)
+    }
+
+    // The corners of the bounds of the curve.
+    pub(crate) fn min_max(&self) -> (P, P) {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+        for axis in 0..P::DIMENSIONS {
+            let (p0, p1, p2) = (
+                self.from.axis(axis),
+                self.ctrl.axis(axis),
+                self.to.axis(axis),
+            );
+            let denom = p0 - 2.0 * p1 + p2;
+            if denom != 0.0 {
+                let t = (p0 - p1) / denom;
+                if t > 0.0 && t < 1.0 {
+                    let p = self.point(t);
+                    min = min.min(p);
+                    max = max.max(p);
+                }
+            }
+        }
+        (min, max)
+    }
+}
+
+impl<P: Point> CubicBezier<P> {
+    /// Construct a cubic bézier curve from its start point, two control points and end point.
+    pub fn new(from: P, ctrl1: P, ctrl2: P, to: P) -> Self {
+        CubicBezier {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+
+    /// Split the curve at `t`, producing the curves before and after the split.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.from, self.ctrl1, t);
+        let b = lerp(self.ctrl1, self.ctrl2, t);
+        let c = lerp(self.ctrl2, self.to, t);
+        let ab = lerp(a, b, t);
+        let bc = lerp(b, c, t);
+        let mid = lerp(ab, bc, t);
+        (
+            Self::new(self.from, a, ab, mid),
+            Self::new(mid, bc, c, self.to),
+        )
+    }
+
+    /// The same curve travelling in the opposite direction.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.to, self.ctrl2, self.ctrl1, self.from)
+    }
+
+    // The corners of the bounds of the curve.
+    pub(crate) fn min_max(&self) -> (P, P) {
+        let mut min = self.from.min(self.to);
+        let mut max = self.from.max(self.to);
+        for axis in 0..P::DIMENSIONS {
+            let (p0, p1, p2, p3) = (
+                self.from.axis(axis),
+                self.ctrl1.axis(axis),
+                self.ctrl2.axis(axis),
+                self.to.axis(axis),
+            );
+            // The roots of the derivative along the axis.
+            let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+            let b = 2.0 * (p0 - 2.0 * p1 + p2);
+            let c = p1 - p0;
+            for t in quadratic_roots(a, b, c).iter().flatten() {
+                let p = self.point(*t);
+                min = min.min(p);
+                max = max.max(p);
+            }
+        }
+        (min, max)
+    }
+}
+
+impl<P: Point> Curve for QuadraticBezier<P> {
+    type Point = P;
+
+    fn point(&self, t: f32) -> P {
+        let mt = 1.0 - t;
+        self.from * (mt * mt) + self.ctrl * (2.0 * mt * t) + self.to * (t * t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        ((self.ctrl - self.from) * (1.0 - t) + (self.to - self.ctrl) * t) * 2.0
+    }
+
+    fn bounding_box(&self) -> P::Bounds {
+        let (min, max) = self.min_max();
+        P::bounds(min, max)
+    }
+
+    fn start(&self) -> P {
+        self.from
+    }
+
+    fn end(&self) -> P {
+        self.to
+    }
+}
+
+impl<P: Point> Curve for CubicBezier<P> {
+    type Point = P;
+
+    fn point(&self, t: f32) -> P {
+        let mt = 1.0 - t;
+        self.from * (mt * mt * mt)
+            + self.ctrl1 * (3.0 * mt * mt * t)
+            + self.ctrl2 * (3.0 * mt * t * t)
+            + self.to * (t * t * t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let mt = 1.0 - t;
+        ((self.ctrl1 - self.from) * (mt * mt)
+            + (self.ctrl2 - self.ctrl1) * (2.0 * mt * t)
+            + (self.to - self.ctrl2) * (t * t))
+            * 3.0
+    }
+
+    fn bounding_box(&self) -> P::Bounds {
+        let (min, max) = self.min_max();
+        P::bounds(min, max)
+    }
+
+    fn start(&self) -> P {
+        self.from
+    }
+
+    fn end(&self) -> P {
+        self.to
+    }
+}
+
+impl<P> From<[P; 3]> for QuadraticBezier<P> {
+    fn from([from, ctrl, to]: [P; 3]) -> Self {
+        QuadraticBezier { from, ctrl, to }
+    }
+}
+
+impl<P> From<[P; 4]> for CubicBezier<P> {
+    fn from([from, ctrl1, ctrl2, to]: [P; 4]) -> Self {
+        CubicBezier {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+}
+
+impl<P> From<QuadraticBezier<P>> for [P; 3] {
+    fn from(q: QuadraticBezier<P>) -> Self {
+        [q.from, q.ctrl, q.to]
+    }
+}
+
+impl<P> From<CubicBezier<P>> for [P; 4] {
+    fn from(c: CubicBezier<P>) -> Self {
+        [c.from, c.ctrl1, c.ctrl2, c.to]
+    }
+}
+
+// Linear interpolation between `a` and `b`.
+fn lerp<P: Point>(a: P, b: P, t: f32) -> P {
+    a + (b - a) * t
+}
@@ -0,0 +1,357 @@
+//! Uniform and non-uniform B-splines of arbitrary degree.
+
+use super::{Curve, Point};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A B-spline curve described by its degree, control points and knot vector.
+///
+/// The curve is evaluated over the domain `knots[degree]..=knots[points.len()]`, which is mapped
+/// to the parameter range `0.0..=1.0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<P> {
+    degree: usize,
+    points: Vec<P>,
+    knots: Vec<f32>,
+}
+
+// The maximum supported degree, limiting the size of the buffer used by `de_boor`.
+const MAX_DEGREE: usize = 15;
+
+// The number of bisection steps used to locate the extrema of a span while bounding the curve.
+const EXTREMA_ITERATIONS: usize = 24;
+
+impl<P: Point> BSpline<P> {
+    /// The maximum supported degree.
+    pub const MAX_DEGREE: usize = MAX_DEGREE;
+
+    /// Construct a B-spline from its degree, control points and knot vector.
+    ///
+    /// Returns `None` if:
+    ///
+    /// - `degree` is zero or greater than `MAX_DEGREE`,
+    /// - there are not more control points than the degree,
+    /// - the number of knots is not `points.len() + degree + 1`,
+    /// - the knots are not finite and non-decreasing,
+    /// - the domain of the curve is empty or
+    /// - any knot within the domain is repeated more than `degree` times, breaking the curve.
+    pub fn new<I>(degree: usize, points: I, knots: Vec<f32>) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let points: Vec<P> = points.into_iter().collect();
+        let n = points.len();
+        if degree == 0 || degree > Self::MAX_DEGREE || n <= degree {
+            return None;
+        }
+        if knots.len() != n + degree + 1
+            || knots.iter().any(|k| !k.is_finite())
+            || knots.windows(2).any(|w| w[1] < w[0])
+        {
+            return None;
+        }
+        let (a, b) = (knots[degree], knots[n]);
+        if a >= b {
+            return None;
+        }
+        let interior = &knots[degree + 1..n];
+        let mut i = 0;
+        while i < interior.len() {
+            let multiplicity = interior[i..]
+                .iter()
+                .take_while(|&&k| k == interior[i])
+                .count();
+            if multiplicity > degree && interior[i] > a && interior[i] < b {
+                return None;
+            }
+            i += multiplicity;
+        }
+        Some(BSpline {
+            degree,
+            points,
+            knots,
+        })
+    }
+
+    /// A B-spline with evenly spaced knots.
+    ///
+    /// The curve does not generally pass through its first and last control points.
+    ///
+    /// Returns `None` if `degree` is zero or greater than `MAX_DEGREE`, or if there are not more
+    /// control points than the degree.
+    pub fn uniform<I>(degree: usize, points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let points: Vec<P> = points.into_iter().collect();
+        let knots = (0..points.len() + degree + 1).map(|i| i as f32).collect();
+        Self::new(degree, points, knots)
+    }
+
+    /// A B-spline with evenly spaced interior knots and end knots repeated `degree + 1` times.
+    ///
+    /// The curve starts at its first control point and ends at its last control point.
+    ///
+    /// Returns `None` if `degree` is zero or greater than `MAX_DEGREE`, or if there are not more
+    /// control points than the degree.
+    pub fn clamped<I>(degree: usize, points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let points: Vec<P> = points.into_iter().collect();
+        let last = points.len().saturating_sub(degree) as f32;
+        let knots = (0..points.len() + degree + 1)
+            .map(|i| (i.saturating_sub(degree) as f32).min(last))
+            .collect();
+        Self::new(degree, points, knots)
+    }
+
+    /// The degree of each polynomial piece of the curve.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The control points of the curve.
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    /// The knot vector of the curve.
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    /// The range of knot values over which the curve is evaluated.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+
+    /// Split the curve at `t`, producing the curves before and after the split.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let p = self.degree;
+        if t <= 0.0 {
+            return (self.degenerate(self.start()), self.clone());
+        } else if t >= 1.0 {
+            return (self.clone(), self.degenerate(self.end()));
+        }
+        let u = self.knot_at(t);
+        let mut curve = self.clone();
+        while curve.multiplicity(u) < p {
+            curve.insert_knot(u);
+        }
+        let first = curve
+            .knots
+            .iter()
+            .position(|&k| k == u)
+            .expect("no inserted knot");
+        let last = first + curve.multiplicity(u) - 1;
+        let mut knots = curve.knots[..first + p].to_vec();
+        knots.push(u);
+        let before = BSpline {
+            degree: p,
+            points: curve.points[..first].to_vec(),
+            knots,
+        };
+        let mut knots = Vec::with_capacity(curve.knots.len() + p + 1 - last);
+        knots.push(u);
+        knots.extend_from_slice(&curve.knots[last + 1 - p..]);
+        let after = BSpline {
+            degree: p,
+            points: curve.points[last - p..].to_vec(),
+            knots,
+        };
+        (before, after)
+    }
+
+    /// The control points of the bézier curves describing each polynomial piece of the curve.
+    ///
+    /// The first `degree + 1` points describe the first piece, and each following `degree` points
+    /// describe the next piece along with the last point of the previous piece. A curve of degree
+    /// `3` yields the points of a chain of cubic béziers.
+    pub fn bezier_points(&self) -> Vec<P> {
+        let p = self.degree;
+        let (a, b) = self.domain();
+        let mut curve = self.clone();
+        let mut distinct: Vec<f32> = self
+            .knots
+            .iter()
+            .cloned()
+            .filter(|&k| k >= a && k <= b)
+            .collect();
+        distinct.dedup();
+        for &u in &distinct {
+            while curve.multiplicity(u) < p {
+                curve.insert_knot(u);
+            }
+        }
+        let mut bezier = Vec::with_capacity((distinct.len() - 1) * p + 1);
+        for k in curve.spans() {
+            let start = if bezier.is_empty() { k - p } else { k - p + 1 };
+            bezier.extend_from_slice(&curve.points[start..=k]);
+        }
+        bezier
+    }
+
+    // A curve of the same degree with all control points at `point`.
+    fn degenerate(&self, point: P) -> Self {
+        Self::clamped(self.degree, vec![point; self.degree + 1]).expect("invalid degree")
+    }
+
+    // The knot value at the parameter `t`.
+    fn knot_at(&self, t: f32) -> f32 {
+        let (a, b) = self.domain();
+        (a + (b - a) * t).clamp(a, b)
+    }
+
+    // The number of times the knot `u` appears within the knot vector.
+    fn multiplicity(&self, u: f32) -> usize {
+        self.knots.iter().filter(|&&k| k == u).count()
+    }
+
+    // The indices `k` of the non-empty knot spans `knots[k]..knots[k + 1]` within the domain.
+    fn spans(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.degree..self.points.len()).filter(move |&k| self.knots[k] < self.knots[k + 1])
+    }
+
+    // The index of the non-empty knot span containing `u`.
+    fn span(&self, u: f32) -> usize {
+        let (p, n) = (self.degree, self.points.len());
+        if u >= self.knots[n] {
+            let mut k = n - 1;
+            while self.knots[k] == self.knots[k + 1] {
+                k -= 1;
+            }
+            k
+        } else {
+            p + self.knots[p + 1..n].iter().take_while(|&&k| k <= u).count()
+        }
+    }
+
+    // Insert the knot `u` once via Boehm's algorithm without changing the shape of the curve.
+    fn insert_knot(&mut self, u: f32) {
+        let (p, k) = (self.degree, self.span(u));
+        let knots = &self.knots;
+        let points = &self.points;
+        let mut inserted = Vec::with_capacity(points.len() + 1);
+        inserted.extend_from_slice(&points[..=k - p]);
+        for i in k - p + 1..=k {
+            let alpha = ratio(u - knots[i], knots[i + p] - knots[i]);
+            inserted.push(points[i - 1] * (1.0 - alpha) + points[i] * alpha);
+        }
+        inserted.extend_from_slice(&points[k..]);
+        self.points = inserted;
+        self.knots.insert(k + 1, u);
+    }
+
+    // The point on the curve at the knot value `u`.
+    fn point_at_knot(&self, u: f32) -> P {
+        let k = self.span(u);
+        de_boor(self.degree, &self.knots, k, u, |i| self.points[i])
+    }
+
+    // The derivative of the curve with respect to the knot value at `u`.
+    fn derivative_at_knot(&self, u: f32) -> P {
+        let (p, k) = (self.degree, self.span(u));
+        let knots = &self.knots;
+        let points = &self.points;
+        // The derivative is a B-spline of one degree lower over the inner knots.
+        de_boor(p - 1, &knots[1..], k - 1, u, |i| {
+            (points[i + 1] - points[i]) * ratio(p as f32, knots[i + p + 1] - knots[i + 1])
+        })
+    }
+}
+
+impl<P: Point> Curve for BSpline<P> {
+    type Point = P;
+
+    fn point(&self, t: f32) -> P {
+        self.point_at_knot(self.knot_at(t))
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let (a, b) = self.domain();
+        self.derivative_at_knot(self.knot_at(t)) * (b - a)
+    }
+
+    fn bounding_box(&self) -> P::Bounds {
+        let (a, b) = self.domain();
+        let start = self.point_at_knot(a);
+        let end = self.point_at_knot(b);
+        let (mut min, mut max) = (start.min(end), start.max(end));
+        // Include the start of each span in case of discontinuities in the derivative, then search
+        // each span for sign changes in the derivative along each axis.
+        let samples = 4 * self.degree;
+        for k in self.spans() {
+            let (u0, u1) = (self.knots[k], self.knots[k + 1]);
+            let p = self.point_at_knot(u0);
+            min = min.min(p);
+            max = max.max(p);
+            let at = |i: usize| u0 + (u1 - u0) * i as f32 / samples as f32;
+            for axis in 0..P::DIMENSIONS {
+                let slope = |u: f32| self.derivative_at_knot(u).axis(axis);
+                for i in 0..samples {
+                    let (mut lo, mut hi) = (at(i), at(i + 1));
+                    let (s_lo, s_hi) = (slope(lo), slope(hi));
+                    if s_lo * s_hi > 0.0 || (s_lo == 0.0 && s_hi == 0.0) {
+                        continue;
+                    }
+                    for _ in 0..EXTREMA_ITERATIONS {
+                        let mid = (lo + hi) * 0.5;
+                        if slope(mid) * s_lo > 0.0 {
+                            lo = mid;
+                        } else {
+                            hi = mid;
+                        }
+                    }
+                    let p = self.point_at_knot((lo + hi) * 0.5);
+                    min = min.min(p);
+                    max = max.max(p);
+                }
+            }
+        }
+        P::bounds(min, max)
+    }
+
+    fn segment_count(&self) -> usize {
+        self.spans().count()
+    }
+
+    fn segment_start(&self, i: usize) -> f32 {
+        let (a, b) = self.domain();
+        match self.spans().nth(i) {
+            Some(k) => (self.knots[k] - a) / (b - a),
+            None => 1.0,
+        }
+    }
+}
+
+// Evaluate the B-spline of the given degree within the knot span `k` at `u` via de Boor's
+// algorithm, where `control` produces the control point at each index.
+fn de_boor<P, F>(degree: usize, knots: &[f32], k: usize, u: f32, control: F) -> P
+where
+    P: Point,
+    F: Fn(usize) -> P,
+{
+    let first = k - degree;
+    let mut d = [control(first); MAX_DEGREE + 1];
+    for (j, d) in d.iter_mut().enumerate().take(degree + 1).skip(1) {
+        *d = control(first + j);
+    }
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = first + j;
+            let alpha = ratio(u - knots[i], knots[i + degree + 1 - r] - knots[i]);
+            d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+        }
+    }
+    d[degree]
+}
+
+// `num / denom`, or zero where the denominator is zero due to repeated knots.
+fn ratio(num: f32, denom: f32) -> f32 {
+    if denom > 0.0 {
+        num / denom
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,206 @@
+//! Catmull-Rom splines passing through each of their points.
+
+use super::{length, CubicBezier, Curve, Point};
+use crate::math::num_traits::Float;
+use alloc::vec::Vec;
+
+/// A smooth curve passing through each of its points.
+///
+/// The curve between each pair of neighbouring points is a cubic whose tangents are determined by
+/// the points either side. `alpha` selects the parameterisation, where `0.0` is uniform, `0.5` is
+/// centripetal and `1.0` is chordal. The centripetal parameterisation avoids cusps and
+/// self-intersections within a segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRom<P> {
+    points: Vec<P>,
+    alpha: f32,
+    closed: bool,
+}
+
+/// An iterator yielding the cubic bézier curve describing each segment of a **CatmullRom** spline.
+#[derive(Clone, Debug)]
+pub struct Segments<'a, P> {
+    spline: &'a CatmullRom<P>,
+    range: core::ops::Range<usize>,
+}
+
+impl<P: Point> CatmullRom<P> {
+    /// The default `alpha`, producing a centripetal spline.
+    pub const DEFAULT_ALPHA: f32 = 0.5;
+
+    /// A centripetal Catmull-Rom spline passing through each of the given points.
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        CatmullRom {
+            points: points.into_iter().collect(),
+            alpha: Self::DEFAULT_ALPHA,
+            closed: false,
+        }
+    }
+
+    /// Specify the parameterisation of the spline, where `0.0` is uniform, `0.5` is centripetal
+    /// and `1.0` is chordal.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Specify whether or not the last point connects back to the first.
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// The points through which the spline passes.
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    /// Whether or not the last point connects back to the first.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// The number of segments between neighbouring points.
+    pub fn len(&self) -> usize {
+        match self.points.len() {
+            0 | 1 => 0,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// Whether or not the spline has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The cubic bézier curve describing the segment starting at the point at index `i`.
+    ///
+    /// **Panics** if `i` is not less than `len`.
+    pub fn segment(&self, i: usize) -> CubicBezier<P> {
+        assert!(i < self.len(), "segment index out of range");
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (
+            self.control(i - 1),
+            self.control(i),
+            self.control(i + 1),
+            self.control(i + 2),
+        );
+        let d1 = Float::powf(length(p1 - p0), self.alpha);
+        let d2 = Float::powf(length(p2 - p1), self.alpha);
+        let d3 = Float::powf(length(p3 - p2), self.alpha);
+        let ctrl1 = if d1 * (d1 + d2) > 0.0 {
+            let a = 2.0 * d1 * d1 + 3.0 * d1 * d2 + d2 * d2;
+            (p2 * (d1 * d1) - p0 * (d2 * d2) + p1 * a) / (3.0 * d1 * (d1 + d2))
+        } else {
+            p1
+        };
+        let ctrl2 = if d3 * (d3 + d2) > 0.0 {
+            let a = 2.0 * d3 * d3 + 3.0 * d3 * d2 + d2 * d2;
+            (p1 * (d3 * d3) - p3 * (d2 * d2) + p2 * a) / (3.0 * d3 * (d3 + d2))
+        } else {
+            p2
+        };
+        CubicBezier::new(p1, ctrl1, ctrl2, p2)
+    }
+
+    /// An iterator yielding the cubic bézier curve describing each segment.
+    pub fn segments(&self) -> Segments<'_, P> {
+        Segments {
+            spline: self,
+            range: 0..self.len(),
+        }
+    }
+
+    /// Split the spline at `t`, producing the segments before and after the split.
+    ///
+    /// The halves of a Catmull-Rom spline are not generally Catmull-Rom splines themselves, so
+    /// each half is described by its cubic bézier segments.
+    pub fn split(&self, t: f32) -> (Vec<CubicBezier<P>>, Vec<CubicBezier<P>>) {
+        if self.is_empty() {
+            return (Vec::new(), Vec::new());
+        }
+        let (i, t) = self.segment_at(t);
+        let (a, b) = self.segment(i).split(t);
+        let mut before: Vec<_> = self.segments().take(i).collect();
+        before.push(a);
+        let mut after = Vec::with_capacity(self.len() - i);
+        after.push(b);
+        after.extend(self.segments().skip(i + 1));
+        (before, after)
+    }
+
+    // The point at index `i`, wrapping for closed splines and mirroring the missing neighbours of
+    // the end points for open splines.
+    fn control(&self, i: isize) -> P {
+        let points = &self.points;
+        let n = points.len();
+        if self.closed {
+            points[i.rem_euclid(n as isize) as usize]
+        } else if i < 0 {
+            points[0] * 2.0 - points[1]
+        } else if i as usize >= n {
+            points[n - 1] * 2.0 - points[n - 2]
+        } else {
+            points[i as usize]
+        }
+    }
+
+    // The index of the segment at `t` along with the position within the segment.
+    fn segment_at(&self, t: f32) -> (usize, f32) {
+        let n = self.len();
+        let t = t.clamp(0.0, 1.0) * n as f32;
+        let i = (t as usize).min(n - 1);
+        (i, t - i as f32)
+    }
+}
+
+impl<P: Point> Curve for CatmullRom<P> {
+    type Point = P;
+
+    /// The point along the spline at `t`.
+    ///
+    /// **Panics** if the spline has fewer than two points.
+    fn point(&self, t: f32) -> P {
+        let (i, t) = self.segment_at(t);
+        self.segment(i).point(t)
+    }
+
+    /// The derivative of the spline with respect to `t`.
+    ///
+    /// **Panics** if the spline has fewer than two points.
+    fn derivative(&self, t: f32) -> P {
+        let (i, t) = self.segment_at(t);
+        self.segment(i).derivative(t) * self.len() as f32
+    }
+
+    /// The smallest axis-aligned bounds containing the spline.
+    ///
+    /// **Panics** if the spline has fewer than two points.
+    fn bounding_box(&self) -> P::Bounds {
+        let mut segments = self.segments().map(|s| s.min_max());
+        let first = segments.next().expect("no segments within spline");
+        let (min, max) = segments.fold(first, |(min, max), (a, b)| (min.min(a), max.max(b)));
+        P::bounds(min, max)
+    }
+
+    fn segment_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, P: Point> Iterator for Segments<'a, P> {
+    type Item = CubicBezier<P>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.spline.segment(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, P: Point> ExactSizeIterator for Segments<'a, P> {}
@@ -0,0 +1,401 @@
+//! Parametric curves including béziers, Catmull-Rom splines and B-splines.
+//!
+//! Each curve is generic over its **Point** type, either a `Vec2` or a `Vec3`, and implements the
+//! **Curve** trait. Curves are evaluated over the parameter range `0.0..=1.0`, where splines map
+//! the range evenly across their segments or knot domain. The **Curve** trait provides arc length
+//! measurement, arc length parameterisation and flattening for every curve type.
+
+use crate::geom::{Cuboid, Point2, Point3, Rect};
+use crate::math::num_traits::Float;
+use core::ops::{Add, Div, Mul, Sub};
+
+pub mod bezier;
+#[cfg(feature = "alloc")]
+pub mod bspline;
+#[cfg(feature = "alloc")]
+pub mod catmull_rom;
+
+pub use self::bezier::{CubicBezier, QuadraticBezier};
+#[cfg(feature = "alloc")]
+pub use self::bspline::BSpline;
+#[cfg(feature = "alloc")]
+pub use self::catmull_rom::CatmullRom;
+
+/// Point types along which curves may be described.
+pub trait Point:
+    Copy
+    + PartialEq
+    + core::fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f32, Output = Self>
+    + Div<f32, Output = Self>
+{
+    /// The type describing an axis-aligned region of space, i.e. a **Rect** or **Cuboid**.
+    type Bounds: Copy + core::fmt::Debug;
+    /// The number of axes.
+    const DIMENSIONS: usize;
+
+    /// The coordinate along the given axis.
+    fn axis(self, axis: usize) -> f32;
+    /// The dot product of two points.
+    fn dot(self, other: Self) -> f32;
+    /// The minimum of each axis of the two points.
+    fn min(self, other: Self) -> Self;
+    /// The maximum of each axis of the two points.
+    fn max(self, other: Self) -> Self;
+    /// The bounds with the given minimum and maximum corners.
+    fn bounds(min: Self, max: Self) -> Self::Bounds;
+}
+
+/// A curve parameterised over the range `0.0..=1.0`.
+pub trait Curve {
+    /// The type of point along the curve.
+    type Point: Point;
+
+    /// The point along the curve at `t`.
+    fn point(&self, t: f32) -> Self::Point;
+
+    /// The derivative of the curve with respect to `t`.
+    fn derivative(&self, t: f32) -> Self::Point;
+
+    /// The smallest axis-aligned bounds containing the curve.
+    fn bounding_box(&self) -> <Self::Point as Point>::Bounds;
+
+    /// The number of polynomial segments making up the curve.
+    ///
+    /// Arc length measurement and flattening are performed separately for each segment, avoiding
+    /// stepping over any discontinuities in the derivative between segments.
+    fn segment_count(&self) -> usize {
+        1
+    }
+
+    /// The parameter `t` at the start of the segment at index `i`, where the segment count yields
+    /// `1.0`.
+    ///
+    /// By default the segments are spread evenly over the parameter range.
+    fn segment_start(&self, i: usize) -> f32 {
+        i as f32 / self.segment_count().max(1) as f32
+    }
+
+    /// The point at the start of the curve.
+    fn start(&self) -> Self::Point {
+        self.point(0.0)
+    }
+
+    /// The point at the end of the curve.
+    fn end(&self) -> Self::Point {
+        self.point(1.0)
+    }
+
+    /// The unit length direction of the curve at `t`.
+    ///
+    /// Returns the zero vector where the derivative is zero.
+    fn tangent(&self, t: f32) -> Self::Point {
+        let d = self.derivative(t);
+        let len = length(d);
+        if len > 0.0 {
+            d / len
+        } else {
+            d
+        }
+    }
+
+    /// The arc length of the curve.
+    fn length(&self) -> f32 {
+        self.length_to(1.0)
+    }
+
+    /// The arc length of the curve from its start to `t`.
+    fn length_to(&self, t: f32) -> f32 {
+        length_between(self, 0.0, t.clamp(0.0, 1.0))
+    }
+
+    /// The parameter `t` at which the arc length from the start of the curve is `distance`.
+    ///
+    /// The `distance` is clamped to the length of the curve.
+    fn t_at_length(&self, distance: f32) -> f32 {
+        let total = self.length();
+        if distance <= 0.0 || total <= 0.0 {
+            return 0.0;
+        } else if distance >= total {
+            return 1.0;
+        }
+        // Newton's method, falling back to bisection when a step leaves the bracket or fails to
+        // halve the error.
+        let (mut lo, mut hi) = (0.0, 1.0);
+        let (mut t, mut t_len) = (0.0, 0.0);
+        let mut next = distance / total;
+        let mut prev_error = f32::INFINITY;
+        for _ in 0..MAX_ARC_LENGTH_ITERATIONS {
+            t_len += signed_length_between(self, t, next);
+            t = next;
+            let error = t_len - distance;
+            if error.abs() <= total * ARC_LENGTH_TOLERANCE {
+                break;
+            }
+            if error > 0.0 {
+                hi = t;
+            } else {
+                lo = t;
+            }
+            let speed = length(self.derivative(t));
+            next = t - error / speed;
+            if !(speed > 0.0 && next > lo && next < hi) || error.abs() > prev_error * 0.5 {
+                next = (lo + hi) * 0.5;
+            }
+            prev_error = error.abs();
+        }
+        t
+    }
+
+    /// The point at the given arc length from the start of the curve.
+    fn point_at_length(&self, distance: f32) -> Self::Point {
+        self.point(self.t_at_length(distance))
+    }
+
+    /// An iterator yielding points along the curve such that the line segments between them stay
+    /// within `tolerance` of the curve.
+    ///
+    /// The first and last points yielded are the start and end of the curve.
+    fn flattened(&self, tolerance: f32) -> Flattened<'_, Self> {
+        Flattened {
+            curve: self,
+            tolerance,
+            segment: 0,
+            segments: self.segment_count().max(1),
+            t: 0.0,
+            dt: 1.0,
+            prev: None,
+        }
+    }
+}
+
+/// An iterator yielding points along a curve such that the line segments between them stay
+/// within a tolerance of the curve.
+#[derive(Clone, Debug)]
+pub struct Flattened<'a, C>
+where
+    C: Curve + ?Sized,
+{
+    curve: &'a C,
+    tolerance: f32,
+    segment: usize,
+    segments: usize,
+    // The position and step size within the current segment.
+    t: f32,
+    dt: f32,
+    prev: Option<C::Point>,
+}
+
+// The nodes and weights used by `gauss_legendre`.
+const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+    (0.0, 0.568_888_9),
+    (-0.538_469_3, 0.478_628_67),
+    (0.538_469_3, 0.478_628_67),
+    (-0.906_179_8, 0.236_926_88),
+    (0.906_179_8, 0.236_926_88),
+];
+
+// The maximum depth of the adaptive subdivision used while measuring arc length.
+const MAX_INTEGRATION_DEPTH: u32 = 12;
+
+// The tolerance of arc length measurements relative to the length being measured.
+const ARC_LENGTH_TOLERANCE: f32 = 1e-5;
+
+// The maximum number of steps taken to find the parameter at an arc length.
+const MAX_ARC_LENGTH_ITERATIONS: usize = 24;
+
+// The smallest step taken while flattening, relative to the width of a segment.
+const MIN_FLATTEN_STEP: f32 = 1.0 / 65_536.0;
+
+impl Point for Point2 {
+    type Bounds = Rect;
+    const DIMENSIONS: usize = 2;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn dot(self, other: Self) -> f32 {
+        Point2::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Point2::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point2::max(self, other)
+    }
+
+    fn bounds(min: Self, max: Self) -> Rect {
+        Rect::from_corners(min, max)
+    }
+}
+
+impl Point for Point3 {
+    type Bounds = Cuboid;
+    const DIMENSIONS: usize = 3;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn dot(self, other: Self) -> f32 {
+        Point3::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Point3::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point3::max(self, other)
+    }
+
+    fn bounds(min: Self, max: Self) -> Cuboid {
+        Cuboid::from_xyz_whd((min + max) * 0.5, max - min)
+    }
+}
+
+impl<'a, C> Iterator for Flattened<'a, C>
+where
+    C: Curve + ?Sized,
+{
+    type Item = C::Point;
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = match self.prev {
+            None => {
+                let start = self.curve.point(0.0);
+                self.prev = Some(start);
+                return Some(start);
+            }
+            Some(_) if self.segment >= self.segments => return None,
+            Some(prev) => prev,
+        };
+        let start = self.curve.segment_start(self.segment);
+        let width = self.curve.segment_start(self.segment + 1) - start;
+        let tolerance_sq = self.tolerance * self.tolerance;
+        loop {
+            let mut t = self.t + self.dt;
+            // Avoid a sliver of a step at the end of the segment due to rounding.
+            if t > 1.0 - self.dt * 1e-3 {
+                t = 1.0;
+            }
+            let (t0, t1) = (start + width * self.t, start + width * t);
+            let end = self.curve.point(t1);
+            // The control points of the cubic bézier matching the position and derivative of the
+            // curve at either end of the step. The convex hull of the bézier bounds a cubic piece
+            // exactly, while the midpoint checks higher degree pieces.
+            let ctrl1 = prev + self.curve.derivative(t0) * ((t1 - t0) / 3.0);
+            let ctrl2 = end - self.curve.derivative(t1) * ((t1 - t0) / 3.0);
+            let hull = distance_squared_to_segment(ctrl1, prev, end)
+                .max(distance_squared_to_segment(ctrl2, prev, end));
+            let mid = self.curve.point((t0 + t1) * 0.5);
+            let flat = hull * (0.75 * 0.75) <= tolerance_sq
+                && distance_squared_to_segment(mid, prev, end) <= tolerance_sq;
+            if flat || self.dt <= MIN_FLATTEN_STEP {
+                if t < 1.0 {
+                    self.t = t;
+                    self.dt = (self.dt * 2.0).min(1.0);
+                } else {
+                    self.segment += 1;
+                    self.t = 0.0;
+                    self.dt = 1.0;
+                }
+                self.prev = Some(end);
+                return Some(end);
+            }
+            self.dt *= 0.5;
+        }
+    }
+}
+
+// The length of the vector.
+pub(crate) fn length<P: Point>(p: P) -> f32 {
+    Float::sqrt(p.dot(p))
+}
+
+// The squared distance from `p` to the nearest point on the segment `ab`.
+fn distance_squared_to_segment<P: Point>(p: P, a: P, b: P) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.dot(ab);
+    let t = if len_sq > 0.0 {
+        ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let d = p - (a + ab * t);
+    d.dot(d)
+}
+
+// The arc length between `t0` and `t1`, negative if `t1 < t0`.
+fn signed_length_between<C: Curve + ?Sized>(curve: &C, t0: f32, t1: f32) -> f32 {
+    if t1 < t0 {
+        -length_between(curve, t1, t0)
+    } else {
+        length_between(curve, t0, t1)
+    }
+}
+
+// The arc length between `t0` and `t1`, integrated separately over each segment.
+fn length_between<C: Curve + ?Sized>(curve: &C, t0: f32, t1: f32) -> f32 {
+    let n = curve.segment_count().max(1);
+    let speed = |t: f32| length(curve.derivative(t));
+    let mut sum = 0.0;
+    for i in 0..n {
+        let a = curve.segment_start(i).max(t0);
+        let b = curve.segment_start(i + 1).min(t1);
+        if a < b {
+            let estimate = gauss_legendre(&speed, a, b);
+            sum += integrate(&speed, a, b, estimate, MAX_INTEGRATION_DEPTH);
+        }
+    }
+    sum
+}
+
+// Adaptively integrate `f` between `a` and `b`, subdividing until the halves agree with the
+// estimate for the whole.
+fn integrate<F: Fn(f32) -> f32>(f: &F, a: f32, b: f32, estimate: f32, depth: u32) -> f32 {
+    let mid = (a + b) * 0.5;
+    let left = gauss_legendre(f, a, mid);
+    let right = gauss_legendre(f, mid, b);
+    let sum = left + right;
+    if depth == 0 || (sum - estimate).abs() <= sum.abs() * ARC_LENGTH_TOLERANCE {
+        sum
+    } else {
+        integrate(f, a, mid, left, depth - 1) + integrate(f, mid, b, right, depth - 1)
+    }
+}
+
+// Integrate `f` between `a` and `b` via 5-point Gauss-Legendre quadrature.
+fn gauss_legendre<F: Fn(f32) -> f32>(f: &F, a: f32, b: f32) -> f32 {
+    let half = (b - a) * 0.5;
+    let mid = (a + b) * 0.5;
+    GAUSS_LEGENDRE
+        .iter()
+        .map(|&(x, w)| w * f(mid + half * x))
+        .sum::<f32>()
+        * half
+}
+
+// The real roots of `a*t^2 + b*t + c` within the open range `0.0..1.0`.
+pub(crate) fn quadratic_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    let in_range = |t: f32| if t > 0.0 && t < 1.0 { Some(t) } else { None };
+    if a.abs() <= f32::EPSILON * (b.abs() + c.abs()) {
+        if b == 0.0 {
+            return [None, None];
+        }
+        return [in_range(-c / b), None];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let sqrt = Float::sqrt(discriminant);
+    [
+        in_range((-b + sqrt) / (2.0 * a)),
+        in_range((-b - sqrt) / (2.0 * a)),
+    ]
+}
@@ -0,0 +1,569 @@
+//! The Delaunay triangulation of a set of points.
+
+use crate::geom::{Point2, Rect, Tri, Voronoi};
+use crate::math::num_traits::Float;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Indicates a half-edge along the convex hull that has no twin.
+const EMPTY: usize = usize::MAX;
+
+/// The Delaunay triangulation of a set of points.
+///
+/// No point lies within the circumcircle of any of the triangles. Triangles are described by
+/// indices into the original points and are wound counter-clockwise. Duplicate points are only
+/// triangulated once. If there are fewer than three unique points or all points are collinear,
+/// the triangulation is empty.
+///
+/// Internally, each triangle is described by three "half-edges" where half-edge `e` belongs to
+/// triangle `e / 3` and runs from vertex `e % 3` to the following vertex. The half-edges of
+/// neighbouring triangles are linked, providing the adjacency between triangles.
+///
+/// This uses the sweep-hull algorithm popularised by the *delaunator* library, running in roughly
+/// *O(n log n)* time.
+#[derive(Clone, Debug, Default)]
+pub struct Delaunay {
+    points: Vec<Point2>,
+    triangles: Vec<usize>,
+    halfedges: Vec<usize>,
+    hull: Vec<usize>,
+}
+
+/// An iterator yielding each triangle within a **Delaunay** triangulation.
+#[derive(Clone, Debug)]
+pub struct Triangles<'a> {
+    points: &'a [Point2],
+    indices: core::slice::ChunksExact<'a, usize>,
+}
+
+/// An iterator yielding each edge within a **Delaunay** triangulation as a pair of point indices.
+#[derive(Clone, Debug)]
+pub struct Edges<'a> {
+    triangles: &'a [usize],
+    halfedges: &'a [usize],
+    index: usize,
+}
+
+// State used while building the triangulation.
+struct Builder<'a> {
+    coords: &'a [[f64; 2]],
+    triangles: Vec<usize>,
+    halfedges: Vec<usize>,
+    hull_prev: Vec<usize>,
+    hull_next: Vec<usize>,
+    // The half-edge along the hull starting at each hull vertex.
+    hull_tri: Vec<usize>,
+    hull_hash: Vec<usize>,
+    hull_start: usize,
+    center: [f64; 2],
+    stack: Vec<usize>,
+}
+
+impl Delaunay {
+    /// Triangulate the given points.
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Point2>,
+    {
+        let points: Vec<Point2> = points.into_iter().collect();
+        let coords: Vec<[f64; 2]> = points.iter().map(|p| [p.x as f64, p.y as f64]).collect();
+        let (triangles, halfedges, hull) = triangulate(&coords);
+        Delaunay {
+            points,
+            triangles,
+            halfedges,
+            hull,
+        }
+    }
+
+    /// The triangulated points.
+    pub fn points(&self) -> &[Point2] {
+        &self.points
+    }
+
+    /// The indices of the vertices of each triangle, three per triangle.
+    ///
+    /// These may be used along with `points` to describe an indexed mesh.
+    pub fn indices(&self) -> &[usize] {
+        &self.triangles
+    }
+
+    /// The number of triangles.
+    pub fn len(&self) -> usize {
+        self.triangles.len() / 3
+    }
+
+    /// Whether or not the triangulation contains no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// The indices of the vertices of the triangle at the given index.
+    pub fn triangle_indices(&self, t: usize) -> [usize; 3] {
+        let i = t * 3;
+        [
+            self.triangles[i],
+            self.triangles[i + 1],
+            self.triangles[i + 2],
+        ]
+    }
+
+    /// The triangle at the given index.
+    pub fn triangle(&self, t: usize) -> Tri<Point2> {
+        Tri::from_index_tri(&self.points, &self.triangle_indices(t))
+    }
+
+    /// An iterator yielding each triangle.
+    pub fn triangles(&self) -> Triangles<'_> {
+        Triangles {
+            points: &self.points,
+            indices: self.triangles.chunks_exact(3),
+        }
+    }
+
+    /// An iterator yielding each edge of the triangulation once as a pair of point indices.
+    pub fn edges(&self) -> Edges<'_> {
+        Edges {
+            triangles: &self.triangles,
+            halfedges: &self.halfedges,
+            index: 0,
+        }
+    }
+
+    /// The triangles adjacent to each edge of the triangle at the given index.
+    ///
+    /// Edge `k` runs from vertex `k` to vertex `(k + 1) % 3`. Edges along the convex hull have no
+    /// adjacent triangle.
+    pub fn adjacent(&self, t: usize) -> [Option<usize>; 3] {
+        let adjacent = |e: usize| match self.halfedges[e] {
+            EMPTY => None,
+            twin => Some(twin / 3),
+        };
+        let i = t * 3;
+        [adjacent(i), adjacent(i + 1), adjacent(i + 2)]
+    }
+
+    /// The centre of the circumcircle of the triangle at the given index.
+    ///
+    /// These are the vertices of the Voronoi diagram of the points.
+    pub fn circumcenter(&self, t: usize) -> Point2 {
+        let [a, b, c] = self.triangle_indices(t);
+        let p = |i: usize| {
+            let p = self.points[i];
+            [p.x as f64, p.y as f64]
+        };
+        let [x, y] = circumcenter(p(a), p(b), p(c));
+        [x as f32, y as f32].into()
+    }
+
+    /// The indices of the points along the convex hull, wound counter-clockwise.
+    ///
+    /// If all points are collinear, this is every unique point in order along the line.
+    pub fn hull(&self) -> &[usize] {
+        &self.hull
+    }
+
+    /// The Voronoi diagram of the points, clipped to the given rectangle.
+    pub fn voronoi(&self, rect: Rect) -> Voronoi {
+        Voronoi::new(self, rect)
+    }
+}
+
+impl<'a> Iterator for Triangles<'a> {
+    type Item = Tri<Point2>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ix = self.indices.next()?;
+        Some(Tri([
+            self.points[ix[0]],
+            self.points[ix[1]],
+            self.points[ix[2]],
+        ]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Triangles<'a> {}
+
+impl<'a> Iterator for Edges<'a> {
+    type Item = [usize; 2];
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.halfedges.len() {
+            let e = self.index;
+            self.index += 1;
+            let twin = self.halfedges[e];
+            // Interior edges are shared by two half-edges, so only yield one of them.
+            if twin == EMPTY || e < twin {
+                return Some([self.triangles[e], self.triangles[next_halfedge(e)]]);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Builder<'a> {
+    fn add_triangle(
+        &mut self,
+        i0: usize,
+        i1: usize,
+        i2: usize,
+        a: usize,
+        b: usize,
+        c: usize,
+    ) -> usize {
+        let t = self.triangles.len();
+        self.triangles.extend_from_slice(&[i0, i1, i2]);
+        self.halfedges.extend_from_slice(&[EMPTY; 3]);
+        self.link(t, a);
+        self.link(t + 1, b);
+        self.link(t + 2, c);
+        t
+    }
+
+    fn link(&mut self, a: usize, b: usize) {
+        self.halfedges[a] = b;
+        if b != EMPTY {
+            self.halfedges[b] = a;
+        }
+    }
+
+    fn hash_key(&self, p: [f64; 2]) -> usize {
+        let n = self.hull_hash.len();
+        let angle = pseudo_angle(p[0] - self.center[0], p[1] - self.center[1]);
+        (angle * n as f64) as usize % n
+    }
+
+    // Flip the triangles sharing half-edge `a` and its twin until the Delaunay condition is
+    // satisfied, returning the half-edge along the hull that starts at the new point.
+    //
+    //          pl                    pl
+    //         /||\                  /  \
+    //      al/ || \bl            al/    \a
+    //       /  ||  \              /      \
+    //      /  a||b  \    flip    /___ar___\
+    //    p0\   ||   /p1   =>   p0\---bl---/p1
+    //       \  ||  /              \      /
+    //      ar\ || /br             b\    /br
+    //         \||/                  \  /
+    //          pr                    pr
+    fn legalize(&mut self, mut a: usize) -> usize {
+        let mut ar;
+        loop {
+            let b = self.halfedges[a];
+            let a0 = a - a % 3;
+            ar = a0 + (a + 2) % 3;
+
+            if b == EMPTY {
+                match self.stack.pop() {
+                    Some(e) => a = e,
+                    None => break,
+                }
+                continue;
+            }
+
+            let b0 = b - b % 3;
+            let al = a0 + (a + 1) % 3;
+            let bl = b0 + (b + 2) % 3;
+            let p0 = self.triangles[ar];
+            let pr = self.triangles[a];
+            let pl = self.triangles[al];
+            let p1 = self.triangles[bl];
+            let c = self.coords;
+
+            if !in_circle(c[p0], c[pr], c[pl], c[p1]) {
+                match self.stack.pop() {
+                    Some(e) => a = e,
+                    None => break,
+                }
+                continue;
+            }
+
+            self.triangles[a] = p1;
+            self.triangles[b] = p0;
+            let hbl = self.halfedges[bl];
+            // The flipped edge was on the other side of the hull, so update the hull reference.
+            if hbl == EMPTY {
+                let mut e = self.hull_start;
+                loop {
+                    if self.hull_tri[e] == bl {
+                        self.hull_tri[e] = a;
+                        break;
+                    }
+                    e = self.hull_prev[e];
+                    if e == self.hull_start {
+                        break;
+                    }
+                }
+            }
+            self.link(a, hbl);
+            self.link(b, self.halfedges[ar]);
+            self.link(ar, bl);
+            self.stack.push(b0 + (b + 1) % 3);
+        }
+        ar
+    }
+}
+
+// Triangulate the given points, returning the triangles, half-edges and hull.
+fn triangulate(coords: &[[f64; 2]]) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let n = coords.len();
+    if n == 0 {
+        return (vec![], vec![], vec![]);
+    }
+
+    // Pick a seed point close to the centre of the bounding box.
+    let (mut min, mut max) = (coords[0], coords[0]);
+    for p in coords {
+        min = [min[0].min(p[0]), min[1].min(p[1])];
+        max = [max[0].max(p[0]), max[1].max(p[1])];
+    }
+    let center = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+    let closest = |to: [f64; 2], skip: usize| {
+        let mut closest = None;
+        let mut min_dist = f64::INFINITY;
+        for (i, &p) in coords.iter().enumerate() {
+            let d = dist2(to, p);
+            if i != skip && d < min_dist && (skip == EMPTY || d > 0.0) {
+                closest = Some(i);
+                min_dist = d;
+            }
+        }
+        closest
+    };
+    let i0 = closest(center, EMPTY).unwrap_or(0);
+    // The point closest to the seed.
+    let i1 = match closest(coords[i0], i0) {
+        None => return (vec![], vec![], collinear_hull(coords)),
+        Some(i) => i,
+    };
+    // The point forming the smallest circumcircle with the first two.
+    let mut i2 = None;
+    let mut min_radius = f64::INFINITY;
+    for (i, &p) in coords.iter().enumerate() {
+        if i == i0 || i == i1 {
+            continue;
+        }
+        let [x, y] = circumdelta(coords[i0], coords[i1], p);
+        let r = x * x + y * y;
+        if r < min_radius {
+            i2 = Some(i);
+            min_radius = r;
+        }
+    }
+    let mut i2 = match i2 {
+        Some(i) if orient(coords[i0], coords[i1], coords[i]) != 0.0 => i,
+        _ => return (vec![], vec![], collinear_hull(coords)),
+    };
+    let mut i1 = i1;
+    if orient(coords[i0], coords[i1], coords[i2]) < 0.0 {
+        core::mem::swap(&mut i1, &mut i2);
+    }
+
+    // Sort the points by distance from the seed triangle's circumcentre. Equal points are sorted
+    // next to each other so that duplicates can be skipped.
+    let center = circumcenter(coords[i0], coords[i1], coords[i2]);
+    let dists: Vec<f64> = coords.iter().map(|&p| dist2(center, p)).collect();
+    let mut ids: Vec<usize> = (0..n).collect();
+    ids.sort_by(|&a, &b| {
+        let cmp = |x: f64, y: f64| x.partial_cmp(&y).unwrap_or(Ordering::Equal);
+        cmp(dists[a], dists[b])
+            .then(cmp(coords[a][0], coords[b][0]))
+            .then(cmp(coords[a][1], coords[b][1]))
+    });
+
+    let max_triangles = 2 * n - 5;
+    let hash_size = Float::ceil(Float::sqrt(n as f64)) as usize;
+    let mut b = Builder {
+        coords,
+        triangles: Vec::with_capacity(max_triangles * 3),
+        halfedges: Vec::with_capacity(max_triangles * 3),
+        hull_prev: vec![0; n],
+        hull_next: vec![0; n],
+        hull_tri: vec![0; n],
+        hull_hash: vec![EMPTY; hash_size],
+        hull_start: i0,
+        center,
+        stack: vec![],
+    };
+
+    // The seed triangle is the initial hull.
+    b.hull_next[i0] = i1;
+    b.hull_next[i1] = i2;
+    b.hull_next[i2] = i0;
+    b.hull_prev[i0] = i2;
+    b.hull_prev[i1] = i0;
+    b.hull_prev[i2] = i1;
+    b.hull_tri[i0] = 0;
+    b.hull_tri[i1] = 1;
+    b.hull_tri[i2] = 2;
+    for &i in &[i0, i1, i2] {
+        let key = b.hash_key(coords[i]);
+        b.hull_hash[key] = i;
+    }
+    b.add_triangle(i0, i1, i2, EMPTY, EMPTY, EMPTY);
+
+    let mut prev: Option<[f64; 2]> = None;
+    for &i in &ids {
+        let p = coords[i];
+        // Skip duplicate points.
+        if prev == Some(p) {
+            continue;
+        }
+        prev = Some(p);
+        if i == i0 || i == i1 || i == i2 {
+            continue;
+        }
+
+        // Find an edge of the hull visible from the point, starting from a nearby hull vertex.
+        let mut start = 0;
+        let key = b.hash_key(p);
+        for j in 0..hash_size {
+            start = b.hull_hash[(key + j) % hash_size];
+            if start != EMPTY && start != b.hull_next[start] {
+                break;
+            }
+        }
+        start = b.hull_prev[start];
+        let mut e = start;
+        loop {
+            let q = b.hull_next[e];
+            if orient(coords[e], coords[q], p) < 0.0 {
+                break;
+            }
+            e = q;
+            if e == start {
+                e = EMPTY;
+                break;
+            }
+        }
+        // The point lies on or within the hull, most likely a near duplicate.
+        if e == EMPTY {
+            continue;
+        }
+
+        // Add the first triangle from the point.
+        let t = b.add_triangle(e, i, b.hull_next[e], EMPTY, EMPTY, b.hull_tri[e]);
+        b.hull_tri[i] = b.legalize(t + 2);
+        b.hull_tri[e] = t;
+
+        // Walk forward along the hull, adding triangles for each visible edge.
+        let mut n = b.hull_next[e];
+        loop {
+            let q = b.hull_next[n];
+            if orient(coords[n], coords[q], p) >= 0.0 {
+                break;
+            }
+            let t = b.add_triangle(n, i, q, b.hull_tri[i], EMPTY, b.hull_tri[n]);
+            b.hull_tri[i] = b.legalize(t + 2);
+            // Mark as removed.
+            b.hull_next[n] = n;
+            n = q;
+        }
+
+        // Walk backward from the other side.
+        if e == start {
+            loop {
+                let q = b.hull_prev[e];
+                if orient(coords[q], coords[e], p) >= 0.0 {
+                    break;
+                }
+                let t = b.add_triangle(q, i, e, EMPTY, b.hull_tri[e], b.hull_tri[q]);
+                b.legalize(t + 2);
+                b.hull_tri[q] = t;
+                b.hull_next[e] = e;
+                e = q;
+            }
+        }
+
+        // Update the hull.
+        b.hull_start = e;
+        b.hull_prev[i] = e;
+        b.hull_next[i] = n;
+        b.hull_next[e] = i;
+        b.hull_prev[n] = i;
+        let key = b.hash_key(p);
+        b.hull_hash[key] = i;
+        let key = b.hash_key(coords[e]);
+        b.hull_hash[key] = e;
+    }
+
+    let mut hull = vec![];
+    let mut e = b.hull_start;
+    loop {
+        hull.push(e);
+        e = b.hull_next[e];
+        if e == b.hull_start {
+            break;
+        }
+    }
+    (b.triangles, b.halfedges, hull)
+}
+
+// The unique points in order along the line on which they all lie.
+fn collinear_hull(coords: &[[f64; 2]]) -> Vec<usize> {
+    let mut ids: Vec<usize> = (0..coords.len()).collect();
+    ids.sort_by(|&a, &b| {
+        let (a, b) = (coords[a], coords[b]);
+        let cmp = |x: f64, y: f64| x.partial_cmp(&y).unwrap_or(Ordering::Equal);
+        cmp(a[0], b[0]).then(cmp(a[1], b[1]))
+    });
+    ids.dedup_by(|a, b| coords[*a] == coords[*b]);
+    ids
+}
+
+fn next_halfedge(e: usize) -> usize {
+    if e % 3 == 2 {
+        e - 2
+    } else {
+        e + 1
+    }
+}
+
+fn dist2(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (dx, dy) = (a[0] - b[0], a[1] - b[1]);
+    dx * dx + dy * dy
+}
+
+// Twice the signed area of the triangle, positive if it is wound counter-clockwise.
+fn orient(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+// Whether or not `p` lies within the circumcircle of the counter-clockwise triangle `abc`.
+fn in_circle(a: [f64; 2], b: [f64; 2], c: [f64; 2], p: [f64; 2]) -> bool {
+    let (dx, dy) = (a[0] - p[0], a[1] - p[1]);
+    let (ex, ey) = (b[0] - p[0], b[1] - p[1]);
+    let (fx, fy) = (c[0] - p[0], c[1] - p[1]);
+    let ap = dx * dx + dy * dy;
+    let bp = ex * ex + ey * ey;
+    let cp = fx * fx + fy * fy;
+    dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx) + ap * (ex * fy - ey * fx) > 0.0
+}
+
+// The offset from `a` to the circumcentre of the triangle `abc`.
+fn circumdelta(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> [f64; 2] {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let (ex, ey) = (c[0] - a[0], c[1] - a[1]);
+    let bl = dx * dx + dy * dy;
+    let cl = ex * ex + ey * ey;
+    let d = 0.5 / (dx * ey - dy * ex);
+    [(ey * bl - dy * cl) * d, (dx * cl - ex * bl) * d]
+}
+
+fn circumcenter(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> [f64; 2] {
+    let [x, y] = circumdelta(a, b, c);
+    [a[0] + x, a[1] + y]
+}
+
+// A value in `[0, 1]` that increases monotonically with the angle of the given vector.
+fn pseudo_angle(dx: f64, dy: f64) -> f64 {
+    let p = dx / (Float::abs(dx) + Float::abs(dy));
+    if dy > 0.0 {
+        (3.0 - p) / 4.0
+    } else {
+        (1.0 + p) / 4.0
+    }
+}
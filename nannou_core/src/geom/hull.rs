@@ -0,0 +1,56 @@
+//! The convex hull of a set of points.
+
+use crate::geom::Point2;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// The convex hull of the given points.
+///
+/// Returns the vertices of the hull wound counter-clockwise, starting from the left-most point.
+/// Points lying along the edges of the hull are omitted.
+///
+/// If all points are collinear, the two end points of the line are returned. If all points are
+/// equal, the single point is returned.
+///
+/// This uses Andrew's monotone chain algorithm and runs in *O(n log n)* time.
+pub fn convex_hull<I>(points: I) -> Vec<Point2>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let mut points: Vec<Point2> = points.into_iter().collect();
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Whether or not the last two points of the hull and `p` make a left turn.
+    fn left_turn(hull: &[Point2], p: Point2) -> bool {
+        let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+        (b - a).perp_dot(p - a) > 0.0
+    }
+
+    let mut hull: Vec<Point2> = Vec::with_capacity(points.len() + 1);
+    // The lower hull, from left to right.
+    for &p in &points {
+        while hull.len() >= 2 && !left_turn(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The upper hull, from right to left.
+    let lower_len = hull.len() + 1;
+    for &p in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !left_turn(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The last point is the first.
+    hull.pop();
+    hull
+}
@@ -0,0 +1,699 @@
+//! Intersection, overlap and closest point queries between 2D shapes, along with ray casting
+//! against 3D shapes.
+//!
+//! Queries between a segment or ray and a shape return a **Hit** describing where the segment or
+//! ray first crosses the shape's boundary. Queries between two shapes return a **Contact**
+//! describing how far and in which direction the shapes must be moved to separate them.
+//!
+//! Circles are described by a centre and a radius, while polygons are described by slices of
+//! points. Tris, Quads and Rects may be queried as polygons via their vertices.
+//!
+//! In 3D, a **Ray3** may be cast against planes, spheres, **Cuboid**s, **Tri**s and iterators of
+//! triangles such as those produced by a mesh, returning a **Hit3**. A **Ray3** may also be cast
+//! from a point within the window through a camera's view-projection matrix, allowing for picking
+//! objects with the mouse.
+
+use crate::geom::{vertex, Cuboid, Point2, Point3, Rect, Tri, Vec2, Vec3};
+use crate::glam::Mat4;
+use crate::math::num_traits::Float;
+
+/// A straight line segment between two points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+/// A half-line starting at `origin` and extending infinitely in the given `direction`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray2 {
+    pub origin: Point2,
+    pub direction: Vec2,
+}
+
+/// The point at which a segment or ray first crosses the boundary of a shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hit {
+    /// The point at which the boundary is crossed.
+    pub point: Point2,
+    /// The unit normal of the boundary at `point`, facing against the direction of travel.
+    pub normal: Vec2,
+    /// The position of `point` along the segment or ray.
+    ///
+    /// For segments this is a fraction of the segment's length in the range `0.0..=1.0`. For rays
+    /// this is a multiple of the ray's `direction`.
+    pub t: f32,
+}
+
+/// A half-line in 3D starting at `origin` and extending infinitely in the given `direction`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray3 {
+    pub origin: Point3,
+    pub direction: Vec3,
+}
+
+/// The point at which a ray first crosses the surface of a 3D shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hit3 {
+    /// The point at which the surface is crossed.
+    pub point: Point3,
+    /// The unit normal of the surface at `point`, facing against the direction of travel.
+    pub normal: Vec3,
+    /// The position of `point` along the ray as a multiple of the ray's `direction`.
+    pub t: f32,
+}
+
+/// Describes the overlap between two shapes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact {
+    /// A point at which the shapes touch.
+    pub point: Point2,
+    /// The unit direction in which the second shape must move to separate it from the first.
+    pub normal: Vec2,
+    /// The distance the second shape must move along `normal` to separate it from the first.
+    pub depth: f32,
+}
+
+impl Segment {
+    /// Construct a segment between the given points.
+    pub fn new(start: Point2, end: Point2) -> Self {
+        Segment { start, end }
+    }
+
+    /// The vector from `start` to `end`.
+    pub fn vector(&self) -> Vec2 {
+        self.end - self.start
+    }
+
+    /// The length of the segment.
+    pub fn length(&self) -> f32 {
+        self.vector().length()
+    }
+
+    /// The point at the given fraction `t` of the way from `start` to `end`.
+    pub fn point_at(&self, t: f32) -> Point2 {
+        self.start + self.vector() * t
+    }
+
+    /// The point on the segment closest to `p`.
+    pub fn closest_point(&self, p: Point2) -> Point2 {
+        closest_point_on_segment(*self, p)
+    }
+}
+
+impl Ray2 {
+    /// Construct a ray from the given origin and direction.
+    pub fn new(origin: Point2, direction: Vec2) -> Self {
+        Ray2 { origin, direction }
+    }
+
+    /// The point at `t` multiples of `direction` from `origin`.
+    pub fn point_at(&self, t: f32) -> Point2 {
+        self.origin + self.direction * t
+    }
+}
+
+impl Ray3 {
+    /// Construct a ray from the given origin and direction.
+    pub fn new(origin: Point3, direction: Vec3) -> Self {
+        Ray3 { origin, direction }
+    }
+
+    /// The ray cast from the camera through the given point within the window.
+    ///
+    /// The `point` is in window coordinates, where the origin is the centre of the `window` rect
+    /// and the y axis points upwards. The `view_projection` matrix maps world space to normalized
+    /// device coordinates, i.e. the camera's projection matrix multiplied by its view matrix.
+    ///
+    /// The ray starts on the near plane with a unit length direction, such that the `t` of each
+    /// hit is its distance from the near plane.
+    pub fn from_window_point(point: Point2, window: Rect, view_projection: Mat4) -> Self {
+        let near = unproject(point.extend(0.0), window, view_projection);
+        let far = unproject(point.extend(1.0), window, view_projection);
+        Ray3 {
+            origin: near,
+            direction: (far - near).normalize_or_zero(),
+        }
+    }
+
+    /// The point at `t` multiples of `direction` from `origin`.
+    pub fn point_at(&self, t: f32) -> Point3 {
+        self.origin + self.direction * t
+    }
+}
+
+impl From<[Point2; 2]> for Segment {
+    fn from([start, end]: [Point2; 2]) -> Self {
+        Segment { start, end }
+    }
+}
+
+/// The point at which segment `a` crosses segment `b`.
+///
+/// The `t` of the hit is the position along `a`. Parallel segments do not intersect.
+pub fn segment_segment(a: Segment, b: Segment) -> Option<Hit> {
+    let (dir, other) = (a.vector(), b.vector());
+    let (t, u) = line_params(a.start, dir, b.start, other)?;
+    if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    Some(Hit {
+        point: a.point_at(t),
+        normal: facing(other.perp(), dir),
+        t,
+    })
+}
+
+/// The point at which the ray crosses the segment.
+///
+/// Segments parallel to the ray are not intersected.
+pub fn ray_segment(ray: Ray2, segment: Segment) -> Option<Hit> {
+    let other = segment.vector();
+    let (t, u) = line_params(ray.origin, ray.direction, segment.start, other)?;
+    if t < 0.0 || !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    Some(Hit {
+        point: ray.point_at(t),
+        normal: facing(other.perp(), ray.direction),
+        t,
+    })
+}
+
+/// The point at which the ray first crosses any edge of the given closed polygon.
+pub fn ray_polygon(ray: Ray2, polygon: &[Point2]) -> Option<Hit> {
+    edges(polygon)
+        .filter_map(|edge| ray_segment(ray, edge))
+        .fold(None, |nearest: Option<Hit>, hit| match nearest {
+            Some(n) if n.t <= hit.t => Some(n),
+            _ => Some(hit),
+        })
+}
+
+/// The point at which the segment first crosses the boundary of the given circle.
+///
+/// If the segment starts within the circle, this is the point at which it exits the circle.
+pub fn segment_circle(segment: Segment, center: Point2, radius: f32) -> Option<Hit> {
+    let dir = segment.vector();
+    let (t0, t1) = circle_params(segment.start, dir, center, radius)?;
+    let t = first_within(t0, t1, 1.0)?;
+    let point = segment.point_at(t);
+    Some(Hit {
+        point,
+        normal: facing(point - center, dir),
+        t,
+    })
+}
+
+/// The point at which the ray first crosses the boundary of the given circle.
+///
+/// If the ray starts within the circle, this is the point at which it exits the circle.
+pub fn ray_circle(ray: Ray2, center: Point2, radius: f32) -> Option<Hit> {
+    let (t0, t1) = circle_params(ray.origin, ray.direction, center, radius)?;
+    let t = first_within(t0, t1, f32::INFINITY)?;
+    let point = ray.point_at(t);
+    Some(Hit {
+        point,
+        normal: facing(point - center, ray.direction),
+        t,
+    })
+}
+
+/// The point at which the segment first crosses the boundary of the given rectangle.
+///
+/// If the segment starts within the rectangle, this is the point at which it exits.
+pub fn segment_rect(segment: Segment, rect: Rect) -> Option<Hit> {
+    let dir = segment.vector();
+    let (t, normal) = first_rect_crossing(segment.start, dir, rect, 1.0)?;
+    Some(Hit {
+        point: segment.point_at(t),
+        normal: facing(normal, dir),
+        t,
+    })
+}
+
+/// The point at which the ray first crosses the boundary of the given rectangle.
+///
+/// If the ray starts within the rectangle, this is the point at which it exits.
+pub fn ray_rect(ray: Ray2, rect: Rect) -> Option<Hit> {
+    let (t, normal) = first_rect_crossing(ray.origin, ray.direction, rect, f32::INFINITY)?;
+    Some(Hit {
+        point: ray.point_at(t),
+        normal: facing(normal, ray.direction),
+        t,
+    })
+}
+
+/// The part of the segment that lies within the given rectangle.
+///
+/// This is the Liang–Barsky line clipping algorithm.
+pub fn clip_segment(segment: Segment, rect: Rect) -> Option<Segment> {
+    let ((t_enter, _), (t_exit, _)) = rect_params(segment.start, segment.vector(), rect)?;
+    let (t0, t1) = (t_enter.max(0.0), t_exit.min(1.0));
+    if t0 > t1 {
+        return None;
+    }
+    Some(Segment::new(segment.point_at(t0), segment.point_at(t1)))
+}
+
+/// The overlap between two rectangles.
+///
+/// The contact normal lies along the axis of least overlap. The contact point is the centre of
+/// the overlapping region. Rectangles that only touch do not overlap.
+///
+/// See `Rect::overlap_area` for the area of the overlapping region.
+pub fn rect_rect(a: Rect, b: Rect) -> Option<Contact> {
+    let overlap = a.overlap(b)?;
+    let (w, h) = overlap.w_h();
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+    let delta = b.xy() - a.xy();
+    let (normal, depth) = if w < h {
+        let x = if delta.x < 0.0 { -1.0 } else { 1.0 };
+        (Vec2::new(x, 0.0), w)
+    } else {
+        let y = if delta.y < 0.0 { -1.0 } else { 1.0 };
+        (Vec2::new(0.0, y), h)
+    };
+    Some(Contact {
+        point: overlap.xy(),
+        normal,
+        depth,
+    })
+}
+
+/// The overlap between two circles.
+///
+/// The contact point lies midway between the overlapping parts of the circles' boundaries.
+pub fn circle_circle(a: Point2, a_radius: f32, b: Point2, b_radius: f32) -> Option<Contact> {
+    let delta = b - a;
+    let distance = delta.length();
+    let depth = a_radius + b_radius - distance;
+    if depth <= 0.0 {
+        return None;
+    }
+    let normal = if distance > 0.0 {
+        delta / distance
+    } else {
+        Vec2::X
+    };
+    Some(Contact {
+        point: a + normal * (a_radius - depth * 0.5),
+        normal,
+        depth,
+    })
+}
+
+/// The overlap between two convex polygons, determined via the separating axis theorem.
+///
+/// The polygons may be wound in either direction. The contact point is the vertex of one polygon
+/// that lies deepest within the other. Polygons that only touch do not overlap.
+///
+/// The result is unspecified for concave polygons.
+pub fn polygon_polygon(a: &[Point2], b: &[Point2]) -> Option<Contact> {
+    // The axis of least overlap and whether it belongs to an edge of `a`.
+    let mut best: Option<(Vec2, f32, bool)> = None;
+    for (polygon, is_a) in [(a, true), (b, false)].iter() {
+        for edge in edges(polygon) {
+            let axis = edge.vector().perp().normalize_or_zero();
+            if axis == Vec2::ZERO {
+                continue;
+            }
+            let (a_min, a_max) = project_onto_axis(a, axis);
+            let (b_min, b_max) = project_onto_axis(b, axis);
+            // The distances `b` must move along the axis in either direction to separate.
+            let (forward, backward) = (a_max - b_min, b_max - a_min);
+            if forward <= 0.0 || backward <= 0.0 {
+                return None;
+            }
+            let (normal, depth) = if forward < backward {
+                (axis, forward)
+            } else {
+                (-axis, backward)
+            };
+            match best {
+                Some((_, d, _)) if d <= depth => (),
+                _ => best = Some((normal, depth, *is_a)),
+            }
+        }
+    }
+    let (normal, depth, is_a) = best?;
+    // The deepest vertex of the polygon whose edge was not used as the axis.
+    let point = if is_a {
+        support(b, -normal)
+    } else {
+        support(a, normal)
+    };
+    Some(Contact {
+        point,
+        normal,
+        depth,
+    })
+}
+
+/// The point on the segment closest to `p`.
+pub fn closest_point_on_segment(segment: Segment, p: Point2) -> Point2 {
+    let dir = segment.vector();
+    let len_sq = dir.length_squared();
+    if len_sq == 0.0 {
+        return segment.start;
+    }
+    let t = ((p - segment.start).dot(dir) / len_sq).clamp(0.0, 1.0);
+    segment.point_at(t)
+}
+
+/// The point on the boundary of the circle closest to `p`.
+pub fn closest_point_on_circle(center: Point2, radius: f32, p: Point2) -> Point2 {
+    let dir = (p - center).normalize_or_zero();
+    let dir = if dir == Vec2::ZERO { Vec2::X } else { dir };
+    center + dir * radius
+}
+
+/// The point on the boundary of the rectangle closest to `p`.
+pub fn closest_point_on_rect(rect: Rect, p: Point2) -> Point2 {
+    let (l, r, b, t) = (rect.left(), rect.right(), rect.bottom(), rect.top());
+    if !rect.contains(p) {
+        return Point2::new(p.x.clamp(l, r), p.y.clamp(b, t));
+    }
+    // Project onto the nearest edge.
+    let distances = [p.x - l, r - p.x, p.y - b, t - p.y];
+    let nearest = (0..4)
+        .min_by(|&i, &j| distances[i].partial_cmp(&distances[j]).unwrap())
+        .unwrap();
+    match nearest {
+        0 => Point2::new(l, p.y),
+        1 => Point2::new(r, p.y),
+        2 => Point2::new(p.x, b),
+        _ => Point2::new(p.x, t),
+    }
+}
+
+/// The point on the boundary of the closed polygon closest to `p`.
+///
+/// Returns `None` if the polygon has no points.
+pub fn closest_point_on_polygon(polygon: &[Point2], p: Point2) -> Option<Point2> {
+    edges(polygon)
+        .map(|edge| closest_point_on_segment(edge, p))
+        .fold(None, |nearest: Option<Point2>, q| match nearest {
+            Some(n) if n.distance_squared(p) <= q.distance_squared(p) => Some(n),
+            _ => Some(q),
+        })
+}
+
+/// The point at which the ray crosses the plane through `point` with the given `normal`.
+///
+/// Planes parallel to the ray are not intersected.
+pub fn ray_plane(ray: Ray3, point: Point3, normal: Vec3) -> Option<Hit3> {
+    let denom = normal.dot(ray.direction);
+    if denom == 0.0 {
+        return None;
+    }
+    let t = (point - ray.origin).dot(normal) / denom;
+    if t < 0.0 {
+        return None;
+    }
+    Some(Hit3 {
+        point: ray.point_at(t),
+        normal: facing3(normal, ray.direction),
+        t,
+    })
+}
+
+/// The point at which the ray first crosses the surface of the given sphere.
+///
+/// If the ray starts within the sphere, this is the point at which it exits the sphere.
+pub fn ray_sphere(ray: Ray3, center: Point3, radius: f32) -> Option<Hit3> {
+    let a = ray.direction.length_squared();
+    if a == 0.0 {
+        return None;
+    }
+    let offset = ray.origin - center;
+    let b = offset.dot(ray.direction);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = Float::sqrt(discriminant);
+    let t = first_within((-b - root) / a, (-b + root) / a, f32::INFINITY)?;
+    let point = ray.point_at(t);
+    Some(Hit3 {
+        point,
+        normal: facing3(point - center, ray.direction),
+        t,
+    })
+}
+
+/// The point at which the ray first crosses the surface of the given cuboid.
+///
+/// If the ray starts within the cuboid, this is the point at which it exits. This is the slab
+/// method, clipping the ray against each pair of parallel faces in turn.
+pub fn ray_cuboid(ray: Ray3, cuboid: Cuboid) -> Option<Hit3> {
+    let cuboid = cuboid.absolute();
+    let min = Vec3::new(cuboid.x.start, cuboid.y.start, cuboid.z.start);
+    let max = Vec3::new(cuboid.x.end, cuboid.y.end, cuboid.z.end);
+    let (origin, dir) = (ray.origin, ray.direction);
+    let mut enter = (f32::NEG_INFINITY, Vec3::ZERO);
+    let mut exit = (f32::INFINITY, Vec3::ZERO);
+    for axis in 0..3 {
+        let normal = Vec3::AXES[axis];
+        if dir[axis] == 0.0 {
+            if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let mut near = ((min[axis] - origin[axis]) / dir[axis], -normal);
+        let mut far = ((max[axis] - origin[axis]) / dir[axis], normal);
+        if near.0 > far.0 {
+            core::mem::swap(&mut near, &mut far);
+        }
+        if near.0 > enter.0 {
+            enter = near;
+        }
+        if far.0 < exit.0 {
+            exit = far;
+        }
+    }
+    if enter.0 > exit.0 {
+        return None;
+    }
+    let (t, normal) = [enter, exit]
+        .iter()
+        .cloned()
+        .find(|&(t, n)| n != Vec3::ZERO && t >= 0.0)?;
+    Some(Hit3 {
+        point: ray.point_at(t),
+        normal: facing3(normal, dir),
+        t,
+    })
+}
+
+/// The point at which the ray crosses the given triangle.
+///
+/// Triangles are intersected from either side, while triangles parallel to the ray are not
+/// intersected. This is the Möller–Trumbore algorithm.
+pub fn ray_tri<V>(ray: Ray3, tri: Tri<V>) -> Option<Hit3>
+where
+    V: vertex::Vertex3d<Scalar = f32>,
+{
+    let [a, b, c] = tri.0.map(|v| Point3::from(v.point3()));
+    let (e1, e2) = (b - a, c - a);
+    let p = ray.direction.cross(e2);
+    let det = e1.dot(p);
+    if det == 0.0 {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let s = ray.origin - a;
+    let u = s.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(e1);
+    let v = ray.direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = e2.dot(q) * inv_det;
+    if t < 0.0 {
+        return None;
+    }
+    Some(Hit3 {
+        point: ray.point_at(t),
+        normal: facing3(e1.cross(e2), ray.direction),
+        t,
+    })
+}
+
+/// The nearest point at which the ray crosses any of the given triangles, along with the index of
+/// the triangle that was hit.
+///
+/// This accepts any iterator of triangles, e.g. those produced by `Cuboid::triangles_iter` or
+/// `nannou_mesh::triangles`.
+pub fn ray_tris<I, V>(ray: Ray3, tris: I) -> Option<(usize, Hit3)>
+where
+    I: IntoIterator<Item = Tri<V>>,
+    V: vertex::Vertex3d<Scalar = f32>,
+{
+    tris.into_iter()
+        .enumerate()
+        .filter_map(|(i, tri)| ray_tri(ray, tri).map(|hit| (i, hit)))
+        .fold(
+            None,
+            |nearest: Option<(usize, Hit3)>, (i, hit)| match nearest {
+                Some(n) if n.1.t <= hit.t => Some(n),
+                _ => Some((i, hit)),
+            },
+        )
+}
+
+/// Project the point from world space into window coordinates.
+///
+/// The `x` and `y` of the result are in window coordinates, where the origin is the centre of the
+/// `window` rect and the y axis points upwards. The `z` is the depth within normalized device
+/// coordinates, where `0.0` lies on the near plane and `1.0` lies on the far plane.
+pub fn project(point: Point3, window: Rect, view_projection: Mat4) -> Point3 {
+    let ndc = view_projection.project_point3(point);
+    let half = window.wh() * 0.5;
+    (ndc.truncate() * half + window.xy()).extend(ndc.z)
+}
+
+/// Unproject the point from window coordinates into world space.
+///
+/// This is the inverse of **project**. The `x` and `y` of the point are in window coordinates,
+/// while the `z` is the depth within normalized device coordinates, where `0.0` lies on the near
+/// plane and `1.0` lies on the far plane.
+pub fn unproject(point: Point3, window: Rect, view_projection: Mat4) -> Point3 {
+    let half = window.wh() * 0.5;
+    let ndc = ((point.truncate() - window.xy()) / half).extend(point.z);
+    // Invert in double precision, as perspective projections with a distant far plane lose much of
+    // their precision when inverted in single precision.
+    let inverse = view_projection.as_f64().inverse();
+    inverse.project_point3(ndc.as_f64()).as_f32()
+}
+
+// Each edge of the closed polygon, including the edge from the last point to the first.
+fn edges(polygon: &[Point2]) -> impl Iterator<Item = Segment> + '_ {
+    let n = polygon.len();
+    (0..n).map(move |i| Segment::new(polygon[i], polygon[(i + 1) % n]))
+}
+
+// The parameters along `p + t * r` and `q + u * s` at which the lines cross, if not parallel.
+fn line_params(p: Point2, r: Vec2, q: Point2, s: Vec2) -> Option<(f32, f32)> {
+    let denom = r.perp_dot(s);
+    if denom == 0.0 {
+        return None;
+    }
+    let qp = q - p;
+    Some((qp.perp_dot(s) / denom, qp.perp_dot(r) / denom))
+}
+
+// The parameters along `origin + t * dir` at which the line enters and exits the circle.
+fn circle_params(origin: Point2, dir: Vec2, center: Point2, radius: f32) -> Option<(f32, f32)> {
+    let a = dir.length_squared();
+    if a == 0.0 {
+        return None;
+    }
+    let offset = origin - center;
+    let b = offset.dot(dir);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = Float::sqrt(discriminant);
+    Some(((-b - root) / a, (-b + root) / a))
+}
+
+// The parameters and outward normals at which `origin + t * dir` enters and exits the rect.
+fn rect_params(origin: Point2, dir: Vec2, rect: Rect) -> Option<((f32, Vec2), (f32, Vec2))> {
+    let min = Point2::new(rect.left(), rect.bottom());
+    let max = Point2::new(rect.right(), rect.top());
+    let mut enter = (f32::NEG_INFINITY, Vec2::ZERO);
+    let mut exit = (f32::INFINITY, Vec2::ZERO);
+    for axis in 0..2 {
+        let normal = if axis == 0 { Vec2::X } else { Vec2::Y };
+        if dir[axis] == 0.0 {
+            if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let mut near = ((min[axis] - origin[axis]) / dir[axis], -normal);
+        let mut far = ((max[axis] - origin[axis]) / dir[axis], normal);
+        if near.0 > far.0 {
+            core::mem::swap(&mut near, &mut far);
+        }
+        if near.0 > enter.0 {
+            enter = near;
+        }
+        if far.0 < exit.0 {
+            exit = far;
+        }
+    }
+    if enter.0 > exit.0 {
+        return None;
+    }
+    Some((enter, exit))
+}
+
+// The first parameter and outward normal at which `origin + t * dir` crosses the rect's boundary
+// within `0.0..=max_t`.
+fn first_rect_crossing(origin: Point2, dir: Vec2, rect: Rect, max_t: f32) -> Option<(f32, Vec2)> {
+    let (enter, exit) = rect_params(origin, dir, rect)?;
+    [enter, exit]
+        .iter()
+        .cloned()
+        .find(|&(t, n)| n != Vec2::ZERO && (0.0..=max_t).contains(&t))
+}
+
+// The first of the two ascending parameters within `0.0..=max_t`.
+fn first_within(t0: f32, t1: f32, max_t: f32) -> Option<f32> {
+    [t0, t1].iter().cloned().find(|t| (0.0..=max_t).contains(t))
+}
+
+// The given normal, normalised and flipped if necessary to face against the direction.
+fn facing(normal: Vec2, dir: Vec2) -> Vec2 {
+    let normal = normal.normalize_or_zero();
+    if normal.dot(dir) > 0.0 {
+        -normal
+    } else {
+        normal
+    }
+}
+
+// The given normal, normalised and flipped if necessary to face against the direction.
+fn facing3(normal: Vec3, dir: Vec3) -> Vec3 {
+    let normal = normal.normalize_or_zero();
+    if normal.dot(dir) > 0.0 {
+        -normal
+    } else {
+        normal
+    }
+}
+
+// The range covered by the projection of the points onto the axis.
+fn project_onto_axis(points: &[Point2], axis: Vec2) -> (f32, f32) {
+    points
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+            let d = p.dot(axis);
+            (min.min(d), max.max(d))
+        })
+}
+
+// The point furthest in the given direction.
+fn support(points: &[Point2], dir: Vec2) -> Point2 {
+    points.iter().cloned().fold(
+        points[0],
+        |best, p| {
+            if p.dot(dir) > best.dot(dir) {
+                p
+            } else {
+                best
+            }
+        },
+    )
+}
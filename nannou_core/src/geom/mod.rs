@@ -10,19 +10,39 @@
 //! - A function for finding the centroid.
 
 pub mod cuboid;
+pub mod curve;
+#[cfg(feature = "alloc")]
+pub mod delaunay;
 pub mod ellipse;
+#[cfg(feature = "alloc")]
+pub mod hull;
+pub mod intersect;
 pub mod point;
 pub mod polygon;
+#[cfg(feature = "alloc")]
+pub mod polyline;
 pub mod quad;
 pub mod range;
 pub mod rect;
 pub mod scalar;
+#[cfg(feature = "alloc")]
+pub mod spatial;
 pub mod tri;
 pub mod vector;
 pub mod vertex;
+#[cfg(feature = "alloc")]
+pub mod voronoi;
 
 pub use self::cuboid::Cuboid;
+#[cfg(feature = "alloc")]
+pub use self::curve::{BSpline, CatmullRom};
+pub use self::curve::{CubicBezier, Curve, QuadraticBezier};
+#[cfg(feature = "alloc")]
+pub use self::delaunay::Delaunay;
 pub use self::ellipse::Ellipse;
+#[cfg(feature = "alloc")]
+pub use self::hull::convex_hull;
+pub use self::intersect::{Contact, Hit, Hit3, Ray2, Ray3, Segment};
 pub use self::point::{pt2, pt3, pt4, Point2, Point3, Point4};
 pub use self::polygon::Polygon;
 pub use self::quad::Quad;
@@ -33,6 +53,8 @@ pub use self::tri::Tri;
 #[allow(deprecated)]
 pub use self::vector::{Vector2, Vector3, Vector4};
 pub use self::vertex::{Vertex, Vertex2d, Vertex3d};
+#[cfg(feature = "alloc")]
+pub use self::voronoi::Voronoi;
 pub use glam::{
     dvec2, dvec3, dvec4, ivec2, ivec3, ivec4, vec2, vec3, vec4, DVec2, DVec3, DVec4, IVec2, IVec3,
     IVec4, Vec2, Vec3, Vec4,
@@ -0,0 +1,600 @@
+//! Boolean operations on polygons described by lists of points.
+//!
+//! A polygon may consist of any number of contours, each of which is an implicitly closed list of
+//! points. The filled region is determined by the given **FillRule**, so holes may be described by
+//! either nested contours (`EvenOdd`) or contours with the opposite winding (`NonZero`). Contours
+//! may intersect themselves and each other.
+//!
+//! The resulting contours never intersect. Outer contours are wound counter-clockwise and holes
+//! clockwise, so the result may be filled using either fill rule.
+
+use crate::geom::Point2;
+use crate::math::num_traits::Float;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// The rule used to determine which regions are inside a polygon.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// A point is inside if a ray cast from it crosses the polygon's edges an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A point is inside if the winding number of the polygon around it is non-zero.
+    NonZero,
+}
+
+/// The boolean operations that may be performed between two polygons.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// The area covered by either polygon.
+    Union,
+    /// The area covered by both polygons.
+    Intersection,
+    /// The area covered by the subject but not the clip polygon.
+    Difference,
+    /// The area covered by exactly one of the polygons.
+    Xor,
+}
+
+// Relative to the extent of the input, the distance within which two vertices are merged.
+const MERGE_EPSILON: f32 = 1e-5;
+
+// Indices of the operands.
+const SUBJECT: usize = 0;
+const CLIP: usize = 1;
+
+// A line segment along with the index of the operand to which it belongs.
+type Segment = (Point2, Point2, usize);
+
+// Unique edges between merged vertices, mapped to the winding contributed to each operand when
+// directed from the lower to the higher vertex index.
+type Edges = BTreeMap<(usize, usize), [i32; 2]>;
+
+impl FillRule {
+    /// Whether or not the given winding number is considered inside.
+    pub fn is_inside(&self, winding: i32) -> bool {
+        match *self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+impl BooleanOp {
+    /// Whether or not a region is inside the result given whether it is inside the subject and
+    /// clip polygons.
+    pub fn is_inside(&self, subject: bool, clip: bool) -> bool {
+        match *self {
+            BooleanOp::Union => subject || clip,
+            BooleanOp::Intersection => subject && clip,
+            BooleanOp::Difference => subject && !clip,
+            BooleanOp::Xor => subject != clip,
+        }
+    }
+}
+
+/// Perform the given boolean operation between the `subject` and `clip` polygons.
+///
+/// Each polygon is described by a list of contours and is filled using the given `fill_rule`.
+///
+/// Returns the contours of the resulting polygon. Outer contours are wound counter-clockwise and
+/// holes are wound clockwise.
+pub fn boolean<A, CA, B, CB>(
+    op: BooleanOp,
+    subject: A,
+    clip: B,
+    fill_rule: FillRule,
+) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = CA>,
+    CA: IntoIterator<Item = Point2>,
+    B: IntoIterator<Item = CB>,
+    CB: IntoIterator<Item = Point2>,
+{
+    let subject = collect_contours(subject);
+    let clip = collect_contours(clip);
+    boolean_with(op, &subject, fill_rule, &clip, fill_rule)
+}
+
+/// The union of the `subject` and `clip` polygons using the default `EvenOdd` fill rule.
+pub fn union<A, CA, B, CB>(subject: A, clip: B) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = CA>,
+    CA: IntoIterator<Item = Point2>,
+    B: IntoIterator<Item = CB>,
+    CB: IntoIterator<Item = Point2>,
+{
+    boolean(BooleanOp::Union, subject, clip, FillRule::default())
+}
+
+/// The intersection of the `subject` and `clip` polygons using the default `EvenOdd` fill rule.
+pub fn intersection<A, CA, B, CB>(subject: A, clip: B) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = CA>,
+    CA: IntoIterator<Item = Point2>,
+    B: IntoIterator<Item = CB>,
+    CB: IntoIterator<Item = Point2>,
+{
+    boolean(BooleanOp::Intersection, subject, clip, FillRule::default())
+}
+
+/// The `subject` polygon with the `clip` polygon removed using the default `EvenOdd` fill rule.
+pub fn difference<A, CA, B, CB>(subject: A, clip: B) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = CA>,
+    CA: IntoIterator<Item = Point2>,
+    B: IntoIterator<Item = CB>,
+    CB: IntoIterator<Item = Point2>,
+{
+    boolean(BooleanOp::Difference, subject, clip, FillRule::default())
+}
+
+/// The area covered by exactly one of the `subject` and `clip` polygons using the default
+/// `EvenOdd` fill rule.
+pub fn xor<A, CA, B, CB>(subject: A, clip: B) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = CA>,
+    CA: IntoIterator<Item = Point2>,
+    B: IntoIterator<Item = CB>,
+    CB: IntoIterator<Item = Point2>,
+{
+    boolean(BooleanOp::Xor, subject, clip, FillRule::default())
+}
+
+/// Resolve all self-intersections and overlapping contours within the given polygon.
+///
+/// The result covers the same area as the given polygon under the given `fill_rule`, however its
+/// contours never intersect. Outer contours are wound counter-clockwise and holes clockwise.
+pub fn simplify<A, C>(contours: A, fill_rule: FillRule) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = C>,
+    C: IntoIterator<Item = Point2>,
+{
+    let contours = collect_contours(contours);
+    boolean_with(BooleanOp::Union, &contours, fill_rule, &[], fill_rule)
+}
+
+/// The winding number of the given contours around the point `p`.
+///
+/// Positive for counter-clockwise contours and negative for clockwise contours.
+pub fn winding_number(contours: &[Vec<Point2>], p: Point2) -> i32 {
+    let mut winding = 0;
+    for contour in contours {
+        for (a, b) in contour_edges(contour) {
+            let side = (b - a).perp_dot(p - a);
+            if a.y <= p.y {
+                if b.y > p.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= p.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+    winding
+}
+
+/// The signed area of the given contour.
+///
+/// Positive for counter-clockwise contours and negative for clockwise contours.
+pub fn signed_area(contour: &[Point2]) -> f32 {
+    contour_edges(contour)
+        .map(|(a, b)| a.perp_dot(b))
+        .sum::<f32>()
+        * 0.5
+}
+
+/// Perform the boolean operation between two polygons with their own fill rules.
+pub(crate) fn boolean_with(
+    op: BooleanOp,
+    subject: &[Vec<Point2>],
+    subject_fill_rule: FillRule,
+    clip: &[Vec<Point2>],
+    clip_fill_rule: FillRule,
+) -> Vec<Vec<Point2>> {
+    // Determine the scale of the input for our tolerances.
+    let extent = subject
+        .iter()
+        .chain(clip)
+        .flatten()
+        .fold(0.0f32, |max, p| max.max(p.x.abs()).max(p.y.abs()));
+    if extent == 0.0 {
+        return Vec::new();
+    }
+    let eps = extent * MERGE_EPSILON;
+
+    // Split all edges at their intersections and merge coincident vertices.
+    let segments: Vec<Segment> = subject
+        .iter()
+        .map(|c| (c, SUBJECT))
+        .chain(clip.iter().map(|c| (c, CLIP)))
+        .flat_map(|(c, operand)| contour_edges(c).map(move |(a, b)| (a, b, operand)))
+        .filter(|&(a, b, _)| a.distance_squared(b) > eps * eps)
+        .collect();
+    let split = split_segments(&segments, eps);
+    let (vertices, edges) = merge_vertices(&split, eps);
+
+    // Keep only the edges that separate the inside of the result from the outside, oriented so
+    // that the inside is on the left.
+    let fill_rules = [subject_fill_rule, clip_fill_rule];
+    let is_inside = |winding: [i32; 2]| {
+        let s = fill_rules[SUBJECT].is_inside(winding[SUBJECT]);
+        let c = fill_rules[CLIP].is_inside(winding[CLIP]);
+        op.is_inside(s, c)
+    };
+    let index = EdgeIndex::new(&vertices, &edges);
+    let mut directed = Vec::new();
+    for (&(a, b), &winding) in &edges {
+        let left = left_winding(&vertices, &index, (a, b), winding);
+        let right = [left[0] - winding[0], left[1] - winding[1]];
+        match (is_inside(left), is_inside(right)) {
+            (true, false) => directed.push((a, b)),
+            (false, true) => directed.push((b, a)),
+            _ => (),
+        }
+    }
+
+    let mut contours = chain_edges(&vertices, &directed);
+    for contour in &mut contours {
+        remove_collinear(contour, eps);
+    }
+    contours.retain(|c| c.len() >= 3);
+    contours
+}
+
+// Collect the given contours, removing consecutive duplicate points.
+pub(crate) fn collect_contours<A, C>(contours: A) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = C>,
+    C: IntoIterator<Item = Point2>,
+{
+    contours
+        .into_iter()
+        .map(|c| {
+            let mut contour: Vec<Point2> = Vec::new();
+            for p in c {
+                if contour.last() != Some(&p) {
+                    contour.push(p);
+                }
+            }
+            if contour.len() > 1 && contour.first() == contour.last() {
+                contour.pop();
+            }
+            contour
+        })
+        .filter(|c| c.len() >= 3)
+        .collect()
+}
+
+// Iterate over the edges of the implicitly closed contour.
+pub(crate) fn contour_edges(contour: &[Point2]) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+    let n = contour.len();
+    (0..n).map(move |i| (contour[i], contour[(i + 1) % n]))
+}
+
+// Split the segments at all points where they intersect or touch one another.
+fn split_segments(segments: &[Segment], eps: f32) -> Vec<Segment> {
+    // The points at which each segment must be split, along with their position along the segment.
+    let mut splits: Vec<Vec<(f32, Point2)>> = segments.iter().map(|_| Vec::new()).collect();
+
+    // Sweep across the segments from left to right, testing each only against the segments that
+    // begin before it ends.
+    let min_x = |i: usize| segments[i].0.x.min(segments[i].1.x);
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|&i, &j| {
+        min_x(i)
+            .partial_cmp(&min_x(j))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    for (k, &i) in order.iter().enumerate() {
+        let max_x = segments[i].0.x.max(segments[i].1.x) + eps;
+        for &j in order[k + 1..].iter().take_while(|&&j| min_x(j) <= max_x) {
+            split_pair(segments, (i.min(j), i.max(j)), eps, &mut splits);
+        }
+    }
+
+    let mut split = Vec::with_capacity(segments.len());
+    for (&(a, b, operand), points) in segments.iter().zip(&mut splits) {
+        points.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(core::cmp::Ordering::Equal));
+        let mut start = a;
+        for &(_, p) in points.iter() {
+            split.push((start, p, operand));
+            start = p;
+        }
+        split.push((start, b, operand));
+    }
+    split
+}
+
+// Find the points at which the segments `i` and `j` must be split where they intersect or touch.
+fn split_pair(
+    segments: &[Segment],
+    (i, j): (usize, usize),
+    eps: f32,
+    splits: &mut [Vec<(f32, Point2)>],
+) {
+    let (a, b, _) = segments[i];
+    let (c, d, _) = segments[j];
+    // Skip segments whose bounding boxes do not overlap.
+    if a.x.max(b.x) + eps < c.x.min(d.x)
+        || c.x.max(d.x) + eps < a.x.min(b.x)
+        || a.y.max(b.y) + eps < c.y.min(d.y)
+        || c.y.max(d.y) + eps < a.y.min(b.y)
+    {
+        return;
+    }
+    let r = b - a;
+    let s = d - c;
+    let denom = r.perp_dot(s);
+    let r_len = r.length();
+    let s_len = s.length();
+    if denom.abs() > 1e-6 * r_len * s_len {
+        let t = (c - a).perp_dot(s) / denom;
+        let u = (c - a).perp_dot(r) / denom;
+        let t_eps = eps / r_len;
+        let u_eps = eps / s_len;
+        if t < -t_eps || t > 1.0 + t_eps || u < -u_eps || u > 1.0 + u_eps {
+            return;
+        }
+        // Prefer existing endpoints to newly computed points.
+        let p = if t <= t_eps {
+            a
+        } else if t >= 1.0 - t_eps {
+            b
+        } else if u <= u_eps {
+            c
+        } else if u >= 1.0 - u_eps {
+            d
+        } else {
+            a + r * t
+        };
+        if t > t_eps && t < 1.0 - t_eps {
+            splits[i].push((t, p));
+        }
+        if u > u_eps && u < 1.0 - u_eps {
+            splits[j].push((u, p));
+        }
+    } else {
+        // Parallel segments only interact if they are collinear, in which case each is
+        // split at the endpoints of the other.
+        if (c - a).perp_dot(r).abs() > eps * r_len {
+            return;
+        }
+        for &p in &[c, d] {
+            let t = (p - a).dot(r) / (r_len * r_len);
+            if t * r_len > eps && (1.0 - t) * r_len > eps {
+                splits[i].push((t, p));
+            }
+        }
+        for &p in &[a, b] {
+            let u = (p - c).dot(s) / (s_len * s_len);
+            if u * s_len > eps && (1.0 - u) * s_len > eps {
+                splits[j].push((u, p));
+            }
+        }
+    }
+}
+
+// Merge all segment endpoints within `eps` of each other into shared vertices.
+//
+// Returns the vertices along with the unique, non-degenerate edges between them.
+fn merge_vertices(segments: &[Segment], eps: f32) -> (Vec<Point2>, Edges) {
+    let points: Vec<Point2> = segments.iter().flat_map(|&(a, b, _)| [a, b]).collect();
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| {
+        points[a]
+            .x
+            .partial_cmp(&points[b].x)
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    // Map each point to the index of the vertex it was merged into.
+    let mut vertex_of = alloc::vec![0; points.len()];
+    let mut vertices: Vec<Point2> = Vec::new();
+    for (k, &i) in order.iter().enumerate() {
+        let p = points[i];
+        let merged = order[..k]
+            .iter()
+            .rev()
+            .take_while(|&&j| p.x - points[j].x <= eps)
+            .find(|&&j| points[j].distance_squared(p) <= eps * eps);
+        vertex_of[i] = match merged {
+            Some(&j) => vertex_of[j],
+            None => {
+                vertices.push(p);
+                vertices.len() - 1
+            }
+        };
+    }
+
+    let mut edges = Edges::new();
+    for (i, &(_, _, operand)) in segments.iter().enumerate() {
+        let a = vertex_of[i * 2];
+        let b = vertex_of[i * 2 + 1];
+        if a != b {
+            let winding = edges.entry((a.min(b), a.max(b))).or_insert([0; 2]);
+            winding[operand] += if a < b { 1 } else { -1 };
+        }
+    }
+    (vertices, edges)
+}
+
+// The edges with a non-zero winding, along with the bands of each axis that they span.
+//
+// Allows rays cast along one axis to be tested only against the edges spanning the band of the
+// other axis in which the ray lies.
+struct EdgeIndex {
+    edges: Vec<((usize, usize), [i32; 2])>,
+    min: [f32; 2],
+    band_size: [f32; 2],
+    bands: [Vec<Vec<usize>>; 2],
+}
+
+impl EdgeIndex {
+    fn new(vertices: &[Point2], edges: &Edges) -> Self {
+        let edges: Vec<_> = edges
+            .iter()
+            .filter(|&(_, &winding)| winding != [0; 2])
+            .map(|(&edge, &winding)| (edge, winding))
+            .collect();
+        let n_bands = Float::ceil(Float::sqrt(edges.len() as f32)).max(1.0) as usize;
+        let mut index = EdgeIndex {
+            edges,
+            min: [0.0; 2],
+            band_size: [0.0; 2],
+            bands: [
+                (0..n_bands).map(|_| Vec::new()).collect(),
+                (0..n_bands).map(|_| Vec::new()).collect(),
+            ],
+        };
+        for axis in 0..2 {
+            let (min, max) = vertices
+                .iter()
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+                    (min.min(p[axis]), max.max(p[axis]))
+                });
+            index.min[axis] = min;
+            index.band_size[axis] = ((max - min) / n_bands as f32).max(f32::MIN_POSITIVE);
+            for (i, &((c, d), _)) in index.edges.iter().enumerate() {
+                let (pc, pd) = (vertices[c][axis], vertices[d][axis]);
+                let start = index.band(axis, pc.min(pd));
+                let end = index.band(axis, pc.max(pd));
+                for band in &mut index.bands[axis][start..=end] {
+                    band.push(i);
+                }
+            }
+        }
+        index
+    }
+
+    // The band of the given axis containing the coordinate `v`.
+    fn band(&self, axis: usize, v: f32) -> usize {
+        let band = ((v - self.min[axis]) / self.band_size[axis]) as usize;
+        band.min(self.bands[axis].len() - 1)
+    }
+}
+
+// The winding number of each operand immediately to the left of the given edge.
+//
+// Casts a ray from the middle of the edge along whichever axis is furthest from parallel with it,
+// summing the windings of all edges that cross it. The ray is aligned with the `x` axis of a
+// rotated frame in which edges are tested with the same half-open rule as `winding_number` so
+// that edges meeting at a vertex on the ray are counted exactly once.
+fn left_winding(
+    vertices: &[Point2],
+    index: &EdgeIndex,
+    (a, b): (usize, usize),
+    winding: [i32; 2],
+) -> [i32; 2] {
+    let (pa, pb) = (vertices[a], vertices[b]);
+    let origin = (pa + pb) * 0.5;
+    let along_x = (pb.y - pa.y).abs() >= (pb.x - pa.x).abs();
+    let frame = |p: Point2| {
+        let p = p - origin;
+        if along_x {
+            (p.x, p.y)
+        } else {
+            (p.y, -p.x)
+        }
+    };
+    let axis = if along_x { 1 } else { 0 };
+    let band = &index.bands[axis][index.band(axis, origin[axis])];
+    let mut side = [0; 2];
+    for &i in band {
+        let ((c, d), crossing) = index.edges[i];
+        if (c, d) == (a, b) {
+            continue;
+        }
+        let (cx, cy) = frame(vertices[c]);
+        let (dx, dy) = frame(vertices[d]);
+        let dir = if cy <= 0.0 && dy > 0.0 {
+            1
+        } else if dy <= 0.0 && cy > 0.0 {
+            -1
+        } else {
+            continue;
+        };
+        if cx + (dx - cx) * (-cy / (dy - cy)) > 0.0 {
+            side[SUBJECT] += dir * crossing[SUBJECT];
+            side[CLIP] += dir * crossing[CLIP];
+        }
+    }
+
+    // The ray leaves from the left of the edge if the edge points down within the frame.
+    if frame(pb).1 < frame(pa).1 {
+        side
+    } else {
+        [side[SUBJECT] + winding[SUBJECT], side[CLIP] + winding[CLIP]]
+    }
+}
+
+// Chain the directed edges into closed contours.
+//
+// At vertices shared by more than one contour, the sharpest left turn is taken so that contours
+// touching at a single vertex are kept separate.
+fn chain_edges(vertices: &[Point2], edges: &[(usize, usize)]) -> Vec<Vec<Point2>> {
+    let mut outgoing: Vec<Vec<usize>> = vertices.iter().map(|_| Vec::new()).collect();
+    for (i, &(a, _)) in edges.iter().enumerate() {
+        outgoing[a].push(i);
+    }
+    let mut used = alloc::vec![false; edges.len()];
+    let mut contours = Vec::new();
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut contour = Vec::new();
+        let mut edge = start;
+        loop {
+            used[edge] = true;
+            let (a, b) = edges[edge];
+            contour.push(vertices[a]);
+            if b == edges[start].0 {
+                break;
+            }
+            // Measure clockwise from the reversed incoming direction.
+            let back = vertices[a] - vertices[b];
+            let back_angle = Float::atan2(back.y, back.x);
+            let next = outgoing[b]
+                .iter()
+                .copied()
+                .filter(|&e| !used[e])
+                .map(|e| {
+                    let d = vertices[edges[e].1] - vertices[b];
+                    let mut angle = back_angle - Float::atan2(d.y, d.x);
+                    if angle <= 0.0 {
+                        angle += core::f32::consts::PI * 2.0;
+                    }
+                    (e, angle)
+                })
+                .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(core::cmp::Ordering::Equal));
+            match next {
+                Some((e, _)) => edge = e,
+                // Only possible for malformed input, e.g. NaN coordinates.
+                None => break,
+            }
+        }
+        contours.push(contour);
+    }
+    contours
+}
+
+// Remove vertices that lie on the straight line between their neighbours.
+fn remove_collinear(contour: &mut Vec<Point2>, eps: f32) {
+    let mut i = 0;
+    while contour.len() >= 3 && i < contour.len() {
+        let n = contour.len();
+        let prev = contour[(i + n - 1) % n];
+        let p = contour[i];
+        let next = contour[(i + 1) % n];
+        let d = next - prev;
+        let len = d.length();
+        let between = (p - prev).dot(d) >= 0.0 && (next - p).dot(d) >= 0.0;
+        if len > 0.0 && between && d.perp_dot(p - prev).abs() <= eps * len {
+            contour.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::geom::tri::Tri;
+use crate::geom::{Cuboid, Rect, Scalar, Vertex, Vertex2d, Vertex3d};
+use crate::math::num_traits::Zero;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+pub mod boolean;
+#[cfg(feature = "alloc")]
+pub mod offset;
+#[cfg(feature = "alloc")]
+pub mod triangulate;
+
+#[cfg(feature = "alloc")]
+pub use self::boolean::{
+    boolean, difference, intersection, simplify, union, xor, BooleanOp, FillRule,
+};
+#[cfg(feature = "alloc")]
+pub use self::offset::{offset, Join, OffsetOptions};
+#[cfg(feature = "alloc")]
+pub use self::triangulate::{crossings, triangulate, Crossing, SelfIntersection};
+
+/// A simple type wrapper around a list of points that describe a polygon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon<I> {
+    /// The iterator yielding all points in the polygon.
+    pub points: I,
+}
+
+/// An iterator yielding indices into a polygon's vertices required to triangulate the polygon.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct TriangleIndices {
+    indices: alloc::vec::IntoIter<usize>,
+}
+
+impl<I> Polygon<I>
+where
+    I: Iterator,
+{
+    /// Construct a new polygon from the given list of points describing its vertices.
+    pub fn new<P>(points: P) -> Self
+    where
+        P: IntoIterator<IntoIter = I, Item = I::Item>,
+    {
+        let points = points.into_iter();
+        Polygon { points }
+    }
+
+    /// Triangulate the polygon given as a list of `Point`s describing its sides.
+    ///
+    /// See the `triangles` function for details.
+    ///
+    /// Returns `None` if the polygon's iterator yields less than two points.
+    #[cfg(feature = "alloc")]
+    pub fn triangles(self) -> Option<Triangles<I>>
+    where
+        I::Item: Vertex2d,
+    {
+        triangles(self.points)
+    }
+
+    /// Returns `Some` with the touched triangle if the given `Point` is over the polygon described
+    /// by the given series of points.
+    ///
+    /// See the `contains` function for details.
+    pub fn contains(self, p: &I::Item) -> Option<Tri<I::Item>>
+    where
+        I::Item: Vertex2d,
+    {
+        contains(self.points, p)
+    }
+
+    /// The `Rect` that bounds the polygon.
+    ///
+    /// Returns `None` if the polygon's point iterator is empty.
+    pub fn bounding_rect(self) -> Option<Rect<<I::Item as Vertex>::Scalar>>
+    where
+        I::Item: Vertex2d,
+    {
+        super::bounding_rect(self.points)
+    }
+
+    /// The `Cuboid that bounds the polygon.
+    ///
+    /// Returns `None` if the polygon's point iterator is empty.
+    pub fn bounding_cuboid(self) -> Option<Cuboid<<I::Item as Vertex>::Scalar>>
+    where
+        I::Item: Vertex3d,
+    {
+        super::bounding_cuboid(self.points)
+    }
+}
+
+/// An iterator that triangulates a polygon represented by a sequence of points describing its
+/// edges.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Triangles<I>
+where
+    I: Iterator,
+{
+    triangles: alloc::vec::IntoIter<Tri<I::Item>>,
+}
+
+/// Triangulate the polygon given as a list of `Point`s describing its sides.
+///
+/// The polygon is triangulated within the *xy* plane via ear clipping, so concave polygons are
+/// handled correctly. Polygons whose edges cross one another produce a best-effort triangulation.
+/// See `polygon::triangulate` for polygons with holes or to detect crossing edges.
+///
+/// Returns `None` if the given iterator yields less than two points.
+#[cfg(feature = "alloc")]
+pub fn triangles<I>(points: I) -> Option<Triangles<I::IntoIter>>
+where
+    I: IntoIterator,
+    I::Item: Vertex2d,
+{
+    let points: Vec<_> = points.into_iter().collect();
+    if points.len() < 2 {
+        return None;
+    }
+    let triangles: Vec<_> = ear_clip(&points)
+        .into_iter()
+        .map(|[a, b, c]| Tri([points[a], points[b], points[c]]))
+        .collect();
+    let triangles = triangles.into_iter();
+    Some(Triangles { triangles })
+}
+
+/// An iterator yielding indices into the given polygon's vertices required to triangulate the
+/// polygon.
+///
+/// The polygon is triangulated in the same manner as the `triangles` function.
+#[cfg(feature = "alloc")]
+pub fn triangle_indices<I>(points: I) -> TriangleIndices
+where
+    I: IntoIterator,
+    I::Item: Vertex2d,
+{
+    let points: Vec<_> = points.into_iter().collect();
+    let indices: Vec<_> = ear_clip(&points).into_iter().flatten().collect();
+    let indices = indices.into_iter();
+    TriangleIndices { indices }
+}
+
+/// Returns `Some` with the touched triangle if the given `Point` is over the polygon described by
+/// the given series of points.
+///
+/// Whether or not the point is over the polygon is determined by its winding number, so concave
+/// and self-intersecting polygons are handled correctly and nothing is allocated. The returned
+/// triangle is formed by the first point and one of the polygon's edges, so it may extend beyond
+/// a concave polygon.
+pub fn contains<I>(points: I, point: &I::Item) -> Option<Tri<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Vertex2d,
+{
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    let mut prev = points.next()?;
+    let p = (*point).point2();
+    let mut winding = edge_winding(first.point2(), prev.point2(), p);
+    let mut touched = None;
+    for v in points {
+        winding += edge_winding(prev.point2(), v.point2(), p);
+        // The triangles of the fan from the first point cover every point with a non-zero
+        // winding number, including points on the fan's inner edges.
+        let tri = Tri([first, prev, v]);
+        if touched.is_none() && fan_contains(tri, p) {
+            touched = Some(tri);
+        }
+        prev = v;
+    }
+    winding += edge_winding(prev.point2(), first.point2(), p);
+    if winding != 0 {
+        touched
+    } else {
+        None
+    }
+}
+
+// Ear clip the polygon described by the given vertices within the *xy* plane.
+#[cfg(feature = "alloc")]
+fn ear_clip<V>(points: &[V]) -> Vec<[usize; 3]>
+where
+    V: Vertex2d,
+{
+    let coords: Vec<_> = points.iter().map(|&p| p.point2()).collect();
+    let contour = 0..coords.len();
+    triangulate::ear_clip(&coords, &[contour])
+}
+
+// The contribution of the edge `a` to `b` to the winding number around `p`.
+fn edge_winding<S>([ax, ay]: [S; 2], [bx, by]: [S; 2], [px, py]: [S; 2]) -> i32
+where
+    S: Scalar,
+{
+    let side = (bx - ax) * (py - ay) - (px - ax) * (by - ay);
+    if ay <= py {
+        if by > py && side > S::zero() {
+            return 1;
+        }
+    } else if by <= py && side < S::zero() {
+        return -1;
+    }
+    0
+}
+
+// Whether or not the triangle contains the point, including its edges.
+fn fan_contains<V>(tri: Tri<V>, p: [V::Scalar; 2]) -> bool
+where
+    V: Vertex2d,
+{
+    let [a, b, c] = tri.0;
+    let (a, b, c) = (a.point2(), b.point2(), c.point2());
+    let side = |[ax, ay]: [V::Scalar; 2], [bx, by]: [V::Scalar; 2]| {
+        (bx - ax) * (p[1] - ay) - (p[0] - ax) * (by - ay)
+    };
+    let (ab, bc, ca) = (side(a, b), side(b, c), side(c, a));
+    let zero = V::Scalar::zero();
+    (ab >= zero && bc >= zero && ca >= zero) || (ab <= zero && bc <= zero && ca <= zero)
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for Triangles<I>
+where
+    I: Iterator,
+{
+    type Item = Tri<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.triangles.next()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for TriangleIndices {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next()
+    }
+}
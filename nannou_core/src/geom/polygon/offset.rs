@@ -0,0 +1,189 @@
+//! Growing and shrinking polygons described by lists of points.
+
+use crate::geom::polygon::boolean::{self, BooleanOp, FillRule};
+use crate::geom::Point2;
+use crate::math::num_traits::Float;
+use alloc::vec::Vec;
+
+/// The shape used to join the offset edges at the corners of a polygon.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Join {
+    /// Extend the offset edges until they meet at a sharp corner.
+    ///
+    /// Corners that would extend further than `miter_limit` times the offset distance are
+    /// bevelled instead.
+    Miter,
+    /// Join the offset edges with a circular arc.
+    Round,
+    /// Join the offset edges with a straight line.
+    Bevel,
+}
+
+/// Parameters describing how a polygon is offset.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OffsetOptions {
+    /// The shape used to join offset edges at corners.
+    pub join: Join,
+    /// The limit of the ratio between the length of a miter and the offset distance.
+    pub miter_limit: f32,
+    /// The maximum distance between round joins and the true arc.
+    pub tolerance: f32,
+    /// The rule used to determine the filled region of the given polygon.
+    pub fill_rule: FillRule,
+}
+
+impl OffsetOptions {
+    /// The default miter limit, matching that of SVG.
+    pub const DEFAULT_MITER_LIMIT: f32 = 4.0;
+    /// The default tolerance used to approximate round joins.
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+    /// Specify the shape used to join offset edges at corners.
+    pub fn join(mut self, join: Join) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Specify the limit of the ratio between the length of a miter and the offset distance.
+    pub fn miter_limit(mut self, limit: f32) -> Self {
+        self.miter_limit = limit;
+        self
+    }
+
+    /// Specify the maximum distance between round joins and the true arc.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Specify the rule used to determine the filled region of the given polygon.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        OffsetOptions {
+            join: Join::Miter,
+            miter_limit: Self::DEFAULT_MITER_LIMIT,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            fill_rule: FillRule::default(),
+        }
+    }
+}
+
+/// Grow the polygon described by the given contours by `delta`, or shrink it if `delta` is
+/// negative.
+///
+/// The offset is applied to the filled region of the polygon, so holes shrink as the polygon
+/// grows and vice versa, regardless of the winding of the given contours. Parts of the polygon
+/// narrower than twice a negative `delta` disappear.
+///
+/// Returns the contours of the resulting polygon. Outer contours are wound counter-clockwise and
+/// holes are wound clockwise.
+pub fn offset<A, C>(contours: A, delta: f32, options: &OffsetOptions) -> Vec<Vec<Point2>>
+where
+    A: IntoIterator<Item = C>,
+    C: IntoIterator<Item = Point2>,
+{
+    let contours = boolean::collect_contours(contours);
+    let distance = delta.abs();
+    if distance == 0.0 {
+        return boolean::boolean_with(
+            BooleanOp::Union,
+            &contours,
+            options.fill_rule,
+            &[],
+            options.fill_rule,
+        );
+    }
+
+    // The area within `distance` of the polygon's edges, described by a counter-clockwise piece
+    // for each edge and each corner.
+    let mut pieces = Vec::new();
+    for contour in &contours {
+        let n = contour.len();
+        for i in 0..n {
+            let a = contour[i];
+            let b = contour[(i + 1) % n];
+            let c = contour[(i + 2) % n];
+            let ab = (b - a).normalize_or_zero();
+            let bc = (c - b).normalize_or_zero();
+            if ab == Point2::ZERO {
+                continue;
+            }
+            let n_ab = ab.perp() * distance;
+            pieces.push(ccw(alloc::vec![a - n_ab, b - n_ab, b + n_ab, a + n_ab]));
+            if bc != Point2::ZERO {
+                if let Some(piece) = join(b, ab, bc, distance, options) {
+                    pieces.push(ccw(piece));
+                }
+            }
+        }
+    }
+
+    let op = if delta > 0.0 {
+        BooleanOp::Union
+    } else {
+        BooleanOp::Difference
+    };
+    boolean::boolean_with(op, &contours, options.fill_rule, &pieces, FillRule::NonZero)
+}
+
+// The piece filling the gap between the offset edges on the outside of the corner at `p`.
+fn join(
+    p: Point2,
+    d1: Point2,
+    d2: Point2,
+    distance: f32,
+    options: &OffsetOptions,
+) -> Option<Vec<Point2>> {
+    let angle = Float::atan2(d1.perp_dot(d2), d1.dot(d2));
+    if angle.abs() <= f32::EPSILON {
+        return None;
+    }
+    // The gap is on the right of a left turn and on the left of a right turn.
+    let side = -angle.signum();
+    let n1 = d1.perp() * side;
+    let n2 = d2.perp() * side;
+    let start = p + n1 * distance;
+    let end = p + n2 * distance;
+    let piece = match options.join {
+        Join::Bevel => alloc::vec![p, start, end],
+        Join::Miter => {
+            let half = angle.abs() * 0.5;
+            let ratio = 1.0 / Float::cos(half);
+            if ratio > options.miter_limit {
+                alloc::vec![p, start, end]
+            } else {
+                let dir = (n1 + n2).normalize();
+                alloc::vec![p, start, p + dir * distance * ratio, end]
+            }
+        }
+        Join::Round => {
+            // The angle step such that the chord stays within the tolerance of the arc.
+            let tolerance = options.tolerance.max(distance * 1e-4).min(distance);
+            let max_step = 2.0 * Float::acos(1.0 - tolerance / distance);
+            let steps = Float::ceil(angle.abs() / max_step).max(1.0) as usize;
+            let mut piece = Vec::with_capacity(steps + 2);
+            piece.push(p);
+            let (start_angle, step) = (Float::atan2(n1.y, n1.x), angle / steps as f32);
+            for i in 0..=steps {
+                let a = start_angle + step * i as f32;
+                piece.push(p + Point2::new(Float::cos(a), Float::sin(a)) * distance);
+            }
+            piece
+        }
+    };
+    Some(piece)
+}
+
+// Ensure the given contour is wound counter-clockwise.
+fn ccw(mut contour: Vec<Point2>) -> Vec<Point2> {
+    if boolean::signed_area(&contour) < 0.0 {
+        contour.reverse();
+    }
+    contour
+}
@@ -0,0 +1,682 @@
+//! Triangulation of concave polygons with holes via ear clipping.
+//!
+//! The implementation is a port of the ear clipping approach used by the `earcut` library. Holes
+//! are joined to the outer contour via bridges before ears are clipped from the resulting contour.
+//! When no ear can be found, e.g. due to self-intersections, the contour is progressively cleaned
+//! up and split so that a best-effort triangulation is still produced.
+
+use crate::geom::{Point2, Scalar};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// A point at which two edges of a polygon's contours cross one another.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Crossing {
+    /// The edges that cross, each given by the index of its first vertex.
+    ///
+    /// Vertices are indexed as though the points of all contours were concatenated in order.
+    pub edges: [usize; 2],
+    /// The point at which the edges cross. For overlapping collinear edges, this is the start of
+    /// the overlap.
+    pub point: Point2,
+}
+
+/// The error returned when attempting to triangulate a polygon whose edges cross one another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfIntersection {
+    /// Every crossing found between the polygon's edges.
+    pub crossings: Vec<Crossing>,
+}
+
+// A vertex within one of the doubly linked contours used during ear clipping.
+#[derive(Clone, Debug)]
+struct Node<S> {
+    // The index of the vertex within the input.
+    i: usize,
+    x: S,
+    y: S,
+    prev: usize,
+    next: usize,
+    // Single-point holes must not be filtered.
+    steiner: bool,
+}
+
+struct EarClipper<S> {
+    nodes: Vec<Node<S>>,
+    triangles: Vec<[usize; 3]>,
+}
+
+/// Triangulate the polygon described by the given contours.
+///
+/// The first contour describes the outline of the polygon and each of the following contours
+/// describes a hole within it. Contours may be wound in either direction and may be concave.
+///
+/// Returns the indices of the vertices of each triangle, where vertices are indexed as though the
+/// points of all contours were concatenated in order. Triangles are wound counter-clockwise.
+///
+/// Returns an error describing each crossing if any of the edges cross one another. Such polygons
+/// may first be resolved into non-crossing contours via `polygon::simplify`.
+pub fn triangulate<C>(contours: &[C]) -> Result<Vec<[usize; 3]>, SelfIntersection>
+where
+    C: AsRef<[Point2]>,
+{
+    let crossings = crossings(contours);
+    if !crossings.is_empty() {
+        return Err(SelfIntersection { crossings });
+    }
+    let mut points = Vec::new();
+    let mut ranges = Vec::with_capacity(contours.len());
+    for contour in contours {
+        let start = points.len();
+        points.extend(contour.as_ref().iter().map(|p| [p.x, p.y]));
+        ranges.push(start..points.len());
+    }
+    Ok(ear_clip(&points, &ranges))
+}
+
+/// Find every point at which the edges of the given contours cross one another.
+///
+/// Edges that only touch at a shared vertex are not considered to cross, however collinear edges
+/// that overlap are.
+pub fn crossings<C>(contours: &[C]) -> Vec<Crossing>
+where
+    C: AsRef<[Point2]>,
+{
+    // Each edge along with the indices of its vertices.
+    let mut edges = Vec::new();
+    let mut start = 0;
+    for contour in contours {
+        let contour = contour.as_ref();
+        let n = contour.len();
+        for i in 0..n {
+            let j = (i + 1) % n;
+            if contour[i] != contour[j] {
+                edges.push((contour[i], contour[j], start + i, start + j));
+            }
+        }
+        start += n;
+    }
+
+    // Sweep along the x axis so that only edges with overlapping x ranges are compared.
+    let min_x = |e: &(Point2, Point2, usize, usize)| e.0.x.min(e.1.x);
+    edges.sort_by(|a, b| {
+        min_x(a)
+            .partial_cmp(&min_x(b))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    let mut crossings = Vec::new();
+    for (k, &(a, b, ai, bi)) in edges.iter().enumerate() {
+        let max_x = a.x.max(b.x);
+        for &(c, d, ci, di) in edges[k + 1..].iter() {
+            if min_x(&(c, d, ci, di)) > max_x {
+                break;
+            }
+            let adjacent = ai == di || bi == ci || ai == ci || bi == di;
+            if let Some(point) = crossing(a, b, c, d, adjacent) {
+                crossings.push(Crossing {
+                    edges: [ai.min(ci), ai.max(ci)],
+                    point,
+                });
+            }
+        }
+    }
+    crossings.sort_by_key(|c| c.edges);
+    crossings
+}
+
+// The point at which the segments `ab` and `cd` cross, if any.
+//
+// Segments only cross if their interiors intersect at a single point, or if they are collinear and
+// overlap. Adjacent segments may only cross by overlapping.
+fn crossing(a: Point2, b: Point2, c: Point2, d: Point2, adjacent: bool) -> Option<Point2> {
+    let (a, b, c, d) = (a.as_f64(), b.as_f64(), c.as_f64(), d.as_f64());
+    let r = b - a;
+    let s = d - c;
+    let denom = r.perp_dot(s);
+    if denom != 0.0 {
+        if adjacent {
+            return None;
+        }
+        let t = (c - a).perp_dot(s) / denom;
+        let u = (c - a).perp_dot(r) / denom;
+        if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 {
+            return Some((a + r * t).as_f32());
+        }
+        return None;
+    }
+    if (c - a).perp_dot(r) != 0.0 {
+        return None;
+    }
+    // Collinear, so compare the positions of `c` and `d` along `ab`.
+    let len_sq = r.length_squared();
+    let tc = (c - a).dot(r) / len_sq;
+    let td = (d - a).dot(r) / len_sq;
+    let (lo, hi) = (tc.min(td).max(0.0), tc.max(td).min(1.0));
+    if lo < hi {
+        Some((a + r * lo).as_f32())
+    } else {
+        None
+    }
+}
+
+// Triangulate the polygon whose contours are described by the given ranges of `points`.
+//
+// The first contour is the outline and the rest are holes. Unlike `triangulate`, this makes a
+// best effort to triangulate polygons whose edges cross one another.
+pub(crate) fn ear_clip<S>(points: &[[S; 2]], contours: &[Range<usize>]) -> Vec<[usize; 3]>
+where
+    S: Scalar,
+{
+    let mut clipper = EarClipper {
+        nodes: Vec::new(),
+        triangles: Vec::new(),
+    };
+    let outer = match contours.first() {
+        Some(range) => clipper.linked_list(points, range.clone(), true),
+        None => None,
+    };
+    let mut outer = match outer {
+        Some(outer) if clipper.next(outer) != clipper.prev(outer) => outer,
+        _ => return clipper.triangles,
+    };
+    if contours.len() > 1 {
+        outer = clipper.eliminate_holes(points, &contours[1..], outer);
+    }
+    clipper.earcut_linked(outer, 0);
+    clipper.triangles
+}
+
+impl<S> EarClipper<S>
+where
+    S: Scalar,
+{
+    fn next(&self, n: usize) -> usize {
+        self.nodes[n].next
+    }
+
+    fn prev(&self, n: usize) -> usize {
+        self.nodes[n].prev
+    }
+
+    fn i(&self, n: usize) -> usize {
+        self.nodes[n].i
+    }
+
+    fn xy(&self, n: usize) -> (S, S) {
+        (self.nodes[n].x, self.nodes[n].y)
+    }
+
+    fn equals(&self, a: usize, b: usize) -> bool {
+        self.xy(a) == self.xy(b)
+    }
+
+    // Twice the signed area of the triangle, negative for counter-clockwise triangles.
+    fn area(&self, p: usize, q: usize, r: usize) -> S {
+        let ((px, py), (qx, qy), (rx, ry)) = (self.xy(p), self.xy(q), self.xy(r));
+        (qy - py) * (rx - qx) - (qx - px) * (ry - qy)
+    }
+
+    // Create a contour from the given range of points with the given winding.
+    fn linked_list(&mut self, points: &[[S; 2]], range: Range<usize>, ccw: bool) -> Option<usize> {
+        let mut last = None;
+        if ccw == (signed_area(&points[range.clone()]) > S::zero()) {
+            for i in range {
+                last = Some(self.insert_node(i, points[i], last));
+            }
+        } else {
+            for i in range.rev() {
+                last = Some(self.insert_node(i, points[i], last));
+            }
+        }
+        if let Some(l) = last {
+            if self.equals(l, self.next(l)) {
+                self.remove_node(l);
+                last = Some(self.next(l));
+            }
+        }
+        last
+    }
+
+    fn insert_node(&mut self, i: usize, [x, y]: [S; 2], last: Option<usize>) -> usize {
+        let n = self.nodes.len();
+        let (prev, next) = match last {
+            None => (n, n),
+            Some(last) => (last, self.next(last)),
+        };
+        self.nodes.push(Node {
+            i,
+            x,
+            y,
+            prev,
+            next,
+            steiner: false,
+        });
+        if let Some(last) = last {
+            self.nodes[next].prev = n;
+            self.nodes[last].next = n;
+        }
+        n
+    }
+
+    fn remove_node(&mut self, n: usize) {
+        let (prev, next) = (self.prev(n), self.next(n));
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+    }
+
+    // Remove duplicate and collinear points between `start` and `end`.
+    fn filter_points(&mut self, start: usize, end: Option<usize>) -> usize {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+        loop {
+            let mut again = false;
+            let (prev, next) = (self.prev(p), self.next(p));
+            if !self.nodes[p].steiner
+                && (self.equals(p, next) || self.area(prev, p, next) == S::zero())
+            {
+                self.remove_node(p);
+                p = prev;
+                end = prev;
+                if p == self.next(p) {
+                    break;
+                }
+                again = true;
+            } else {
+                p = next;
+            }
+            if !again && p == end {
+                break;
+            }
+        }
+        end
+    }
+
+    // Clip ears from the contour, falling back to increasingly forgiving passes when stuck.
+    fn earcut_linked(&mut self, ear: usize, pass: u8) {
+        let mut ear = ear;
+        let mut stop = ear;
+        while self.prev(ear) != self.next(ear) {
+            let (prev, next) = (self.prev(ear), self.next(ear));
+            if self.is_ear(ear) {
+                self.triangles
+                    .push([self.i(prev), self.i(ear), self.i(next)]);
+                self.remove_node(ear);
+                ear = self.next(next);
+                stop = ear;
+                continue;
+            }
+            ear = next;
+            if ear == stop {
+                match pass {
+                    0 => {
+                        let ear = self.filter_points(ear, None);
+                        self.earcut_linked(ear, 1);
+                    }
+                    1 => {
+                        let ear = self.filter_points(ear, None);
+                        let ear = self.cure_local_intersections(ear);
+                        self.earcut_linked(ear, 2);
+                    }
+                    _ => self.split_earcut(ear),
+                }
+                break;
+            }
+        }
+    }
+
+    fn is_ear(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.prev(ear), ear, self.next(ear));
+        if self.area(a, b, c) >= S::zero() {
+            return false;
+        }
+        let (ta, tb, tc) = (self.xy(a), self.xy(b), self.xy(c));
+        let (x0, x1) = min_max(ta.0, tb.0, tc.0);
+        let (y0, y1) = min_max(ta.1, tb.1, tc.1);
+        let mut p = self.next(c);
+        while p != a {
+            let (px, py) = self.xy(p);
+            if px >= x0
+                && px <= x1
+                && py >= y0
+                && py <= y1
+                && point_in_triangle(ta, tb, tc, (px, py))
+                && self.area(self.prev(p), p, self.next(p)) >= S::zero()
+            {
+                return false;
+            }
+            p = self.next(p);
+        }
+        true
+    }
+
+    // Clip triangles formed by small loops where two consecutive edges cross.
+    fn cure_local_intersections(&mut self, start: usize) -> usize {
+        let mut start = start;
+        let mut p = start;
+        loop {
+            let a = self.prev(p);
+            let b = self.next(self.next(p));
+            if !self.equals(a, b)
+                && self.intersects(a, p, self.next(p), b)
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                self.triangles.push([self.i(a), self.i(p), self.i(b)]);
+                let next = self.next(p);
+                self.remove_node(p);
+                self.remove_node(next);
+                p = b;
+                start = b;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        self.filter_points(p, None)
+    }
+
+    // Split the contour in two along a valid diagonal and triangulate each half.
+    fn split_earcut(&mut self, start: usize) {
+        let mut a = start;
+        loop {
+            let mut b = self.next(self.next(a));
+            while b != self.prev(a) {
+                if self.i(a) != self.i(b) && self.is_valid_diagonal(a, b) {
+                    let c = self.split_polygon(a, b);
+                    let a = self.filter_points(a, Some(self.next(a)));
+                    let c = self.filter_points(c, Some(self.next(c)));
+                    self.earcut_linked(a, 0);
+                    self.earcut_linked(c, 0);
+                    return;
+                }
+                b = self.next(b);
+            }
+            a = self.next(a);
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    // Join each hole to the outer contour via a bridge, from left to right.
+    fn eliminate_holes(
+        &mut self,
+        points: &[[S; 2]],
+        holes: &[Range<usize>],
+        mut outer: usize,
+    ) -> usize {
+        let mut queue = Vec::with_capacity(holes.len());
+        for range in holes {
+            if let Some(list) = self.linked_list(points, range.clone(), false) {
+                if list == self.next(list) {
+                    self.nodes[list].steiner = true;
+                }
+                queue.push(self.leftmost(list));
+            }
+        }
+        queue.sort_by(|&a, &b| {
+            self.nodes[a]
+                .x
+                .partial_cmp(&self.nodes[b].x)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        for hole in queue {
+            outer = self.eliminate_hole(hole, outer);
+        }
+        outer
+    }
+
+    fn eliminate_hole(&mut self, hole: usize, outer: usize) -> usize {
+        match self.find_hole_bridge(hole, outer) {
+            None => outer,
+            Some(bridge) => {
+                let bridge_reverse = self.split_polygon(bridge, hole);
+                self.filter_points(bridge_reverse, Some(self.next(bridge_reverse)));
+                self.filter_points(bridge, Some(self.next(bridge)))
+            }
+        }
+    }
+
+    // Find a vertex of the outer contour visible from the leftmost vertex of the hole.
+    fn find_hole_bridge(&self, hole: usize, outer: usize) -> Option<usize> {
+        let (hx, hy) = self.xy(hole);
+        let mut qx = None;
+        let mut m = None;
+
+        // Find the nearest edge to the left of the hole vertex along a horizontal ray.
+        let mut p = outer;
+        loop {
+            let next = self.next(p);
+            let ((px, py), (nx, ny)) = (self.xy(p), self.xy(next));
+            if hy <= py && hy >= ny && ny != py {
+                let x = px + (hy - py) * (nx - px) / (ny - py);
+                let nearer = match qx {
+                    None => true,
+                    Some(qx) => x > qx,
+                };
+                if x <= hx && nearer {
+                    qx = Some(x);
+                    m = Some(if px < nx { p } else { next });
+                    if x == hx {
+                        return m;
+                    }
+                }
+            }
+            p = next;
+            if p == outer {
+                break;
+            }
+        }
+        let (mut m, qx) = (m?, qx?);
+
+        // Look for points inside the triangle formed by the hole vertex, the intersection point
+        // and the endpoint, choosing the one with the smallest angle to the ray.
+        let stop = m;
+        let (mx, my) = self.xy(m);
+        let mut tan_min = None;
+        p = m;
+        loop {
+            let (px, py) = self.xy(p);
+            let (ax, cx) = if hy < my { (hx, qx) } else { (qx, hx) };
+            if hx >= px
+                && px >= mx
+                && hx != px
+                && point_in_triangle((ax, hy), (mx, my), (cx, hy), (px, py))
+            {
+                let dy = if hy < py { py - hy } else { hy - py };
+                let tan = dy / (hx - px);
+                let better = match tan_min {
+                    None => true,
+                    Some(min) => {
+                        let m_x = self.nodes[m].x;
+                        tan < min
+                            || (tan == min
+                                && (px > m_x || (px == m_x && self.sector_contains_sector(m, p))))
+                    }
+                };
+                if self.locally_inside(p, hole) && better {
+                    m = p;
+                    tan_min = Some(tan);
+                }
+            }
+            p = self.next(p);
+            if p == stop {
+                break;
+            }
+        }
+        Some(m)
+    }
+
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        self.area(self.prev(m), m, self.prev(p)) < S::zero()
+            && self.area(self.next(p), m, self.next(m)) < S::zero()
+    }
+
+    fn leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+        loop {
+            let ((px, py), (lx, ly)) = (self.xy(p), self.xy(leftmost));
+            if px < lx || (px == lx && py < ly) {
+                leftmost = p;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        leftmost
+    }
+
+    // Whether a diagonal between `a` and `b` lies within the contour without crossing its edges.
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let (an, ap) = (self.next(a), self.prev(a));
+        let (bn, bp) = (self.next(b), self.prev(b));
+        let zero = S::zero();
+        self.i(an) != self.i(b)
+            && self.i(ap) != self.i(b)
+            && !self.intersects_polygon(a, b)
+            && ((self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+                && self.middle_inside(a, b)
+                && (self.area(ap, a, bp) != zero || self.area(a, bp, b) != zero))
+                || (self.equals(a, b)
+                    && self.area(ap, a, an) > zero
+                    && self.area(bp, b, bn) > zero))
+    }
+
+    // Whether the segments `p1q1` and `p2q2` intersect.
+    fn intersects(&self, p1: usize, q1: usize, p2: usize, q2: usize) -> bool {
+        let o1 = sign(self.area(p1, q1, p2));
+        let o2 = sign(self.area(p1, q1, q2));
+        let o3 = sign(self.area(p2, q2, p1));
+        let o4 = sign(self.area(p2, q2, q1));
+        (o1 != o2 && o3 != o4)
+            || (o1 == 0 && self.on_segment(p1, p2, q1))
+            || (o2 == 0 && self.on_segment(p1, q2, q1))
+            || (o3 == 0 && self.on_segment(p2, p1, q2))
+            || (o4 == 0 && self.on_segment(p2, q1, q2))
+    }
+
+    // Whether `q` lies within the bounds of the collinear segment `pr`.
+    fn on_segment(&self, p: usize, q: usize, r: usize) -> bool {
+        let ((px, py), (qx, qy), (rx, ry)) = (self.xy(p), self.xy(q), self.xy(r));
+        let (x0, x1) = if px < rx { (px, rx) } else { (rx, px) };
+        let (y0, y1) = if py < ry { (py, ry) } else { (ry, py) };
+        qx <= x1 && qx >= x0 && qy <= y1 && qy >= y0
+    }
+
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.i(a), self.i(b));
+        let mut p = a;
+        loop {
+            let next = self.next(p);
+            let (pi, ni) = (self.i(p), self.i(next));
+            if pi != ai && ni != ai && pi != bi && ni != bi && self.intersects(p, next, a, b) {
+                return true;
+            }
+            p = next;
+            if p == a {
+                return false;
+            }
+        }
+    }
+
+    // Whether the diagonal from `a` to `b` lies within the contour near `a`.
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let (prev, next) = (self.prev(a), self.next(a));
+        let zero = S::zero();
+        if self.area(prev, a, next) < zero {
+            self.area(a, b, next) >= zero && self.area(a, prev, b) >= zero
+        } else {
+            self.area(a, b, prev) < zero || self.area(a, next, b) < zero
+        }
+    }
+
+    // Whether the middle of the diagonal from `a` to `b` lies within the contour.
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let two = S::one() + S::one();
+        let ((ax, ay), (bx, by)) = (self.xy(a), self.xy(b));
+        let (mx, my) = ((ax + bx) / two, (ay + by) / two);
+        let mut inside = false;
+        let mut p = a;
+        loop {
+            let next = self.next(p);
+            let ((px, py), (nx, ny)) = (self.xy(p), self.xy(next));
+            if (py > my) != (ny > my) && ny != py && mx < (nx - px) * (my - py) / (ny - py) + px {
+                inside = !inside;
+            }
+            p = next;
+            if p == a {
+                return inside;
+            }
+        }
+    }
+
+    // Link `a` to `b` with a pair of duplicated vertices, splitting the contour in two.
+    //
+    // Returns the duplicate of `b`, which lies within the second contour.
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let (a2, b2) = (self.nodes.len(), self.nodes.len() + 1);
+        let (an, bp) = (self.next(a), self.prev(b));
+        let mut a_copy = self.nodes[a].clone();
+        let mut b_copy = self.nodes[b].clone();
+        a_copy.steiner = false;
+        b_copy.steiner = false;
+        self.nodes.push(a_copy);
+        self.nodes.push(b_copy);
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[a2].next = an;
+        self.nodes[an].prev = a2;
+        self.nodes[b2].next = a2;
+        self.nodes[a2].prev = b2;
+        self.nodes[bp].next = b2;
+        self.nodes[b2].prev = bp;
+        b2
+    }
+}
+
+impl fmt::Display for SelfIntersection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "polygon edges cross one another at {} point(s)",
+            self.crossings.len()
+        )
+    }
+}
+
+// Twice the signed area of the contour, positive for counter-clockwise contours.
+fn signed_area<S: Scalar>(points: &[[S; 2]]) -> S {
+    let n = points.len();
+    (0..n).fold(S::zero(), |sum, i| {
+        let ([ax, ay], [bx, by]) = (points[i], points[(i + 1) % n]);
+        sum + ax * by - bx * ay
+    })
+}
+
+fn point_in_triangle<S: Scalar>(a: (S, S), b: (S, S), c: (S, S), p: (S, S)) -> bool {
+    let ((ax, ay), (bx, by), (cx, cy), (px, py)) = (a, b, c, p);
+    (cx - px) * (ay - py) >= (ax - px) * (cy - py)
+        && (ax - px) * (by - py) >= (bx - px) * (ay - py)
+        && (bx - px) * (cy - py) >= (cx - px) * (by - py)
+}
+
+fn min_max<S: Scalar>(a: S, b: S, c: S) -> (S, S) {
+    let min = |a: S, b: S| if b < a { b } else { a };
+    let max = |a: S, b: S| if b > a { b } else { a };
+    (min(min(a, b), c), max(max(a, b), c))
+}
+
+fn sign<S: Scalar>(s: S) -> i8 {
+    if s > S::zero() {
+        1
+    } else if s < S::zero() {
+        -1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,438 @@
+//! Simplification, smoothing and curve fitting for polylines described by lists of points.
+//!
+//! Each function accepts a `closed` flag indicating whether or not the last point connects back
+//! to the first. Closed polylines should not repeat their first point at the end.
+//!
+//! To smooth a polyline with a curve passing through each of its points, see **CatmullRom**.
+
+use crate::geom::curve::{CubicBezier, Curve};
+use crate::geom::Point2;
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+// The maximum number of newton-raphson reparameterisation passes made while fitting a cubic.
+const MAX_REPARAMETERISATIONS: usize = 4;
+
+/// Simplify the polyline using the Ramer–Douglas–Peucker algorithm.
+///
+/// Points closer than `tolerance` to the simplified line are removed. The first and last points
+/// of an open polyline are always kept.
+pub fn rdp<I>(points: I, tolerance: f32, closed: bool) -> Vec<Point2>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let mut points: Vec<Point2> = points.into_iter().collect();
+    if closed && points.len() > 2 {
+        let first = points[0];
+        points.push(first);
+    }
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut keep = alloc::vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = alloc::vec![(0, points.len() - 1)];
+    if closed {
+        // Split the loop at the point furthest from the first, simplifying each half.
+        let furthest = furthest_from(&points, 0);
+        keep[furthest] = true;
+        stack = alloc::vec![(0, furthest), (furthest, points.len() - 1)];
+    }
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+        let (a, b) = (points[start], points[end]);
+        let (index, distance) = (start + 1..end)
+            .map(|i| (i, distance_to_segment(points[i], a, b)))
+            .fold((start, 0.0), |max, d| if d.1 > max.1 { d } else { max });
+        if distance > tolerance {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    let mut simplified: Vec<Point2> = points
+        .iter()
+        .zip(keep)
+        .filter(|&(_, keep)| keep)
+        .map(|(&p, _)| p)
+        .collect();
+    if closed {
+        simplified.pop();
+    }
+    simplified
+}
+
+/// Simplify the polyline using the Visvalingam–Whyatt algorithm.
+///
+/// Points are removed in order of the area of the triangle they form with their neighbours until
+/// all remaining triangles have an area of at least `min_area`. The first and last points of an
+/// open polyline are always kept, while a closed polyline always keeps at least three points.
+pub fn visvalingam<I>(points: I, min_area: f32, closed: bool) -> Vec<Point2>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let points: Vec<Point2> = points.into_iter().collect();
+    let n = points.len();
+    let min_len = if closed { 3 } else { 2 };
+    if n <= min_len {
+        return points;
+    }
+
+    // A doubly linked list over the remaining points.
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut removed = alloc::vec![false; n];
+    let mut areas = alloc::vec![f32::INFINITY; n];
+    let is_end = |i: usize| !closed && (i == 0 || i == n - 1);
+
+    let mut heap = BinaryHeap::new();
+    for i in 0..n {
+        if !is_end(i) {
+            areas[i] = triangle_area(points[prev[i]], points[i], points[next[i]]);
+            heap.push(Entry(areas[i], i));
+        }
+    }
+
+    let mut len = n;
+    while let Some(Entry(area, i)) = heap.pop() {
+        // Skip entries that were invalidated by the removal of a neighbour.
+        if removed[i] || area != areas[i] {
+            continue;
+        }
+        if area >= min_area || len <= min_len {
+            break;
+        }
+        removed[i] = true;
+        len -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+        // Neighbouring areas never drop below that of the removed point, so that points are
+        // removed in order of significance.
+        for &j in &[p, q] {
+            if !is_end(j) {
+                let a = triangle_area(points[prev[j]], points[j], points[next[j]]);
+                areas[j] = a.max(area);
+                heap.push(Entry(areas[j], j));
+            }
+        }
+    }
+
+    points
+        .iter()
+        .zip(removed)
+        .filter(|&(_, removed)| !removed)
+        .map(|(&p, _)| p)
+        .collect()
+}
+
+/// Smooth the polyline using Chaikin's corner cutting algorithm.
+///
+/// Each iteration replaces every corner with two points a quarter of the way along each adjacent
+/// edge, doubling the number of points. The first and last points of an open polyline are kept.
+pub fn chaikin<I>(points: I, iterations: usize, closed: bool) -> Vec<Point2>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let mut points: Vec<Point2> = points.into_iter().collect();
+    for _ in 0..iterations {
+        let n = points.len();
+        if n < 3 {
+            break;
+        }
+        let n_edges = if closed { n } else { n - 1 };
+        let mut smoothed = Vec::with_capacity(n_edges * 2 + 2);
+        if !closed {
+            smoothed.push(points[0]);
+        }
+        for i in 0..n_edges {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            if closed || i > 0 {
+                smoothed.push(a.lerp(b, 0.25));
+            }
+            if closed || i < n_edges - 1 {
+                smoothed.push(a.lerp(b, 0.75));
+            }
+        }
+        if !closed {
+            smoothed.push(points[n - 1]);
+        }
+        points = smoothed;
+    }
+    points
+}
+
+/// Fit a sequence of cubic bézier segments to the polyline using Schneider's algorithm.
+///
+/// Segments are split until no point is further than `tolerance` from the curve. Neighbouring
+/// segments share their tangents, so the resulting curve is smooth.
+pub fn fit_cubics<I>(points: I, tolerance: f32, closed: bool) -> Vec<CubicBezier<Point2>>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    // Duplicate points would produce degenerate tangents.
+    let mut deduped: Vec<Point2> = Vec::new();
+    for p in points {
+        if deduped.last() != Some(&p) {
+            deduped.push(p);
+        }
+    }
+    let mut points = deduped;
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let n = points.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let (start_tangent, end_tangent) = if closed && n > 2 {
+        let tangent = (points[1] - points[n - 1]).normalize_or_zero();
+        points.push(points[0]);
+        (tangent, -tangent)
+    } else {
+        let last = points.len() - 1;
+        (
+            (points[1] - points[0]).normalize_or_zero(),
+            (points[last - 1] - points[last]).normalize_or_zero(),
+        )
+    };
+    let mut cubics = Vec::new();
+    let last = points.len() - 1;
+    fit_cubic(
+        &points,
+        0,
+        last,
+        start_tangent,
+        end_tangent,
+        tolerance,
+        &mut cubics,
+    );
+    cubics
+}
+
+/// Sample each of the given cubic segments at `subdivisions` evenly spaced parameters, producing
+/// a polyline.
+///
+/// The first point of each segment is skipped if it matches the last point of the previous.
+pub fn sample_cubics(cubics: &[CubicBezier<Point2>], subdivisions: usize) -> Vec<Point2> {
+    let subdivisions = subdivisions.max(1);
+    let mut points: Vec<Point2> = Vec::with_capacity(cubics.len() * subdivisions + 1);
+    for cubic in cubics {
+        if points.last() != Some(&cubic.from) {
+            points.push(cubic.from);
+        }
+        for i in 1..=subdivisions {
+            points.push(cubic.point(i as f32 / subdivisions as f32));
+        }
+    }
+    points
+}
+
+// Fit a cubic to `points[first..=last]`, splitting recursively until within the tolerance.
+fn fit_cubic(
+    points: &[Point2],
+    first: usize,
+    last: usize,
+    start_tangent: Point2,
+    end_tangent: Point2,
+    tolerance: f32,
+    cubics: &mut Vec<CubicBezier<Point2>>,
+) {
+    let (p0, p3) = (points[first], points[last]);
+    if last - first == 1 {
+        let dist = p0.distance(p3) / 3.0;
+        let (ctrl1, ctrl2) = (p0 + start_tangent * dist, p3 + end_tangent * dist);
+        cubics.push(CubicBezier::new(p0, ctrl1, ctrl2, p3));
+        return;
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    let sub = &points[first..=last];
+    let mut params = chord_length_params(sub);
+    let mut cubic = generate_cubic(sub, &params, start_tangent, end_tangent);
+    let (mut error, mut split) = max_error(sub, &cubic, &params);
+    if error <= tolerance_sq {
+        cubics.push(cubic);
+        return;
+    }
+
+    // If close enough, try improving the parameterisation before splitting.
+    if error <= tolerance_sq * 4.0 {
+        for _ in 0..MAX_REPARAMETERISATIONS {
+            reparameterise(sub, &cubic, &mut params);
+            cubic = generate_cubic(sub, &params, start_tangent, end_tangent);
+            let (e, s) = max_error(sub, &cubic, &params);
+            error = e;
+            split = s;
+            if error <= tolerance_sq {
+                cubics.push(cubic);
+                return;
+            }
+        }
+    }
+
+    // Split at the point of maximum error and fit each side.
+    let center = (sub[split - 1] - sub[split + 1]).normalize_or_zero();
+    let split = first + split;
+    fit_cubic(
+        points,
+        first,
+        split,
+        start_tangent,
+        center,
+        tolerance,
+        cubics,
+    );
+    fit_cubic(points, split, last, -center, end_tangent, tolerance, cubics);
+}
+
+// Normalised parameters for each point in proportion to the distance along the polyline.
+fn chord_length_params(points: &[Point2]) -> Vec<f32> {
+    let mut params = Vec::with_capacity(points.len());
+    let mut total = 0.0;
+    params.push(0.0);
+    for w in points.windows(2) {
+        total += w[0].distance(w[1]);
+        params.push(total);
+    }
+    if total > 0.0 {
+        for u in &mut params {
+            *u /= total;
+        }
+    }
+    params
+}
+
+// The least-squares cubic with the given end tangents for the parameterised points.
+fn generate_cubic(
+    points: &[Point2],
+    params: &[f32],
+    start_tangent: Point2,
+    end_tangent: Point2,
+) -> CubicBezier<Point2> {
+    let (p0, p3) = (points[0], points[points.len() - 1]);
+    let mut c = [[0.0f32; 2]; 2];
+    let mut x = [0.0f32; 2];
+    for (&p, &u) in points.iter().zip(params) {
+        let mu = 1.0 - u;
+        let (b0, b1, b2, b3) = (mu * mu * mu, 3.0 * mu * mu * u, 3.0 * mu * u * u, u * u * u);
+        let a0 = start_tangent * b1;
+        let a1 = end_tangent * b2;
+        c[0][0] += a0.dot(a0);
+        c[0][1] += a0.dot(a1);
+        c[1][1] += a1.dot(a1);
+        let tmp = p - (p0 * (b0 + b1) + p3 * (b2 + b3));
+        x[0] += a0.dot(tmp);
+        x[1] += a1.dot(tmp);
+    }
+    c[1][0] = c[0][1];
+    let det_c0_c1 = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let det_c0_x = c[0][0] * x[1] - c[1][0] * x[0];
+    let det_x_c1 = x[0] * c[1][1] - x[1] * c[0][1];
+    let (alpha_l, alpha_r) = if det_c0_c1 == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (det_x_c1 / det_c0_c1, det_c0_x / det_c0_c1)
+    };
+
+    // Fall back to a heuristic if the least-squares solution is degenerate.
+    let seg_len = p0.distance(p3);
+    let epsilon = 1e-6 * seg_len;
+    if alpha_l < epsilon || alpha_r < epsilon {
+        let dist = seg_len / 3.0;
+        let (ctrl1, ctrl2) = (p0 + start_tangent * dist, p3 + end_tangent * dist);
+        return CubicBezier::new(p0, ctrl1, ctrl2, p3);
+    }
+    let (ctrl1, ctrl2) = (p0 + start_tangent * alpha_l, p3 + end_tangent * alpha_r);
+    CubicBezier::new(p0, ctrl1, ctrl2, p3)
+}
+
+// The maximum squared distance between the points and the curve, along with its index.
+fn max_error(points: &[Point2], cubic: &CubicBezier<Point2>, params: &[f32]) -> (f32, usize) {
+    let mut max = (0.0, points.len() / 2);
+    for i in 1..points.len() - 1 {
+        let d = cubic.point(params[i]).distance_squared(points[i]);
+        if d >= max.0 {
+            max = (d, i);
+        }
+    }
+    max
+}
+
+// Improve the parameter of each point via a newton-raphson step towards the closest point on the
+// curve.
+fn reparameterise(points: &[Point2], cubic: &CubicBezier<Point2>, params: &mut [f32]) {
+    // The second derivative is linear between its values at either end.
+    let dd0 = (cubic.from - cubic.ctrl1 * 2.0 + cubic.ctrl2) * 6.0;
+    let dd1 = (cubic.ctrl1 - cubic.ctrl2 * 2.0 + cubic.to) * 6.0;
+    for (&p, u) in points.iter().zip(params.iter_mut()) {
+        let t = *u;
+        let q = cubic.point(t);
+        let q1 = cubic.derivative(t);
+        let q2 = dd0.lerp(dd1, t);
+        let diff = q - p;
+        let numerator = diff.dot(q1);
+        let denominator = q1.dot(q1) + diff.dot(q2);
+        if denominator != 0.0 {
+            *u = (t - numerator / denominator).clamp(0.0, 1.0);
+        }
+    }
+}
+
+// The distance from `p` to the line segment `a` to `b`.
+fn distance_to_segment(p: Point2, a: Point2, b: Point2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 {
+        return p.distance(a);
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
+// The index of the point furthest from the point at index `i`.
+fn furthest_from(points: &[Point2], i: usize) -> usize {
+    let p = points[i];
+    (0..points.len())
+        .map(|j| (j, points[j].distance_squared(p)))
+        .fold((i, 0.0), |max, d| if d.1 > max.1 { d } else { max })
+        .0
+}
+
+// The area of the triangle `a`, `b`, `c`.
+fn triangle_area(a: Point2, b: Point2, c: Point2) -> f32 {
+    ((b - a).perp_dot(c - a) * 0.5).abs()
+}
+
+// An entry in the visvalingam heap, ordered so that the smallest area is popped first.
+struct Entry(f32, usize);
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .partial_cmp(&self.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.1.cmp(&self.1))
+    }
+}
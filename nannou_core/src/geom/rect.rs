@@ -732,6 +732,18 @@ where
         math::partial_max(self.w(), self.h())
     }
 
+    /// The area of the rectangle.
+    pub fn area(&self) -> S {
+        self.w() * self.h()
+    }
+
+    /// The area of the region in which two Rects overlap, or zero if they do not overlap.
+    pub fn overlap_area(self, other: Self) -> S {
+        self.overlap(other)
+            .map(|rect| rect.area())
+            .unwrap_or_else(S::zero)
+    }
+
     /// The left and top edges of the **Rect** along with the width and height.
     pub fn l_t_w_h(&self) -> (S, S, S, S) {
         let (w, h) = self.w_h();
@@ -0,0 +1,261 @@
+//! A uniform grid of cells stored within a hash table.
+
+use super::{
+    bounds_contain, distance_squared, nearest_limit, push_nearest, Candidate, Item, Point, Slab,
+};
+use crate::math::num_traits::Float;
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A grid of uniformly sized cells in which each item is stored within the cell containing it.
+///
+/// Rather than allocating every cell, cells are hashed into a table of buckets that grows with
+/// the number of items, allowing the grid to cover unbounded space.
+///
+/// Queries only visit the cells overlapping the queried region, making the grid ideal for finding
+/// items within a radius similar to the cell size, e.g. the neighbours of each agent in a flock.
+#[derive(Clone, Debug)]
+pub struct HashGrid<P, T> {
+    items: Slab<P, T>,
+    cell_size: f32,
+    buckets: Vec<Vec<usize>>,
+    // The index of the bucket holding each key.
+    locations: Vec<usize>,
+}
+
+// The coordinates of a cell, with unused axes set to zero.
+type Cell = [i32; 3];
+
+impl<P: Point, T> HashGrid<P, T> {
+    // The initial number of buckets, which must be a power of two.
+    const INITIAL_BUCKETS: usize = 64;
+
+    /// An empty grid with cells of the given size.
+    pub fn new(cell_size: f32) -> Self {
+        HashGrid {
+            items: Slab::new(),
+            cell_size,
+            buckets: vec![vec![]; Self::INITIAL_BUCKETS],
+            locations: vec![],
+        }
+    }
+
+    /// The width of each cell along every axis.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The number of items within the grid.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether or not the grid contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all items from the grid.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.buckets.iter_mut().for_each(|bucket| bucket.clear());
+        self.locations.clear();
+    }
+
+    /// Insert an item at the given point, returning its key.
+    pub fn insert(&mut self, point: P, value: T) -> usize {
+        let key = self.items.insert(point, value);
+        if self.locations.len() <= key {
+            self.locations.resize(key + 1, 0);
+        }
+        self.insert_key(key, point);
+        if self.len() > self.buckets.len() * 2 {
+            self.grow();
+        }
+        key
+    }
+
+    /// Remove the item with the given key, returning its value.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let (_, value) = self.items.remove(key)?;
+        self.remove_key(key);
+        Some(value)
+    }
+
+    /// Move the item with the given key to a new point.
+    ///
+    /// Returns `false` if there is no item with the given key.
+    pub fn update(&mut self, key: usize, point: P) -> bool {
+        match self.items.get_mut(key) {
+            None => return false,
+            Some(entry) => entry.0 = point,
+        }
+        let bucket = self.bucket(self.cell(point));
+        if bucket != self.locations[key] {
+            self.remove_key(key);
+            self.insert_key(key, point);
+        }
+        true
+    }
+
+    /// The item with the given key.
+    pub fn get(&self, key: usize) -> Option<Item<'_, P, T>> {
+        self.items.get(key).map(|_| self.items.item(key))
+    }
+
+    /// All items within the given bounds.
+    pub fn within_bounds(&self, bounds: P::Bounds) -> Vec<Item<'_, P, T>> {
+        let (min, max) = (P::bounds_min(bounds), P::bounds_max(bounds));
+        self.query(min, max, |p| bounds_contain(min, max, p))
+    }
+
+    /// All items within `radius` of the given point.
+    pub fn within_radius(&self, center: P, radius: f32) -> Vec<Item<'_, P, T>> {
+        let radius_sq = radius * radius;
+        let min = P::from_axes(|i| center.axis(i) - radius);
+        let max = P::from_axes(|i| center.axis(i) + radius);
+        self.query(min, max, |p| distance_squared(center, p) <= radius_sq)
+    }
+
+    /// The `k` items nearest to the given point, ordered from nearest to furthest.
+    ///
+    /// Cells are searched in rings of increasing distance from the point's cell, so this is
+    /// fastest when the nearest items lie within a few cells of the point.
+    pub fn nearest(&self, point: P, k: usize) -> Vec<Item<'_, P, T>> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut nearest = BinaryHeap::new();
+        let center = self.cell(point);
+        let push_key = |nearest: &mut BinaryHeap<Candidate>, key: usize| {
+            let dist_sq = distance_squared(point, self.items.item(key).point);
+            push_nearest(nearest, k, Candidate { dist_sq, key });
+        };
+        let mut ring: i32 = 0;
+        loop {
+            // Once there are more cells to check than items, check every item instead.
+            let side = (2 * ring + 1) as f64;
+            if Float::powi(side, P::DIMENSIONS as i32) > self.len() as f64 {
+                nearest.clear();
+                for key in self.items.keys() {
+                    push_key(&mut nearest, key);
+                }
+                break;
+            }
+            let (min, max) = (offset::<P>(center, -ring), offset::<P>(center, ring));
+            self.for_each_cell(min, max, |cell| {
+                let on_ring = (0..3).any(|i| (cell[i] - center[i]).abs() == ring);
+                if !on_ring {
+                    return;
+                }
+                for &key in &self.buckets[self.bucket(cell)] {
+                    if self.cell(self.items.item(key).point) == cell {
+                        push_key(&mut nearest, key);
+                    }
+                }
+            });
+            // All unvisited items are at least `ring` cells away.
+            let reach = ring as f32 * self.cell_size;
+            if nearest.len() >= k.min(self.len()) && nearest_limit(&nearest, k) <= reach * reach {
+                break;
+            }
+            ring += 1;
+        }
+        nearest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|c| self.items.item(c.key))
+            .collect()
+    }
+
+    // Collect the matching items within the cells overlapping the given bounds.
+    fn query<M>(&self, min: P, max: P, matches: M) -> Vec<Item<'_, P, T>>
+    where
+        M: Fn(P) -> bool,
+    {
+        let mut items = vec![];
+        let (min_cell, max_cell) = (self.cell(min), self.cell(max));
+        let n_cells = (0..3).fold(1.0, |n, i| {
+            n * (max_cell[i] as f64 - min_cell[i] as f64 + 1.0)
+        });
+        if n_cells > self.len() as f64 {
+            for key in self.items.keys() {
+                let item = self.items.item(key);
+                if matches(item.point) {
+                    items.push(item);
+                }
+            }
+            return items;
+        }
+        self.for_each_cell(min_cell, max_cell, |cell| {
+            for &key in &self.buckets[self.bucket(cell)] {
+                let item = self.items.item(key);
+                // Buckets may be shared by many cells.
+                if self.cell(item.point) == cell && matches(item.point) {
+                    items.push(item);
+                }
+            }
+        });
+        items
+    }
+
+    fn for_each_cell<F>(&self, min: Cell, max: Cell, mut f: F)
+    where
+        F: FnMut(Cell),
+    {
+        for x in min[0]..=max[0] {
+            for y in min[1]..=max[1] {
+                for z in min[2]..=max[2] {
+                    f([x, y, z]);
+                }
+            }
+        }
+    }
+
+    fn cell(&self, point: P) -> Cell {
+        let mut cell = [0; 3];
+        for (i, c) in cell.iter_mut().enumerate().take(P::DIMENSIONS) {
+            *c = Float::floor(point.axis(i) / self.cell_size) as i32;
+        }
+        cell
+    }
+
+    fn bucket(&self, cell: Cell) -> usize {
+        let hash = (cell[0] as usize).wrapping_mul(73_856_093)
+            ^ (cell[1] as usize).wrapping_mul(19_349_663)
+            ^ (cell[2] as usize).wrapping_mul(83_492_791);
+        hash & (self.buckets.len() - 1)
+    }
+
+    fn insert_key(&mut self, key: usize, point: P) {
+        let bucket = self.bucket(self.cell(point));
+        self.buckets[bucket].push(key);
+        self.locations[key] = bucket;
+    }
+
+    fn remove_key(&mut self, key: usize) {
+        let bucket = &mut self.buckets[self.locations[key]];
+        if let Some(i) = bucket.iter().position(|&k| k == key) {
+            bucket.swap_remove(i);
+        }
+    }
+
+    // Double the number of buckets and redistribute the items.
+    fn grow(&mut self) {
+        let n = self.buckets.len() * 2;
+        self.buckets.clear();
+        self.buckets.resize(n, vec![]);
+        let keys: Vec<usize> = self.items.keys().collect();
+        for key in keys {
+            let point = self.items.item(key).point;
+            self.insert_key(key, point);
+        }
+    }
+}
+
+// The cell offset by `d` along each of the point type's axes.
+fn offset<P: Point>(mut cell: Cell, d: i32) -> Cell {
+    cell.iter_mut().take(P::DIMENSIONS).for_each(|c| *c += d);
+    cell
+}
@@ -0,0 +1,308 @@
+//! A k-d tree that recursively splits space along the axis of greatest spread.
+
+use super::{
+    bounds_contain, distance_squared, nearest_limit, push_nearest, Candidate, Item, Point, Slab,
+};
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A k-d tree that recursively splits space along the axis of greatest spread.
+///
+/// Items are stored in buckets at the leaves of the tree. When a bucket holds more than the
+/// bucket size it is split in two along the axis in which its items are most spread out. Unlike a
+/// **Quadtree** or **Octree**, the tree requires no bounds and adapts to the distribution of its
+/// items, making it well suited to nearest neighbour queries.
+#[derive(Clone, Debug)]
+pub struct KdTree<P, T> {
+    items: Slab<P, T>,
+    nodes: Vec<Node>,
+    // The index of the leaf holding each key.
+    locations: Vec<usize>,
+    bucket_size: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Leaf(Vec<usize>),
+    // Points with a coordinate less than `value` along the axis lie to the `left`.
+    Split {
+        axis: usize,
+        value: f32,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl<P: Point, T> KdTree<P, T> {
+    /// The default number of items a leaf may hold before it is split.
+    pub const DEFAULT_BUCKET_SIZE: usize = 8;
+
+    /// An empty tree.
+    pub fn new() -> Self {
+        KdTree {
+            items: Slab::new(),
+            nodes: vec![Node::Leaf(vec![])],
+            locations: vec![],
+            bucket_size: Self::DEFAULT_BUCKET_SIZE,
+        }
+    }
+
+    /// Specify the number of items a leaf may hold before it is split.
+    pub fn bucket_size(mut self, bucket_size: usize) -> Self {
+        self.bucket_size = bucket_size.max(1);
+        self
+    }
+
+    /// The number of items within the tree.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether or not the tree contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all items from the tree.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.nodes.clear();
+        self.nodes.push(Node::Leaf(vec![]));
+        self.locations.clear();
+    }
+
+    /// Insert an item at the given point, returning its key.
+    pub fn insert(&mut self, point: P, value: T) -> usize {
+        let key = self.items.insert(point, value);
+        if self.locations.len() <= key {
+            self.locations.resize(key + 1, 0);
+        }
+        self.insert_key(key, point);
+        key
+    }
+
+    /// Remove the item with the given key, returning its value.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let (_, value) = self.items.remove(key)?;
+        self.remove_key(key);
+        Some(value)
+    }
+
+    /// Move the item with the given key to a new point.
+    ///
+    /// Returns `false` if there is no item with the given key.
+    pub fn update(&mut self, key: usize, point: P) -> bool {
+        match self.items.get_mut(key) {
+            None => return false,
+            Some(entry) => entry.0 = point,
+        }
+        self.remove_key(key);
+        self.insert_key(key, point);
+        true
+    }
+
+    /// The item with the given key.
+    pub fn get(&self, key: usize) -> Option<Item<'_, P, T>> {
+        self.items.get(key).map(|_| self.items.item(key))
+    }
+
+    /// All items within the given bounds.
+    pub fn within_bounds(&self, bounds: P::Bounds) -> Vec<Item<'_, P, T>> {
+        let (min, max) = (P::bounds_min(bounds), P::bounds_max(bounds));
+        self.query(
+            |axis, value| (min.axis(axis) < value, max.axis(axis) >= value),
+            |p| bounds_contain(min, max, p),
+        )
+    }
+
+    /// All items within `radius` of the given point.
+    pub fn within_radius(&self, center: P, radius: f32) -> Vec<Item<'_, P, T>> {
+        let radius_sq = radius * radius;
+        self.query(
+            |axis, value| {
+                let v = center.axis(axis);
+                (v - radius < value, v + radius >= value)
+            },
+            |p| distance_squared(center, p) <= radius_sq,
+        )
+    }
+
+    /// The `k` items nearest to the given point, ordered from nearest to furthest.
+    pub fn nearest(&self, point: P, k: usize) -> Vec<Item<'_, P, T>> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut nearest = BinaryHeap::new();
+        // Each node along with a lower bound on the squared distance to its items.
+        let mut stack = vec![(0, 0.0)];
+        while let Some((index, bound)) = stack.pop() {
+            if bound > nearest_limit(&nearest, k) {
+                continue;
+            }
+            match self.nodes[index] {
+                Node::Leaf(ref keys) => {
+                    for &key in keys {
+                        let dist_sq = distance_squared(point, self.items.item(key).point);
+                        push_nearest(&mut nearest, k, Candidate { dist_sq, key });
+                    }
+                }
+                Node::Split {
+                    axis,
+                    value,
+                    left,
+                    right,
+                } => {
+                    let d = point.axis(axis) - value;
+                    let (near, far) = if d < 0.0 {
+                        (left, right)
+                    } else {
+                        (right, left)
+                    };
+                    // Push the far side first so that the near side is searched first.
+                    stack.push((far, bound.max(d * d)));
+                    stack.push((near, bound));
+                }
+            }
+        }
+        nearest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|c| self.items.item(c.key))
+            .collect()
+    }
+
+    // Collect the items that match, descending into the left and/or right side of each split as
+    // indicated by `sides`.
+    fn query<S, M>(&self, sides: S, matches: M) -> Vec<Item<'_, P, T>>
+    where
+        S: Fn(usize, f32) -> (bool, bool),
+        M: Fn(P) -> bool,
+    {
+        let mut items = vec![];
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            match self.nodes[index] {
+                Node::Leaf(ref keys) => {
+                    for &key in keys {
+                        let item = self.items.item(key);
+                        if matches(item.point) {
+                            items.push(item);
+                        }
+                    }
+                }
+                Node::Split {
+                    axis,
+                    value,
+                    left,
+                    right,
+                } => {
+                    let (visit_left, visit_right) = sides(axis, value);
+                    if visit_left {
+                        stack.push(left);
+                    }
+                    if visit_right {
+                        stack.push(right);
+                    }
+                }
+            }
+        }
+        items
+    }
+
+    fn insert_key(&mut self, key: usize, point: P) {
+        let mut index = 0;
+        while let Node::Split {
+            axis,
+            value,
+            left,
+            right,
+        } = self.nodes[index]
+        {
+            index = if point.axis(axis) < value {
+                left
+            } else {
+                right
+            };
+        }
+        self.place(index, key);
+    }
+
+    fn remove_key(&mut self, key: usize) {
+        if let Node::Leaf(ref mut keys) = self.nodes[self.locations[key]] {
+            if let Some(i) = keys.iter().position(|&k| k == key) {
+                keys.swap_remove(i);
+            }
+        }
+    }
+
+    // Add the key to the given leaf, splitting the leaf if it is over capacity.
+    fn place(&mut self, index: usize, key: usize) {
+        self.locations[key] = index;
+        if let Node::Leaf(ref mut keys) = self.nodes[index] {
+            keys.push(key);
+            if keys.len() > self.bucket_size {
+                self.split(index);
+            }
+        }
+    }
+
+    fn split(&mut self, index: usize) {
+        let keys = match self.nodes[index] {
+            Node::Leaf(ref mut keys) => core::mem::take(keys),
+            Node::Split { .. } => return,
+        };
+
+        // Find the axis along which the points are most spread out.
+        let mut best = None;
+        for axis in 0..P::DIMENSIONS {
+            let (min, max) =
+                keys.iter()
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &k| {
+                        let v = self.items.item(k).point.axis(axis);
+                        (lo.min(v), hi.max(v))
+                    });
+            match best {
+                Some((_, lo, hi)) if hi - lo >= max - min => (),
+                _ => best = Some((axis, min, max)),
+            }
+        }
+        let (axis, min, max) = match best {
+            // All points are equal, so the leaf cannot be split.
+            Some((_, min, max)) if max <= min => {
+                self.nodes[index] = Node::Leaf(keys);
+                return;
+            }
+            Some(best) => best,
+            None => return,
+        };
+        let mut value = (min + max) * 0.5;
+        if value <= min {
+            value = max;
+        }
+
+        let (left, right) = (self.nodes.len(), self.nodes.len() + 1);
+        self.nodes.push(Node::Leaf(vec![]));
+        self.nodes.push(Node::Leaf(vec![]));
+        self.nodes[index] = Node::Split {
+            axis,
+            value,
+            left,
+            right,
+        };
+        for key in keys {
+            let side = if self.items.item(key).point.axis(axis) < value {
+                left
+            } else {
+                right
+            };
+            self.place(side, key);
+        }
+    }
+}
+
+impl<P: Point, T> Default for KdTree<P, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
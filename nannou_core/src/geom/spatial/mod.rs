@@ -0,0 +1,287 @@
+//! Spatial indices for quickly finding items near a point or within a region.
+//!
+//! - **Quadtree** and **Octree** recursively subdivide a **Rect** or **Cuboid** into quadrants or
+//!   octants as they fill up.
+//! - **KdTree** recursively splits space along the axis of greatest spread, adapting to the
+//!   distribution of its items without requiring bounds.
+//! - **HashGrid** buckets items into uniformly sized cells, ideal for radius queries where the
+//!   radius is similar to the cell size, e.g. flocking.
+//!
+//! Each index stores items of any type at a **Point**, either a `Vec2` or a `Vec3`. Inserting an
+//! item returns a key that may be used to `update` its position or `remove` it. Queries return
+//! each matching **Item** along with its key and position.
+
+use crate::geom::{Cuboid, Rect, Vec2, Vec3};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+pub mod grid;
+pub mod kdtree;
+pub mod tree;
+
+pub use self::grid::HashGrid;
+pub use self::kdtree::KdTree;
+pub use self::tree::{Octree, Quadtree, Tree};
+
+/// Point types that may be stored within spatial indices.
+pub trait Point: Copy + PartialEq {
+    /// The type describing an axis-aligned region of space, i.e. a **Rect** or **Cuboid**.
+    type Bounds: Copy + core::fmt::Debug;
+    /// The number of axes.
+    const DIMENSIONS: usize;
+
+    /// The coordinate along the given axis.
+    fn axis(self, axis: usize) -> f32;
+    /// Produce a point from its coordinate along each axis.
+    fn from_axes<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> f32;
+    /// The corner of the bounds with the lowest coordinates.
+    fn bounds_min(bounds: Self::Bounds) -> Self;
+    /// The corner of the bounds with the highest coordinates.
+    fn bounds_max(bounds: Self::Bounds) -> Self;
+}
+
+/// An item within a spatial index.
+#[derive(Debug)]
+pub struct Item<'a, P, T> {
+    /// The key returned when the item was inserted.
+    pub key: usize,
+    /// The position of the item.
+    pub point: P,
+    /// The item's value.
+    pub value: &'a T,
+}
+
+// Storage for the items of an index, reusing the keys of removed items.
+#[derive(Clone, Debug)]
+pub(crate) struct Slab<P, T> {
+    entries: Vec<Option<(P, T)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+// A neighbour candidate ordered by its distance, for use with a `BinaryHeap`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Candidate {
+    pub dist_sq: f32,
+    pub key: usize,
+}
+
+impl Point for Vec2 {
+    type Bounds = Rect;
+    const DIMENSIONS: usize = 2;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn from_axes<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> f32,
+    {
+        Vec2::new(f(0), f(1))
+    }
+
+    fn bounds_min(rect: Rect) -> Self {
+        Vec2::new(rect.left(), rect.bottom())
+    }
+
+    fn bounds_max(rect: Rect) -> Self {
+        Vec2::new(rect.right(), rect.top())
+    }
+}
+
+impl Point for Vec3 {
+    type Bounds = Cuboid;
+    const DIMENSIONS: usize = 3;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn from_axes<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> f32,
+    {
+        Vec3::new(f(0), f(1), f(2))
+    }
+
+    fn bounds_min(cuboid: Cuboid) -> Self {
+        let (x, y, z) = (
+            cuboid.x.absolute(),
+            cuboid.y.absolute(),
+            cuboid.z.absolute(),
+        );
+        Vec3::new(x.start, y.start, z.start)
+    }
+
+    fn bounds_max(cuboid: Cuboid) -> Self {
+        let (x, y, z) = (
+            cuboid.x.absolute(),
+            cuboid.y.absolute(),
+            cuboid.z.absolute(),
+        );
+        Vec3::new(x.end, y.end, z.end)
+    }
+}
+
+impl<'a, P: Clone, T> Clone for Item<'a, P, T> {
+    fn clone(&self) -> Self {
+        Item {
+            key: self.key,
+            point: self.point.clone(),
+            value: self.value,
+        }
+    }
+}
+
+impl<'a, P: Copy, T> Copy for Item<'a, P, T> {}
+
+impl<P, T> Slab<P, T> {
+    pub fn new() -> Self {
+        Slab {
+            entries: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.free.clear();
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, point: P, value: T) -> usize {
+        self.len += 1;
+        match self.free.pop() {
+            Some(key) => {
+                self.entries[key] = Some((point, value));
+                key
+            }
+            None => {
+                self.entries.push(Some((point, value)));
+                self.entries.len() - 1
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: usize) -> Option<(P, T)> {
+        let entry = self.entries.get_mut(key)?.take()?;
+        self.free.push(key);
+        self.len -= 1;
+        Some(entry)
+    }
+
+    pub fn get(&self, key: usize) -> Option<&(P, T)> {
+        self.entries.get(key)?.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut (P, T)> {
+        self.entries.get_mut(key)?.as_mut()
+    }
+
+    // The item at the given key, which must be occupied.
+    pub fn item(&self, key: usize) -> Item<'_, P, T>
+    where
+        P: Copy,
+    {
+        let (point, ref value) = *self.get(key).expect("no item for key");
+        Item { key, point, value }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = usize> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(key, entry)| entry.as_ref().map(|_| key))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist_sq
+            .partial_cmp(&other.dist_sq)
+            .unwrap_or(Ordering::Equal)
+            .then(self.key.cmp(&other.key))
+    }
+}
+
+// The squared distance between two points.
+pub(crate) fn distance_squared<P: Point>(a: P, b: P) -> f32 {
+    (0..P::DIMENSIONS)
+        .map(|i| {
+            let d = a.axis(i) - b.axis(i);
+            d * d
+        })
+        .sum()
+}
+
+// The squared distance from the point to the nearest point within the bounds.
+pub(crate) fn distance_squared_to_bounds<P: Point>(p: P, min: P, max: P) -> f32 {
+    (0..P::DIMENSIONS)
+        .map(|i| {
+            let v = p.axis(i);
+            let d = if v < min.axis(i) {
+                min.axis(i) - v
+            } else if v > max.axis(i) {
+                v - max.axis(i)
+            } else {
+                0.0
+            };
+            d * d
+        })
+        .sum()
+}
+
+// Whether or not the point lies within the bounds, inclusive.
+pub(crate) fn bounds_contain<P: Point>(min: P, max: P, p: P) -> bool {
+    (0..P::DIMENSIONS).all(|i| min.axis(i) <= p.axis(i) && p.axis(i) <= max.axis(i))
+}
+
+// Whether or not the two bounds overlap, inclusive.
+pub(crate) fn bounds_overlap<P: Point>(a_min: P, a_max: P, b_min: P, b_max: P) -> bool {
+    (0..P::DIMENSIONS).all(|i| a_min.axis(i) <= b_max.axis(i) && b_min.axis(i) <= a_max.axis(i))
+}
+
+// Push the candidate onto the max-heap of the `k` nearest candidates found so far.
+pub(crate) fn push_nearest(
+    nearest: &mut alloc::collections::BinaryHeap<Candidate>,
+    k: usize,
+    candidate: Candidate,
+) {
+    if nearest.len() < k {
+        nearest.push(candidate);
+    } else if let Some(mut worst) = nearest.peek_mut() {
+        if candidate < *worst {
+            *worst = candidate;
+        }
+    }
+}
+
+// The squared distance beyond which candidates can no longer be among the `k` nearest.
+pub(crate) fn nearest_limit(nearest: &alloc::collections::BinaryHeap<Candidate>, k: usize) -> f32 {
+    match nearest.peek() {
+        Some(worst) if nearest.len() >= k => worst.dist_sq,
+        _ => f32::INFINITY,
+    }
+}
@@ -0,0 +1,311 @@
+//! Quadtrees and octrees that recursively subdivide a region of space as they fill up.
+
+use super::{
+    bounds_contain, bounds_overlap, distance_squared, distance_squared_to_bounds, nearest_limit,
+    push_nearest, Candidate, Item, Point, Slab,
+};
+use crate::geom::{Vec2, Vec3};
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// A tree that subdivides a **Rect** into quadrants as it fills up.
+pub type Quadtree<T> = Tree<Vec2, T>;
+
+/// A tree that subdivides a **Cuboid** into octants as it fills up.
+pub type Octree<T> = Tree<Vec3, T>;
+
+/// A tree that recursively subdivides its bounds into `2^DIMENSIONS` equally sized children
+/// whenever a node holds more than its capacity.
+///
+/// See the **Quadtree** and **Octree** aliases.
+#[derive(Clone, Debug)]
+pub struct Tree<P: Point, T> {
+    bounds: P::Bounds,
+    items: Slab<P, T>,
+    nodes: Vec<Node<P>>,
+    // The index of the node holding each key.
+    locations: Vec<usize>,
+    capacity: usize,
+    max_depth: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<P> {
+    min: P,
+    max: P,
+    depth: usize,
+    keys: Vec<usize>,
+    // The index of the first of the node's children, which are stored consecutively.
+    children: Option<usize>,
+}
+
+impl<P: Point, T> Tree<P, T> {
+    /// The default number of items a node may hold before it is subdivided.
+    pub const DEFAULT_CAPACITY: usize = 8;
+    /// The default maximum depth of the tree.
+    pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+    /// An empty tree covering the given bounds.
+    ///
+    /// Items may be inserted outside of the bounds, however they are stored within the root node
+    /// and are checked by every query.
+    pub fn new(bounds: P::Bounds) -> Self {
+        Tree {
+            bounds,
+            items: Slab::new(),
+            nodes: vec![Node::new(P::bounds_min(bounds), P::bounds_max(bounds), 0)],
+            locations: vec![],
+            capacity: Self::DEFAULT_CAPACITY,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Specify the number of items a node may hold before it is subdivided.
+    pub fn node_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Specify the maximum depth of the tree.
+    ///
+    /// Nodes at the maximum depth are never subdivided, limiting the cost of many items sharing
+    /// the same position.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The bounds covered by the tree.
+    pub fn bounds(&self) -> P::Bounds {
+        self.bounds
+    }
+
+    /// The number of items within the tree.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether or not the tree contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all items from the tree.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.nodes.truncate(1);
+        self.nodes[0].keys.clear();
+        self.nodes[0].children = None;
+        self.locations.clear();
+    }
+
+    /// Insert an item at the given point, returning its key.
+    pub fn insert(&mut self, point: P, value: T) -> usize {
+        let key = self.items.insert(point, value);
+        if self.locations.len() <= key {
+            self.locations.resize(key + 1, 0);
+        }
+        self.insert_key(key, point);
+        key
+    }
+
+    /// Remove the item with the given key, returning its value.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let (_, value) = self.items.remove(key)?;
+        self.remove_key(key);
+        Some(value)
+    }
+
+    /// Move the item with the given key to a new point.
+    ///
+    /// Returns `false` if there is no item with the given key.
+    pub fn update(&mut self, key: usize, point: P) -> bool {
+        match self.items.get_mut(key) {
+            None => return false,
+            Some(entry) => entry.0 = point,
+        }
+        self.remove_key(key);
+        self.insert_key(key, point);
+        true
+    }
+
+    /// The item with the given key.
+    pub fn get(&self, key: usize) -> Option<Item<'_, P, T>> {
+        self.items.get(key).map(|_| self.items.item(key))
+    }
+
+    /// All items within the given bounds.
+    pub fn within_bounds(&self, bounds: P::Bounds) -> Vec<Item<'_, P, T>> {
+        let (min, max) = (P::bounds_min(bounds), P::bounds_max(bounds));
+        self.query(
+            |node| bounds_overlap(node.min, node.max, min, max),
+            |p| bounds_contain(min, max, p),
+        )
+    }
+
+    /// All items within `radius` of the given point.
+    pub fn within_radius(&self, center: P, radius: f32) -> Vec<Item<'_, P, T>> {
+        let radius_sq = radius * radius;
+        self.query(
+            |node| distance_squared_to_bounds(center, node.min, node.max) <= radius_sq,
+            |p| distance_squared(center, p) <= radius_sq,
+        )
+    }
+
+    /// The `k` items nearest to the given point, ordered from nearest to furthest.
+    pub fn nearest(&self, point: P, k: usize) -> Vec<Item<'_, P, T>> {
+        let mut nearest = BinaryHeap::new();
+        if k == 0 {
+            return vec![];
+        }
+        // Visit nodes in order of their distance from the point.
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse(Candidate {
+            dist_sq: 0.0,
+            key: 0,
+        }));
+        while let Some(Reverse(candidate)) = queue.pop() {
+            if candidate.dist_sq > nearest_limit(&nearest, k) {
+                break;
+            }
+            let node = &self.nodes[candidate.key];
+            for &key in &node.keys {
+                let dist_sq = distance_squared(point, self.items.item(key).point);
+                push_nearest(&mut nearest, k, Candidate { dist_sq, key });
+            }
+            if let Some(first) = node.children {
+                for child in first..first + (1 << P::DIMENSIONS) {
+                    let c = &self.nodes[child];
+                    let dist_sq = distance_squared_to_bounds(point, c.min, c.max);
+                    queue.push(Reverse(Candidate {
+                        dist_sq,
+                        key: child,
+                    }));
+                }
+            }
+        }
+        nearest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|c| self.items.item(c.key))
+            .collect()
+    }
+
+    // Visit each node for which `visit_node` returns `true`, collecting the items that match.
+    // The root node is always visited as it may contain items outside the bounds.
+    fn query<N, M>(&self, visit_node: N, matches: M) -> Vec<Item<'_, P, T>>
+    where
+        N: Fn(&Node<P>) -> bool,
+        M: Fn(P) -> bool,
+    {
+        let mut items = vec![];
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            for &key in &node.keys {
+                let item = self.items.item(key);
+                if matches(item.point) {
+                    items.push(item);
+                }
+            }
+            if let Some(first) = node.children {
+                let children = first..first + (1 << P::DIMENSIONS);
+                stack.extend(children.filter(|&child| visit_node(&self.nodes[child])));
+            }
+        }
+        items
+    }
+
+    fn insert_key(&mut self, key: usize, point: P) {
+        let mut index = 0;
+        loop {
+            let node = &self.nodes[index];
+            match node.children {
+                Some(first) if bounds_contain(node.min, node.max, point) => {
+                    index = first + node.child_index(point);
+                }
+                _ => break,
+            }
+        }
+        self.place(index, key);
+    }
+
+    fn remove_key(&mut self, key: usize) {
+        let keys = &mut self.nodes[self.locations[key]].keys;
+        if let Some(i) = keys.iter().position(|&k| k == key) {
+            keys.swap_remove(i);
+        }
+    }
+
+    // Add the key to the given node, subdividing the node if it is over capacity.
+    fn place(&mut self, index: usize, key: usize) {
+        self.locations[key] = index;
+        let node = &mut self.nodes[index];
+        node.keys.push(key);
+        if node.children.is_none() && node.keys.len() > self.capacity && node.depth < self.max_depth
+        {
+            self.subdivide(index);
+        }
+    }
+
+    fn subdivide(&mut self, index: usize) {
+        let (min, max, depth) = {
+            let node = &self.nodes[index];
+            (node.min, node.max, node.depth)
+        };
+        let center = P::from_axes(|i| (min.axis(i) + max.axis(i)) * 0.5);
+        let first = self.nodes.len();
+        for child in 0..(1 << P::DIMENSIONS) {
+            let upper = |i: usize| child & (1 << i) != 0;
+            let c_min = P::from_axes(|i| {
+                if upper(i) {
+                    center.axis(i)
+                } else {
+                    min.axis(i)
+                }
+            });
+            let c_max = P::from_axes(|i| {
+                if upper(i) {
+                    max.axis(i)
+                } else {
+                    center.axis(i)
+                }
+            });
+            self.nodes.push(Node::new(c_min, c_max, depth + 1));
+        }
+        self.nodes[index].children = Some(first);
+
+        // Move the keys within the bounds of the node down to its children.
+        let keys = core::mem::take(&mut self.nodes[index].keys);
+        for key in keys {
+            let point = self.items.item(key).point;
+            if bounds_contain(min, max, point) {
+                let child = first + self.nodes[index].child_index(point);
+                self.place(child, key);
+            } else {
+                self.nodes[index].keys.push(key);
+            }
+        }
+    }
+}
+
+impl<P: Point> Node<P> {
+    fn new(min: P, max: P, depth: usize) -> Self {
+        Node {
+            min,
+            max,
+            depth,
+            keys: vec![],
+            children: None,
+        }
+    }
+
+    // The index of the child containing the given point.
+    fn child_index(&self, point: P) -> usize {
+        (0..P::DIMENSIONS)
+            .filter(|&i| point.axis(i) >= (self.min.axis(i) + self.max.axis(i)) * 0.5)
+            .fold(0, |index, i| index | 1 << i)
+    }
+}
@@ -0,0 +1,148 @@
+//! The Voronoi diagram of a set of points, clipped to a rectangle.
+
+use crate::geom::{Delaunay, Point2, Rect};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The Voronoi diagram of a set of points, clipped to a rectangle.
+///
+/// The diagram consists of a cell for each point, describing the region of the rectangle closer
+/// to that point than to any other. Each cell is a convex polygon wound counter-clockwise.
+///
+/// The cells of duplicate points and of points whose cell lies entirely outside the rectangle are
+/// empty.
+#[derive(Clone, Debug, Default)]
+pub struct Voronoi {
+    vertices: Vec<Point2>,
+    // The range of `vertices` describing each cell.
+    offsets: Vec<usize>,
+}
+
+/// An iterator yielding the polygon of each cell within a **Voronoi** diagram.
+#[derive(Clone, Debug)]
+pub struct Cells<'a> {
+    voronoi: &'a Voronoi,
+    index: usize,
+}
+
+impl Voronoi {
+    /// Produce the Voronoi diagram of the given Delaunay triangulation's points, clipped to the
+    /// given rectangle.
+    pub fn new(delaunay: &Delaunay, rect: Rect) -> Self {
+        let points = delaunay.points();
+        let n = points.len();
+
+        // The neighbours of each point within the triangulation. The Voronoi neighbours of each
+        // point are a subset of these.
+        let mut neighbours = vec![Vec::new(); n];
+        let mut used = vec![false; n];
+        let mut add_edge = |a: usize, b: usize| {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        };
+        if delaunay.is_empty() {
+            for w in delaunay.hull().windows(2) {
+                add_edge(w[0], w[1]);
+            }
+        } else {
+            for [a, b] in delaunay.edges() {
+                add_edge(a, b);
+            }
+        }
+        for &i in delaunay.hull().iter().chain(delaunay.indices()) {
+            used[i] = true;
+        }
+
+        // Clip the rectangle by the bisector between each point and each of its neighbours.
+        let corners = [
+            rect.bottom_left(),
+            rect.bottom_right(),
+            rect.top_right(),
+            rect.top_left(),
+        ];
+        let mut vertices = vec![];
+        let mut offsets = vec![0];
+        let (mut cell, mut clipped) = (vec![], vec![]);
+        for i in 0..n {
+            if used[i] {
+                cell.clear();
+                cell.extend_from_slice(&corners);
+                for &j in &neighbours[i] {
+                    if cell.is_empty() {
+                        break;
+                    }
+                    clip(&cell, points[i], points[j], &mut clipped);
+                    core::mem::swap(&mut cell, &mut clipped);
+                }
+                vertices.extend_from_slice(&cell);
+            }
+            offsets.push(vertices.len());
+        }
+
+        Voronoi { vertices, offsets }
+    }
+
+    /// The number of cells, equal to the number of points.
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Whether or not the diagram has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The polygon describing the cell of the point at the given index.
+    pub fn cell(&self, i: usize) -> &[Point2] {
+        &self.vertices[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// An iterator yielding the polygon describing each cell, in the order of their points.
+    pub fn cells(&self) -> Cells<'_> {
+        Cells {
+            voronoi: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Cells<'a> {
+    type Item = &'a [Point2];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.voronoi.len() {
+            return None;
+        }
+        let cell = self.voronoi.cell(self.index);
+        self.index += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.voronoi.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Cells<'a> {}
+
+// Clip the convex polygon to the half-plane closer to `site` than to `other`.
+fn clip(polygon: &[Point2], site: Point2, other: Point2, clipped: &mut Vec<Point2>) {
+    clipped.clear();
+    let normal = other - site;
+    if normal == Point2::ZERO {
+        clipped.extend_from_slice(polygon);
+        return;
+    }
+    let mid = (site + other) * 0.5;
+    let dist = |p: Point2| (p - mid).dot(normal);
+    for (k, &a) in polygon.iter().enumerate() {
+        let b = polygon[(k + 1) % polygon.len()];
+        let (da, db) = (dist(a), dist(b));
+        if da <= 0.0 {
+            clipped.push(a);
+        }
+        if (da < 0.0 && db > 0.0) || (da > 0.0 && db < 0.0) {
+            clipped.push(a + (b - a) * (da / (da - db)));
+        }
+    }
+}
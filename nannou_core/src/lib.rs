@@ -25,6 +25,14 @@
 //!   crate is support for `#![no_std]`. This means we can use the crate for embedded applications
 //!   and in some cases rust-gpu shaders. For compatibility with a `#![no_std]` environment be sure
 //!   to disable default features (i.e. `default-features = false`) and enable the `libm` feature.
+//! - `alloc`: enables items that require dynamic allocation via the `alloc` crate. Enabled by
+//!   `std`. For `#![no_std]` targets that provide a global allocator, enable it alongside `libm`,
+//!   i.e. `features = ["libm", "alloc"]`. Items requiring `alloc` include:
+//!   - polygon triangulation, boolean operations and offsetting in `geom::polygon`,
+//!   - `geom::convex_hull`, `geom::Delaunay` triangulation and `geom::Voronoi` diagrams,
+//!   - `geom::polyline` simplification, smoothing and fitting,
+//!   - the `geom::spatial` indices and the `BSpline` and `CatmullRom` curves,
+//!   - `rand::PoissonDisk` and `rand::InPolygon` sampling.
 //! - `libm`: provides some core math support in the case that `std` is not enabled. This feature
 //!   must be enabled if `std` is disabled.
 //! - `serde`: enables the associated serde serialization/deserialization features in `glam`,
@@ -32,9 +40,15 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod color;
 pub mod geom;
 pub mod math;
+pub mod noise;
 pub mod prelude;
 pub mod rand;
 
@@ -0,0 +1,183 @@
+//! Fractal combinators layering octaves of a noise function.
+
+use super::{NoiseFn, Point, DECORRELATION_OFFSETS};
+use crate::math::num_traits::Float;
+
+/// Fractal brownian motion, summing octaves of increasing frequency and decreasing amplitude.
+///
+/// The result is normalised by the sum of the amplitudes of each octave so that it remains within
+/// the range of the source noise.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fbm<N> {
+    source: N,
+    octaves: usize,
+    frequency: f32,
+    lacunarity: f32,
+    persistence: f32,
+}
+
+/// Ridged multifractal noise, producing sharp ridges where the source noise crosses zero.
+///
+/// The contribution of each octave is weighted by the previous octave such that detail gathers
+/// along the ridges, as is common of mountainous terrain.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ridged<N> {
+    source: N,
+    octaves: usize,
+    frequency: f32,
+    lacunarity: f32,
+    persistence: f32,
+}
+
+/// The default number of octaves.
+pub const DEFAULT_OCTAVES: usize = 6;
+/// The default frequency of the first octave.
+pub const DEFAULT_FREQUENCY: f32 = 1.0;
+/// The default factor by which the frequency increases with each octave.
+pub const DEFAULT_LACUNARITY: f32 = 2.0;
+/// The default factor by which the amplitude decreases with each octave.
+pub const DEFAULT_PERSISTENCE: f32 = 0.5;
+
+impl<N> Fbm<N> {
+    /// Layer octaves of the given source noise.
+    pub fn new(source: N) -> Self {
+        Fbm {
+            source,
+            octaves: DEFAULT_OCTAVES,
+            frequency: DEFAULT_FREQUENCY,
+            lacunarity: DEFAULT_LACUNARITY,
+            persistence: DEFAULT_PERSISTENCE,
+        }
+    }
+
+    /// Specify the number of octaves.
+    pub fn octaves(mut self, octaves: usize) -> Self {
+        self.octaves = octaves;
+        self
+    }
+
+    /// Specify the frequency of the first octave.
+    pub fn frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Specify the factor by which the frequency increases with each octave.
+    pub fn lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// Specify the factor by which the amplitude decreases with each octave.
+    pub fn persistence(mut self, persistence: f32) -> Self {
+        self.persistence = persistence;
+        self
+    }
+
+    /// The source noise.
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+}
+
+impl<N> Ridged<N> {
+    /// Layer octaves of the given source noise.
+    pub fn new(source: N) -> Self {
+        Ridged {
+            source,
+            octaves: DEFAULT_OCTAVES,
+            frequency: DEFAULT_FREQUENCY,
+            lacunarity: DEFAULT_LACUNARITY,
+            persistence: DEFAULT_PERSISTENCE,
+        }
+    }
+
+    /// Specify the number of octaves.
+    pub fn octaves(mut self, octaves: usize) -> Self {
+        self.octaves = octaves;
+        self
+    }
+
+    /// Specify the frequency of the first octave.
+    pub fn frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Specify the factor by which the frequency increases with each octave.
+    pub fn lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// Specify the factor by which the amplitude decreases with each octave.
+    pub fn persistence(mut self, persistence: f32) -> Self {
+        self.persistence = persistence;
+        self
+    }
+
+    /// The source noise.
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+}
+
+impl<N, P> NoiseFn<P> for Fbm<N>
+where
+    N: NoiseFn<P>,
+    P: Point,
+{
+    fn get(&self, point: P) -> f32 {
+        let mut sum = 0.0;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = self.frequency;
+        for octave in 0..self.octaves {
+            let p = octave_point(point, octave, frequency);
+            sum += self.source.get(p) * amplitude;
+            total += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+        if total > 0.0 {
+            sum / total
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<N, P> NoiseFn<P> for Ridged<N>
+where
+    N: NoiseFn<P>,
+    P: Point,
+{
+    fn get(&self, point: P) -> f32 {
+        let mut sum = 0.0;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = self.frequency;
+        let mut weight = 1.0;
+        for octave in 0..self.octaves {
+            let p = octave_point(point, octave, frequency);
+            let ridge = 1.0 - Float::abs(self.source.get(p));
+            let signal = ridge * ridge * weight;
+            weight = (signal * 2.0).clamp(0.0, 1.0);
+            sum += signal * amplitude;
+            total += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+        if total > 0.0 {
+            sum / total * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+// Scale the point by the frequency and offset it so that each octave samples a different region
+// of the source noise.
+fn octave_point<P: Point>(point: P, octave: usize, frequency: f32) -> P {
+    point.map(|i, x| x * frequency + octave as f32 * DECORRELATION_OFFSETS[i])
+}
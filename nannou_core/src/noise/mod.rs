@@ -0,0 +1,257 @@
+//! Deterministic, seeded noise functions in 1 to 4 dimensions.
+//!
+//! All noise functions implement the **NoiseFn** trait for points of type `f32`, `Vec2`, `Vec3`
+//! and `Vec4`. The generators produce values within approximately `-1.0..=1.0`:
+//!
+//! - **Value**: interpolated random values at each integer lattice point.
+//! - **Perlin**: interpolated random gradients at each integer lattice point.
+//! - **Simplex**: random gradients at the vertices of a simplex lattice, cheaper than **Perlin** in
+//!   higher dimensions and with fewer directional artifacts.
+//! - **OpenSimplex**: a smoother variant of **Simplex** where each point blends the gradients of
+//!   every lattice vertex within a larger radius.
+//! - **Worley**: the distance to, or value of, the nearest of a set of randomly placed points.
+//!
+//! The **Fbm**, **Ridged** and **DomainWarp** combinators layer or distort any other noise
+//! function.
+//!
+//! Rather than sampling permutation tables produced by a random number generator, each generator
+//! hashes the lattice coordinates along with its seed. The same seed produces the same noise on
+//! every target, with or without `std`.
+//!
+//! ```
+//! use nannou_core::glam::Vec2;
+//! use nannou_core::noise::{Fbm, NoiseFn, Perlin};
+//!
+//! let noise = Fbm::new(Perlin::new(42)).octaves(4);
+//! let value = noise.get(Vec2::new(1.5, -0.25));
+//! assert!((-1.0..=1.0).contains(&value));
+//! assert_eq!(value, Fbm::new(Perlin::new(42)).octaves(4).get(Vec2::new(1.5, -0.25)));
+//! ```
+
+use crate::glam::{Vec2, Vec3, Vec4};
+
+pub use self::fractal::{Fbm, Ridged};
+pub use self::open_simplex::OpenSimplex;
+pub use self::perlin::Perlin;
+pub use self::simplex::Simplex;
+pub use self::value::Value;
+pub use self::warp::DomainWarp;
+pub use self::worley::{Feature, Metric, Worley};
+
+pub mod fractal;
+pub mod open_simplex;
+pub mod perlin;
+pub mod simplex;
+pub mod value;
+pub mod warp;
+pub mod worley;
+
+/// The seed used by the `Default` implementation of each generator.
+pub const DEFAULT_SEED: u32 = 0;
+
+/// A function producing a noise value for each point.
+pub trait NoiseFn<P> {
+    /// The noise value at the given point.
+    fn get(&self, point: P) -> f32;
+}
+
+/// Point types at which noise may be sampled, i.e. `f32`, `Vec2`, `Vec3` and `Vec4`.
+pub trait Point: Copy {
+    /// The number of axes.
+    const DIMENSIONS: usize;
+
+    /// The coordinate along the given axis.
+    fn axis(self, axis: usize) -> f32;
+
+    /// Produce a new point by mapping each axis index and coordinate to a new coordinate.
+    fn map<F>(self, f: F) -> Self
+    where
+        F: FnMut(usize, f32) -> f32;
+}
+
+// Large primes with which lattice coordinates are multiplied before hashing.
+const PRIMES: [u32; 4] = [501_125_321, 1_136_930_381, 1_720_413_743, 1_066_037_191];
+
+// Offsets applied to the point for each octave of a fractal or each axis of a domain warp, so
+// that each samples an unrelated region of the source noise.
+const DECORRELATION_OFFSETS: [f32; 4] = [31.416, 27.183, 14.142, 17.321];
+
+// 2D gradients, evenly spaced around the unit circle.
+const GRADIENTS_2D: [[f32; 2]; 8] = [
+    [1.0, 0.0],
+    [0.707_106_77, 0.707_106_77],
+    [0.0, 1.0],
+    [-0.707_106_77, 0.707_106_77],
+    [-1.0, 0.0],
+    [-0.707_106_77, -0.707_106_77],
+    [0.0, -1.0],
+    [0.707_106_77, -0.707_106_77],
+];
+
+// 3D gradients towards the midpoints of the edges of a cube, with four repeated so that the
+// gradient may be selected via a bit mask.
+const GRADIENTS_3D: [[f32; 3]; 16] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+    [1.0, 1.0, 0.0],
+    [0.0, -1.0, 1.0],
+    [-1.0, 1.0, 0.0],
+    [0.0, -1.0, -1.0],
+];
+
+impl<P, N> NoiseFn<P> for &N
+where
+    N: NoiseFn<P> + ?Sized,
+{
+    fn get(&self, point: P) -> f32 {
+        (**self).get(point)
+    }
+}
+
+impl Point for f32 {
+    const DIMENSIONS: usize = 1;
+
+    fn axis(self, _axis: usize) -> f32 {
+        self
+    }
+
+    fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(usize, f32) -> f32,
+    {
+        f(0, self)
+    }
+}
+
+impl Point for Vec2 {
+    const DIMENSIONS: usize = 2;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(usize, f32) -> f32,
+    {
+        Vec2::new(f(0, self.x), f(1, self.y))
+    }
+}
+
+impl Point for Vec3 {
+    const DIMENSIONS: usize = 3;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(usize, f32) -> f32,
+    {
+        Vec3::new(f(0, self.x), f(1, self.y), f(2, self.z))
+    }
+}
+
+impl Point for Vec4 {
+    const DIMENSIONS: usize = 4;
+
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(usize, f32) -> f32,
+    {
+        Vec4::new(f(0, self.x), f(1, self.y), f(2, self.z), f(3, self.w))
+    }
+}
+
+// The coordinates of the point as an array along with the number of dimensions.
+fn coords<P: Point>(p: P) -> ([f32; 4], usize) {
+    let mut coords = [0.0; 4];
+    for (i, c) in coords.iter_mut().enumerate().take(P::DIMENSIONS) {
+        *c = p.axis(i);
+    }
+    (coords, P::DIMENSIONS)
+}
+
+// Hash the seed along with the coordinates of a lattice point.
+fn hash(seed: u32, cell: &[i32]) -> u32 {
+    let mut h = mix(seed);
+    for (&c, &prime) in cell.iter().zip(PRIMES.iter()) {
+        h ^= (c as u32).wrapping_mul(prime);
+    }
+    mix(h)
+}
+
+// Scramble the bits of `h` such that each input bit affects every output bit.
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^ (h >> 16)
+}
+
+// Map a hash to a value within `-1.0..1.0`.
+fn hash_to_signed(h: u32) -> f32 {
+    (h >> 8) as f32 * (2.0 / (1 << 24) as f32) - 1.0
+}
+
+// The dot product of the gradient selected by the hash and the given offset.
+fn gradient(h: u32, d: &[f32]) -> f32 {
+    match *d {
+        [x] => {
+            // Magnitudes within `1/8..=1` with a random sign.
+            let g = ((h & 7) + 1) as f32 * 0.125;
+            if h & 8 == 0 {
+                g * x
+            } else {
+                -g * x
+            }
+        }
+        [x, y] => {
+            let [gx, gy] = GRADIENTS_2D[(h & 7) as usize];
+            gx * x + gy * y
+        }
+        [x, y, z] => {
+            let [gx, gy, gz] = GRADIENTS_3D[(h & 15) as usize];
+            gx * x + gy * y + gz * z
+        }
+        [x, y, z, w] => {
+            // The 32 gradients towards the midpoints of the edges of a tesseract, where one axis
+            // is zero and the others are either `1` or `-1`.
+            let (a, b, c) = match (h >> 3) & 3 {
+                0 => (y, z, w),
+                1 => (x, z, w),
+                2 => (x, y, w),
+                _ => (x, y, z),
+            };
+            let a = if h & 1 == 0 { a } else { -a };
+            let b = if h & 2 == 0 { b } else { -b };
+            let c = if h & 4 == 0 { c } else { -c };
+            a + b + c
+        }
+        _ => unreachable!("noise is only supported in 1 to 4 dimensions"),
+    }
+}
+
+// The factors used to skew points onto, and unskew points from, the simplex lattice for each
+// number of dimensions.
+fn skew_factors(dimensions: usize) -> (f32, f32) {
+    const SKEW: [f32; 4] = [0.414_213_57, 0.366_025_4, 0.333_333_34, 0.309_017];
+    const UNSKEW: [f32; 4] = [0.292_893_23, 0.211_324_87, 0.166_666_67, 0.138_196_6];
+    (SKEW[dimensions - 1], UNSKEW[dimensions - 1])
+}
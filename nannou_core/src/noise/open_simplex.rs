@@ -0,0 +1,238 @@
+//! OpenSimplex noise, a smoother variant of simplex noise.
+
+use super::{coords, gradient, hash, skew_factors, NoiseFn, Point, DEFAULT_SEED};
+use crate::glam::Vec2;
+use crate::math::num_traits::Float;
+
+/// Smooth simplex-style noise in the spirit of Kurt Spencer's OpenSimplex2S.
+///
+/// Like **Simplex** noise, the noise at a point is the sum of the gradients of nearby vertices of
+/// a simplex lattice, each attenuated by its distance to the point. The radius of each vertex's
+/// contribution is extended to the length of an edge of the lattice, so that each point blends
+/// more vertices and the result is smoother with fewer visible lattice artifacts, at a higher cost.
+///
+/// The 1D noise is sampled from the 2D noise along the *x* axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpenSimplex {
+    seed: u32,
+}
+
+// The squared radius of the contribution of each vertex for 2, 3 and 4 dimensions, equal to the
+// squared length of the edges of the lattice.
+const RADIUS_SQUARED: [f32; 3] = [2.0 / 3.0, 0.75, 0.8];
+
+// Scales the result of each number of dimensions to approximately `-1.0..=1.0`.
+const SCALE: [f32; 3] = [18.4, 9.0, 5.5];
+
+// The offsets from the origin of a cell of the skewed lattice to every vertex that may lie within
+// the radius of a point within the cell.
+const VERTICES_2D: [[i32; 2]; 8] = [
+    [0, -1],
+    [-1, 0],
+    [0, 0],
+    [1, 0],
+    [0, 1],
+    [1, 1],
+    [2, 1],
+    [1, 2],
+];
+const VERTICES_3D: [[i32; 3]; 32] = [
+    [0, -1, -1],
+    [-1, 0, -1],
+    [0, 0, -1],
+    [1, 0, -1],
+    [0, 1, -1],
+    [-1, -1, 0],
+    [0, -1, 0],
+    [1, -1, 0],
+    [-1, 0, 0],
+    [0, 0, 0],
+    [1, 0, 0],
+    [-1, 1, 0],
+    [0, 1, 0],
+    [1, 1, 0],
+    [2, 1, 0],
+    [1, 2, 0],
+    [0, -1, 1],
+    [-1, 0, 1],
+    [0, 0, 1],
+    [1, 0, 1],
+    [2, 0, 1],
+    [0, 1, 1],
+    [1, 1, 1],
+    [2, 1, 1],
+    [0, 2, 1],
+    [1, 2, 1],
+    [2, 2, 1],
+    [1, 0, 2],
+    [0, 1, 2],
+    [1, 1, 2],
+    [2, 1, 2],
+    [1, 2, 2],
+];
+const VERTICES_4D: [[i32; 4]; 92] = [
+    [0, -1, -1, -1],
+    [-1, 0, -1, -1],
+    [0, 0, -1, -1],
+    [-1, -1, 0, -1],
+    [0, -1, 0, -1],
+    [-1, 0, 0, -1],
+    [0, 0, 0, -1],
+    [1, 0, 0, -1],
+    [0, 1, 0, -1],
+    [1, 1, 0, -1],
+    [0, 0, 1, -1],
+    [1, 0, 1, -1],
+    [0, 1, 1, -1],
+    [-1, -1, -1, 0],
+    [0, -1, -1, 0],
+    [-1, 0, -1, 0],
+    [0, 0, -1, 0],
+    [1, 0, -1, 0],
+    [0, 1, -1, 0],
+    [1, 1, -1, 0],
+    [-1, -1, 0, 0],
+    [0, -1, 0, 0],
+    [1, -1, 0, 0],
+    [-1, 0, 0, 0],
+    [0, 0, 0, 0],
+    [1, 0, 0, 0],
+    [-1, 1, 0, 0],
+    [0, 1, 0, 0],
+    [1, 1, 0, 0],
+    [2, 1, 0, 0],
+    [1, 2, 0, 0],
+    [0, -1, 1, 0],
+    [1, -1, 1, 0],
+    [-1, 0, 1, 0],
+    [0, 0, 1, 0],
+    [1, 0, 1, 0],
+    [2, 0, 1, 0],
+    [-1, 1, 1, 0],
+    [0, 1, 1, 0],
+    [1, 1, 1, 0],
+    [2, 1, 1, 0],
+    [0, 2, 1, 0],
+    [1, 2, 1, 0],
+    [1, 0, 2, 0],
+    [0, 1, 2, 0],
+    [1, 1, 2, 0],
+    [0, 0, -1, 1],
+    [1, 0, -1, 1],
+    [0, 1, -1, 1],
+    [0, -1, 0, 1],
+    [1, -1, 0, 1],
+    [-1, 0, 0, 1],
+    [0, 0, 0, 1],
+    [1, 0, 0, 1],
+    [2, 0, 0, 1],
+    [-1, 1, 0, 1],
+    [0, 1, 0, 1],
+    [1, 1, 0, 1],
+    [2, 1, 0, 1],
+    [0, 2, 0, 1],
+    [1, 2, 0, 1],
+    [0, -1, 1, 1],
+    [-1, 0, 1, 1],
+    [0, 0, 1, 1],
+    [1, 0, 1, 1],
+    [2, 0, 1, 1],
+    [0, 1, 1, 1],
+    [1, 1, 1, 1],
+    [2, 1, 1, 1],
+    [0, 2, 1, 1],
+    [1, 2, 1, 1],
+    [2, 2, 1, 1],
+    [0, 0, 2, 1],
+    [1, 0, 2, 1],
+    [0, 1, 2, 1],
+    [1, 1, 2, 1],
+    [2, 1, 2, 1],
+    [1, 2, 2, 1],
+    [2, 2, 2, 1],
+    [1, 0, 0, 2],
+    [0, 1, 0, 2],
+    [1, 1, 0, 2],
+    [0, 0, 1, 2],
+    [1, 0, 1, 2],
+    [0, 1, 1, 2],
+    [1, 1, 1, 2],
+    [2, 1, 1, 2],
+    [1, 2, 1, 2],
+    [2, 2, 1, 2],
+    [1, 1, 2, 2],
+    [2, 1, 2, 2],
+    [1, 2, 2, 2],
+];
+
+impl OpenSimplex {
+    /// OpenSimplex noise with the given seed.
+    pub fn new(seed: u32) -> Self {
+        OpenSimplex { seed }
+    }
+
+    /// The seed from which the noise is produced.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Default for OpenSimplex {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl<P: Point> NoiseFn<P> for OpenSimplex {
+    fn get(&self, point: P) -> f32 {
+        let (p, n) = coords(point);
+        match n {
+            1 => self.get(Vec2::new(p[0], 0.0)),
+            2 => self.sum(&p[..2], VERTICES_2D.iter().map(|v| &v[..])),
+            3 => self.sum(&p[..3], VERTICES_3D.iter().map(|v| &v[..])),
+            _ => self.sum(&p[..4], VERTICES_4D.iter().map(|v| &v[..])),
+        }
+    }
+}
+
+impl OpenSimplex {
+    // Sum the contributions of each of the given vertices relative to the point's cell.
+    fn sum<'a, I>(&self, p: &[f32], vertices: I) -> f32
+    where
+        I: Iterator<Item = &'a [i32]>,
+    {
+        let n = p.len();
+        let (skew, unskew) = skew_factors(n);
+        let radius_squared = RADIUS_SQUARED[n - 2];
+
+        // Find the cell of the skewed lattice containing the point and the offset of the point
+        // from the cell's origin in unskewed space.
+        let s = p.iter().sum::<f32>() * skew;
+        let mut cell = [0; 4];
+        let mut local = [0.0; 4];
+        for i in 0..n {
+            let skewed = p[i] + s;
+            let floor = Float::floor(skewed);
+            cell[i] = floor as i32;
+            local[i] = skewed - floor;
+        }
+        let t = local[..n].iter().sum::<f32>() * unskew;
+
+        let mut sum = 0.0;
+        for offset in vertices {
+            let o = offset.iter().sum::<i32>() as f32 * unskew;
+            let mut vertex = [0; 4];
+            let mut d = [0.0; 4];
+            for i in 0..n {
+                vertex[i] = cell[i] + offset[i];
+                d[i] = local[i] - t - offset[i] as f32 + o;
+            }
+            let a = radius_squared - d[..n].iter().map(|d| d * d).sum::<f32>();
+            if a > 0.0 {
+                let a2 = a * a;
+                sum += a2 * a2 * gradient(hash(self.seed, &vertex[..n]), &d[..n]);
+            }
+        }
+        sum * SCALE[n - 2]
+    }
+}
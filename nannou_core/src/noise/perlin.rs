@@ -0,0 +1,69 @@
+//! Perlin noise, interpolating random gradients at each integer lattice point.
+
+use super::value::quintic;
+use super::{coords, gradient, hash, NoiseFn, Point, DEFAULT_SEED};
+use crate::math::num_traits::Float;
+
+/// Ken Perlin's "improved" gradient noise.
+///
+/// Each integer lattice point is assigned a random gradient, and the noise at a point is the
+/// smooth interpolation of the gradients at the corners of its cell. The noise is zero at every
+/// lattice point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    seed: u32,
+}
+
+// Scales the result of each number of dimensions to approximately `-1.0..=1.0`.
+const SCALE: [f32; 4] = [2.0, 1.414, 0.98, 0.8];
+
+impl Perlin {
+    /// Perlin noise with the given seed.
+    pub fn new(seed: u32) -> Self {
+        Perlin { seed }
+    }
+
+    /// The seed from which the noise is produced.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl<P: Point> NoiseFn<P> for Perlin {
+    fn get(&self, point: P) -> f32 {
+        let (p, n) = coords(point);
+        let mut cell = [0; 4];
+        let mut frac = [0.0; 4];
+        let mut fade = [0.0; 4];
+        for i in 0..n {
+            let floor = Float::floor(p[i]);
+            cell[i] = floor as i32;
+            frac[i] = p[i] - floor;
+            fade[i] = quintic(frac[i]);
+        }
+        // Interpolate between the gradients at each corner of the surrounding cell.
+        let mut sum = 0.0;
+        for corner in 0..1 << n {
+            let mut c = cell;
+            let mut d = frac;
+            let mut weight = 1.0;
+            for i in 0..n {
+                if corner & (1 << i) == 0 {
+                    weight *= 1.0 - fade[i];
+                } else {
+                    c[i] += 1;
+                    d[i] -= 1.0;
+                    weight *= fade[i];
+                }
+            }
+            sum += weight * gradient(hash(self.seed, &c[..n]), &d[..n]);
+        }
+        sum * SCALE[n - 1]
+    }
+}
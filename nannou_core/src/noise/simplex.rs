@@ -0,0 +1,90 @@
+//! Simplex noise, summing random gradients at the vertices of a simplex lattice.
+
+use super::{coords, gradient, hash, skew_factors, NoiseFn, Point, DEFAULT_SEED};
+use crate::math::num_traits::Float;
+use core::cmp::Ordering;
+
+/// Ken Perlin's simplex noise.
+///
+/// Space is divided into simplices, i.e. triangles in 2D and tetrahedra in 3D. The noise at a point
+/// is the sum of the gradients at the vertices of its simplex, each attenuated by its distance to
+/// the point. Only `N + 1` vertices contribute to each point rather than the `2^N` corners used by
+/// **Perlin** noise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Simplex {
+    seed: u32,
+}
+
+// The squared radius of the contribution of each vertex, no larger than the distance from any
+// point to the vertices outside of its simplex.
+const RADIUS_SQUARED: f32 = 0.5;
+
+// Scales the result of each number of dimensions to approximately `-1.0..=1.0`.
+const SCALE: [f32; 4] = [71.0, 99.0, 76.0, 62.0];
+
+impl Simplex {
+    /// Simplex noise with the given seed.
+    pub fn new(seed: u32) -> Self {
+        Simplex { seed }
+    }
+
+    /// The seed from which the noise is produced.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Default for Simplex {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl<P: Point> NoiseFn<P> for Simplex {
+    fn get(&self, point: P) -> f32 {
+        let (p, n) = coords(point);
+        let (skew, unskew) = skew_factors(n);
+
+        // Find the cell of the skewed lattice containing the point.
+        let s = p[..n].iter().sum::<f32>() * skew;
+        let mut cell = [0; 4];
+        let mut local = [0.0; 4];
+        for i in 0..n {
+            let skewed = p[i] + s;
+            let floor = Float::floor(skewed);
+            cell[i] = floor as i32;
+            local[i] = skewed - floor;
+        }
+
+        // The simplex is traversed from the cell's origin by stepping along each axis in order of
+        // the point's position along the axis within the cell, largest first.
+        let mut order = [0, 1, 2, 3];
+        order[..n]
+            .sort_unstable_by(|&a, &b| local[b].partial_cmp(&local[a]).unwrap_or(Ordering::Equal));
+
+        // The offset of the point from each vertex in unskewed space.
+        let t = local[..n].iter().sum::<f32>() * unskew;
+        let mut d = [0.0; 4];
+        for i in 0..n {
+            d[i] = local[i] - t;
+        }
+
+        let mut sum = 0.0;
+        let mut vertex = cell;
+        for k in 0..=n {
+            if k > 0 {
+                let axis = order[k - 1];
+                vertex[axis] += 1;
+                for (i, d) in d.iter_mut().enumerate().take(n) {
+                    *d += unskew - if i == axis { 1.0 } else { 0.0 };
+                }
+            }
+            let a = RADIUS_SQUARED - d[..n].iter().map(|d| d * d).sum::<f32>();
+            if a > 0.0 {
+                let a2 = a * a;
+                sum += a2 * a2 * gradient(hash(self.seed, &vertex[..n]), &d[..n]);
+            }
+        }
+        sum * SCALE[n - 1]
+    }
+}
@@ -0,0 +1,65 @@
+//! Value noise, interpolating random values at each integer lattice point.
+
+use super::{coords, hash, hash_to_signed, NoiseFn, Point, DEFAULT_SEED};
+use crate::math::num_traits::Float;
+
+/// Noise produced by smoothly interpolating between random values at each integer lattice point.
+///
+/// Value noise is the cheapest of the lattice noise functions, though its features tend to align
+/// with the axes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Value {
+    seed: u32,
+}
+
+impl Value {
+    /// Value noise with the given seed.
+    pub fn new(seed: u32) -> Self {
+        Value { seed }
+    }
+
+    /// The seed from which the noise is produced.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl<P: Point> NoiseFn<P> for Value {
+    fn get(&self, point: P) -> f32 {
+        let (p, n) = coords(point);
+        let mut cell = [0; 4];
+        let mut fade = [0.0; 4];
+        for i in 0..n {
+            let floor = Float::floor(p[i]);
+            cell[i] = floor as i32;
+            fade[i] = quintic(p[i] - floor);
+        }
+        // Interpolate between the values at each corner of the surrounding cell.
+        let mut sum = 0.0;
+        for corner in 0..1 << n {
+            let mut c = cell;
+            let mut weight = 1.0;
+            for i in 0..n {
+                if corner & (1 << i) == 0 {
+                    weight *= 1.0 - fade[i];
+                } else {
+                    c[i] += 1;
+                    weight *= fade[i];
+                }
+            }
+            sum += weight * hash_to_signed(hash(self.seed, &c[..n]));
+        }
+        sum
+    }
+}
+
+// The quintic curve `6t^5 - 15t^4 + 10t^3`, with zero first and second derivatives at `0` and `1`.
+pub(crate) fn quintic(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
@@ -0,0 +1,60 @@
+//! Domain warping, distorting the input of one noise function by another.
+
+use super::{NoiseFn, Point, DECORRELATION_OFFSETS};
+
+/// Distorts the point at which the source noise is sampled by the warp noise.
+///
+/// Each axis of the point is displaced by the warp noise sampled at an offset unique to the axis,
+/// multiplied by the strength. Warping fractal noise by itself produces the swirling, marbled
+/// patterns described by Inigo Quilez.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DomainWarp<N, W> {
+    source: N,
+    warp: W,
+    strength: f32,
+}
+
+impl<N, W> DomainWarp<N, W> {
+    /// The strength used by default.
+    pub const DEFAULT_STRENGTH: f32 = 1.0;
+
+    /// Warp the source noise by the given warp noise.
+    pub fn new(source: N, warp: W) -> Self {
+        DomainWarp {
+            source,
+            warp,
+            strength: Self::DEFAULT_STRENGTH,
+        }
+    }
+
+    /// Specify the distance by which points are displaced for each unit of the warp noise.
+    pub fn strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// The source noise.
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+
+    /// The noise by which the source is warped.
+    pub fn warp(&self) -> &W {
+        &self.warp
+    }
+}
+
+impl<N, W, P> NoiseFn<P> for DomainWarp<N, W>
+where
+    N: NoiseFn<P>,
+    W: NoiseFn<P>,
+    P: Point,
+{
+    fn get(&self, point: P) -> f32 {
+        let warped = point.map(|i, x| {
+            let offset = point.map(|_, y| y + DECORRELATION_OFFSETS[i]);
+            x + self.warp.get(offset) * self.strength
+        });
+        self.source.get(warped)
+    }
+}
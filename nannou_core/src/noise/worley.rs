@@ -0,0 +1,162 @@
+//! Worley noise, also known as cellular or Voronoi noise.
+
+use super::{coords, hash, hash_to_signed, mix, NoiseFn, Point, DEFAULT_SEED};
+use crate::math::num_traits::Float;
+
+/// Steven Worley's cellular noise.
+///
+/// Each integer lattice cell contains a single feature point at a random position within the
+/// cell. The noise at a point is derived from the distances to the nearest feature points, as
+/// selected by the **Feature**. Distances are mapped such that a distance of `0.0` produces `-1.0`
+/// and a distance of `1.0` produces `1.0`. Feature points may lie further than `1.0` from a point,
+/// particularly with the **Manhattan** metric or in higher dimensions, in which case the value is
+/// clamped to `1.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Worley {
+    seed: u32,
+    metric: Metric,
+    feature: Feature,
+    jitter: f32,
+}
+
+/// The function used to measure the distance between a point and each feature point.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The straight-line distance, producing round cells.
+    #[default]
+    Euclidean,
+    /// The sum of the distances along each axis, producing diamond-shaped cells.
+    Manhattan,
+    /// The greatest distance along any axis, producing square cells.
+    Chebyshev,
+}
+
+/// The value produced for each point by **Worley** noise.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// The distance to the nearest feature point, often named *F1*.
+    #[default]
+    Distance,
+    /// The distance to the second nearest feature point, often named *F2*.
+    SecondDistance,
+    /// The difference between the distances to the second nearest and the nearest feature points,
+    /// i.e. *F2 - F1*, producing lines along the borders between cells.
+    Difference,
+    /// A random value within `-1.0..1.0` unique to the cell of the nearest feature point,
+    /// producing flat Voronoi cells.
+    Value,
+}
+
+// The offsets of the searched cells along each axis, nearer cells first.
+const SEARCH_OFFSETS: [i32; 5] = [0, -1, 1, -2, 2];
+
+impl Worley {
+    /// The jitter used by default, allowing feature points to lie anywhere within their cell.
+    pub const DEFAULT_JITTER: f32 = 1.0;
+
+    /// Worley noise with the given seed, measuring the euclidean distance to the nearest feature
+    /// point.
+    pub fn new(seed: u32) -> Self {
+        Worley {
+            seed,
+            metric: Metric::default(),
+            feature: Feature::default(),
+            jitter: Self::DEFAULT_JITTER,
+        }
+    }
+
+    /// Specify the function used to measure distances.
+    pub fn metric(mut self, metric: Metric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Specify the value produced for each point.
+    pub fn feature(mut self, feature: Feature) -> Self {
+        self.feature = feature;
+        self
+    }
+
+    /// Specify how far each feature point may stray from the centre of its cell, where `0.0`
+    /// produces a regular grid and `1.0` allows feature points anywhere within their cell.
+    pub fn jitter(mut self, jitter: f32) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The seed from which the noise is produced.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Metric {
+    // The distance described by the given offset along each axis.
+    fn distance(&self, d: &[f32]) -> f32 {
+        match *self {
+            Metric::Euclidean => Float::sqrt(d.iter().map(|d| d * d).sum::<f32>()),
+            Metric::Manhattan => d.iter().map(|d| Float::abs(*d)).sum(),
+            Metric::Chebyshev => d.iter().fold(0.0, |max, d| Float::abs(*d).max(max)),
+        }
+    }
+}
+
+impl Default for Worley {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl<P: Point> NoiseFn<P> for Worley {
+    fn get(&self, point: P) -> f32 {
+        let (p, n) = coords(point);
+        let mut cell = [0; 4];
+        for i in 0..n {
+            cell[i] = Float::floor(p[i]) as i32;
+        }
+
+        // Search the surrounding `5^n` cells for the two nearest feature points, nearer cells
+        // first, skipping cells whose feature point cannot be nearer than the second nearest.
+        let reach = 0.5 * self.jitter;
+        let (mut f1, mut f2) = (f32::INFINITY, f32::INFINITY);
+        let mut nearest = 0;
+        for neighbour in 0..5usize.pow(n as u32) {
+            let mut c = cell;
+            let mut rest = neighbour;
+            for c in c.iter_mut().take(n) {
+                *c += SEARCH_OFFSETS[rest % 5];
+                rest /= 5;
+            }
+            let mut gap = [0.0; 4];
+            for i in 0..n {
+                let centre = c[i] as f32 + 0.5;
+                gap[i] = (Float::abs(centre - p[i]) - reach).max(0.0);
+            }
+            if self.metric.distance(&gap[..n]) >= f2 {
+                continue;
+            }
+            let h = hash(self.seed, &c[..n]);
+            let mut d = [0.0; 4];
+            for i in 0..n {
+                let offset = hash_to_signed(mix(h ^ (i as u32 + 1))) * reach;
+                d[i] = c[i] as f32 + 0.5 + offset - p[i];
+            }
+            let distance = self.metric.distance(&d[..n]);
+            if distance < f1 {
+                f2 = f1;
+                f1 = distance;
+                nearest = h;
+            } else if distance < f2 {
+                f2 = distance;
+            }
+        }
+
+        let distance = match self.feature {
+            Feature::Distance => f1,
+            Feature::SecondDistance => f2,
+            Feature::Difference => f2 - f1,
+            Feature::Value => return hash_to_signed(mix(nearest)),
+        };
+        (distance * 2.0 - 1.0).min(1.0)
+    }
+}
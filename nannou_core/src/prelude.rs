@@ -23,9 +23,13 @@ pub use crate::math::{
     turns_to_rad, Mat4LookTo, Vec2Angle, Vec2Rotate,
 };
 
+pub use crate::rand::SeededRng;
 // NOTE: These helper functions rely on a thread-local RNG and are currently only available via std.
 #[cfg(feature = "std")]
-pub use crate::rand::{random, random_ascii, random_f32, random_f64, random_range};
+pub use crate::rand::{
+    random, random_ascii, random_exponential, random_f32, random_f64, random_gaussian,
+    random_range, random_seed, random_weighted,
+};
 
 pub use core::f32::consts::{PI, TAU};
 pub use core::f64::consts::{PI as PI_F64, TAU as TAU_F64};
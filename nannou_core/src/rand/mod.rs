@@ -0,0 +1,436 @@
+//! Items related to randomness and random number generators. Also provides some high-level helper
+//! functions.
+//!
+//! Helper functions include [**random_f32()**](./fn.random_f32.html),
+//! [**random_f64()**](./fn.random_f64.html) and [**random_range(min,
+//! max)**](./fn.random_range.html).
+//!
+//! The helper functions use a thread-local generator that is seeded from entropy by default.
+//! Call [**random_seed(seed)**](./fn.random_seed.html) to reseed the generators so that the same
+//! sequence of values is produced every time the program runs. The generator of every thread is
+//! derived from the same seed, see **random_seed** for details. Alternatively, a
+//! [**SeededRng**](./struct.SeededRng.html) may be stored within the model and used in place of
+//! the helper functions.
+//!
+//! Beyond uniform values, the module provides the **Gaussian**, **Exponential** and **Weighted**
+//! distributions, distributions of points on and within shapes such as **InCircle** and
+//! **OnSphere**, and **PoissonDisk** sampling. Each may be used with any `Rng`, including the
+//! thread-local generator via [**global_rng()**](./fn.global_rng.html).
+
+#[cfg(feature = "alloc")]
+pub use self::poisson::PoissonDisk;
+pub use self::rand::*;
+pub use self::scalar::{Exponential, Gaussian, Weighted};
+#[cfg(feature = "alloc")]
+pub use self::shape::InPolygon;
+pub use self::shape::{InCircle, InRect, InSphere, InTriangle, OnCircle, OnSphere};
+pub use rand;
+
+use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+#[cfg(feature = "alloc")]
+pub mod poisson;
+pub mod scalar;
+pub mod shape;
+
+/// A random number generator that produces the same sequence of values for the same seed.
+///
+/// The generator uses the ChaCha algorithm with 8 rounds, producing the same values on every
+/// platform and across versions of nannou. Store a **SeededRng** within the model in order to
+/// re-create a sketch exactly from its seed.
+///
+/// **SeededRng** implements `RngCore` so that it may be used with any of the `rand` crate's
+/// methods and distributions, e.g. `rng.gen_bool(0.5)` or `slice.shuffle(&mut rng)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+/// A handle to the thread-local generator shared by the helper functions within this module.
+///
+/// **GlobalRng** implements `RngCore` so that the thread-local generator may be used wherever an
+/// `Rng` is expected, producing the same values for the same
+/// [**random_seed(seed)**](./fn.random_seed.html).
+///
+/// NOTE: This is currently only available with the "std" feature enabled.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GlobalRng {
+    _private: (),
+}
+
+// The ascii characters produced by `random_ascii`.
+const ASCIISET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+                        abcdefghijklmnopqrstuvwxyz\
+                        0123456789)(*&^%$#@!~. ";
+
+// The seed from which the generator of every thread is derived.
+#[cfg(feature = "std")]
+struct GlobalSeed {
+    // `None` until `random_seed` is first called, in which case generators are seeded from entropy.
+    seed: Option<u64>,
+    // Incremented each time `random_seed` is called.
+    generation: u64,
+    // The next stream of the seed to hand out to a thread.
+    next_stream: u64,
+}
+
+// A thread's generator along with the generation of the global seed from which it was derived.
+#[cfg(feature = "std")]
+struct ThreadRng {
+    generation: u64,
+    rng: SeededRng,
+}
+
+#[cfg(feature = "std")]
+static GLOBAL_SEED: Mutex<GlobalSeed> = Mutex::new(GlobalSeed {
+    seed: None,
+    generation: 0,
+    next_stream: 0,
+});
+
+// Mirrors `GlobalSeed::generation` so that threads may check for reseeding without locking.
+#[cfg(feature = "std")]
+static GLOBAL_SEED_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static THREAD_RNG: RefCell<Option<ThreadRng>> = const { RefCell::new(None) };
+}
+
+impl SeededRng {
+    /// A generator producing the sequence of values determined by the given seed.
+    pub fn new(seed: u64) -> Self {
+        SeededRng {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    // A generator producing the given stream of the sequences determined by the given seed.
+    //
+    // Stream `0` produces the same sequence as `SeededRng::new(seed)`.
+    #[cfg(feature = "std")]
+    fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        rng.set_stream(stream);
+        SeededRng { seed, rng }
+    }
+
+    /// A generator with a seed chosen by the thread-local entropy source.
+    ///
+    /// The chosen seed can be retrieved via `seed` in order to re-create the sequence later.
+    ///
+    /// NOTE: This is currently only available with the "std" feature enabled.
+    #[cfg(feature = "std")]
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// The seed from which the generator was created or last reseeded.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restart the generator from the given seed.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Generate a random value of type `T` via the `Standard` distribution.
+    pub fn random<T>(&mut self) -> T
+    where
+        distributions::Standard: distributions::Distribution<T>,
+    {
+        self.gen()
+    }
+
+    /// Generate a random `f32` within the range `[0.0, 1.0)`.
+    pub fn random_f32(&mut self) -> f32 {
+        self.gen()
+    }
+
+    /// Generate a random `f64` within the range `[0.0, 1.0)`.
+    pub fn random_f64(&mut self) -> f64 {
+        self.gen()
+    }
+
+    /// Generate a random value within the range [min, max).
+    ///
+    /// If the given `min` is greater than the given `max`, they will be swapped before calling
+    /// `gen_range` internally to avoid triggering a `panic!`.
+    pub fn random_range<T>(&mut self, min: T, max: T) -> T
+    where
+        T: PartialOrd + distributions::uniform::SampleUniform,
+    {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.gen_range(min..max)
+    }
+
+    /// Generate a random ascii character from the set used by
+    /// [**random_ascii()**](./fn.random_ascii.html).
+    pub fn random_ascii(&mut self) -> char {
+        let idx = self.gen_range(0..ASCIISET.len());
+        ASCIISET[idx] as char
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "std")]
+impl RngCore for GlobalRng {
+    fn next_u32(&mut self) -> u32 {
+        with_thread_rng(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        with_thread_rng(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        with_thread_rng(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        with_thread_rng(|rng| rng.try_fill_bytes(dest))
+    }
+}
+
+/// A handle to the thread-local generator shared by the helper functions within this module.
+///
+/// ```
+/// use nannou_core::prelude::*;
+/// use nannou_core::rand::{global_rng, InCircle, PoissonDisk, Rng};
+///
+/// random_seed(42);
+/// let p = global_rng().sample(InCircle::new(pt2(0.0, 0.0), 100.0));
+/// let points = PoissonDisk::new(20.0).sample_rect(&mut global_rng(), Rect::from_w_h(400.0, 400.0));
+/// assert!(p.length() <= 100.0);
+/// assert!(!points.is_empty());
+/// ```
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn global_rng() -> GlobalRng {
+    GlobalRng::default()
+}
+
+/// Reseed the thread-local generators used by the helper functions within this module.
+///
+/// All following calls to the helper functions on the current thread produce the same sequence
+/// of values for the same seed.
+///
+/// The generators of all other threads, including those that already exist, are also derived
+/// from the seed upon their next use. Each thread is given its own stream of the seed in the order
+/// in which the threads first use the helper functions after reseeding, so that threads do not
+/// produce the same values as one another. As a result, values produced on other threads are only
+/// reproducible if the threads first use the helpers in the same order, e.g. one thread for
+/// audio. Where work is distributed across a thread pool, prefer passing a
+/// [**SeededRng**](./struct.SeededRng.html) to each task.
+///
+/// ```
+/// use nannou_core::rand::{random_f32, random_range, random_seed};
+///
+/// random_seed(7);
+/// let a = (random_f32(), random_range(0, 100));
+/// random_seed(7);
+/// assert_eq!(a, (random_f32(), random_range(0, 100)));
+///
+/// // Other threads are seeded too.
+/// let spawn = || std::thread::spawn(|| random_f32()).join().unwrap();
+/// random_seed(7);
+/// let b = spawn();
+/// random_seed(7);
+/// assert_eq!(b, spawn());
+/// assert_ne!(b, random_f32());
+/// ```
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_seed(seed: u64) {
+    let mut global = lock_global_seed();
+    global.seed = Some(seed);
+    global.generation += 1;
+    // The current thread takes the first stream.
+    global.next_stream = 1;
+    GLOBAL_SEED_GENERATION.store(global.generation, Ordering::Release);
+    let generation = global.generation;
+    let rng = SeededRng::new(seed);
+    THREAD_RNG.with(|cell| *cell.borrow_mut() = Some(ThreadRng { generation, rng }));
+}
+
+/// Generate a random value of type `T` via the `Standard` distribution.
+///
+/// Unlike `rand::random`, this uses the thread-local generator that may be reseeded via
+/// [**random_seed(seed)**](./fn.random_seed.html).
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random<T>() -> T
+where
+    distributions::Standard: distributions::Distribution<T>,
+{
+    with_thread_rng(|rng| rng.random())
+}
+
+/// A wrapper function around the `random` function that avoids the need for specifying a type in
+/// the case that it cannot be inferred. The primary purpose for this is to simplify the random API
+/// for new rust users.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_f32() -> f32 {
+    with_thread_rng(|rng| rng.random_f32())
+}
+
+/// A wrapper function around the `random` function that avoids the need for specifying a type in
+/// the case that it cannot be inferred. The primary purpose for this is to simplify the random API
+/// for new rust users.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_f64() -> f64 {
+    with_thread_rng(|rng| rng.random_f64())
+}
+
+/// A function for generating a random value within the given range.
+///
+/// The generated value may be within the range [min, max). That is, the result is inclusive of
+/// `min`, but will never be `max`.
+///
+/// If the given `min` is greater than the given `max`, they will be swapped before calling
+/// `gen_range` internally to avoid triggering a `panic!`.
+///
+/// This uses the thread-local generator that may be reseeded via
+/// [**random_seed(seed)**](./fn.random_seed.html).
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_range<T>(min: T, max: T) -> T
+where
+    T: PartialOrd + distributions::uniform::SampleUniform,
+{
+    with_thread_rng(|rng| rng.random_range(min, max))
+}
+
+/// Generates and returns a random ascii character.
+///
+/// The ascii characters that can be generated are:
+///
+///  ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+/// abcdefghijklmnopqrstuvwxyz\
+/// 0123456789)(*&^%$#@!~.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_ascii() -> char {
+    with_thread_rng(|rng| rng.random_ascii())
+}
+
+/// Generate a random value from the normal distribution with the given mean and standard
+/// deviation.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_gaussian(mean: f32, std_dev: f32) -> f32 {
+    with_thread_rng(|rng| rng.sample(Gaussian::new(mean, std_dev)))
+}
+
+/// Generate a random value from the exponential distribution with the given rate, i.e. with a
+/// mean of `1.0 / rate`.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_exponential(rate: f32) -> f32 {
+    with_thread_rng(|rng| rng.sample(Exponential::new(rate)))
+}
+
+/// Choose a random index with a probability proportional to the weight at each index.
+///
+/// Returns `None` if any weight is negative or not finite, or if the weights sum to zero.
+///
+/// NOTE: This helper function relies on a thread-local RNG and is currently only available with
+/// the "std" feature enabled.
+#[cfg(feature = "std")]
+pub fn random_weighted(weights: &[f32]) -> Option<usize> {
+    let weighted = Weighted::new(weights)?;
+    Some(with_thread_rng(|rng| rng.sample(weighted)))
+}
+
+// Borrow the thread-local generator used by the helper functions.
+//
+// The generator is first derived from the global seed if it has not yet been, or if the global
+// seed has since changed.
+#[cfg(feature = "std")]
+fn with_thread_rng<F, T>(f: F) -> T
+where
+    F: FnOnce(&mut SeededRng) -> T,
+{
+    THREAD_RNG.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        let generation = GLOBAL_SEED_GENERATION.load(Ordering::Acquire);
+        let stale = match *cell {
+            None => true,
+            Some(ref thread_rng) => thread_rng.generation != generation,
+        };
+        if stale {
+            *cell = Some(derive_thread_rng());
+        }
+        let thread_rng = cell.as_mut().expect("thread rng was just initialised");
+        f(&mut thread_rng.rng)
+    })
+}
+
+// Derive a generator for the current thread from the global seed.
+#[cfg(feature = "std")]
+fn derive_thread_rng() -> ThreadRng {
+    let mut global = lock_global_seed();
+    let rng = match global.seed {
+        None => SeededRng::from_entropy(),
+        Some(seed) => {
+            let stream = global.next_stream;
+            global.next_stream += 1;
+            SeededRng::with_stream(seed, stream)
+        }
+    };
+    let generation = global.generation;
+    ThreadRng { generation, rng }
+}
+
+// The global seed remains valid even if a thread panicked while holding the lock.
+#[cfg(feature = "std")]
+fn lock_global_seed() -> std::sync::MutexGuard<'static, GlobalSeed> {
+    GLOBAL_SEED.lock().unwrap_or_else(|err| err.into_inner())
+}
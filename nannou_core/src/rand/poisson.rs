@@ -0,0 +1,169 @@
+//! Poisson-disk sampling, producing evenly spaced random points also known as blue noise.
+
+use super::shape::InRect;
+use super::Rng;
+use crate::geom::polygon::boolean::winding_number;
+use crate::geom::{self, Point2, Rect};
+use crate::math::num_traits::Float;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f32::consts::TAU;
+
+/// Produces random points where no two points are closer than the given radius, via Robert
+/// Bridson's algorithm.
+///
+/// Unlike points distributed uniformly, Poisson-disk samples do not clump together, making them
+/// well suited to placing objects, stippling and the like.
+///
+/// ```
+/// use nannou_core::geom::Rect;
+/// use nannou_core::rand::{PoissonDisk, SeededRng};
+///
+/// let mut rng = SeededRng::new(7);
+/// let rect = Rect::from_w_h(200.0, 100.0);
+/// let points = PoissonDisk::new(10.0).sample_rect(&mut rng, rect);
+/// assert!(points.iter().all(|&p| rect.contains(p)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PoissonDisk {
+    radius: f32,
+    attempts: u32,
+}
+
+impl PoissonDisk {
+    /// The default number of candidates tried around each point before it is retired.
+    pub const DEFAULT_ATTEMPTS: u32 = 30;
+
+    /// Points separated by at least the given radius.
+    pub fn new(radius: f32) -> Self {
+        PoissonDisk {
+            radius,
+            attempts: Self::DEFAULT_ATTEMPTS,
+        }
+    }
+
+    /// Specify the number of candidates tried around each point before it is retired.
+    ///
+    /// More attempts produce a denser packing of points at a higher cost.
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// The minimum distance between points.
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Produce evenly spaced points within the given rectangle.
+    ///
+    /// Returns no points if the radius is not greater than zero.
+    pub fn sample_rect<R>(&self, rng: &mut R, rect: Rect) -> Vec<Point2>
+    where
+        R: Rng + ?Sized,
+    {
+        let start = rng.sample(InRect::new(rect));
+        self.sample(rng, rect, start, |p| rect.contains(p))
+    }
+
+    /// Produce evenly spaced points within the polygon described by the given contours.
+    ///
+    /// The first contour describes the outline of the polygon and each of the following contours
+    /// describes a hole within it, as with `InPolygon`. Points are produced within the areas
+    /// enclosed by an odd number of contours, so holes are respected regardless of their winding.
+    ///
+    /// Points spread outwards from a single point within the polygon, so areas of the polygon
+    /// separated by more than twice the radius may not be reached. Returns no points if the
+    /// radius is not greater than zero or no point within the polygon could be found.
+    pub fn sample_polygon<R, C>(&self, rng: &mut R, contours: &[C]) -> Vec<Point2>
+    where
+        R: Rng + ?Sized,
+        C: AsRef<[Point2]>,
+    {
+        let contours: Vec<Vec<Point2>> = contours.iter().map(|c| c.as_ref().to_vec()).collect();
+        let bounds = match contours
+            .first()
+            .and_then(|c| geom::bounding_rect(c.iter().cloned()))
+        {
+            Some(bounds) => bounds,
+            None => return Vec::new(),
+        };
+        let contains = |p| winding_number(&contours, p) % 2 != 0;
+        let start = (0..MAX_START_ATTEMPTS)
+            .map(|_| rng.sample(InRect::new(bounds)))
+            .find(|&p| contains(p));
+        match start {
+            Some(start) => self.sample(rng, bounds, start, contains),
+            None => Vec::new(),
+        }
+    }
+
+    // Spread points outwards from `start` across the area of `bounds` for which `contains` is
+    // true.
+    fn sample<R, F>(&self, rng: &mut R, bounds: Rect, start: Point2, contains: F) -> Vec<Point2>
+    where
+        R: Rng + ?Sized,
+        F: Fn(Point2) -> bool,
+    {
+        if self.radius <= 0.0 || !self.radius.is_finite() {
+            return Vec::new();
+        }
+
+        // Each cell of the grid is small enough to contain at most one point.
+        let r = self.radius;
+        let cell = r / core::f32::consts::SQRT_2;
+        let cols = Float::ceil(bounds.w() / cell).max(1.0) as usize;
+        let rows = Float::ceil(bounds.h() / cell).max(1.0) as usize;
+        let origin = Point2::new(bounds.left(), bounds.bottom());
+        let cell_of = |p: Point2| {
+            let c = ((p - origin) / cell).floor();
+            let col = (c.x.max(0.0) as usize).min(cols - 1);
+            let row = (c.y.max(0.0) as usize).min(rows - 1);
+            (col, row)
+        };
+        let mut grid: Vec<Option<usize>> = vec![None; cols * rows];
+
+        let mut points = vec![start];
+        let mut active = vec![0];
+        let (col, row) = cell_of(start);
+        grid[row * cols + col] = Some(0);
+
+        while !active.is_empty() {
+            let a = rng.gen_range(0..active.len());
+            let center = points[active[a]];
+            let mut found = false;
+            for _ in 0..self.attempts {
+                // A candidate distributed uniformly across the annulus between `r` and `2r`.
+                let distance = Float::sqrt(rng.gen::<f32>() * 3.0 + 1.0) * r;
+                let (sin, cos) = Float::sin_cos(rng.gen::<f32>() * TAU);
+                let candidate = center + Point2::new(cos, sin) * distance;
+                if !bounds.contains(candidate) || !contains(candidate) {
+                    continue;
+                }
+                // Check the cells within two cells of the candidate for neighbours.
+                let (col, row) = cell_of(candidate);
+                let near = (row.saturating_sub(2)..(row + 3).min(rows)).any(|row| {
+                    (col.saturating_sub(2)..(col + 3).min(cols)).any(|col| {
+                        grid[row * cols + col]
+                            .map(|i| points[i].distance_squared(candidate) < r * r)
+                            .unwrap_or(false)
+                    })
+                });
+                if !near {
+                    grid[row * cols + col] = Some(points.len());
+                    active.push(points.len());
+                    points.push(candidate);
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                active.swap_remove(a);
+            }
+        }
+        points
+    }
+}
+
+// The number of random points tested while searching for the first point within a polygon.
+const MAX_START_ATTEMPTS: usize = 1_000;
@@ -0,0 +1,118 @@
+//! Non-uniform distributions of scalar values.
+
+use super::distributions::Distribution;
+use super::Rng;
+use crate::math::num_traits::Float;
+use core::f32::consts::TAU;
+
+/// The normal distribution with the given mean and standard deviation.
+///
+/// Values are produced via the Box-Muller transform.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gaussian {
+    mean: f32,
+    std_dev: f32,
+}
+
+/// The exponential distribution with the given rate, e.g. the time between events that occur
+/// `rate` times per unit of time on average.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Exponential {
+    rate: f32,
+}
+
+/// A distribution producing indices with a probability proportional to the weight at each index.
+///
+/// Unlike `rand::distributions::WeightedIndex`, the weights are borrowed rather than collected and
+/// no allocation is required.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Weighted<'a> {
+    weights: &'a [f32],
+    total: f32,
+}
+
+impl Gaussian {
+    /// The normal distribution with the given mean and standard deviation.
+    pub fn new(mean: f32, std_dev: f32) -> Self {
+        Gaussian { mean, std_dev }
+    }
+
+    /// The mean of the distribution.
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    /// The standard deviation of the distribution.
+    pub fn std_dev(&self) -> f32 {
+        self.std_dev
+    }
+}
+
+impl Exponential {
+    /// The exponential distribution with the given rate, i.e. with a mean of `1.0 / rate`.
+    pub fn new(rate: f32) -> Self {
+        Exponential { rate }
+    }
+
+    /// The rate of the distribution.
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+}
+
+impl<'a> Weighted<'a> {
+    /// A distribution over the indices of the given weights.
+    ///
+    /// Returns `None` if any weight is negative or not finite, or if the weights sum to zero.
+    pub fn new(weights: &'a [f32]) -> Option<Self> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return None;
+        }
+        let total = weights.iter().sum::<f32>();
+        if total > 0.0 && total.is_finite() {
+            Some(Weighted { weights, total })
+        } else {
+            None
+        }
+    }
+
+    /// The weights over which indices are distributed.
+    pub fn weights(&self) -> &'a [f32] {
+        self.weights
+    }
+}
+
+impl Distribution<f32> for Gaussian {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        // Map to `(0.0, 1.0]` to avoid the logarithm of zero.
+        let u = 1.0 - rng.gen::<f32>();
+        let angle = rng.gen::<f32>() * TAU;
+        let z = Float::sqrt(-2.0 * Float::ln(u)) * Float::cos(angle);
+        self.mean + z * self.std_dev
+    }
+}
+
+impl Distribution<f32> for Exponential {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        let u = 1.0 - rng.gen::<f32>();
+        -Float::ln(u) / self.rate
+    }
+}
+
+impl<'a> Distribution<usize> for Weighted<'a> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let mut target = rng.gen::<f32>() * self.total;
+        let mut last = 0;
+        for (i, &w) in self.weights.iter().enumerate() {
+            if w > 0.0 {
+                if target < w {
+                    return i;
+                }
+                target -= w;
+                last = i;
+            }
+        }
+        // Rounding may leave a remainder, in which case the last non-zero weight is chosen.
+        last
+    }
+}
@@ -0,0 +1,236 @@
+//! Distributions of points on and within shapes.
+//!
+//! Each distribution produces `Point2` or `Point3` values and may be sampled via any `Rng`, e.g.
+//! `rng.sample(InCircle::new(pt2(0.0, 0.0), 100.0))`.
+
+use super::distributions::Distribution;
+use super::Rng;
+use crate::geom::{Point2, Point3, Rect};
+use crate::math::num_traits::Float;
+use core::f32::consts::TAU;
+use core::ops::{Add, Mul};
+#[cfg(feature = "alloc")]
+use {
+    crate::geom::polygon::{triangulate, SelfIntersection},
+    alloc::vec::Vec,
+};
+
+/// Points distributed uniformly within a rectangle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InRect {
+    rect: Rect,
+}
+
+/// Points distributed uniformly within a circle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InCircle {
+    center: Point2,
+    radius: f32,
+}
+
+/// Points distributed uniformly along the circumference of a circle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OnCircle {
+    center: Point2,
+    radius: f32,
+}
+
+/// Points distributed uniformly within a sphere.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InSphere {
+    center: Point3,
+    radius: f32,
+}
+
+/// Points distributed uniformly across the surface of a sphere.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OnSphere {
+    center: Point3,
+    radius: f32,
+}
+
+/// Points distributed uniformly within a triangle in either 2D or 3D.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InTriangle<P> {
+    vertices: [P; 3],
+}
+
+/// Points distributed uniformly within a polygon, which may be concave and contain holes.
+///
+/// The polygon is triangulated upon construction, after which each sample selects a triangle
+/// with a probability proportional to its area.
+///
+/// **Panics** when sampled if the polygon is empty, e.g. if all of its points are collinear.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InPolygon {
+    triangles: Vec<[Point2; 3]>,
+    // The sum of the areas of each triangle and all those before it.
+    areas: Vec<f32>,
+}
+
+impl InRect {
+    /// Points within the given rectangle.
+    pub fn new(rect: Rect) -> Self {
+        InRect { rect }
+    }
+}
+
+impl InCircle {
+    /// Points within the circle with the given center and radius.
+    pub fn new(center: Point2, radius: f32) -> Self {
+        InCircle { center, radius }
+    }
+}
+
+impl OnCircle {
+    /// Points along the circle with the given center and radius.
+    pub fn new(center: Point2, radius: f32) -> Self {
+        OnCircle { center, radius }
+    }
+}
+
+impl InSphere {
+    /// Points within the sphere with the given center and radius.
+    pub fn new(center: Point3, radius: f32) -> Self {
+        InSphere { center, radius }
+    }
+}
+
+impl OnSphere {
+    /// Points across the surface of the sphere with the given center and radius.
+    pub fn new(center: Point3, radius: f32) -> Self {
+        OnSphere { center, radius }
+    }
+}
+
+impl<P> InTriangle<P> {
+    /// Points within the triangle with the given vertices.
+    pub fn new(a: P, b: P, c: P) -> Self {
+        InTriangle {
+            vertices: [a, b, c],
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl InPolygon {
+    /// Points within the polygon described by the given contours.
+    ///
+    /// The first contour describes the outline of the polygon and each of the following contours
+    /// describes a hole within it, as with `geom::polygon::triangulate`.
+    ///
+    /// Returns an error if any of the edges of the contours cross one another.
+    pub fn new<C>(contours: &[C]) -> Result<Self, SelfIntersection>
+    where
+        C: AsRef<[Point2]>,
+    {
+        let points: Vec<Point2> = contours
+            .iter()
+            .flat_map(|c| c.as_ref().iter().cloned())
+            .collect();
+        let mut triangles = Vec::new();
+        let mut areas = Vec::new();
+        let mut total = 0.0;
+        for [a, b, c] in triangulate(contours)? {
+            let tri = [points[a], points[b], points[c]];
+            let area = Float::abs((tri[1] - tri[0]).perp_dot(tri[2] - tri[0])) * 0.5;
+            if area > 0.0 {
+                total += area;
+                triangles.push(tri);
+                areas.push(total);
+            }
+        }
+        Ok(InPolygon { triangles, areas })
+    }
+
+    /// The area of the polygon.
+    pub fn area(&self) -> f32 {
+        self.areas.last().cloned().unwrap_or(0.0)
+    }
+
+    /// Whether or not the polygon has no area within which points may be produced.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+}
+
+impl Distribution<Point2> for InRect {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2 {
+        let x = self.rect.left() + rng.gen::<f32>() * self.rect.w();
+        let y = self.rect.bottom() + rng.gen::<f32>() * self.rect.h();
+        Point2::new(x, y)
+    }
+}
+
+impl Distribution<Point2> for InCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2 {
+        // The square root compensates for the larger circumference further from the center.
+        let radius = Float::sqrt(rng.gen::<f32>()) * self.radius;
+        self.center + unit_circle(rng) * radius
+    }
+}
+
+impl Distribution<Point2> for OnCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2 {
+        self.center + unit_circle(rng) * self.radius
+    }
+}
+
+impl Distribution<Point3> for InSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3 {
+        // The cube root compensates for the larger surface area further from the center.
+        let radius = Float::cbrt(rng.gen::<f32>()) * self.radius;
+        self.center + unit_sphere(rng) * radius
+    }
+}
+
+impl Distribution<Point3> for OnSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point3 {
+        self.center + unit_sphere(rng) * self.radius
+    }
+}
+
+impl<P> Distribution<P> for InTriangle<P>
+where
+    P: Copy + Add<Output = P> + Mul<f32, Output = P>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> P {
+        let [a, b, c] = self.vertices;
+        let s = Float::sqrt(rng.gen::<f32>());
+        let t = rng.gen::<f32>();
+        a * (1.0 - s) + b * (s * (1.0 - t)) + c * (s * t)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<Point2> for InPolygon {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point2 {
+        assert!(
+            !self.is_empty(),
+            "cannot sample points within an empty polygon"
+        );
+        let target = rng.gen::<f32>() * self.area();
+        let i = self
+            .areas
+            .partition_point(|&area| area <= target)
+            .min(self.triangles.len() - 1);
+        let [a, b, c] = self.triangles[i];
+        InTriangle::new(a, b, c).sample(rng)
+    }
+}
+
+// A point on the unit circle at a random angle.
+fn unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Point2 {
+    let (sin, cos) = Float::sin_cos(rng.gen::<f32>() * TAU);
+    Point2::new(cos, sin)
+}
+
+// A point on the unit sphere in a random direction.
+fn unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Point3 {
+    // Points distributed uniformly along the z axis are distributed uniformly across the sphere.
+    let z = rng.gen::<f32>() * 2.0 - 1.0;
+    let r = Float::sqrt((1.0 - z * z).max(0.0));
+    let (sin, cos) = Float::sin_cos(rng.gen::<f32>() * TAU);
+    Point3::new(r * cos, r * sin, z)
+}